@@ -0,0 +1,323 @@
+use crate::geo_traits::{
+    LineStringTrait, MultiLineStringTrait, MultiPointTrait, MultiPolygonTrait, PointTrait,
+    PolygonTrait,
+};
+use crate::{LineString, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon};
+use geo::coord;
+
+/// Applies `f` to every coordinate of `geom`, same signature as georust's own `map_coords`:
+/// `f` is taken by value as `impl Fn + Copy` rather than `&dyn Fn` so the compiler can inline it
+/// into the per-coordinate loop below instead of paying for a dynamic dispatch per point.
+pub fn map_coords_point(geom: &'_ Point, f: impl Fn(f64, f64) -> (f64, f64) + Copy) -> geo::Point {
+    let (x, y) = f(geom.x(), geom.y());
+    geo::Point::new(x, y)
+}
+
+pub fn map_coords_multipoint(
+    geom: &'_ MultiPoint,
+    f: impl Fn(f64, f64) -> (f64, f64) + Copy,
+) -> geo::MultiPoint {
+    let points = (0..geom.num_points())
+        .map(|i| {
+            let point = geom.point(i).unwrap();
+            map_coords_point(&point, f)
+        })
+        .collect();
+    geo::MultiPoint(points)
+}
+
+pub fn map_coords_linestring(
+    geom: &'_ LineString,
+    f: impl Fn(f64, f64) -> (f64, f64) + Copy,
+) -> geo::LineString {
+    let coords = (0..geom.num_points())
+        .map(|i| {
+            let point = geom.point(i).unwrap();
+            let (x, y) = f(point.x(), point.y());
+            coord! { x: x, y: y }
+        })
+        .collect();
+    geo::LineString::new(coords)
+}
+
+pub fn map_coords_multilinestring(
+    geom: &'_ MultiLineString,
+    f: impl Fn(f64, f64) -> (f64, f64) + Copy,
+) -> geo::MultiLineString {
+    let lines = (0..geom.num_lines())
+        .map(|i| {
+            let line = geom.line(i).unwrap();
+            map_coords_linestring(&line, f)
+        })
+        .collect();
+    geo::MultiLineString(lines)
+}
+
+pub fn map_coords_polygon(
+    geom: &'_ Polygon,
+    f: impl Fn(f64, f64) -> (f64, f64) + Copy,
+) -> geo::Polygon {
+    let exterior = map_coords_linestring(&geom.exterior(), f);
+    let interiors = (0..geom.num_interiors())
+        .map(|i| {
+            let interior = geom.interior(i).unwrap();
+            map_coords_linestring(&interior, f)
+        })
+        .collect();
+    geo::Polygon::new(exterior, interiors)
+}
+
+pub fn map_coords_multipolygon(
+    geom: &'_ MultiPolygon,
+    f: impl Fn(f64, f64) -> (f64, f64) + Copy,
+) -> geo::MultiPolygon {
+    let polygons = (0..geom.num_polygons())
+        .map(|i| {
+            let polygon = geom.polygon(i).unwrap();
+            map_coords_polygon(&polygon, f)
+        })
+        .collect();
+    geo::MultiPolygon(polygons)
+}
+
+/// `x' = coeffs[0] * x + coeffs[1] * y + coeffs[2]`, `y' = coeffs[3] * x + coeffs[4] * y +
+/// coeffs[5]`, in the same `[a, b, xoff, d, e, yoff]` order as `geo::AffineTransform`'s matrix.
+///
+/// Each `affine_transform_*` function below is just [`map_coords_point`] (and friends) with this
+/// closure plugged in; `coeffs` is `Copy`, so the closure capturing it by value is `Copy` too.
+fn apply(coeffs: [f64; 6], x: f64, y: f64) -> (f64, f64) {
+    (
+        coeffs[0] * x + coeffs[1] * y + coeffs[2],
+        coeffs[3] * x + coeffs[4] * y + coeffs[5],
+    )
+}
+
+pub fn affine_transform_point(geom: &'_ Point, coeffs: [f64; 6]) -> geo::Point {
+    map_coords_point(geom, move |x, y| apply(coeffs, x, y))
+}
+
+pub fn affine_transform_multipoint(geom: &'_ MultiPoint, coeffs: [f64; 6]) -> geo::MultiPoint {
+    map_coords_multipoint(geom, move |x, y| apply(coeffs, x, y))
+}
+
+pub fn affine_transform_linestring(geom: &'_ LineString, coeffs: [f64; 6]) -> geo::LineString {
+    map_coords_linestring(geom, move |x, y| apply(coeffs, x, y))
+}
+
+pub fn affine_transform_multilinestring(
+    geom: &'_ MultiLineString,
+    coeffs: [f64; 6],
+) -> geo::MultiLineString {
+    map_coords_multilinestring(geom, move |x, y| apply(coeffs, x, y))
+}
+
+pub fn affine_transform_polygon(geom: &'_ Polygon, coeffs: [f64; 6]) -> geo::Polygon {
+    map_coords_polygon(geom, move |x, y| apply(coeffs, x, y))
+}
+
+pub fn affine_transform_multipolygon(
+    geom: &'_ MultiPolygon,
+    coeffs: [f64; 6],
+) -> geo::MultiPolygon {
+    map_coords_multipolygon(geom, move |x, y| apply(coeffs, x, y))
+}
+
+/// A composable 2D affine transform, represented as the six coefficients `[a, b, xoff, d, e,
+/// yoff]` mapping `x' = a*x + b*y + xoff`, `y' = d*x + e*y + yoff`.
+///
+/// Unlike [`affine_transform_point`] and friends, which apply a fixed `[f64; 6]` to `geo` scalar
+/// types one coordinate at a time, this type exists so several transforms (translate, then scale,
+/// then rotate, ...) can be composed into a single matrix *before* any coordinates are touched,
+/// and that one matrix applied directly to an array's coordinate buffer in one pass. Composition
+/// is 3x3 matrix multiplication, treating each transform as
+/// `[[a, b, xoff], [d, e, yoff], [0, 0, 1]]`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AffineTransform {
+    matrix: [f64; 6],
+}
+
+impl Default for AffineTransform {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl AffineTransform {
+    /// The identity transform: every coordinate is left unchanged.
+    pub fn identity() -> Self {
+        Self {
+            matrix: [1., 0., 0., 0., 1., 0.],
+        }
+    }
+
+    /// Builds a transform directly from its six coefficients, in `[a, b, xoff, d, e, yoff]`
+    /// order.
+    pub fn new(matrix: [f64; 6]) -> Self {
+        Self { matrix }
+    }
+
+    /// The six coefficients backing this transform, in `[a, b, xoff, d, e, yoff]` order.
+    pub fn matrix(&self) -> [f64; 6] {
+        self.matrix
+    }
+
+    /// Applies this transform to a single coordinate.
+    #[inline]
+    pub fn apply(&self, x: f64, y: f64) -> (f64, f64) {
+        apply(self.matrix, x, y)
+    }
+
+    /// Composes `self` with `other`, returning the transform equivalent to applying `self`
+    /// first and `other` second.
+    fn compose(&self, other: &Self) -> Self {
+        let [a1, b1, xoff1, d1, e1, yoff1] = self.matrix;
+        let [a2, b2, xoff2, d2, e2, yoff2] = other.matrix;
+
+        Self::new([
+            a2 * a1 + b2 * d1,
+            a2 * b1 + b2 * e1,
+            a2 * xoff1 + b2 * yoff1 + xoff2,
+            d2 * a1 + e2 * d1,
+            d2 * b1 + e2 * e1,
+            d2 * xoff1 + e2 * yoff1 + yoff2,
+        ])
+    }
+
+    /// Composes this transform with a translation applied afterwards.
+    #[must_use]
+    pub fn then_translate(&self, xoff: f64, yoff: f64) -> Self {
+        self.compose(&Self::new([1., 0., xoff, 0., 1., yoff]))
+    }
+
+    /// Composes this transform with a scale applied afterwards, about `origin`.
+    #[must_use]
+    pub fn then_scale(&self, xfact: f64, yfact: f64, origin: (f64, f64)) -> Self {
+        let (x0, y0) = origin;
+        self.compose(&Self::new([
+            xfact,
+            0.,
+            x0 - xfact * x0,
+            0.,
+            yfact,
+            y0 - yfact * y0,
+        ]))
+    }
+
+    /// Composes this transform with a rotation by `degrees` (counterclockwise) applied
+    /// afterwards, about `origin`.
+    #[must_use]
+    pub fn then_rotate(&self, degrees: f64, origin: (f64, f64)) -> Self {
+        let (x0, y0) = origin;
+        let theta = degrees.to_radians();
+        let (sin, cos) = theta.sin_cos();
+        self.compose(&Self::new([
+            cos,
+            -sin,
+            x0 - cos * x0 + sin * y0,
+            sin,
+            cos,
+            y0 - sin * x0 - cos * y0,
+        ]))
+    }
+
+    /// Composes this transform with a skew by `xs`/`ys` degrees applied afterwards, about
+    /// `origin`.
+    #[must_use]
+    pub fn then_skew(&self, xs: f64, ys: f64, origin: (f64, f64)) -> Self {
+        let (x0, y0) = origin;
+        let tx = xs.to_radians().tan();
+        let ty = ys.to_radians().tan();
+        self.compose(&Self::new([
+            1.,
+            tx,
+            -tx * y0,
+            ty,
+            1.,
+            -ty * x0,
+        ]))
+    }
+}
+
+/// Applies an [`AffineTransform`] directly to every coordinate in a [`crate::coord::CoordBuffer`]
+/// in a single pass, preserving whichever physical layout (separated or interleaved) the buffer
+/// was built with. `geom_offsets` and the validity bitmap are untouched by callers of this
+/// function, since the transform only ever rewrites coordinate values in place.
+pub fn affine_transform_coords(
+    coords: &crate::coord::CoordBuffer,
+    transform: &AffineTransform,
+) -> crate::coord::CoordBuffer {
+    use crate::coord::CoordBuffer;
+
+    match coords {
+        CoordBuffer::Separated(x, y) => {
+            let (new_x, new_y): (Vec<f64>, Vec<f64>) = x
+                .iter()
+                .zip(y.iter())
+                .map(|(&x, &y)| transform.apply(x, y))
+                .unzip();
+            CoordBuffer::Separated(new_x.into(), new_y.into())
+        }
+        CoordBuffer::Interleaved(xy) => {
+            let new_xy: Vec<f64> = xy
+                .chunks_exact(2)
+                .flat_map(|pair| {
+                    let (x, y) = transform.apply(pair[0], pair[1]);
+                    [x, y]
+                })
+                .collect();
+            CoordBuffer::Interleaved(new_xy.into())
+        }
+    }
+}
+
+/// Applies an [`AffineTransform`] to every coordinate of an array, leaving `geom_offsets` and
+/// validity untouched.
+///
+/// Because coordinates are stored contiguously regardless of how many geometries they belong to,
+/// this is a single tight loop over the whole coordinate buffer rather than the per-geometry
+/// `geo` round-trip that [`affine_transform_multipoint`] (and friends) perform.
+pub trait AffineOps {
+    /// Applies `transform` to every coordinate in `self`, returning a new array.
+    #[must_use]
+    fn affine_transform(&self, transform: &AffineTransform) -> Self;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MultiPointArray;
+
+    #[test]
+    fn translate_then_rotate_composes_into_one_matrix() {
+        // Translate (1, 0) then rotate 90 degrees about the origin: (0, 0) -> (1, 0) -> (0, 1).
+        let transform = AffineTransform::identity()
+            .then_translate(1., 0.)
+            .then_rotate(90., (0., 0.));
+
+        let (x, y) = transform.apply(0., 0.);
+        assert!((x - 0.).abs() < 1e-10);
+        assert!((y - 1.).abs() < 1e-10);
+    }
+
+    #[test]
+    fn identity_leaves_coordinates_unchanged() {
+        let transform = AffineTransform::identity();
+        assert_eq!(transform.apply(3.5, -2.0), (3.5, -2.0));
+    }
+
+    #[test]
+    fn multipoint_array_affine_transform_applies_to_every_coordinate() {
+        use crate::GeometryArrayTrait;
+
+        let arr: MultiPointArray =
+            vec![geo::MultiPoint::new(vec![geo::Point::new(1., 2.), geo::Point::new(3., 4.)])]
+                .into();
+
+        let transform = AffineTransform::identity().then_translate(10., 100.);
+        let transformed = AffineOps::affine_transform(&arr, &transform);
+
+        let expected =
+            geo::MultiPoint::new(vec![geo::Point::new(11., 102.), geo::Point::new(13., 104.)]);
+        assert_eq!(transformed.value_as_geo(0), expected);
+    }
+}
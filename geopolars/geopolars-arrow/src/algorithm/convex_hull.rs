@@ -0,0 +1,121 @@
+use crate::geo_traits::{
+    LineStringTrait, MultiLineStringTrait, MultiPointTrait, MultiPolygonTrait, PointTrait,
+    PolygonTrait,
+};
+use crate::{LineString, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon};
+use geo::{coord, Coord};
+
+/// `(a - o) x (b - o)`: positive for a counterclockwise turn at `a`, zero when `o`, `a`, `b` are
+/// collinear.
+fn cross(o: Coord, a: Coord, b: Coord) -> f64 {
+    (a.x - o.x) * (b.y - o.y) - (a.y - o.y) * (b.x - o.x)
+}
+
+/// Andrew's monotone chain convex hull.
+///
+/// Sorts `coords` by `(x, y)`, then sweeps once left-to-right to build the lower hull and once
+/// right-to-left to build the upper hull, popping the last hull point whenever it and the next
+/// candidate make a non-counterclockwise turn (`cross(..) <= 0`), which also drops collinear
+/// points. The two chains are concatenated, each dropping its last point since it duplicates the
+/// other chain's first. Fewer than 3 distinct points has no hull to build, so the (deduplicated)
+/// points are returned unchanged.
+fn monotone_chain_hull(coords: &mut Vec<Coord>) -> Vec<Coord> {
+    coords.sort_by(|a, b| (a.x, a.y).partial_cmp(&(b.x, b.y)).unwrap());
+    coords.dedup_by(|a, b| (a.x, a.y) == (b.x, b.y));
+
+    if coords.len() < 3 {
+        return coords.clone();
+    }
+
+    let mut lower: Vec<Coord> = Vec::with_capacity(coords.len());
+    for &p in coords.iter() {
+        while lower.len() >= 2 && cross(lower[lower.len() - 2], lower[lower.len() - 1], p) <= 0.0 {
+            lower.pop();
+        }
+        lower.push(p);
+    }
+
+    let mut upper: Vec<Coord> = Vec::with_capacity(coords.len());
+    for &p in coords.iter().rev() {
+        while upper.len() >= 2 && cross(upper[upper.len() - 2], upper[upper.len() - 1], p) <= 0.0 {
+            upper.pop();
+        }
+        upper.push(p);
+    }
+
+    lower.pop();
+    upper.pop();
+    lower.extend(upper);
+    lower.push(lower[0]);
+    lower
+}
+
+fn hull_of_coords(mut coords: Vec<Coord>) -> geo::Polygon {
+    let ring = monotone_chain_hull(&mut coords);
+    geo::Polygon::new(geo::LineString::new(ring), vec![])
+}
+
+pub fn convex_hull_point(geom: &'_ Point) -> geo::Polygon {
+    hull_of_coords(vec![coord! { x: geom.x(), y: geom.y() }])
+}
+
+pub fn convex_hull_multipoint(geom: &'_ MultiPoint) -> geo::Polygon {
+    let coords = (0..geom.num_points())
+        .map(|i| {
+            let point = geom.point(i).unwrap();
+            coord! { x: point.x(), y: point.y() }
+        })
+        .collect();
+    hull_of_coords(coords)
+}
+
+pub fn convex_hull_linestring(geom: &'_ LineString) -> geo::Polygon {
+    let coords = (0..geom.num_points())
+        .map(|i| {
+            let point = geom.point(i).unwrap();
+            coord! { x: point.x(), y: point.y() }
+        })
+        .collect();
+    hull_of_coords(coords)
+}
+
+pub fn convex_hull_multilinestring(geom: &'_ MultiLineString) -> geo::Polygon {
+    let mut coords = Vec::new();
+    for geom_idx in 0..geom.num_lines() {
+        let linestring = geom.line(geom_idx).unwrap();
+        for coord_idx in 0..linestring.num_points() {
+            let point = linestring.point(coord_idx).unwrap();
+            coords.push(coord! { x: point.x(), y: point.y() });
+        }
+    }
+    hull_of_coords(coords)
+}
+
+/// Only the exterior ring contributes: interior rings are, by definition, inside the exterior's
+/// hull already.
+pub fn convex_hull_polygon(geom: &'_ Polygon) -> geo::Polygon {
+    let exterior = geom.exterior();
+    let coords = (0..exterior.num_points())
+        .map(|i| {
+            let point = exterior.point(i).unwrap();
+            coord! { x: point.x(), y: point.y() }
+        })
+        .collect();
+    hull_of_coords(coords)
+}
+
+/// Only each part's exterior ring contributes, for the same reason as [`convex_hull_polygon`].
+pub fn convex_hull_multipolygon(geom: &'_ MultiPolygon) -> geo::Polygon {
+    let mut coords = Vec::new();
+    for geom_idx in 0..geom.num_polygons() {
+        let polygon = geom.polygon(geom_idx).unwrap();
+        let exterior = polygon.exterior();
+        for coord_idx in 0..exterior.num_points() {
+            let point = exterior.point(coord_idx).unwrap();
+            coords.push(coord! { x: point.x(), y: point.y() });
+        }
+    }
+    hull_of_coords(coords)
+}
+
+// TODO: add tests from geo
@@ -0,0 +1,54 @@
+use crate::geo_traits::{
+    LineStringTrait, MultiLineStringTrait, MultiPointTrait, MultiPolygonTrait, PointTrait,
+    PolygonTrait,
+};
+use crate::{LineString, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon};
+
+/// A `Point` is never empty: even a null row is tracked by the array's validity bitmap rather
+/// than by the point itself, and every non-null point has a location.
+pub fn is_empty_point(_geom: &'_ Point) -> bool {
+    false
+}
+
+pub fn is_empty_linestring(geom: &'_ LineString) -> bool {
+    geom.num_points() == 0
+}
+
+/// Mirrors `geo`'s definition for `Polygon`: empty iff the exterior ring has no points,
+/// regardless of interior rings.
+pub fn is_empty_polygon(geom: &'_ Polygon) -> bool {
+    geom.exterior().num_points() == 0
+}
+
+pub fn is_empty_multipoint(geom: &'_ MultiPoint) -> bool {
+    geom.num_points() == 0
+}
+
+pub fn is_empty_multilinestring(geom: &'_ MultiLineString) -> bool {
+    geom.num_lines() == 0
+}
+
+pub fn is_empty_multipolygon(geom: &'_ MultiPolygon) -> bool {
+    geom.num_polygons() == 0
+}
+
+/// Whether `geom`'s first and last points coincide. An empty or single-point linestring is
+/// never considered closed.
+pub fn is_closed_linestring(geom: &'_ LineString) -> bool {
+    let num_points = geom.num_points();
+    if num_points < 2 {
+        return false;
+    }
+
+    let first = geom.point(0).unwrap();
+    let last = geom.point(num_points - 1).unwrap();
+    first.x() == last.x() && first.y() == last.y()
+}
+
+/// Whether `geom` is a valid linear ring per the OGC Simple Features definition: closed, with at
+/// least four points (three distinct vertices plus the repeated closing point).
+pub fn is_ring_linestring(geom: &'_ LineString) -> bool {
+    geom.num_points() >= 4 && is_closed_linestring(geom)
+}
+
+// TODO: add tests from geo
@@ -0,0 +1,9 @@
+use crate::geo_traits::PointTrait;
+
+/// Planar distance between two points, read directly from their backing coordinate buffers via
+/// [`PointTrait`] rather than materializing a `geo::Point` first.
+pub fn euclidean_distance_point_point(p1: impl PointTrait, p2: impl PointTrait) -> f64 {
+    let (x1, y1) = p1.x_y();
+    let (x2, y2) = p2.x_y();
+    ((x1 - x2).powi(2) + (y1 - y2).powi(2)).sqrt()
+}
@@ -0,0 +1,35 @@
+use crate::algorithm::euclidean_distance::euclidean_distance_point_point;
+use crate::geo_traits::{LineStringTrait, MultiLineStringTrait, PolygonTrait};
+use crate::{LineString, MultiLineString, Polygon};
+
+/// Planar length of a line string: the sum of the distances between consecutive points, read
+/// directly from the backing coordinate buffers via [`LineStringTrait`].
+pub fn euclidean_length_line_string(geom: &'_ LineString) -> f64 {
+    let mut length = 0.0;
+
+    for coord_idx in 1..geom.num_points() {
+        let prev = geom.point(coord_idx - 1).unwrap();
+        let cur = geom.point(coord_idx).unwrap();
+        length += euclidean_distance_point_point(prev, cur);
+    }
+
+    length
+}
+
+/// Planar length of a multi line string: the sum of the lengths of its parts.
+pub fn euclidean_length_multilinestring(geom: &'_ MultiLineString) -> f64 {
+    let mut length = 0.0;
+
+    for geom_idx in 0..geom.num_lines() {
+        let linestring = geom.line(geom_idx).unwrap();
+        length += euclidean_length_line_string(&linestring);
+    }
+
+    length
+}
+
+/// Planar length of a polygon's exterior ring, matching `geo`'s convention that a polygon's
+/// "length" is the perimeter of its exterior (ignoring interior rings).
+pub fn euclidean_length_polygon(geom: &'_ Polygon) -> f64 {
+    euclidean_length_line_string(&geom.exterior())
+}
@@ -0,0 +1,199 @@
+use crate::algorithm::bounding_rect::bounding_rect_polygon;
+use crate::geo_traits::{LineStringTrait, PointTrait, PolygonTrait};
+use crate::{LineString, Polygon};
+use geo::{coord, Coord, Point};
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+/// A candidate square cell, covering `[x - h, x + h] x [y - h, y + h]`.
+struct Cell {
+    x: f64,
+    y: f64,
+    h: f64,
+    /// Signed distance from the cell's center to the polygon boundary (positive inside).
+    distance: f64,
+    /// An upper bound on the distance any point within this cell could have to the boundary.
+    potential: f64,
+}
+
+impl Cell {
+    fn new(x: f64, y: f64, h: f64, geom: &'_ Polygon) -> Self {
+        let distance = signed_distance(geom, x, y);
+        Cell {
+            x,
+            y,
+            h,
+            distance,
+            potential: distance + h * std::f64::consts::SQRT_2,
+        }
+    }
+}
+
+impl PartialEq for Cell {
+    fn eq(&self, other: &Self) -> bool {
+        self.potential == other.potential
+    }
+}
+impl Eq for Cell {}
+impl PartialOrd for Cell {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for Cell {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.potential.partial_cmp(&other.potential).unwrap()
+    }
+}
+
+/// The pole of inaccessibility of `geom` (the interior point farthest from the boundary), found
+/// via the polylabel grid-refinement algorithm to within `precision`.
+///
+/// Covers `geom`'s bbox (via [`bounding_rect_polygon`]) with square cells of half-size `h`, tracking
+/// each cell's center, half-size, signed boundary distance and an upper bound
+/// `potential = distance + h * sqrt(2)` on what any point inside it could achieve. Cells are
+/// popped off a max-heap keyed by `potential`; the best cell seen so far is kept, and a popped
+/// cell is split into four quarter-size sub-cells whenever it could still beat `best` by more
+/// than `precision`. The search starts from the cell centered on the polygon's centroid (or the
+/// bbox center, whichever is farther inside), which is already a reasonable approximation. A
+/// polygon whose bbox has zero width or height returns the bbox's min corner.
+pub fn label_point(geom: &'_ Polygon, precision: f64) -> Point {
+    let (min, max) = bounding_rect_polygon(geom);
+    let width = max[0] - min[0];
+    let height = max[1] - min[1];
+    let cell_size = width.min(height);
+
+    if cell_size == 0.0 {
+        return Point::new(min[0], min[1]);
+    }
+
+    let mut h = cell_size / 2.0;
+    let mut heap = BinaryHeap::new();
+
+    let mut x = min[0];
+    while x < max[0] {
+        let mut y = min[1];
+        while y < max[1] {
+            heap.push(Cell::new(x + h, y + h, h, geom));
+            y += cell_size;
+        }
+        x += cell_size;
+    }
+
+    let centroid = polygon_centroid(geom);
+    let mut best = Cell::new(centroid.x, centroid.y, 0.0, geom);
+
+    let bbox_center = Cell::new(min[0] + width / 2.0, min[1] + height / 2.0, 0.0, geom);
+    if bbox_center.distance > best.distance {
+        best = bbox_center;
+    }
+
+    while let Some(cell) = heap.pop() {
+        if cell.distance > best.distance {
+            best = Cell::new(cell.x, cell.y, cell.h, geom);
+        }
+
+        if cell.potential - best.distance <= precision {
+            continue;
+        }
+
+        h = cell.h / 2.0;
+        heap.push(Cell::new(cell.x - h, cell.y - h, h, geom));
+        heap.push(Cell::new(cell.x + h, cell.y - h, h, geom));
+        heap.push(Cell::new(cell.x - h, cell.y + h, h, geom));
+        heap.push(Cell::new(cell.x + h, cell.y + h, h, geom));
+    }
+
+    Point::new(best.x, best.y)
+}
+
+/// The arithmetic mean of the exterior ring's vertices, used only to seed the search.
+fn polygon_centroid(geom: &'_ Polygon) -> Coord {
+    let exterior = geom.exterior();
+    let n = exterior.num_points().max(1) as f64;
+
+    let mut sum = coord! { x: 0.0, y: 0.0 };
+    for i in 0..exterior.num_points() {
+        let point = exterior.point(i).unwrap();
+        sum.x += point.x();
+        sum.y += point.y();
+    }
+
+    coord! { x: sum.x / n, y: sum.y / n }
+}
+
+/// The distance from `(x, y)` to `geom`'s boundary: positive when `(x, y)` is inside the
+/// polygon (accounting for interior rings), negative otherwise.
+///
+/// Walks the exterior ring and every interior ring directly off `geom` (mirroring how
+/// [`bounding_rect_polygon`] walks `geom.num_interiors()`), accumulating both an even-odd
+/// ray-casting crossing count and the minimum point-to-segment distance in one pass per ring.
+fn signed_distance(geom: &'_ Polygon, x: f64, y: f64) -> f64 {
+    let point = coord! { x: x, y: y };
+
+    let mut inside = false;
+    let mut min_dist = f64::INFINITY;
+
+    let exterior_ring = geom.exterior();
+    let (crossing, dist) = ring_crossing_and_distance(&exterior_ring, point);
+    inside ^= crossing;
+    min_dist = min_dist.min(dist);
+
+    for interior_idx in 0..geom.num_interiors() {
+        let interior_ring = geom.interior(interior_idx).unwrap();
+        let (crossing, dist) = ring_crossing_and_distance(&interior_ring, point);
+        inside ^= crossing;
+        min_dist = min_dist.min(dist);
+    }
+
+    if inside {
+        min_dist
+    } else {
+        -min_dist
+    }
+}
+
+/// For a single ring: whether a ray cast from `point` to `+x infinity` crosses it an odd number
+/// of times (even-odd point-in-ring test), paired with the minimum distance from `point` to any
+/// of its edge segments.
+fn ring_crossing_and_distance(ring: &'_ LineString, point: Coord) -> (bool, f64) {
+    let n = ring.num_points();
+    let mut crossing = false;
+    let mut min_dist = f64::INFINITY;
+
+    let mut j = n - 1;
+    for i in 0..n {
+        let a = ring.point(i).unwrap();
+        let b = ring.point(j).unwrap();
+        let (ax, ay) = a.x_y();
+        let (bx, by) = b.x_y();
+
+        if (ay > point.y) != (by > point.y)
+            && point.x < (bx - ax) * (point.y - ay) / (by - ay) + ax
+        {
+            crossing = !crossing;
+        }
+
+        min_dist = min_dist.min(point_to_segment_distance(
+            point,
+            coord! { x: ax, y: ay },
+            coord! { x: bx, y: by },
+        ));
+
+        j = i;
+    }
+
+    (crossing, min_dist)
+}
+
+fn point_to_segment_distance(p: Coord, a: Coord, b: Coord) -> f64 {
+    let (dx, dy) = (b.x - a.x, b.y - a.y);
+
+    if dx == 0.0 && dy == 0.0 {
+        return ((p.x - a.x).powi(2) + (p.y - a.y).powi(2)).sqrt();
+    }
+
+    let t = (((p.x - a.x) * dx + (p.y - a.y) * dy) / (dx * dx + dy * dy)).clamp(0.0, 1.0);
+    let (cx, cy) = (a.x + t * dx, a.y + t * dy);
+    ((p.x - cx).powi(2) + (p.y - cy).powi(2)).sqrt()
+}
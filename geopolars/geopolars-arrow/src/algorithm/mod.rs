@@ -0,0 +1,11 @@
+//! Free functions implementing geometric algorithms over this crate's `geo` scalar types and
+//! raw coordinate buffers, kept separate from the array/scalar types themselves.
+
+pub mod affine;
+pub mod bounding_rect;
+pub mod convex_hull;
+pub mod dimensions;
+pub mod euclidean_distance;
+pub mod euclidean_length;
+pub mod label_point;
+pub mod triangulate;
@@ -0,0 +1,296 @@
+//! Ear-clipping triangulation of ring-encoded polygons (`PolygonArray`/`MultiLineStringArray`'s
+//! shared `geom_offsets`/`ring_offsets` layout), producing a flat triangle mesh for rendering or
+//! area-weighted sampling.
+
+use crate::coord::CoordBuffer;
+use arrow2::buffer::Buffer;
+use arrow2::offset::{Offsets, OffsetsBuffer};
+
+/// A triangle mesh produced by [`tessellate`].
+#[derive(Debug, Clone)]
+pub struct Tessellation {
+    /// Flattened `[x0, y0, x1, y1, ...]` vertex coordinates. Holes are bridged into their
+    /// exterior ring, so this includes a handful of duplicated bridge vertices per hole.
+    pub vertices: Buffer<f64>,
+    /// Triangle vertex indices into [`Self::vertices`] (as coordinate, not buffer, indices),
+    /// grouped in threes: `indices[[3k, 3k + 1, 3k + 2]]` is the `k`th triangle.
+    pub indices: Buffer<i64>,
+    /// Maps each input geometry to the span of [`Self::indices`] it contributed: geometry `i`'s
+    /// triangles are `indices[triangle_offsets[i]..triangle_offsets[i + 1]]`.
+    pub triangle_offsets: OffsetsBuffer<i64>,
+}
+
+/// Tessellates a set of polygons sharing one [`CoordBuffer`], where each geometry's rings
+/// (first the exterior, then any holes) are given by `geom_offsets` indexing into
+/// `ring_offsets`, which in turn indexes into `coords`. This is exactly the layout
+/// `PolygonArray` and `MultiLineStringArray` already use internally.
+pub fn tessellate(
+    coords: &CoordBuffer,
+    geom_offsets: &OffsetsBuffer<i64>,
+    ring_offsets: &OffsetsBuffer<i64>,
+) -> Tessellation {
+    let mut vertices: Vec<f64> = Vec::new();
+    let mut indices: Vec<i64> = Vec::new();
+    let mut triangle_offsets = Offsets::<i64>::with_capacity(geom_offsets.len());
+
+    for geom_idx in 0..geom_offsets.len() {
+        let (ring_start, ring_end) = geom_offsets.start_end(geom_idx);
+        let indices_start = indices.len();
+
+        if ring_start < ring_end {
+            let vertex_base = (vertices.len() / 2) as i64;
+
+            let rings: Vec<Vec<[f64; 2]>> = (ring_start..ring_end)
+                .map(|ring_idx| {
+                    let (coord_start, coord_end) = ring_offsets.start_end(ring_idx);
+                    (coord_start..coord_end)
+                        .map(|i| {
+                            let (x, y) = coords.value(i);
+                            [x, y]
+                        })
+                        .collect()
+                })
+                .collect();
+
+            let (ring_vertices, triangles) = earcut_with_holes(rings);
+            for vertex in &ring_vertices {
+                vertices.push(vertex[0]);
+                vertices.push(vertex[1]);
+            }
+            indices.extend(triangles.into_iter().map(|i| vertex_base + i as i64));
+        }
+
+        triangle_offsets.try_push_usize(indices.len() - indices_start).unwrap();
+    }
+
+    Tessellation {
+        vertices: vertices.into(),
+        indices: indices.into(),
+        triangle_offsets: triangle_offsets.into(),
+    }
+}
+
+/// Bridges any holes into the exterior ring, then ear-clips the resulting simple polygon.
+/// Returns the (possibly bridge-duplicated) vertex list alongside triangle indices into it.
+fn earcut_with_holes(rings: Vec<Vec<[f64; 2]>>) -> (Vec<[f64; 2]>, Vec<usize>) {
+    if rings.is_empty() {
+        return (Vec::new(), Vec::new());
+    }
+
+    let merged = bridge_holes(rings);
+    let triangles = earcut(&merged);
+    (merged, triangles)
+}
+
+/// Splices each hole into the exterior ring via a bridge edge to a mutually visible vertex,
+/// turning a polygon-with-holes into a single simple ring that ear clipping can consume
+/// directly. Rings are normalized to opposite windings first (exterior CCW, holes CW) so the
+/// bridged ring keeps one consistent winding throughout.
+fn bridge_holes(mut rings: Vec<Vec<[f64; 2]>>) -> Vec<[f64; 2]> {
+    let mut holes: Vec<Vec<[f64; 2]>> = rings.split_off(1);
+    let mut outer = rings.remove(0);
+    if signed_area(&outer) < 0.0 {
+        outer.reverse();
+    }
+
+    // Bridge the rightmost hole first; this matches earcut.js's heuristic and keeps bridges
+    // from crossing each other for the common case of non-overlapping holes.
+    holes.sort_by(|a, b| ring_max_x(b).partial_cmp(&ring_max_x(a)).unwrap());
+
+    for mut hole in holes {
+        if hole.len() < 3 {
+            continue;
+        }
+        if signed_area(&hole) > 0.0 {
+            hole.reverse();
+        }
+
+        let hole_idx = hole
+            .iter()
+            .enumerate()
+            .max_by(|(_, a), (_, b)| a[0].partial_cmp(&b[0]).unwrap())
+            .map(|(i, _)| i)
+            .unwrap();
+        let outer_idx = nearest_bridge_vertex(&outer, hole[hole_idx]);
+
+        let mut bridged = Vec::with_capacity(outer.len() + hole.len() + 2);
+        bridged.extend_from_slice(&outer[..=outer_idx]);
+        bridged.extend_from_slice(&hole[hole_idx..]);
+        bridged.extend_from_slice(&hole[..=hole_idx]);
+        bridged.push(outer[outer_idx]);
+        bridged.extend_from_slice(&outer[outer_idx + 1..]);
+        outer = bridged;
+    }
+
+    outer
+}
+
+fn ring_max_x(ring: &[[f64; 2]]) -> f64 {
+    ring.iter().fold(f64::NEG_INFINITY, |m, c| m.max(c[0]))
+}
+
+/// Finds the vertex of `ring` to bridge `point` (a hole's rightmost vertex) to: cast a ray from
+/// `point` in the `-x` direction, take the nearest edge it crosses, and bridge to whichever
+/// endpoint of that edge is farther in `+x` (closer to `point`).
+fn nearest_bridge_vertex(ring: &[[f64; 2]], point: [f64; 2]) -> usize {
+    let n = ring.len();
+    let mut best_idx = 0;
+    let mut best_x = f64::NEG_INFINITY;
+
+    for i in 0..n {
+        let a = ring[i];
+        let b = ring[(i + 1) % n];
+        if (a[1] > point[1]) == (b[1] > point[1]) {
+            continue;
+        }
+
+        let x = a[0] + (point[1] - a[1]) / (b[1] - a[1]) * (b[0] - a[0]);
+        if x <= point[0] && x > best_x {
+            best_x = x;
+            best_idx = if a[0] > b[0] { i } else { (i + 1) % n };
+        }
+    }
+
+    best_idx
+}
+
+/// Ear-clipping triangulation of a simple, consistently-wound polygon ring. Returns triangle
+/// vertex indices into `ring`.
+fn earcut(ring: &[[f64; 2]]) -> Vec<usize> {
+    let mut remaining: Vec<usize> = (0..ring.len()).collect();
+    let mut triangles = Vec::new();
+
+    while remaining.len() > 3 {
+        let n = remaining.len();
+        let mut clipped = false;
+
+        for i in 0..n {
+            let prev = remaining[(i + n - 1) % n];
+            let curr = remaining[i];
+            let next = remaining[(i + 1) % n];
+
+            if cross(ring[prev], ring[curr], ring[next]) <= 0.0 {
+                continue;
+            }
+
+            // Vertices that coincide with a corner (the duplicates `bridge_holes` introduces)
+            // don't block the ear.
+            let is_ear = !remaining.iter().any(|&idx| {
+                idx != prev
+                    && idx != curr
+                    && idx != next
+                    && ring[idx] != ring[prev]
+                    && ring[idx] != ring[curr]
+                    && ring[idx] != ring[next]
+                    && point_in_triangle(ring[idx], ring[prev], ring[curr], ring[next])
+            });
+
+            if !is_ear {
+                continue;
+            }
+
+            triangles.push(prev);
+            triangles.push(curr);
+            triangles.push(next);
+            remaining.remove(i);
+            clipped = true;
+            break;
+        }
+
+        if !clipped {
+            // Degenerate or self-intersecting input: stop rather than loop forever.
+            break;
+        }
+    }
+
+    if remaining.len() == 3 {
+        triangles.push(remaining[0]);
+        triangles.push(remaining[1]);
+        triangles.push(remaining[2]);
+    }
+
+    triangles
+}
+
+fn signed_area(ring: &[[f64; 2]]) -> f64 {
+    let n = ring.len();
+    let mut area = 0.0;
+    for i in 0..n {
+        let [x1, y1] = ring[i];
+        let [x2, y2] = ring[(i + 1) % n];
+        area += x1 * y2 - x2 * y1;
+    }
+    area * 0.5
+}
+
+fn cross(a: [f64; 2], b: [f64; 2], c: [f64; 2]) -> f64 {
+    (b[0] - a[0]) * (c[1] - a[1]) - (b[1] - a[1]) * (c[0] - a[0])
+}
+
+fn point_in_triangle(p: [f64; 2], a: [f64; 2], b: [f64; 2], c: [f64; 2]) -> bool {
+    let d1 = cross(a, b, p);
+    let d2 = cross(b, c, p);
+    let d3 = cross(c, a, p);
+
+    let has_neg = d1 < 0.0 || d2 < 0.0 || d3 < 0.0;
+    let has_pos = d1 > 0.0 || d2 > 0.0 || d3 > 0.0;
+    !(has_neg && has_pos)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tessellates_a_square() {
+        let x: Buffer<f64> = vec![0.0, 4.0, 4.0, 0.0].into();
+        let y: Buffer<f64> = vec![0.0, 0.0, 4.0, 4.0].into();
+        let coords = CoordBuffer::Separated(x, y);
+        let geom_offsets = OffsetsBuffer::try_from(vec![0i64, 1]).unwrap();
+        let ring_offsets = OffsetsBuffer::try_from(vec![0i64, 4]).unwrap();
+
+        let mesh = tessellate(&coords, &geom_offsets, &ring_offsets);
+
+        assert_eq!(mesh.triangle_offsets.as_slice(), &[0, 6]);
+        assert_eq!(mesh.indices.len(), 6);
+        assert_eq!(mesh.vertices.len(), 8);
+
+        let area: f64 = mesh
+            .indices
+            .chunks_exact(3)
+            .map(|tri| {
+                let p = |i: i64| {
+                    let base = (i as usize) * 2;
+                    [mesh.vertices[base], mesh.vertices[base + 1]]
+                };
+                cross(p(tri[0]), p(tri[1]), p(tri[2])).abs() / 2.0
+            })
+            .sum();
+        assert_eq!(area, 16.0);
+    }
+
+    #[test]
+    fn tessellates_a_square_with_a_hole() {
+        // A 10x10 square with a 2x2 hole cut out of its center.
+        let x: Buffer<f64> = vec![0.0, 10.0, 10.0, 0.0, 4.0, 6.0, 6.0, 4.0].into();
+        let y: Buffer<f64> = vec![0.0, 0.0, 10.0, 10.0, 4.0, 4.0, 6.0, 6.0].into();
+        let coords = CoordBuffer::Separated(x, y);
+        let geom_offsets = OffsetsBuffer::try_from(vec![0i64, 2]).unwrap();
+        let ring_offsets = OffsetsBuffer::try_from(vec![0i64, 4, 8]).unwrap();
+
+        let mesh = tessellate(&coords, &geom_offsets, &ring_offsets);
+
+        let area: f64 = mesh
+            .indices
+            .chunks_exact(3)
+            .map(|tri| {
+                let p = |i: i64| {
+                    let base = (i as usize) * 2;
+                    [mesh.vertices[base], mesh.vertices[base + 1]]
+                };
+                cross(p(tri[0]), p(tri[1]), p(tri[2])).abs() / 2.0
+            })
+            .sum();
+        assert_eq!(area, 96.0);
+    }
+}
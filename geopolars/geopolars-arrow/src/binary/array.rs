@@ -1,8 +1,13 @@
+use crate::enum_::GeometryType;
 use crate::error::GeoArrowError;
+use crate::offset::widen_offsets;
+use crate::trait_::GeometryArray;
 use crate::{GeometryArrayTrait, MutableWKBArray, WKB};
 use arrow2::array::{Array, BinaryArray};
 use arrow2::bitmap::utils::{BitmapIter, ZipValidity};
 use arrow2::bitmap::Bitmap;
+use arrow2::datatypes::{DataType, Field, Metadata};
+use geozero::{GeomProcessor, GeozeroGeometry};
 use rstar::RTree;
 
 /// A [`GeometryArray`] semantically equivalent to `Vec<Option<Geometry>>` using Arrow's
@@ -25,6 +30,21 @@ impl WKBArray {
     pub fn with_validity(&self, validity: Option<Bitmap>) -> Self {
         WKBArray::new(self.0.clone().with_validity(validity))
     }
+
+    /// Builds the Arrow [`Field`] describing this array as a GeoArrow extension column.
+    ///
+    /// See [`crate::MultiLineStringArray::extension_field`] for why this doesn't flow through
+    /// [`GeometryArrayTrait::into_arrow`]: polars doesn't yet carry extension-type metadata
+    /// through a `Series`, so this is for callers that write Arrow/Parquet schemas, or hand a
+    /// column across the C Data Interface via [`crate::ffi`], directly.
+    pub fn extension_field(&self, name: &str) -> Field {
+        let mut metadata = Metadata::new();
+        metadata.insert(
+            "ARROW:extension:name".to_string(),
+            "geoarrow.wkb".to_string(),
+        );
+        Field::new(name, self.0.data_type().clone(), true).with_metadata(metadata)
+    }
 }
 
 impl<'a> GeometryArrayTrait<'a> for WKBArray {
@@ -142,6 +162,49 @@ impl WKBArray {
     ) -> ZipValidity<geos::Geometry, impl Iterator<Item = geos::Geometry> + '_, BitmapIter> {
         ZipValidity::new_with_validity(self.iter_geos_values(), self.validity())
     }
+
+    /// Re-encodes every geometry as Extended WKB (the PostGIS variant of WKB), stamping `srid`
+    /// into each row's header. The counterpart to
+    /// [`MutableWKBArray::from_ewkb`](crate::MutableWKBArray::from_ewkb), which strips the SRID
+    /// back out on the way in.
+    pub fn to_ewkb(&self, srid: i32) -> Vec<Option<Vec<u8>>> {
+        use geozero::{CoordDimensions, ToWkb};
+
+        self.iter_geo()
+            .map(|maybe_g| maybe_g.map(|g| g.to_ewkb(CoordDimensions::xy(), Some(srid)).unwrap()))
+            .collect()
+    }
+
+    /// Converts to a [`WKBViewArray`](crate::WKBViewArray), re-copying every WKB blob into the
+    /// `BinaryView` layout's inline-or-buffer representation.
+    ///
+    /// Worth doing once up front for a column that will be sliced or queried repeatedly
+    /// afterwards (e.g. before building an [`rstar_tree`](crate::GeometryArrayTrait::rstar_tree)
+    /// or feeding [`GeoDataFrame::centroid`](../../geopolars/trait.GeoDataFrame.html)-style row
+    /// scans), since `BinaryView` slicing is then `O(1)`. Repeated geometries are not
+    /// deduplicated: each row's bytes are stored separately.
+    #[cfg(feature = "binary_view")]
+    pub fn to_binview(&self) -> crate::WKBViewArray {
+        let mut values = crate::MutableWKBViewArray::with_capacity(self.len());
+        for i in 0..self.len() {
+            values.push_wkb(self.is_valid(i).then(|| self.0.value(i)));
+        }
+        values.into()
+    }
+
+    /// Converts a [`WKBViewArray`](crate::WKBViewArray) back to the classic offsets-plus-values
+    /// layout, re-copying every view's bytes into one contiguous values buffer.
+    #[cfg(feature = "binary_view")]
+    pub fn from_binview(array: &crate::WKBViewArray) -> Self {
+        use crate::GeometryArrayTrait;
+        use arrow2::array::MutableBinaryArray;
+
+        let mut values = MutableBinaryArray::<i64>::with_capacity(array.len());
+        for i in 0..array.len() {
+            values.push(array.get(i).map(|view| view.arr.value(view.geom_index)));
+        }
+        WKBArray(values.into())
+    }
 }
 
 impl From<BinaryArray<i64>> for WKBArray {
@@ -153,9 +216,30 @@ impl From<BinaryArray<i64>> for WKBArray {
 impl TryFrom<Box<dyn Array>> for WKBArray {
     type Error = GeoArrowError;
 
+    /// Accepts either a `BinaryArray<i64>` (the layout `WKBArray` itself stores) or a
+    /// `BinaryArray<i32>`, widening the latter's offsets up front via [`widen_offsets`] so
+    /// 32-bit-offset producers (plain Arrow `Binary`, as opposed to `LargeBinary`, and many
+    /// Parquet/GeoParquet readers) are accepted transparently instead of panicking on a downcast
+    /// that silently assumed `i64`.
     fn try_from(value: Box<dyn Array>) -> Result<Self, Self::Error> {
-        let arr = value.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
-        Ok(arr.clone().into())
+        if let Some(arr) = value.as_any().downcast_ref::<BinaryArray<i64>>() {
+            return Ok(arr.clone().into());
+        }
+
+        if let Some(arr) = value.as_any().downcast_ref::<BinaryArray<i32>>() {
+            let widened = BinaryArray::<i64>::new(
+                DataType::LargeBinary,
+                widen_offsets(arr.offsets()),
+                arr.values().clone(),
+                arr.validity().cloned(),
+            );
+            return Ok(widened.into());
+        }
+
+        Err(GeoArrowError::General(format!(
+            "WKBArray can only be constructed from a BinaryArray<i32> or BinaryArray<i64>, got {:?}",
+            value.data_type()
+        )))
     }
 }
 
@@ -165,3 +249,188 @@ impl From<Vec<Option<geo::Geometry>>> for WKBArray {
         mut_arr.into()
     }
 }
+
+impl TryFrom<WKBArray> for crate::PointArray {
+    type Error = GeoArrowError;
+
+    fn try_from(value: WKBArray) -> Result<Self, Self::Error> {
+        let geoms: Vec<Option<geo::Point>> = value
+            .iter_geo()
+            .map(|geom| match geom {
+                None => Ok(None),
+                Some(geo::Geometry::Point(geom)) => Ok(Some(geom)),
+                Some(_) => Err(GeoArrowError::General(
+                    "WKB geometry is not a Point".to_string(),
+                )),
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(geoms.into())
+    }
+}
+
+impl TryFrom<WKBArray> for crate::LineStringArray {
+    type Error = GeoArrowError;
+
+    fn try_from(value: WKBArray) -> Result<Self, Self::Error> {
+        let geoms: Vec<Option<geo::LineString>> = value
+            .iter_geo()
+            .map(|geom| match geom {
+                None => Ok(None),
+                Some(geo::Geometry::LineString(geom)) => Ok(Some(geom)),
+                Some(_) => Err(GeoArrowError::General(
+                    "WKB geometry is not a LineString".to_string(),
+                )),
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(geoms.into())
+    }
+}
+
+impl TryFrom<WKBArray> for crate::PolygonArray {
+    type Error = GeoArrowError;
+
+    fn try_from(value: WKBArray) -> Result<Self, Self::Error> {
+        let geoms: Vec<Option<geo::Polygon>> = value
+            .iter_geo()
+            .map(|geom| match geom {
+                None => Ok(None),
+                Some(geo::Geometry::Polygon(geom)) => Ok(Some(geom)),
+                Some(_) => Err(GeoArrowError::General(
+                    "WKB geometry is not a Polygon".to_string(),
+                )),
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(geoms.into())
+    }
+}
+
+impl TryFrom<WKBArray> for crate::MultiPointArray {
+    type Error = GeoArrowError;
+
+    fn try_from(value: WKBArray) -> Result<Self, Self::Error> {
+        let geoms: Vec<Option<geo::MultiPoint>> = value
+            .iter_geo()
+            .map(|geom| match geom {
+                None => Ok(None),
+                Some(geo::Geometry::MultiPoint(geom)) => Ok(Some(geom)),
+                Some(_) => Err(GeoArrowError::General(
+                    "WKB geometry is not a MultiPoint".to_string(),
+                )),
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(geoms.into())
+    }
+}
+
+impl TryFrom<WKBArray> for crate::MultiLineStringArray {
+    type Error = GeoArrowError;
+
+    fn try_from(value: WKBArray) -> Result<Self, Self::Error> {
+        let geoms: Vec<Option<geo::MultiLineString>> = value
+            .iter_geo()
+            .map(|geom| match geom {
+                None => Ok(None),
+                Some(geo::Geometry::MultiLineString(geom)) => Ok(Some(geom)),
+                Some(_) => Err(GeoArrowError::General(
+                    "WKB geometry is not a MultiLineString".to_string(),
+                )),
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(geoms.into())
+    }
+}
+
+impl TryFrom<WKBArray> for crate::MultiPolygonArray {
+    type Error = GeoArrowError;
+
+    fn try_from(value: WKBArray) -> Result<Self, Self::Error> {
+        let geoms: Vec<Option<geo::MultiPolygon>> = value
+            .iter_geo()
+            .map(|geom| match geom {
+                None => Ok(None),
+                Some(geo::Geometry::MultiPolygon(geom)) => Ok(Some(geom)),
+                Some(_) => Err(GeoArrowError::General(
+                    "WKB geometry is not a MultiPolygon".to_string(),
+                )),
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(geoms.into())
+    }
+}
+
+impl TryFrom<WKBArray> for crate::GeometryCollectionArray {
+    type Error = GeoArrowError;
+
+    fn try_from(value: WKBArray) -> Result<Self, Self::Error> {
+        let geoms: Vec<Option<geo::GeometryCollection>> = value
+            .iter_geo()
+            .map(|geom| match geom {
+                None => Ok(None),
+                Some(geo::Geometry::GeometryCollection(geom)) => Ok(Some(geom)),
+                Some(_) => Err(GeoArrowError::General(
+                    "WKB geometry is not a GeometryCollection".to_string(),
+                )),
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(geoms.into())
+    }
+}
+
+impl GeometryArray for WKBArray {
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn geometry_type(&self) -> GeometryType {
+        GeometryType::WKB
+    }
+
+    fn validity(&self) -> Option<&Bitmap> {
+        self.validity()
+    }
+
+    fn slice(&self, offset: usize, length: usize) -> Box<dyn GeometryArray> {
+        Box::new(self.slice(offset, length))
+    }
+
+    unsafe fn slice_unchecked(&self, offset: usize, length: usize) -> Box<dyn GeometryArray> {
+        Box::new(self.slice_unchecked(offset, length))
+    }
+
+    fn to_boxed(&self) -> Box<dyn GeometryArray> {
+        Box::new(self.clone())
+    }
+}
+
+impl GeozeroGeometry for WKBArray {
+    /// Streams every blob in the array through `processor` as a single geometry collection,
+    /// decoding each one lazily via [`GeometryArrayTrait::value_as_geo`]. Unlike the other
+    /// GeoArrow array types, a `WKBArray` has no fixed geometry shape to walk, so each slot's
+    /// already-parsed `geo::Geometry` drives the processor through its own `GeozeroGeometry` impl.
+    fn process_geom<P: GeomProcessor>(&self, processor: &mut P) -> geozero::error::Result<()>
+    where
+        Self: Sized,
+    {
+        let num_geometries = self.len();
+        processor.geometrycollection_begin(num_geometries, 0)?;
+
+        for idx in 0..num_geometries {
+            self.value_as_geo(idx).process_geom(processor)?;
+        }
+
+        processor.geometrycollection_end(num_geometries)?;
+        Ok(())
+    }
+}
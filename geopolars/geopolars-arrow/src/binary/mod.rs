@@ -3,7 +3,14 @@
 pub use array::WKBArray;
 pub use mutable::MutableWKBArray;
 pub use scalar::WKB;
+pub use sjoin::{predicate_holds, SpatialPredicate};
+#[cfg(feature = "binary_view")]
+pub use view::{MutableWKBViewArray, WKBView, WKBViewArray};
 
 mod array;
+mod iterator;
 mod mutable;
 mod scalar;
+mod sjoin;
+#[cfg(feature = "binary_view")]
+mod view;
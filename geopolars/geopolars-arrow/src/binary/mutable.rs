@@ -1,7 +1,7 @@
 use arrow2::array::{MutableArray, MutableBinaryArray};
 use arrow2::bitmap::MutableBitmap;
 use geo::Geometry;
-use geozero::{CoordDimensions, ToWkb};
+use geozero::{wkb::Ewkb, CoordDimensions, ToGeo, ToWkb};
 
 use crate::enum_::GeometryType;
 use crate::trait_::MutableGeometryArray;
@@ -11,7 +11,14 @@ use super::array::WKBArray;
 /// The Arrow equivalent to `Vec<Option<Geometry>>`.
 /// Converting a [`MutableWKBArray`] into a [`WKBArray`] is `O(1)`.
 #[derive(Debug, Clone)]
-pub struct MutableWKBArray(MutableBinaryArray<i64>);
+pub struct MutableWKBArray {
+    values: MutableBinaryArray<i64>,
+
+    /// The coordinate reference system of these geometries, if known. Populated by
+    /// [`MutableWKBArray::from_ewkb`] from the source EWKB's embedded SRID; `None` for
+    /// geometries built any other way.
+    crs: Option<String>,
+}
 
 impl Default for MutableWKBArray {
     fn default() -> Self {
@@ -36,21 +43,79 @@ impl MutableWKBArray {
     /// # Implementation
     /// This does not allocate the validity.
     pub fn with_capacities(capacity: usize, values: usize) -> Self {
-        Self(MutableBinaryArray::<i64>::with_capacities(capacity, values))
+        Self {
+            values: MutableBinaryArray::<i64>::with_capacities(capacity, values),
+            crs: None,
+        }
+    }
+
+    /// Appends a geometry, encoding it as WKB, or a null if `value` is `None`.
+    pub fn push(&mut self, value: Option<&Geometry>) {
+        let wkb = value.map(|g| g.to_wkb(CoordDimensions::xy()).unwrap());
+        self.values.push(wkb);
+    }
+
+    /// The coordinate reference system of these geometries, if known.
+    pub fn crs(&self) -> Option<&str> {
+        self.crs.as_deref()
+    }
+
+    /// Parses a row of Extended WKB (the PostGIS variant of WKB with an embedded SRID) buffers
+    /// into a [`MutableWKBArray`], re-encoding each geometry as plain WKB and recording the last
+    /// non-null SRID seen as [`Self::crs`].
+    pub fn from_ewkb(bufs: &[Option<Vec<u8>>]) -> geozero::error::Result<Self> {
+        let mut array = Self::with_capacity(bufs.len());
+
+        for buf in bufs {
+            match buf {
+                Some(buf) => {
+                    if let Some(srid) = ewkb_srid(buf) {
+                        array.crs = Some(format!("EPSG:{srid}"));
+                    }
+                    let geom: Geometry = Ewkb(buf.clone()).to_geo()?;
+                    array.push(Some(&geom));
+                }
+                None => array.push(None),
+            }
+        }
+
+        Ok(array)
     }
 }
 
+/// Reads the SRID out of an EWKB buffer's header, without decoding the rest of the geometry.
+///
+/// EWKB extends the leading WKB geometry-type `u32` with high flag bits (`0x2000_0000` for "an
+/// SRID follows"), so the SRID, if present, is the 4 bytes right after that type field.
+fn ewkb_srid(buf: &[u8]) -> Option<i32> {
+    let little_endian = *buf.first()? == 1;
+    let read_u32 = |bytes: &[u8]| -> Option<u32> {
+        let bytes: [u8; 4] = bytes.try_into().ok()?;
+        Some(if little_endian {
+            u32::from_le_bytes(bytes)
+        } else {
+            u32::from_be_bytes(bytes)
+        })
+    };
+
+    let geom_type = read_u32(buf.get(1..5)?)?;
+    if geom_type & 0x2000_0000 == 0 {
+        return None;
+    }
+    read_u32(buf.get(5..9)?).map(|srid| srid as i32)
+}
+
 impl MutableGeometryArray for MutableWKBArray {
     fn geometry_type(&self) -> GeometryType {
         GeometryType::WKB
     }
 
     fn len(&self) -> usize {
-        self.0.values().len()
+        self.values.values().len()
     }
 
     fn validity(&self) -> Option<&MutableBitmap> {
-        self.0.validity()
+        self.values.validity()
     }
 
     // fn as_box(&mut self) -> Box<dyn GeometryArray> {
@@ -81,12 +146,15 @@ impl From<Vec<Option<Geometry>>> for MutableWKBArray {
             wkb_array.push(wkb);
         }
 
-        Self(wkb_array)
+        Self {
+            values: wkb_array,
+            crs: None,
+        }
     }
 }
 
 impl From<MutableWKBArray> for WKBArray {
     fn from(other: MutableWKBArray) -> Self {
-        Self::new(other.0.into())
+        Self::new(other.values.into())
     }
 }
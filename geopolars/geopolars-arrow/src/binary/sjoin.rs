@@ -0,0 +1,127 @@
+use crate::{GeometryArrayTrait, WKBArray, WKB};
+use geo::{Contains, EuclideanDistance, Geometry, Intersects, Within};
+use rstar::{RTree, RTreeObject, AABB};
+
+/// The predicate used to decide whether a candidate pair from [`WKBArray::sjoin`] is a true
+/// match.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SpatialPredicate {
+    /// The left geometry intersects the right geometry.
+    Intersects,
+    /// The left geometry contains the right geometry.
+    Contains,
+    /// The left geometry is within the right geometry.
+    Within,
+    /// The left geometry is within `dist` of the right geometry.
+    DWithin(f64),
+}
+
+/// Decides whether `predicate` holds between two decoded geometries.
+///
+/// This is the one place that logic lives: [`WKBArray::sjoin`] calls it directly, and
+/// [`geopolars-geo`](https://docs.rs/geopolars-geo)'s `GeometryArray`-level spatial join/index
+/// (which candidate-prunes with an R-tree the same way, but over already-decoded geometries
+/// rather than WKB bytes) calls it too, so the two layers can't drift on what a predicate means.
+pub fn predicate_holds(predicate: SpatialPredicate, left: &Geometry, right: &Geometry) -> bool {
+    match predicate {
+        SpatialPredicate::Intersects => left.intersects(right),
+        SpatialPredicate::Contains => left.contains(right),
+        SpatialPredicate::Within => left.is_within(right),
+        SpatialPredicate::DWithin(dist) => left.euclidean_distance(right) <= dist,
+    }
+}
+
+/// A [`WKB`] scalar paired with its row index in the array it was built from, so an [`RTree`]
+/// built over one array's geometries can still report which row each hit came from.
+struct IndexedWKB<'a> {
+    index: u32,
+    wkb: WKB<'a>,
+}
+
+impl RTreeObject for IndexedWKB<'_> {
+    type Envelope = AABB<[f64; 2]>;
+
+    fn envelope(&self) -> Self::Envelope {
+        self.wkb.envelope()
+    }
+}
+
+fn build_index(other: &WKBArray) -> RTree<IndexedWKB> {
+    let nodes: Vec<IndexedWKB> = (0..other.len())
+        .filter_map(|i| other.get(i).map(|wkb| IndexedWKB { index: i as u32, wkb }))
+        .collect();
+    RTree::bulk_load(nodes)
+}
+
+fn envelope_center(envelope: &AABB<[f64; 2]>) -> [f64; 2] {
+    let lower = envelope.lower();
+    let upper = envelope.upper();
+    [(lower[0] + upper[0]) / 2.0, (lower[1] + upper[1]) / 2.0]
+}
+
+impl WKBArray {
+    /// Joins `self` against `other` on `predicate`, returning the matched `(self_index,
+    /// other_index)` pairs as two parallel index vectors, in the same shape a `GeoDataFrame`
+    /// spatial join would hand back to build a result frame from.
+    ///
+    /// Builds an [`RTree`] over `other`'s envelopes, then for each geometry in `self` prunes
+    /// candidates by bounding-box overlap (`locate_in_envelope_intersecting`, or
+    /// `locate_within_distance` around the query envelope's center for
+    /// [`SpatialPredicate::DWithin`]) before confirming the exact predicate on the decoded
+    /// geometries - the index alone can only rule candidates out, not confirm them.
+    pub fn sjoin(&self, other: &WKBArray, predicate: SpatialPredicate) -> (Vec<u32>, Vec<u32>) {
+        let tree = build_index(other);
+
+        let mut left_indices = Vec::new();
+        let mut right_indices = Vec::new();
+
+        for i in 0..self.len() {
+            let Some(left_wkb) = self.get(i) else {
+                continue;
+            };
+            let envelope = left_wkb.envelope();
+            let left_geom: Geometry = (&left_wkb).into();
+
+            let candidates: Box<dyn Iterator<Item = &IndexedWKB>> = match predicate {
+                SpatialPredicate::DWithin(dist) => Box::new(
+                    tree.locate_within_distance(envelope_center(&envelope), dist * dist),
+                ),
+                _ => Box::new(tree.locate_in_envelope_intersecting(&envelope)),
+            };
+
+            for candidate in candidates {
+                let right_geom: Geometry = (&candidate.wkb).into();
+                if predicate_holds(predicate, &left_geom, &right_geom) {
+                    left_indices.push(i as u32);
+                    right_indices.push(candidate.index);
+                }
+            }
+        }
+
+        (left_indices, right_indices)
+    }
+
+    /// Returns, for each non-null geometry in `self`, the indices of the `k` geometries in
+    /// `other` whose envelopes are nearest, nearest first, as `(self_index, other_index)` pairs.
+    ///
+    /// "Nearest" is judged by distance from `self`'s envelope center to `other`'s envelopes, the
+    /// same approximation [`rstar`]'s own `nearest_neighbor_iter` works from; it is exact for
+    /// point data and a close ranking for everything else.
+    pub fn nearest(&self, other: &WKBArray, k: usize) -> Vec<(u32, u32)> {
+        let tree = build_index(other);
+
+        let mut pairs = Vec::new();
+        for i in 0..self.len() {
+            let Some(left_wkb) = self.get(i) else {
+                continue;
+            };
+            let center = envelope_center(&left_wkb.envelope());
+
+            for candidate in tree.nearest_neighbor_iter(&center).take(k) {
+                pairs.push((i as u32, candidate.index));
+            }
+        }
+
+        pairs
+    }
+}
@@ -0,0 +1,337 @@
+//! A [`BinaryViewArray`]-backed alternative to [`WKBArray`](crate::WKBArray).
+//!
+//! Arrow's BinaryView layout inlines short values and stores long values as a buffer index plus
+//! offset and length, so reading a geometry's bytes never chases the offsets-plus-values-buffer
+//! indirection `BinaryArray<i64>` requires, and appending during WKB-producing conversions never
+//! re-copies a growing values buffer. This lives behind the `binary_view` feature since it
+//! requires an arrow2/polars version new enough to ship `BinaryViewArray`; callers on older
+//! versions should keep using [`WKBArray`](crate::WKBArray).
+
+use crate::enum_::GeometryType;
+use crate::trait_::GeometryArray;
+use crate::GeometryArrayTrait;
+use arrow2::array::{BinaryViewArray, MutableBinaryViewArray};
+use arrow2::bitmap::utils::{BitmapIter, ZipValidity};
+use arrow2::bitmap::Bitmap;
+use geo::BoundingRect;
+use geozero::{CoordDimensions, ToGeo, ToWkb};
+use rstar::{RTree, RTreeObject, AABB};
+
+/// A WKB blob read out of a [`WKBViewArray`]'s underlying `BinaryViewArray` as a view, without
+/// copying the values buffer.
+#[derive(Debug, Clone)]
+pub struct WKBView<'a> {
+    pub arr: &'a BinaryViewArray,
+    pub geom_index: usize,
+}
+
+impl From<WKBView<'_>> for geo::Geometry {
+    fn from(value: WKBView<'_>) -> Self {
+        (&value).into()
+    }
+}
+
+impl From<&WKBView<'_>> for geo::Geometry {
+    fn from(value: &WKBView<'_>) -> Self {
+        let buf = value.arr.value(value.geom_index);
+        geozero::wkb::Wkb(buf.to_vec()).to_geo().unwrap()
+    }
+}
+
+impl<'a> WKBView<'a> {
+    /// Reads the WKB geometry-type code (the `u32` immediately following the byte-order byte)
+    /// without decoding the rest of the geometry, so predicate/dispatch code that only needs to
+    /// know a row's geometry type never has to build and immediately discard a `geo::Geometry`.
+    /// Most WKB headers fall within `BinaryViewArray`'s inlined prefix, so this rarely touches the
+    /// out-of-line values buffer at all.
+    pub fn geometry_type_code(&self) -> u32 {
+        let buf = self.arr.value(self.geom_index);
+        let bytes: [u8; 4] = buf[1..5].try_into().unwrap();
+        if buf[0] == 0 {
+            u32::from_be_bytes(bytes)
+        } else {
+            u32::from_le_bytes(bytes)
+        }
+    }
+}
+
+impl RTreeObject for WKBView<'_> {
+    type Envelope = AABB<[f64; 2]>;
+
+    fn envelope(&self) -> Self::Envelope {
+        let geom: geo::Geometry = self.into();
+        let rect = geom.bounding_rect().unwrap();
+        let lower: [f64; 2] = rect.min().into();
+        let upper: [f64; 2] = rect.max().into();
+        AABB::from_corners(lower, upper)
+    }
+}
+
+/// A `Vec<Option<Geometry>>`-equivalent WKB array backed by a [`BinaryViewArray`] rather than
+/// the classic offsets-plus-values [`BinaryArray`](arrow2::array::BinaryArray) used by
+/// [`WKBArray`](crate::WKBArray).
+#[derive(Debug, Clone)]
+pub struct WKBViewArray(BinaryViewArray);
+
+impl WKBViewArray {
+    /// Create a new WKBViewArray from a BinaryViewArray
+    pub fn new(arr: BinaryViewArray) -> Self {
+        Self(arr)
+    }
+
+    /// Returns the number of geometries in this array
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns true if the array is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the optional validity.
+    pub fn validity(&self) -> Option<&Bitmap> {
+        self.0.validity()
+    }
+
+    /// Returns the value at slot `i` as a view into the underlying buffer, without copying.
+    pub fn value(&self, i: usize) -> WKBView {
+        WKBView {
+            arr: &self.0,
+            geom_index: i,
+        }
+    }
+
+    /// Gets the value at slot `i`, additionally checking the validity bitmap.
+    pub fn get(&self, i: usize) -> Option<WKBView> {
+        if self.is_null(i) {
+            return None;
+        }
+        Some(self.value(i))
+    }
+
+    /// Returns the value at slot `i`, decoded into an owned `geo::Geometry`.
+    pub fn value_as_geo(&self, i: usize) -> geo::Geometry {
+        self.value(i).into()
+    }
+
+    /// Reads row `i`'s WKB geometry-type code via [`WKBView::geometry_type_code`], without
+    /// decoding the full geometry.
+    pub fn geometry_type_code(&self, i: usize) -> u32 {
+        self.value(i).geometry_type_code()
+    }
+
+    /// Gets the value at slot `i` as a geo object, additionally checking the validity bitmap
+    pub fn get_as_geo(&self, i: usize) -> Option<geo::Geometry> {
+        self.get(i).map(Into::into)
+    }
+
+    /// Iterator over geo Geometry objects, not looking at validity
+    pub fn iter_geo_values(&self) -> impl Iterator<Item = geo::Geometry> + '_ {
+        (0..self.len()).map(|i| self.value_as_geo(i))
+    }
+
+    /// Iterator over geo Geometry objects, taking into account validity
+    pub fn iter_geo(
+        &self,
+    ) -> ZipValidity<geo::Geometry, impl Iterator<Item = geo::Geometry> + '_, BitmapIter> {
+        ZipValidity::new_with_validity(self.iter_geo_values(), self.validity())
+    }
+
+    pub fn into_arrow(self) -> BinaryViewArray {
+        self.0
+    }
+
+    #[inline]
+    fn is_null(&self, i: usize) -> bool {
+        self.validity().map(|x| !x.get_bit(i)).unwrap_or(false)
+    }
+
+    /// Returns the value at slot `i` as a GEOS geometry, reading straight out of the view's
+    /// buffer rather than copying it into an owned `Vec<u8>` first.
+    #[cfg(feature = "geos")]
+    pub fn value_as_geos(&self, i: usize) -> geos::Geometry {
+        let buf = self.0.value(i);
+        geos::Geometry::new_from_wkb(buf).expect("Unable to parse WKB")
+    }
+
+    /// Gets the value at slot `i` as a GEOS geometry, additionally checking the validity bitmap.
+    #[cfg(feature = "geos")]
+    pub fn get_as_geos(&self, i: usize) -> Option<geos::Geometry> {
+        if self.is_null(i) {
+            return None;
+        }
+
+        Some(self.value_as_geos(i))
+    }
+
+    /// Iterator over GEOS geometry objects, not looking at validity.
+    #[cfg(feature = "geos")]
+    pub fn iter_geos_values(&self) -> impl Iterator<Item = geos::Geometry> + '_ {
+        (0..self.len()).map(|i| self.value_as_geos(i))
+    }
+
+    /// Iterator over GEOS geometry objects, taking validity into account.
+    #[cfg(feature = "geos")]
+    pub fn iter_geos(
+        &self,
+    ) -> ZipValidity<geos::Geometry, impl Iterator<Item = geos::Geometry> + '_, BitmapIter> {
+        ZipValidity::new_with_validity(self.iter_geos_values(), self.validity())
+    }
+}
+
+impl From<BinaryViewArray> for WKBViewArray {
+    fn from(other: BinaryViewArray) -> Self {
+        Self(other)
+    }
+}
+
+impl<'a> GeometryArrayTrait<'a> for WKBViewArray {
+    type Scalar = WKBView<'a>;
+    type ScalarGeo = geo::Geometry;
+    type ArrowArray = BinaryViewArray;
+
+    fn value(&'a self, i: usize) -> Self::Scalar {
+        WKBView {
+            arr: &self.0,
+            geom_index: i,
+        }
+    }
+
+    fn into_arrow(self) -> BinaryViewArray {
+        self.0
+    }
+
+    fn rstar_tree(&'a self) -> RTree<Self::Scalar> {
+        let mut tree = RTree::new();
+        self.iter().flatten().for_each(|geom| tree.insert(geom));
+        tree
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn validity(&self) -> Option<&Bitmap> {
+        self.0.validity()
+    }
+
+    #[inline]
+    #[must_use]
+    fn slice(&self, offset: usize, length: usize) -> Self {
+        WKBViewArray(self.0.slice(offset, length))
+    }
+
+    #[inline]
+    #[must_use]
+    unsafe fn slice_unchecked(&self, offset: usize, length: usize) -> Self {
+        WKBViewArray(self.0.slice_unchecked(offset, length))
+    }
+
+    fn to_boxed(&self) -> Box<Self> {
+        Box::new(self.clone())
+    }
+}
+
+impl GeometryArray for WKBViewArray {
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn geometry_type(&self) -> GeometryType {
+        GeometryType::WKB
+    }
+
+    fn validity(&self) -> Option<&Bitmap> {
+        self.validity()
+    }
+
+    fn slice(&self, offset: usize, length: usize) -> Box<dyn GeometryArray> {
+        Box::new(<Self as GeometryArrayTrait>::slice(self, offset, length))
+    }
+
+    unsafe fn slice_unchecked(&self, offset: usize, length: usize) -> Box<dyn GeometryArray> {
+        Box::new(<Self as GeometryArrayTrait>::slice_unchecked(
+            self, offset, length,
+        ))
+    }
+
+    fn to_boxed(&self) -> Box<dyn GeometryArray> {
+        Box::new(self.clone())
+    }
+}
+
+/// The Arrow equivalent to `Vec<Option<Geometry>>`, backed by a growable [`BinaryViewArray`].
+/// Converting a [`MutableWKBViewArray`] into a [`WKBViewArray`] is `O(1)`.
+#[derive(Debug, Clone)]
+pub struct MutableWKBViewArray(MutableBinaryViewArray<[u8]>);
+
+impl Default for MutableWKBViewArray {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MutableWKBViewArray {
+    /// Creates a new empty [`MutableWKBViewArray`].
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Initializes a new [`MutableWKBViewArray`] with a pre-allocated capacity of slots.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self(MutableBinaryViewArray::with_capacity(capacity))
+    }
+
+    /// Appends a geometry, encoding it as WKB, or a null if `value` is `None`.
+    pub fn push(&mut self, value: Option<&geo::Geometry>) {
+        let wkb = value.map(|g| g.to_wkb(CoordDimensions::xy()).unwrap());
+        self.0.push(wkb);
+    }
+
+    /// Appends an already-encoded WKB blob as-is, or a null if `value` is `None`.
+    ///
+    /// Unlike [`Self::push`], this doesn't go through `geo::Geometry`/`ToWkb`, so it's the entry
+    /// point for copying WKB bytes that are already on hand (e.g. out of a [`WKBArray`][wkb]'s
+    /// `BinaryArray`, as [`WKBArray::to_binview`][wkb::to_binview] does) straight into the
+    /// view's inline prefix or append-only data buffer, whichever `arrow2`'s BinaryView layout
+    /// picks for a blob of `value`'s length.
+    ///
+    /// [wkb]: crate::WKBArray
+    /// [wkb::to_binview]: crate::WKBArray::to_binview
+    pub fn push_wkb(&mut self, value: Option<&[u8]>) {
+        self.0.push(value);
+    }
+}
+
+impl From<Vec<Option<geo::Geometry>>> for MutableWKBViewArray {
+    fn from(other: Vec<Option<geo::Geometry>>) -> Self {
+        let mut wkb_array = MutableBinaryViewArray::<[u8]>::with_capacity(other.len());
+
+        for geom in other {
+            let wkb = geom.map(|g| g.to_wkb(CoordDimensions::xy()).unwrap());
+            wkb_array.push(wkb);
+        }
+
+        Self(wkb_array)
+    }
+}
+
+impl From<MutableWKBViewArray> for WKBViewArray {
+    fn from(other: MutableWKBViewArray) -> Self {
+        Self::new(other.0.into())
+    }
+}
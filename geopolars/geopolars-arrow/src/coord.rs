@@ -0,0 +1,315 @@
+//! The physical layout used by `Mutable*Array` builders to store coordinates.
+//!
+//! GeoArrow producers disagree on this: some ship a single interleaved `FixedSizeList<f64>[2]`
+//! buffer (`[x0, y0, x1, y1, ...]`), others ship separated `x`/`y` primitive buffers. Every
+//! `Mutable*Array` builder in this crate used to hardcode the separated layout; [`CoordType`] and
+//! [`MutableCoordBuffer`] let callers pick either one, so interleaved GeoArrow data can round-trip
+//! through a builder without a re-striping copy.
+//!
+//! [`CoordBuffer`] (the immutable counterpart) and [`Point`](crate::Point)/[`PointTrait`](crate::geo_traits::PointTrait)
+//! already read through this abstraction, and `MutableLineStringArray` already accepts a
+//! [`CoordType`] to choose its layout — this module is the one place both sides of that split
+//! are defined.
+
+use arrow2::array::{Array, FixedSizeListArray, PrimitiveArray, StructArray};
+use arrow2::buffer::Buffer;
+use arrow2::datatypes::{DataType, Field};
+
+/// Which physical layout a `Mutable*Array` builder stores its coordinates in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CoordType {
+    /// Two separate `x` and `y` buffers. This is the layout every builder in this crate used
+    /// before [`CoordType`] was introduced.
+    #[default]
+    Separated,
+    /// A single `[x0, y0, x1, y1, ...]` buffer, matching GeoArrow producers that pack
+    /// coordinates into a `FixedSizeList<f64>[2]`.
+    Interleaved,
+}
+
+/// A growable coordinate buffer storing either interleaved or separated `x`/`y` values,
+/// mirroring [`CoordType`].
+#[derive(Debug, Clone)]
+pub enum MutableCoordBuffer {
+    /// Two separate `x` and `y` buffers.
+    Separated(Vec<f64>, Vec<f64>),
+    /// A single `[x0, y0, x1, y1, ...]` buffer.
+    Interleaved(Vec<f64>),
+}
+
+impl MutableCoordBuffer {
+    /// Creates a new, empty buffer in the given layout with room for `capacity` coordinates.
+    pub fn with_capacity(coord_type: CoordType, capacity: usize) -> Self {
+        match coord_type {
+            CoordType::Separated => {
+                MutableCoordBuffer::Separated(Vec::with_capacity(capacity), Vec::with_capacity(capacity))
+            }
+            CoordType::Interleaved => {
+                MutableCoordBuffer::Interleaved(Vec::with_capacity(capacity * 2))
+            }
+        }
+    }
+
+    /// Which [`CoordType`] this buffer is currently storing.
+    pub fn coord_type(&self) -> CoordType {
+        match self {
+            MutableCoordBuffer::Separated(_, _) => CoordType::Separated,
+            MutableCoordBuffer::Interleaved(_) => CoordType::Interleaved,
+        }
+    }
+
+    /// The number of coordinates pushed so far.
+    pub fn len(&self) -> usize {
+        match self {
+            MutableCoordBuffer::Separated(x, _) => x.len(),
+            MutableCoordBuffer::Interleaved(xy) => xy.len() / 2,
+        }
+    }
+
+    /// Returns true if no coordinates have been pushed.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Appends a single coordinate.
+    pub fn push_xy(&mut self, x: f64, y: f64) {
+        match self {
+            MutableCoordBuffer::Separated(xs, ys) => {
+                xs.push(x);
+                ys.push(y);
+            }
+            MutableCoordBuffer::Interleaved(xy) => {
+                xy.push(x);
+                xy.push(y);
+            }
+        }
+    }
+
+    /// Removes and returns the last coordinate, if any.
+    pub fn pop_xy(&mut self) -> Option<(f64, f64)> {
+        match self {
+            MutableCoordBuffer::Separated(xs, ys) => {
+                let y = ys.pop()?;
+                let x = xs.pop()?;
+                Some((x, y))
+            }
+            MutableCoordBuffer::Interleaved(xy) => {
+                let y = xy.pop()?;
+                let x = xy.pop()?;
+                Some((x, y))
+            }
+        }
+    }
+
+    /// Converts this buffer into the separated `(x, y)` layout, materializing new buffers if it
+    /// was interleaved.
+    pub fn into_separated(self) -> (Vec<f64>, Vec<f64>) {
+        match self {
+            MutableCoordBuffer::Separated(x, y) => (x, y),
+            MutableCoordBuffer::Interleaved(xy) => {
+                let mut x = Vec::with_capacity(xy.len() / 2);
+                let mut y = Vec::with_capacity(xy.len() / 2);
+                for pair in xy.chunks_exact(2) {
+                    x.push(pair[0]);
+                    y.push(pair[1]);
+                }
+                (x, y)
+            }
+        }
+    }
+
+    /// Builds the Arrow child array for a single coordinate buffer: a two-field `x`/`y`
+    /// [`StructArray`] when separated, or a [`FixedSizeListArray`] of length 2 when interleaved.
+    pub fn into_arrow(self) -> Box<dyn Array> {
+        match self {
+            MutableCoordBuffer::Separated(x, y) => {
+                let coord_field_x = Field::new("x", DataType::Float64, false);
+                let coord_field_y = Field::new("y", DataType::Float64, false);
+                let struct_data_type = DataType::Struct(vec![coord_field_x, coord_field_y]);
+
+                let array_x = PrimitiveArray::new(DataType::Float64, x.into(), None).boxed();
+                let array_y = PrimitiveArray::new(DataType::Float64, y.into(), None).boxed();
+
+                StructArray::new(struct_data_type, vec![array_x, array_y], None).boxed()
+            }
+            MutableCoordBuffer::Interleaved(xy) => {
+                let values_field = Field::new("xy", DataType::Float64, false);
+                let values = PrimitiveArray::new(DataType::Float64, xy.into(), None).boxed();
+
+                FixedSizeListArray::new(
+                    DataType::FixedSizeList(Box::new(values_field), 2),
+                    values,
+                    None,
+                )
+                .boxed()
+            }
+        }
+    }
+}
+
+/// An immutable coordinate buffer storing either interleaved or separated `x`/`y` values,
+/// mirroring [`CoordType`]. This is the `Buffer<f64>`-backed counterpart of
+/// [`MutableCoordBuffer`] used by the immutable `*Array` types, so `value(i)` can read a
+/// coordinate out of either physical layout without a re-striping copy.
+#[derive(Debug, Clone)]
+pub enum CoordBuffer {
+    /// Two separate `x` and `y` buffers.
+    Separated(Buffer<f64>, Buffer<f64>),
+    /// A single `[x0, y0, x1, y1, ...]` buffer.
+    Interleaved(Buffer<f64>),
+}
+
+impl CoordBuffer {
+    /// Which [`CoordType`] this buffer is currently storing.
+    pub fn coord_type(&self) -> CoordType {
+        match self {
+            CoordBuffer::Separated(_, _) => CoordType::Separated,
+            CoordBuffer::Interleaved(_) => CoordType::Interleaved,
+        }
+    }
+
+    /// The number of coordinates in this buffer.
+    pub fn len(&self) -> usize {
+        match self {
+            CoordBuffer::Separated(x, _) => x.len(),
+            CoordBuffer::Interleaved(xy) => xy.len() / 2,
+        }
+    }
+
+    /// Returns true if this buffer holds no coordinates.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the `(x, y)` value at `i`, regardless of physical layout.
+    #[inline]
+    pub fn value(&self, i: usize) -> (f64, f64) {
+        match self {
+            CoordBuffer::Separated(x, y) => (x[i], y[i]),
+            CoordBuffer::Interleaved(xy) => (xy[i * 2], xy[i * 2 + 1]),
+        }
+    }
+
+    /// Returns the `x` value at `i`.
+    #[inline]
+    pub fn get_x(&self, i: usize) -> f64 {
+        self.value(i).0
+    }
+
+    /// Returns the `y` value at `i`.
+    #[inline]
+    pub fn get_y(&self, i: usize) -> f64 {
+        self.value(i).1
+    }
+
+    /// Returns a clone of this buffer sliced by an offset and length.
+    /// # Implementation
+    /// This operation is `O(1)` as it amounts to increasing ref counts.
+    /// # Panic
+    /// This function panics iff `offset + length > self.len()`.
+    #[must_use]
+    pub fn slice(&self, offset: usize, length: usize) -> Self {
+        assert!(
+            offset + length <= self.len(),
+            "offset + length may not exceed length of array"
+        );
+        match self {
+            CoordBuffer::Separated(x, y) => CoordBuffer::Separated(
+                x.clone().slice(offset, length),
+                y.clone().slice(offset, length),
+            ),
+            CoordBuffer::Interleaved(xy) => {
+                CoordBuffer::Interleaved(xy.clone().slice(offset * 2, length * 2))
+            }
+        }
+    }
+
+    /// Builds the Arrow child array for a single coordinate buffer: a two-field `x`/`y`
+    /// [`StructArray`] when separated, or a [`FixedSizeListArray`] of length 2 when interleaved.
+    pub fn into_arrow(self) -> Box<dyn Array> {
+        match self {
+            CoordBuffer::Separated(x, y) => {
+                let coord_field_x = Field::new("x", DataType::Float64, false);
+                let coord_field_y = Field::new("y", DataType::Float64, false);
+                let struct_data_type = DataType::Struct(vec![coord_field_x, coord_field_y]);
+
+                let array_x = PrimitiveArray::new(DataType::Float64, x, None).boxed();
+                let array_y = PrimitiveArray::new(DataType::Float64, y, None).boxed();
+
+                StructArray::new(struct_data_type, vec![array_x, array_y], None).boxed()
+            }
+            CoordBuffer::Interleaved(xy) => {
+                let values_field = Field::new("xy", DataType::Float64, false);
+                let values = PrimitiveArray::new(DataType::Float64, xy, None).boxed();
+
+                FixedSizeListArray::new(
+                    DataType::FixedSizeList(Box::new(values_field), 2),
+                    values,
+                    None,
+                )
+                .boxed()
+            }
+        }
+    }
+
+    /// Converts this buffer into the separated `(x, y)` layout, materializing new buffers if it
+    /// was interleaved.
+    pub fn into_separated(self) -> (Buffer<f64>, Buffer<f64>) {
+        match self {
+            CoordBuffer::Separated(x, y) => (x, y),
+            CoordBuffer::Interleaved(xy) => {
+                let mut x = Vec::with_capacity(xy.len() / 2);
+                let mut y = Vec::with_capacity(xy.len() / 2);
+                for pair in xy.chunks_exact(2) {
+                    x.push(pair[0]);
+                    y.push(pair[1]);
+                }
+                (x.into(), y.into())
+            }
+        }
+    }
+}
+
+impl From<MutableCoordBuffer> for CoordBuffer {
+    fn from(value: MutableCoordBuffer) -> Self {
+        match value {
+            MutableCoordBuffer::Separated(x, y) => CoordBuffer::Separated(x.into(), y.into()),
+            MutableCoordBuffer::Interleaved(xy) => CoordBuffer::Interleaved(xy.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn separated_and_interleaved_agree_on_values() {
+        let mut separated = MutableCoordBuffer::with_capacity(CoordType::Separated, 2);
+        let mut interleaved = MutableCoordBuffer::with_capacity(CoordType::Interleaved, 2);
+
+        for (x, y) in [(1.0, 2.0), (3.0, 4.0)] {
+            separated.push_xy(x, y);
+            interleaved.push_xy(x, y);
+        }
+
+        assert_eq!(separated.len(), 2);
+        assert_eq!(interleaved.len(), 2);
+        assert_eq!(separated.into_separated(), interleaved.into_separated());
+    }
+
+    #[test]
+    fn coord_buffer_value_agrees_across_layouts() {
+        let separated: CoordBuffer =
+            MutableCoordBuffer::Separated(vec![1.0, 3.0], vec![2.0, 4.0]).into();
+        let interleaved: CoordBuffer =
+            MutableCoordBuffer::Interleaved(vec![1.0, 2.0, 3.0, 4.0]).into();
+
+        assert_eq!(separated.coord_type(), CoordType::Separated);
+        assert_eq!(interleaved.coord_type(), CoordType::Interleaved);
+
+        for i in 0..2 {
+            assert_eq!(separated.value(i), interleaved.value(i));
+        }
+    }
+}
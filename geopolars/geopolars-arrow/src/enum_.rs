@@ -4,8 +4,8 @@ use arrow2::bitmap::Bitmap;
 use rstar::{RTree, RTreeObject, AABB};
 
 use crate::{
-    LineStringArray, MultiLineStringArray, MultiPointArray, MultiPolygonArray, PointArray,
-    PolygonArray, WKBArray,
+    GeometryCollectionArray, LineStringArray, MixedGeometryArray, MultiLineStringArray,
+    MultiPointArray, MultiPolygonArray, PointArray, PolygonArray, WKBArray, WKTArray,
 };
 
 pub enum Geometry<'a> {
@@ -15,7 +15,9 @@ pub enum Geometry<'a> {
     MultiPoint(crate::MultiPoint<'a>),
     MultiLineString(crate::MultiLineString<'a>),
     MultiPolygon(crate::MultiPolygon<'a>),
+    GeometryCollection(crate::GeometryCollection<'a>),
     WKB(crate::WKB<'a>),
+    WKT(crate::WKT<'a>),
 }
 
 impl RTreeObject for Geometry<'_> {
@@ -29,7 +31,9 @@ impl RTreeObject for Geometry<'_> {
             Geometry::MultiPoint(geom) => geom.envelope(),
             Geometry::MultiLineString(geom) => geom.envelope(),
             Geometry::MultiPolygon(geom) => geom.envelope(),
+            Geometry::GeometryCollection(geom) => geom.envelope(),
             Geometry::WKB(geom) => geom.envelope(),
+            Geometry::WKT(geom) => geom.envelope(),
         }
     }
 }
@@ -43,7 +47,9 @@ impl From<Geometry<'_>> for geo::Geometry {
             Geometry::MultiPoint(geom) => geom.into(),
             Geometry::MultiLineString(geom) => geom.into(),
             Geometry::MultiPolygon(geom) => geom.into(),
+            Geometry::GeometryCollection(geom) => geom.into(),
             Geometry::WKB(geom) => geom.into(),
+            Geometry::WKT(geom) => geom.into(),
         }
     }
 }
@@ -56,7 +62,11 @@ pub enum GeometryArray {
     MultiPoint(MultiPointArray),
     MultiLineString(MultiLineStringArray),
     MultiPolygon(MultiPolygonArray),
+    GeometryCollection(GeometryCollectionArray),
     WKB(WKBArray),
+    WKT(WKTArray),
+    /// A column whose rows are not all the same geometry type. See [`MixedGeometryArray`].
+    Mixed(MixedGeometryArray),
 }
 
 impl<'a> GeometryArrayTrait<'a> for GeometryArray {
@@ -72,19 +82,27 @@ impl<'a> GeometryArrayTrait<'a> for GeometryArray {
             GeometryArray::MultiPoint(arr) => Geometry::MultiPoint(arr.value(i)),
             GeometryArray::MultiLineString(arr) => Geometry::MultiLineString(arr.value(i)),
             GeometryArray::MultiPolygon(arr) => Geometry::MultiPolygon(arr.value(i)),
+            GeometryArray::GeometryCollection(arr) => Geometry::GeometryCollection(arr.value(i)),
             GeometryArray::WKB(arr) => Geometry::WKB(arr.value(i)),
+            GeometryArray::WKT(arr) => Geometry::WKT(arr.value(i)),
+            // `MixedGeometryArray::value` already resolves to whichever [`Geometry`] variant the
+            // row's own geometry type is, so no extra wrapping is needed here.
+            GeometryArray::Mixed(arr) => arr.value(i),
         }
     }
 
     fn into_arrow(self) -> Self::ArrowArray {
         match self {
-            GeometryArray::Point(arr) => arr.into_arrow().boxed(),
+            GeometryArray::Point(arr) => arr.into_arrow(),
             GeometryArray::LineString(arr) => arr.into_arrow().boxed(),
             GeometryArray::Polygon(arr) => arr.into_arrow().boxed(),
             GeometryArray::MultiPoint(arr) => arr.into_arrow().boxed(),
             GeometryArray::MultiLineString(arr) => arr.into_arrow().boxed(),
             GeometryArray::MultiPolygon(arr) => arr.into_arrow().boxed(),
+            GeometryArray::GeometryCollection(arr) => arr.into_arrow().boxed(),
             GeometryArray::WKB(arr) => arr.into_arrow().boxed(),
+            GeometryArray::WKT(arr) => arr.into_arrow().boxed(),
+            GeometryArray::Mixed(arr) => arr.into_arrow().boxed(),
         }
     }
 
@@ -107,7 +125,10 @@ impl<'a> GeometryArrayTrait<'a> for GeometryArray {
             GeometryArray::MultiPoint(arr) => arr.len(),
             GeometryArray::MultiLineString(arr) => arr.len(),
             GeometryArray::MultiPolygon(arr) => arr.len(),
+            GeometryArray::GeometryCollection(arr) => arr.len(),
             GeometryArray::WKB(arr) => arr.len(),
+            GeometryArray::WKT(arr) => arr.len(),
+            GeometryArray::Mixed(arr) => arr.len(),
         }
     }
 
@@ -122,7 +143,10 @@ impl<'a> GeometryArrayTrait<'a> for GeometryArray {
             GeometryArray::MultiPoint(arr) => arr.validity(),
             GeometryArray::MultiLineString(arr) => arr.validity(),
             GeometryArray::MultiPolygon(arr) => arr.validity(),
+            GeometryArray::GeometryCollection(arr) => arr.validity(),
             GeometryArray::WKB(arr) => arr.validity(),
+            GeometryArray::WKT(arr) => arr.validity(),
+            GeometryArray::Mixed(arr) => arr.validity(),
         }
     }
 
@@ -144,7 +168,12 @@ impl<'a> GeometryArrayTrait<'a> for GeometryArray {
             GeometryArray::MultiPolygon(arr) => {
                 GeometryArray::MultiPolygon(arr.slice(offset, length))
             }
+            GeometryArray::GeometryCollection(arr) => {
+                GeometryArray::GeometryCollection(arr.slice(offset, length))
+            }
             GeometryArray::WKB(arr) => GeometryArray::WKB(arr.slice(offset, length)),
+            GeometryArray::WKT(arr) => GeometryArray::WKT(arr.slice(offset, length)),
+            GeometryArray::Mixed(arr) => GeometryArray::Mixed(arr.slice(offset, length)),
         }
     }
 
@@ -172,7 +201,14 @@ impl<'a> GeometryArrayTrait<'a> for GeometryArray {
             GeometryArray::MultiPolygon(arr) => {
                 GeometryArray::MultiPolygon(arr.slice_unchecked(offset, length))
             }
+            GeometryArray::GeometryCollection(arr) => {
+                GeometryArray::GeometryCollection(arr.slice_unchecked(offset, length))
+            }
             GeometryArray::WKB(arr) => GeometryArray::WKB(arr.slice_unchecked(offset, length)),
+            GeometryArray::WKT(arr) => GeometryArray::WKT(arr.slice_unchecked(offset, length)),
+            GeometryArray::Mixed(arr) => {
+                GeometryArray::Mixed(arr.slice_unchecked(offset, length))
+            }
         }
     }
 
@@ -190,7 +226,10 @@ impl<'a> GeometryArrayTrait<'a> for GeometryArray {
             GeometryArray::MultiPoint(arr) => GeometryArray::MultiPoint(arr.clone()),
             GeometryArray::MultiLineString(arr) => GeometryArray::MultiLineString(arr.clone()),
             GeometryArray::MultiPolygon(arr) => GeometryArray::MultiPolygon(arr.clone()),
+            GeometryArray::GeometryCollection(arr) => GeometryArray::GeometryCollection(arr.clone()),
             GeometryArray::WKB(arr) => GeometryArray::WKB(arr.clone()),
+            GeometryArray::WKT(arr) => GeometryArray::WKT(arr.clone()),
+            GeometryArray::Mixed(arr) => GeometryArray::Mixed(arr.clone()),
         })
     }
 }
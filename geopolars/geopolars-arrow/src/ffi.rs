@@ -0,0 +1,176 @@
+//! Arrow C Data Interface (FFI) import/export for geometry arrays.
+//!
+//! Every geometry array in this crate is already fully expressible as a plain arrow2 [`Array`]
+//! (via `into_arrow`, e.g. a `ListArray<i64>` for the nested types, a struct array for points or a
+//! `BinaryArray<i64>` for WKB; reconstructible via `TryFrom<Box<dyn Array>>`), so
+//! exporting across the C Data Interface is just a matter of routing through arrow2's own
+//! [`ffi::export_array_to_c`]/[`ffi::import_array_from_c`], which already walks the nested
+//! `OffsetsBuffer`'s backing buffer and logical offset, the coordinate buffers, and the
+//! validity bitmap. This module is the one place that plumbing is wired up, so any geometry
+//! array type gets zero-copy FFI handoff by calling [`to_ffi`]/[`from_ffi`] rather than every
+//! array module hand-rolling pointer arithmetic itself.
+
+use arrow2::array::Array;
+use arrow2::datatypes::{DataType, Field};
+use arrow2::ffi;
+
+use crate::error::GeoArrowError;
+
+/// Exports `array` across the Arrow C Data Interface, consuming it.
+///
+/// `array` is typically the result of a geometry array's `into_arrow()` (e.g.
+/// [`crate::PolygonArray::into_arrow`] or [`crate::MultiPolygonArray::into_arrow`]), whose
+/// nested `OffsetsBuffer<i64>` levels, coordinate buffers, and validity bitmap are walked by
+/// arrow2's own FFI export.
+pub fn to_ffi<A: Array + 'static>(array: A) -> ffi::ArrowArray {
+    ffi::export_array_to_c(array.boxed())
+}
+
+/// Imports an array across the Arrow C Data Interface and converts it into `A`.
+///
+/// `data_type` must match the Arrow logical type `array` was exported with (e.g. the
+/// `DataType::LargeList(..)` that `PolygonArray::into_arrow` produces); passing the data type
+/// of a different geometry array is undefined behavior, mirroring arrow2's own
+/// `import_array_from_c` contract. The imported array is converted by `A::try_from`, i.e. each
+/// array's existing `TryFrom<Box<dyn Array>>` constructor.
+///
+/// # Safety
+/// `array` must have been populated by a valid Arrow C Data Interface producer, matching
+/// `data_type`.
+pub unsafe fn from_ffi<A>(array: ffi::ArrowArray, data_type: DataType) -> Result<A, GeoArrowError>
+where
+    A: TryFrom<Box<dyn Array>, Error = GeoArrowError>,
+{
+    let imported = ffi::import_array_from_c(array, data_type)
+        .map_err(|err| GeoArrowError::General(err.to_string()))?;
+    A::try_from(imported)
+}
+
+/// Exports `array` together with `field` (the array's logical type plus, for a GeoArrow
+/// extension column, its `ARROW:extension:name`/`ARROW:extension:metadata` pair) across the
+/// Arrow C Data Interface, consuming both.
+///
+/// `field` is typically built by a geometry array's own `extension_field` (e.g.
+/// [`crate::PointArray::extension_field`], [`crate::WKBArray::extension_field`], or
+/// [`crate::MultiLineStringArray::extension_field`]), so a consumer on the other side of the
+/// interface (pyarrow, DuckDB, GDAL) can recognize the column as GeoArrow data rather than a
+/// plain struct/binary column.
+pub fn to_ffi_with_schema<A: Array + 'static>(
+    array: A,
+    field: &Field,
+) -> (ffi::ArrowArray, ffi::ArrowSchema) {
+    (
+        ffi::export_array_to_c(array.boxed()),
+        ffi::export_field_to_c(field),
+    )
+}
+
+/// Imports an array together with its schema across the Arrow C Data Interface, converting the
+/// array into `A` and returning the schema's [`Field`] (including any GeoArrow extension
+/// metadata) alongside it.
+///
+/// Unlike [`from_ffi`], the array's [`DataType`] doesn't need to be supplied by the caller: it's
+/// recovered from `schema` itself.
+///
+/// # Safety
+/// `array` and `schema` must have been populated by a valid Arrow C Data Interface producer, and
+/// must describe the same column.
+pub unsafe fn from_ffi_with_schema<A>(
+    array: ffi::ArrowArray,
+    schema: &ffi::ArrowSchema,
+) -> Result<(A, Field), GeoArrowError>
+where
+    A: TryFrom<Box<dyn Array>, Error = GeoArrowError>,
+{
+    let field = ffi::import_field_from_c(schema)
+        .map_err(|err| GeoArrowError::General(err.to_string()))?;
+    let imported = from_ffi(array, field.data_type.clone())?;
+    Ok((imported, field))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{MultiPolygonArray, PolygonArray};
+    use geo::polygon;
+
+    fn p0() -> geo::Polygon {
+        polygon![
+            (x: 0., y: 0.),
+            (x: 4., y: 0.),
+            (x: 4., y: 4.),
+            (x: 0., y: 4.),
+            (x: 0., y: 0.),
+        ]
+    }
+
+    #[test]
+    fn polygon_array_roundtrips_through_ffi() {
+        let arr: PolygonArray = vec![p0()].into();
+        let arrow_arr = arr.clone().into_arrow();
+        let data_type = arrow_arr.data_type().clone();
+
+        let exported = to_ffi(arrow_arr);
+        let imported: PolygonArray = unsafe { from_ffi(exported, data_type) }.unwrap();
+
+        assert_eq!(imported.value_as_geo(0), arr.value_as_geo(0));
+    }
+
+    #[test]
+    fn multipolygon_array_roundtrips_through_ffi() {
+        let mp0 = geo::MultiPolygon::new(vec![p0()]);
+        let arr: MultiPolygonArray = vec![mp0.clone()].into();
+        let arrow_arr = arr.into_arrow();
+        let data_type = arrow_arr.data_type().clone();
+
+        let exported = to_ffi(arrow_arr);
+        let imported: MultiPolygonArray = unsafe { from_ffi(exported, data_type) }.unwrap();
+
+        assert_eq!(imported.value_as_geo(0), mp0);
+    }
+
+    #[test]
+    fn point_array_roundtrips_through_ffi_with_extension_schema() {
+        use crate::PointArray;
+        use geo::point;
+
+        let p0 = point!(x: 1., y: 2.);
+        let arr: PointArray = vec![p0].into();
+        let field = arr.extension_field("geometry");
+        let arrow_arr = arr.clone().into_arrow();
+
+        let (exported_array, exported_schema) = to_ffi_with_schema(arrow_arr, &field);
+        let (imported, imported_field): (PointArray, _) =
+            unsafe { from_ffi_with_schema(exported_array, &exported_schema) }.unwrap();
+
+        assert_eq!(
+            imported_field.metadata.get("ARROW:extension:name").unwrap(),
+            "geoarrow.point"
+        );
+        assert_eq!(imported.value_as_geo(0), p0);
+    }
+
+    #[test]
+    fn wkb_array_roundtrips_through_ffi_with_extension_schema() {
+        use crate::{GeometryArrayTrait, WKBArray};
+        use geo::{point, Geometry};
+
+        let geoms: Vec<Option<Geometry>> = vec![Some(Geometry::Point(point!(x: 1., y: 2.)))];
+        let arr: WKBArray = geoms.into();
+        let field = arr.extension_field("geometry");
+        let arrow_arr = arr.into_arrow();
+
+        let (exported_array, exported_schema) = to_ffi_with_schema(arrow_arr, &field);
+        let (imported, imported_field): (WKBArray, _) =
+            unsafe { from_ffi_with_schema(exported_array, &exported_schema) }.unwrap();
+
+        assert_eq!(
+            imported_field.metadata.get("ARROW:extension:name").unwrap(),
+            "geoarrow.wkb"
+        );
+        assert_eq!(
+            imported.get_as_geo(0),
+            Some(Geometry::Point(point!(x: 1., y: 2.)))
+        );
+    }
+}
@@ -0,0 +1,322 @@
+use crate::enum_::GeometryType;
+use crate::error::GeoArrowError;
+use crate::trait_::GeometryArray;
+use arrow2::array::{Array, BinaryArray, ListArray};
+use arrow2::bitmap::utils::{BitmapIter, ZipValidity};
+use arrow2::bitmap::Bitmap;
+use arrow2::datatypes::{DataType, Field};
+use arrow2::offset::OffsetsBuffer;
+use rstar::RTree;
+
+use super::MutableGeometryCollectionArray;
+
+/// A [`GeometryArray`] semantically equivalent to `Vec<Option<GeometryCollection>>` using Arrow's
+/// in-memory representation.
+///
+/// Each row's children are stored as WKB-encoded geometries rather than raw coordinate buffers,
+/// since a collection's children aren't restricted to a single geometry type the way a
+/// MultiPolygon's are.
+#[derive(Debug, Clone)]
+pub struct GeometryCollectionArray {
+    /// WKB-encoded child geometries, flattened across every row
+    children: BinaryArray<i64>,
+
+    /// Offsets into `children` where each row's geometries start
+    geom_offsets: OffsetsBuffer<i64>,
+
+    /// Validity bitmap
+    validity: Option<Bitmap>,
+}
+
+pub(super) fn check(
+    _children_len: usize,
+    validity_len: Option<usize>,
+    geom_offsets: &OffsetsBuffer<i64>,
+) -> Result<(), GeoArrowError> {
+    // TODO: check geom offsets against children_len?
+    if validity_len.map_or(false, |len| len != geom_offsets.len()) {
+        return Err(GeoArrowError::General(
+            "validity mask length must match the number of values".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+impl GeometryCollectionArray {
+    /// Create a new GeometryCollectionArray from parts
+    /// # Implementation
+    /// This function is `O(1)`.
+    pub fn new(
+        children: BinaryArray<i64>,
+        geom_offsets: OffsetsBuffer<i64>,
+        validity: Option<Bitmap>,
+    ) -> Self {
+        check(
+            children.len(),
+            validity.as_ref().map(|v| v.len()),
+            &geom_offsets,
+        )
+        .unwrap();
+        Self {
+            children,
+            geom_offsets,
+            validity,
+        }
+    }
+
+    /// Create a new GeometryCollectionArray from parts
+    /// # Implementation
+    /// This function is `O(1)`.
+    pub fn try_new(
+        children: BinaryArray<i64>,
+        geom_offsets: OffsetsBuffer<i64>,
+        validity: Option<Bitmap>,
+    ) -> Result<Self, GeoArrowError> {
+        check(
+            children.len(),
+            validity.as_ref().map(|v| v.len()),
+            &geom_offsets,
+        )?;
+        Ok(Self {
+            children,
+            geom_offsets,
+            validity,
+        })
+    }
+
+    /// Returns the number of geometries in this array
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.geom_offsets.len()
+    }
+
+    /// Returns true if the array is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the optional validity.
+    #[inline]
+    pub fn validity(&self) -> Option<&Bitmap> {
+        self.validity.as_ref()
+    }
+
+    /// Returns a clone of this array sliced by an offset and length.
+    /// # Implementation
+    /// This operation is `O(1)` as it amounts to increase two ref counts.
+    /// # Panic
+    /// This function panics iff `offset + length > self.len()`.
+    #[inline]
+    #[must_use]
+    pub fn slice(&self, offset: usize, length: usize) -> Self {
+        assert!(
+            offset + length <= self.len(),
+            "offset + length may not exceed length of array"
+        );
+        unsafe { self.slice_unchecked(offset, length) }
+    }
+
+    /// Returns a clone of this array sliced by an offset and length.
+    /// # Implementation
+    /// This operation is `O(1)` as it amounts to increase two ref counts.
+    /// # Safety
+    /// The caller must ensure that `offset + length <= self.len()`.
+    #[inline]
+    #[must_use]
+    pub unsafe fn slice_unchecked(&self, offset: usize, length: usize) -> Self {
+        let validity = self
+            .validity
+            .clone()
+            .map(|bitmap| bitmap.slice_unchecked(offset, length))
+            .and_then(|bitmap| (bitmap.unset_bits() > 0).then_some(bitmap));
+
+        let geom_offsets = self
+            .geom_offsets
+            .clone()
+            .slice_unchecked(offset, length + 1);
+
+        Self {
+            children: self.children.clone(),
+            geom_offsets,
+            validity,
+        }
+    }
+}
+
+// Implement geometry accessors
+impl GeometryCollectionArray {
+    pub fn value(&self, i: usize) -> crate::GeometryCollection {
+        crate::GeometryCollection {
+            children: &self.children,
+            geom_offsets: &self.geom_offsets,
+            geom_index: i,
+        }
+    }
+
+    pub fn get(&self, i: usize) -> Option<crate::GeometryCollection> {
+        if self.is_null(i) {
+            return None;
+        }
+
+        Some(self.value(i))
+    }
+
+    pub fn iter_values(&self) -> impl Iterator<Item = crate::GeometryCollection> + '_ {
+        (0..self.len()).map(|i| self.value(i))
+    }
+
+    pub fn iter(
+        &self,
+    ) -> ZipValidity<
+        crate::GeometryCollection,
+        impl Iterator<Item = crate::GeometryCollection> + '_,
+        BitmapIter,
+    > {
+        ZipValidity::new_with_validity(self.iter_values(), self.validity())
+    }
+
+    /// Returns the value at slot `i` as a geo object.
+    pub fn value_as_geo(&self, i: usize) -> geo::GeometryCollection {
+        self.value(i).into()
+    }
+
+    /// Gets the value at slot `i` as a geo object, additionally checking the validity bitmap
+    pub fn get_as_geo(&self, i: usize) -> Option<geo::GeometryCollection> {
+        if self.is_null(i) {
+            return None;
+        }
+
+        Some(self.value_as_geo(i))
+    }
+
+    /// Iterator over geo GeometryCollection objects, not looking at validity
+    pub fn iter_geo_values(&self) -> impl Iterator<Item = geo::GeometryCollection> + '_ {
+        (0..self.len()).map(|i| self.value_as_geo(i))
+    }
+
+    /// Iterator over geo GeometryCollection objects, taking into account validity
+    pub fn iter_geo(
+        &self,
+    ) -> ZipValidity<
+        geo::GeometryCollection,
+        impl Iterator<Item = geo::GeometryCollection> + '_,
+        BitmapIter,
+    > {
+        ZipValidity::new_with_validity(self.iter_geo_values(), self.validity())
+    }
+
+    pub fn into_arrow(self) -> ListArray<i64> {
+        let children_field = Field::new("item", DataType::LargeBinary, true);
+        let list_data_type = DataType::LargeList(Box::new(children_field));
+
+        let validity: Option<Bitmap> = self.validity;
+        let children = self.children.boxed();
+
+        ListArray::new(list_data_type, self.geom_offsets, children, validity)
+    }
+
+    /// Build a spatial index containing this array's geometries
+    pub fn rstar_tree(&self) -> RTree<crate::GeometryCollection> {
+        let mut tree = RTree::new();
+        self.iter().flatten().for_each(|geom| tree.insert(geom));
+        tree
+    }
+}
+
+impl TryFrom<ListArray<i64>> for GeometryCollectionArray {
+    type Error = GeoArrowError;
+
+    fn try_from(value: ListArray<i64>) -> Result<Self, Self::Error> {
+        let geom_offsets = value.offsets();
+        let validity = value.validity();
+
+        let children_dyn_array = value.values();
+        let children = children_dyn_array
+            .as_any()
+            .downcast_ref::<BinaryArray<i64>>()
+            .unwrap();
+
+        Ok(Self::new(
+            children.clone(),
+            geom_offsets.clone(),
+            validity.cloned(),
+        ))
+    }
+}
+
+impl TryFrom<Box<dyn Array>> for GeometryCollectionArray {
+    type Error = GeoArrowError;
+
+    fn try_from(value: Box<dyn Array>) -> Result<Self, Self::Error> {
+        // Accept either `LargeList` (i64 offsets, our own native width) or `List` (i32 offsets,
+        // e.g. from a producer that didn't opt into large offsets) by widening the latter up
+        // front; everything past this point only ever deals with `ListArray<i64>`.
+        if let Some(arr) = value.as_any().downcast_ref::<ListArray<i64>>() {
+            arr.clone().try_into()
+        } else {
+            let arr = value.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+            crate::offset::widen_list_array(arr).try_into()
+        }
+    }
+}
+
+impl GeometryArray for GeometryCollectionArray {
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn geometry_type(&self) -> GeometryType {
+        GeometryType::WKB
+    }
+
+    fn validity(&self) -> Option<&Bitmap> {
+        self.validity()
+    }
+
+    fn slice(&self, offset: usize, length: usize) -> Box<dyn GeometryArray> {
+        Box::new(self.slice(offset, length))
+    }
+
+    unsafe fn slice_unchecked(&self, offset: usize, length: usize) -> Box<dyn GeometryArray> {
+        Box::new(self.slice_unchecked(offset, length))
+    }
+
+    fn to_boxed(&self) -> Box<dyn GeometryArray> {
+        Box::new(self.clone())
+    }
+}
+
+impl From<Vec<Option<geo::GeometryCollection>>> for GeometryCollectionArray {
+    fn from(other: Vec<Option<geo::GeometryCollection>>) -> Self {
+        let mut_arr: MutableGeometryCollectionArray = other.into();
+        mut_arr.into()
+    }
+}
+
+impl From<Vec<geo::GeometryCollection>> for GeometryCollectionArray {
+    fn from(other: Vec<geo::GeometryCollection>) -> Self {
+        let mut_arr: MutableGeometryCollectionArray = other.into();
+        mut_arr.into()
+    }
+}
+
+impl From<GeometryCollectionArray> for crate::WKBArray {
+    fn from(value: GeometryCollectionArray) -> Self {
+        let geoms: Vec<Option<geo::Geometry>> = (0..value.len())
+            .map(|i| value.get_as_geo(i).map(geo::Geometry::GeometryCollection))
+            .collect();
+        geoms.into()
+    }
+}
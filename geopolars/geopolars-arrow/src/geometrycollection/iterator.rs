@@ -0,0 +1,80 @@
+use crate::{GeometryCollection, GeometryCollectionArray};
+use arrow2::bitmap::utils::{BitmapIter, ZipValidity};
+use arrow2::trusted_len::TrustedLen;
+
+/// Iterator of values of a [`GeometryCollectionArray`]
+#[derive(Clone, Debug)]
+pub struct GeometryCollectionArrayValuesIter<'a> {
+    array: &'a GeometryCollectionArray,
+    index: usize,
+    end: usize,
+}
+
+impl<'a> GeometryCollectionArrayValuesIter<'a> {
+    #[inline]
+    pub fn new(array: &'a GeometryCollectionArray) -> Self {
+        Self {
+            array,
+            index: 0,
+            end: array.len(),
+        }
+    }
+}
+
+impl<'a> Iterator for GeometryCollectionArrayValuesIter<'a> {
+    type Item = GeometryCollection<'a>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index == self.end {
+            return None;
+        }
+        let old = self.index;
+        self.index += 1;
+        Some(self.array.value(old))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.end - self.index, Some(self.end - self.index))
+    }
+}
+
+unsafe impl<'a> TrustedLen for GeometryCollectionArrayValuesIter<'a> {}
+
+impl<'a> DoubleEndedIterator for GeometryCollectionArrayValuesIter<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.index == self.end {
+            None
+        } else {
+            self.end -= 1;
+            Some(self.array.value(self.end))
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a GeometryCollectionArray {
+    type Item = Option<GeometryCollection<'a>>;
+    type IntoIter =
+        ZipValidity<GeometryCollection<'a>, GeometryCollectionArrayValuesIter<'a>, BitmapIter<'a>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a> GeometryCollectionArray {
+    /// Returns an iterator of `Option<GeometryCollection>`
+    pub fn iter(
+        &'a self,
+    ) -> ZipValidity<GeometryCollection<'a>, GeometryCollectionArrayValuesIter<'a>, BitmapIter<'a>>
+    {
+        ZipValidity::new_with_validity(GeometryCollectionArrayValuesIter::new(self), self.validity())
+    }
+
+    /// Returns an iterator of `GeometryCollection`
+    pub fn values_iter(&'a self) -> GeometryCollectionArrayValuesIter<'a> {
+        GeometryCollectionArrayValuesIter::new(self)
+    }
+}
@@ -0,0 +1,8 @@
+pub use array::GeometryCollectionArray;
+pub use mutable::MutableGeometryCollectionArray;
+pub use scalar::GeometryCollection;
+
+mod array;
+mod iterator;
+mod mutable;
+mod scalar;
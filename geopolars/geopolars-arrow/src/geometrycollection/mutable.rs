@@ -0,0 +1,166 @@
+use arrow2::array::{MutableArray, MutableBinaryArray};
+use arrow2::bitmap::{Bitmap, MutableBitmap};
+use arrow2::offset::{Offsets, OffsetsBuffer};
+use geo::GeometryCollection;
+use geozero::{CoordDimensions, ToWkb};
+
+use crate::error::GeoArrowError;
+use crate::GeometryCollectionArray;
+
+use super::array::check;
+
+pub type MutableGeometryCollectionParts = (
+    MutableBinaryArray<i64>,
+    Offsets<i64>,
+    Option<MutableBitmap>,
+);
+
+/// The Arrow equivalent to `Vec<Option<GeometryCollection>>`.
+/// Converting a [`MutableGeometryCollectionArray`] into a [`GeometryCollectionArray`] is `O(1)`.
+///
+/// Unlike the single/multi-geometry `Mutable*Array` builders in this crate, this one has no
+/// [`geozero::GeomProcessor`] implementation: its children are heterogeneous geometries stored
+/// as flattened WKB, so a push-based builder would need to buffer each child's own begin/end
+/// callbacks into its own independent WKB blob before it could append one to `children` - real
+/// complexity for no payoff, since every producer of a `GeometryCollection` in this crate already
+/// has it as an owned `geo::GeometryCollection` by the time it gets here. [`MutableMixedGeometryArray`](crate::MutableMixedGeometryArray)
+/// makes the same call for the same reason.
+#[derive(Debug, Clone)]
+pub struct MutableGeometryCollectionArray {
+    /// WKB-encoded child geometries, flattened across every row
+    children: MutableBinaryArray<i64>,
+
+    /// Offsets into `children` where each row's geometries start
+    geom_offsets: Offsets<i64>,
+
+    /// Validity is only defined at the geometry (row) level
+    validity: Option<MutableBitmap>,
+}
+
+impl MutableGeometryCollectionArray {
+    /// Creates a new empty [`MutableGeometryCollectionArray`].
+    pub fn new() -> Self {
+        Self::with_capacities(0, 0)
+    }
+
+    /// Creates a new [`MutableGeometryCollectionArray`] with a capacity.
+    pub fn with_capacities(children_capacity: usize, geom_capacity: usize) -> Self {
+        Self {
+            children: MutableBinaryArray::<i64>::with_capacity(children_capacity),
+            geom_offsets: Offsets::<i64>::with_capacity(geom_capacity),
+            validity: None,
+        }
+    }
+
+    /// The canonical method to create a [`MutableGeometryCollectionArray`] out of its internal
+    /// components.
+    /// # Implementation
+    /// This function is `O(1)`.
+    ///
+    /// # Errors
+    /// This function errors iff:
+    /// * The validity is not `None` and its length is different from the number of geometries
+    pub fn try_new(
+        children: MutableBinaryArray<i64>,
+        geom_offsets: Offsets<i64>,
+        validity: Option<MutableBitmap>,
+    ) -> Result<Self, GeoArrowError> {
+        check(
+            children.len(),
+            validity.as_ref().map(|x| x.len()),
+            &geom_offsets.clone().into(),
+        )?;
+        Ok(Self {
+            children,
+            geom_offsets,
+            validity,
+        })
+    }
+
+    /// Extract the low-level APIs from the [`MutableGeometryCollectionArray`].
+    pub fn into_inner(self) -> MutableGeometryCollectionParts {
+        (self.children, self.geom_offsets, self.validity)
+    }
+
+    pub fn into_arrow(self) -> arrow2::array::ListArray<i64> {
+        let arr: GeometryCollectionArray = self.into();
+        arr.into_arrow()
+    }
+}
+
+impl Default for MutableGeometryCollectionArray {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<MutableGeometryCollectionArray> for GeometryCollectionArray {
+    fn from(other: MutableGeometryCollectionArray) -> Self {
+        let validity = other.validity.and_then(|x| {
+            let bitmap: Bitmap = x.into();
+            if bitmap.unset_bits() == 0 {
+                None
+            } else {
+                Some(bitmap)
+            }
+        });
+
+        let geom_offsets: OffsetsBuffer<i64> = other.geom_offsets.into();
+        let children: arrow2::array::BinaryArray<i64> = other.children.into();
+
+        Self::new(children, geom_offsets, validity)
+    }
+}
+
+impl From<Vec<GeometryCollection>> for MutableGeometryCollectionArray {
+    fn from(geoms: Vec<GeometryCollection>) -> Self {
+        let mut children = MutableBinaryArray::<i64>::with_capacity(geoms.len());
+        let mut geom_offsets = Offsets::<i64>::with_capacity(geoms.len());
+
+        for collection in &geoms {
+            geom_offsets.try_push_usize(collection.0.len()).unwrap();
+
+            for geom in &collection.0 {
+                let wkb = geom.to_wkb(CoordDimensions::xy()).unwrap();
+                children.push(Some(wkb));
+            }
+        }
+
+        MutableGeometryCollectionArray {
+            children,
+            geom_offsets,
+            validity: None,
+        }
+    }
+}
+
+impl From<Vec<Option<GeometryCollection>>> for MutableGeometryCollectionArray {
+    fn from(geoms: Vec<Option<GeometryCollection>>) -> Self {
+        let mut validity = MutableBitmap::with_capacity(geoms.len());
+        let mut children = MutableBinaryArray::<i64>::with_capacity(geoms.len());
+        let mut geom_offsets = Offsets::<i64>::with_capacity(geoms.len());
+
+        for maybe_collection in &geoms {
+            if let Some(collection) = maybe_collection {
+                validity.push(true);
+                geom_offsets.try_push_usize(collection.0.len()).unwrap();
+            } else {
+                validity.push(false);
+                geom_offsets.try_push_usize(0).unwrap();
+            }
+        }
+
+        for collection in geoms.into_iter().flatten() {
+            for geom in collection.0 {
+                let wkb = geom.to_wkb(CoordDimensions::xy()).unwrap();
+                children.push(Some(wkb));
+            }
+        }
+
+        MutableGeometryCollectionArray {
+            children,
+            geom_offsets,
+            validity: Some(validity),
+        }
+    }
+}
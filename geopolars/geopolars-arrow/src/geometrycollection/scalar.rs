@@ -0,0 +1,78 @@
+use arrow2::array::BinaryArray;
+use arrow2::offset::OffsetsBuffer;
+use geo::BoundingRect;
+use geozero::wkb::Wkb;
+use geozero::ToGeo;
+use rstar::{RTreeObject, AABB};
+
+/// An arrow equivalent of a GeometryCollection.
+///
+/// Unlike the other scalar types, a collection's children aren't restricted to a single geometry
+/// type, so each child is stored as a WKB-encoded geometry rather than as raw coordinate buffers.
+#[derive(Debug, Clone)]
+pub struct GeometryCollection<'a> {
+    /// WKB-encoded child geometries, flattened across every row
+    pub children: &'a BinaryArray<i64>,
+
+    /// Offsets into `children` where each row's geometries start
+    pub geom_offsets: &'a OffsetsBuffer<i64>,
+
+    pub geom_index: usize,
+}
+
+impl<'a> GeometryCollection<'a> {
+    /// Returns the number of child geometries in this row
+    pub fn num_geometries(&self) -> usize {
+        let (start, end) = self.geom_offsets.start_end(self.geom_index);
+        end - start
+    }
+
+    /// Returns the child geometry at `i`, decoded from its WKB encoding
+    pub fn geometry(&self, i: usize) -> Option<geo::Geometry> {
+        let (start, end) = self.geom_offsets.start_end(self.geom_index);
+        if i >= (end - start) {
+            return None;
+        }
+
+        let buf = self.children.value(start + i);
+        Some(Wkb(buf.to_vec()).to_geo().unwrap())
+    }
+}
+
+impl From<GeometryCollection<'_>> for geo::GeometryCollection {
+    fn from(value: GeometryCollection<'_>) -> Self {
+        (&value).into()
+    }
+}
+
+impl From<&GeometryCollection<'_>> for geo::GeometryCollection {
+    fn from(value: &GeometryCollection<'_>) -> Self {
+        let (start, end) = value.geom_offsets.start_end(value.geom_index);
+        let geoms: Vec<geo::Geometry> = (start..end)
+            .map(|i| {
+                let buf = value.children.value(i);
+                Wkb(buf.to_vec()).to_geo().unwrap()
+            })
+            .collect();
+
+        geo::GeometryCollection(geoms)
+    }
+}
+
+impl From<GeometryCollection<'_>> for geo::Geometry {
+    fn from(value: GeometryCollection<'_>) -> Self {
+        geo::Geometry::GeometryCollection(value.into())
+    }
+}
+
+impl RTreeObject for GeometryCollection<'_> {
+    type Envelope = AABB<[f64; 2]>;
+
+    fn envelope(&self) -> Self::Envelope {
+        let geom: geo::GeometryCollection = self.into();
+        let rect = geom.bounding_rect().unwrap();
+        let lower: [f64; 2] = rect.min().into();
+        let upper: [f64; 2] = rect.max().into();
+        AABB::from_corners(lower, upper)
+    }
+}
@@ -0,0 +1,241 @@
+use geozero::error::Result;
+use geozero::{CoordDimensions, GeomProcessor};
+
+/// Wraps any [`GeomProcessor`], applying `transform` to every coordinate before it reaches the
+/// wrapped processor's `xy`/`coordinate` calls. Every structural callback (`*_begin`/`*_end`,
+/// `srid`, `dimensions`) is forwarded to `inner` unchanged, so geometry topology and metadata are
+/// preserved — only the coordinate values themselves are rewritten.
+///
+/// This lets a caller stream a GeoArrow array through a reprojection closure straight into a
+/// writer (WKT, GeoJSON, ...) in a single pass, with no intermediate array of transformed
+/// coordinates: `source.process_geom(&mut WrappedXYProcessor::new(&mut writer, reproject))`.
+pub struct WrappedXYProcessor<'a, P: GeomProcessor, F: FnMut(f64, f64) -> (f64, f64)> {
+    inner: &'a mut P,
+    transform: F,
+}
+
+impl<'a, P: GeomProcessor, F: FnMut(f64, f64) -> (f64, f64)> WrappedXYProcessor<'a, P, F> {
+    pub fn new(inner: &'a mut P, transform: F) -> Self {
+        Self { inner, transform }
+    }
+}
+
+impl<'a, P: GeomProcessor, F: FnMut(f64, f64) -> (f64, f64)> GeomProcessor
+    for WrappedXYProcessor<'a, P, F>
+{
+    fn dimensions(&self) -> CoordDimensions {
+        self.inner.dimensions()
+    }
+
+    fn multi_dim(&self) -> bool {
+        self.inner.multi_dim()
+    }
+
+    fn srid(&mut self, srid: Option<i32>) -> Result<()> {
+        self.inner.srid(srid)
+    }
+
+    fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
+        let (x, y) = (self.transform)(x, y);
+        self.inner.xy(x, y, idx)
+    }
+
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        t: Option<f64>,
+        tm: Option<u64>,
+        idx: usize,
+    ) -> Result<()> {
+        let (x, y) = (self.transform)(x, y);
+        self.inner.coordinate(x, y, z, m, t, tm, idx)
+    }
+
+    fn point_begin(&mut self, idx: usize) -> Result<()> {
+        self.inner.point_begin(idx)
+    }
+
+    fn point_end(&mut self, idx: usize) -> Result<()> {
+        self.inner.point_end(idx)
+    }
+
+    fn multipoint_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.inner.multipoint_begin(size, idx)
+    }
+
+    fn multipoint_end(&mut self, idx: usize) -> Result<()> {
+        self.inner.multipoint_end(idx)
+    }
+
+    fn linestring_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        self.inner.linestring_begin(tagged, size, idx)
+    }
+
+    fn linestring_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.inner.linestring_end(tagged, idx)
+    }
+
+    fn multilinestring_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.inner.multilinestring_begin(size, idx)
+    }
+
+    fn multilinestring_end(&mut self, idx: usize) -> Result<()> {
+        self.inner.multilinestring_end(idx)
+    }
+
+    fn polygon_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        self.inner.polygon_begin(tagged, size, idx)
+    }
+
+    fn polygon_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.inner.polygon_end(tagged, idx)
+    }
+
+    fn multipolygon_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.inner.multipolygon_begin(size, idx)
+    }
+
+    fn multipolygon_end(&mut self, idx: usize) -> Result<()> {
+        self.inner.multipolygon_end(idx)
+    }
+
+    fn geometrycollection_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.inner.geometrycollection_begin(size, idx)
+    }
+
+    fn geometrycollection_end(&mut self, idx: usize) -> Result<()> {
+        self.inner.geometrycollection_end(idx)
+    }
+
+    fn circularstring_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.inner.circularstring_begin(size, idx)
+    }
+
+    fn circularstring_end(&mut self, idx: usize) -> Result<()> {
+        self.inner.circularstring_end(idx)
+    }
+
+    fn compoundcurve_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.inner.compoundcurve_begin(size, idx)
+    }
+
+    fn compoundcurve_end(&mut self, idx: usize) -> Result<()> {
+        self.inner.compoundcurve_end(idx)
+    }
+
+    fn curvepolygon_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.inner.curvepolygon_begin(size, idx)
+    }
+
+    fn curvepolygon_end(&mut self, idx: usize) -> Result<()> {
+        self.inner.curvepolygon_end(idx)
+    }
+
+    fn multicurve_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.inner.multicurve_begin(size, idx)
+    }
+
+    fn multicurve_end(&mut self, idx: usize) -> Result<()> {
+        self.inner.multicurve_end(idx)
+    }
+
+    fn multisurface_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.inner.multisurface_begin(size, idx)
+    }
+
+    fn multisurface_end(&mut self, idx: usize) -> Result<()> {
+        self.inner.multisurface_end(idx)
+    }
+
+    fn triangle_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        self.inner.triangle_begin(tagged, size, idx)
+    }
+
+    fn triangle_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.inner.triangle_end(tagged, idx)
+    }
+
+    fn polyhedralsurface_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.inner.polyhedralsurface_begin(size, idx)
+    }
+
+    fn polyhedralsurface_end(&mut self, idx: usize) -> Result<()> {
+        self.inner.polyhedralsurface_end(idx)
+    }
+
+    fn tin_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.inner.tin_begin(size, idx)
+    }
+
+    fn tin_end(&mut self, idx: usize) -> Result<()> {
+        self.inner.tin_end(idx)
+    }
+}
+
+/// Extension trait adding [`WrappedXYProcessor`] support to any `geozero`-processable source.
+///
+/// `array.process_geom_with(transform, &mut writer)` streams `array` through `writer`, rewriting
+/// every coordinate with `transform` on the fly, without materializing an intermediate array.
+pub trait GeozeroGeometryExt: geozero::GeozeroGeometry {
+    fn process_geom_with<F: FnMut(f64, f64) -> (f64, f64), P: GeomProcessor>(
+        &self,
+        transform: F,
+        processor: &mut P,
+    ) -> Result<()> {
+        let mut wrapped = WrappedXYProcessor::new(processor, transform);
+        self.process_geom(&mut wrapped)
+    }
+}
+
+impl<T: geozero::GeozeroGeometry> GeozeroGeometryExt for T {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::multipoint::array::MultiPointArray;
+
+    /// Records every coordinate it sees along with how many `multipoint_begin`/`_end` pairs it
+    /// was sent, so a test can assert both that coordinates were transformed and that structural
+    /// callbacks reached it unchanged.
+    #[derive(Default)]
+    struct RecordingProcessor {
+        coords: Vec<(f64, f64)>,
+        multipoint_begins: usize,
+        multipoint_ends: usize,
+    }
+
+    impl GeomProcessor for RecordingProcessor {
+        fn xy(&mut self, x: f64, y: f64, _idx: usize) -> Result<()> {
+            self.coords.push((x, y));
+            Ok(())
+        }
+
+        fn multipoint_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+            self.multipoint_begins += 1;
+            Ok(())
+        }
+
+        fn multipoint_end(&mut self, _idx: usize) -> Result<()> {
+            self.multipoint_ends += 1;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn process_geom_with_translates_coords_and_forwards_structure() {
+        let arr: MultiPointArray =
+            vec![geo::MultiPoint::new(vec![geo::Point::new(1., 2.), geo::Point::new(3., 4.)])]
+                .into();
+
+        let mut recorder = RecordingProcessor::default();
+        arr.process_geom_with(|x, y| (x + 10., y + 100.), &mut recorder)
+            .unwrap();
+
+        assert_eq!(recorder.coords, vec![(11., 102.), (13., 104.)]);
+        assert_eq!(recorder.multipoint_begins, 1);
+        assert_eq!(recorder.multipoint_ends, 1);
+    }
+}
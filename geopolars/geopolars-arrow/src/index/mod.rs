@@ -0,0 +1,5 @@
+//! A static spatial index built on top of [`RectArray`](crate::RectArray) bounding boxes.
+
+pub use packed_rtree::PackedHilbertRTree;
+
+mod packed_rtree;
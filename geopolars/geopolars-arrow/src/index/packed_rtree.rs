@@ -0,0 +1,391 @@
+use crate::RectArray;
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+/// The number of children packed under each non-leaf node.
+///
+/// This mirrors the node size used by JS's `flatbush`/`geoarrow`-adjacent packed Hilbert R-trees:
+/// small enough to keep query fan-out cheap, large enough to keep the tree shallow.
+const NODE_SIZE: usize = 16;
+
+/// A read-only, cache-friendly spatial index over a [`RectArray`] of bounding boxes.
+///
+/// Building the index sorts item indices by the Hilbert curve value of each box's center, then
+/// packs them bottom-up into fixed-size (`NODE_SIZE`) nodes, each storing the union bbox of its
+/// children. Everything lives in three flat `Vec`s (`boxes`, `indices`, `level_bounds`), so the
+/// whole structure is allocation-light to build and trivially cheap to query: [`Self::query`]
+/// descends the tree with an explicit stack rather than recursion, pruning any node whose bbox
+/// doesn't intersect the query rectangle.
+///
+/// There is no incremental insert: the index is built once from a [`RectArray`] (typically the
+/// output of [`bounds`](crate::trait_::GeometryArrayTrait::bounds)) and queried many times.
+#[derive(Debug, Clone)]
+pub struct PackedHilbertRTree {
+    num_items: usize,
+    /// Node index where each level starts, plus a trailing entry equal to the total node count.
+    /// Level `0` (the leaves) spans `level_bounds[0]..level_bounds[1]`, level `1` spans
+    /// `level_bounds[1]..level_bounds[2]`, and so on up to the single root node.
+    level_bounds: Vec<usize>,
+    /// `[minx, miny, maxx, maxy]` per node, across every level, leaves first.
+    boxes: Vec<f64>,
+    /// One entry per node. For a leaf node, the original index of that item in the `RectArray`
+    /// this index was built from. For a non-leaf node, the node index of its first child.
+    indices: Vec<u32>,
+}
+
+impl PackedHilbertRTree {
+    /// Builds a packed Hilbert R-tree over every box in `rects`.
+    pub fn new(rects: &RectArray) -> Self {
+        let num_items = rects.len();
+
+        if num_items == 0 {
+            return Self {
+                num_items,
+                level_bounds: vec![0],
+                boxes: Vec::new(),
+                indices: Vec::new(),
+            };
+        }
+
+        // Number of nodes at each level, leaves (level 0) first, shrinking by NODE_SIZE each
+        // level until a single root node remains.
+        let mut level_num_nodes = vec![num_items];
+        let mut n = num_items;
+        while n > 1 {
+            n = (n + NODE_SIZE - 1) / NODE_SIZE;
+            level_num_nodes.push(n);
+        }
+
+        let mut level_bounds = Vec::with_capacity(level_num_nodes.len() + 1);
+        level_bounds.push(0);
+        for &level_n in &level_num_nodes {
+            level_bounds.push(level_bounds.last().unwrap() + level_n);
+        }
+        let total_nodes = *level_bounds.last().unwrap();
+
+        let mut boxes = vec![0f64; total_nodes * 4];
+        let mut indices = vec![0u32; total_nodes];
+
+        // The overall extent is used to normalize box centers onto the 16-bit-per-axis Hilbert
+        // grid; it doesn't need to be exact, just cover every item.
+        let (mut min_x, mut min_y, mut max_x, mut max_y) =
+            (f64::INFINITY, f64::INFINITY, -f64::INFINITY, -f64::INFINITY);
+        for i in 0..num_items {
+            let rect = rects.value(i);
+            min_x = min_x.min(rect.minx());
+            min_y = min_y.min(rect.miny());
+            max_x = max_x.max(rect.maxx());
+            max_y = max_y.max(rect.maxy());
+        }
+
+        let mut sorted_items: Vec<usize> = (0..num_items).collect();
+        sorted_items.sort_by_key(|&i| {
+            let rect = rects.value(i);
+            let cx = (rect.minx() + rect.maxx()) / 2.0;
+            let cy = (rect.miny() + rect.maxy()) / 2.0;
+            hilbert_xy_to_d(
+                16,
+                hilbert_coord(cx, min_x, max_x),
+                hilbert_coord(cy, min_y, max_y),
+            )
+        });
+
+        // Fill the leaf level (level 0) in Hilbert order.
+        for (slot, &item_idx) in sorted_items.iter().enumerate() {
+            let rect = rects.value(item_idx);
+            boxes[slot * 4] = rect.minx();
+            boxes[slot * 4 + 1] = rect.miny();
+            boxes[slot * 4 + 2] = rect.maxx();
+            boxes[slot * 4 + 3] = rect.maxy();
+            indices[slot] = item_idx as u32;
+        }
+
+        // Pack each subsequent level bottom-up from the union bbox of NODE_SIZE children.
+        for level in 0..level_num_nodes.len() - 1 {
+            let children_start = level_bounds[level];
+            let children_end = level_bounds[level + 1];
+            let parent_start = level_bounds[level + 1];
+
+            let mut child = children_start;
+            let mut parent = parent_start;
+            while child < children_end {
+                let child_group_end = (child + NODE_SIZE).min(children_end);
+
+                let (mut minx, mut miny, mut maxx, mut maxy) =
+                    (f64::INFINITY, f64::INFINITY, -f64::INFINITY, -f64::INFINITY);
+                for c in child..child_group_end {
+                    minx = minx.min(boxes[c * 4]);
+                    miny = miny.min(boxes[c * 4 + 1]);
+                    maxx = maxx.max(boxes[c * 4 + 2]);
+                    maxy = maxy.max(boxes[c * 4 + 3]);
+                }
+
+                boxes[parent * 4] = minx;
+                boxes[parent * 4 + 1] = miny;
+                boxes[parent * 4 + 2] = maxx;
+                boxes[parent * 4 + 3] = maxy;
+                indices[parent] = child as u32;
+
+                child = child_group_end;
+                parent += 1;
+            }
+        }
+
+        Self {
+            num_items,
+            level_bounds,
+            boxes,
+            indices,
+        }
+    }
+
+    /// The number of items this index was built from.
+    pub fn len(&self) -> usize {
+        self.num_items
+    }
+
+    /// Returns true if this index was built from an empty `RectArray`.
+    pub fn is_empty(&self) -> bool {
+        self.num_items == 0
+    }
+
+    /// Returns the original `RectArray` index of every item whose bounding box intersects
+    /// `(min_x, min_y, max_x, max_y)`.
+    ///
+    /// This descends the tree with an explicit stack, pushing the children of any visited node
+    /// whose box intersects the query rectangle and skipping the rest, so the cost is
+    /// proportional to the number of nodes actually overlapping the query, not `self.len()`.
+    pub fn query(&self, min_x: f64, min_y: f64, max_x: f64, max_y: f64) -> Vec<u32> {
+        let mut results = Vec::new();
+        if self.is_empty() {
+            return results;
+        }
+
+        let num_levels = self.level_bounds.len() - 1;
+        let root = self.boxes.len() / 4 - 1;
+        let mut stack = vec![(root, num_levels - 1)];
+
+        while let Some((node_index, level)) = stack.pop() {
+            let b = node_index * 4;
+            let intersects = self.boxes[b] <= max_x
+                && self.boxes[b + 1] <= max_y
+                && self.boxes[b + 2] >= min_x
+                && self.boxes[b + 3] >= min_y;
+            if !intersects {
+                continue;
+            }
+
+            if level == 0 {
+                results.push(self.indices[node_index]);
+                continue;
+            }
+
+            let child_start = self.indices[node_index] as usize;
+            let child_end = (child_start + NODE_SIZE).min(self.level_bounds[level]);
+            for child in child_start..child_end {
+                stack.push((child, level - 1));
+            }
+        }
+
+        results
+    }
+
+    /// Returns up to `k` item indices, nearest first, ordered by the distance from `(px, py)` to
+    /// each item's bounding box.
+    ///
+    /// This is a best-first search: a [`BinaryHeap`] always expands whichever candidate (node or
+    /// item) currently has the smallest possible distance to `(px, py)`, so nodes that can't hold
+    /// anything closer than what's already in `results` are never visited.
+    pub fn nearest(&self, px: f64, py: f64, k: usize) -> Vec<u32> {
+        let mut results = Vec::new();
+        if self.is_empty() || k == 0 {
+            return results;
+        }
+
+        let num_levels = self.level_bounds.len() - 1;
+        let root = self.boxes.len() / 4 - 1;
+
+        let mut heap = BinaryHeap::new();
+        heap.push(Candidate {
+            dist: self.box_dist_sq(root, px, py),
+            node_index: root,
+            level: num_levels - 1,
+        });
+
+        while let Some(candidate) = heap.pop() {
+            if results.len() >= k {
+                break;
+            }
+
+            if candidate.level == 0 {
+                results.push(self.indices[candidate.node_index]);
+                continue;
+            }
+
+            let child_start = self.indices[candidate.node_index] as usize;
+            let child_end = (child_start + NODE_SIZE).min(self.level_bounds[candidate.level]);
+            for child in child_start..child_end {
+                heap.push(Candidate {
+                    dist: self.box_dist_sq(child, px, py),
+                    node_index: child,
+                    level: candidate.level - 1,
+                });
+            }
+        }
+
+        results
+    }
+
+    /// Squared distance from `(px, py)` to the closest point of node `node_index`'s box (`0` if
+    /// `(px, py)` is inside it).
+    fn box_dist_sq(&self, node_index: usize, px: f64, py: f64) -> f64 {
+        let b = node_index * 4;
+        let dx = if px < self.boxes[b] {
+            self.boxes[b] - px
+        } else if px > self.boxes[b + 2] {
+            px - self.boxes[b + 2]
+        } else {
+            0.0
+        };
+        let dy = if py < self.boxes[b + 1] {
+            self.boxes[b + 1] - py
+        } else if py > self.boxes[b + 3] {
+            py - self.boxes[b + 3]
+        } else {
+            0.0
+        };
+        dx * dx + dy * dy
+    }
+}
+
+/// A candidate node or leaf item awaiting expansion in [`PackedHilbertRTree::nearest`], ordered
+/// by ascending `dist` so a [`BinaryHeap`] (a max-heap) pops the closest candidate first.
+struct Candidate {
+    dist: f64,
+    node_index: usize,
+    level: usize,
+}
+
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Self) -> bool {
+        self.dist == other.dist
+    }
+}
+impl Eq for Candidate {}
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.dist.partial_cmp(&self.dist).unwrap()
+    }
+}
+
+/// Maps `value` onto a `0..=65535` grid coordinate given the overall `[min, max]` extent.
+fn hilbert_coord(value: f64, min: f64, max: f64) -> u32 {
+    if max <= min {
+        return 0;
+    }
+    let t = (value - min) / (max - min);
+    (t * 65535.0).clamp(0.0, 65535.0) as u32
+}
+
+/// Converts an `(x, y)` point on a `2^order`-per-axis grid to its distance along the Hilbert
+/// curve, per the standard iterative xy2d algorithm.
+fn hilbert_xy_to_d(order: u32, mut x: u32, mut y: u32) -> u64 {
+    let n: u32 = 1 << order;
+    let mut d: u64 = 0;
+    let mut s = n / 2;
+    while s > 0 {
+        let rx: u32 = if (x & s) > 0 { 1 } else { 0 };
+        let ry: u32 = if (y & s) > 0 { 1 } else { 0 };
+        d += (s as u64) * (s as u64) * ((3 * rx) ^ ry) as u64;
+
+        // Rotate/flip the quadrant.
+        if ry == 0 {
+            if rx == 1 {
+                x = n - 1 - x;
+                y = n - 1 - y;
+            }
+            std::mem::swap(&mut x, &mut y);
+        }
+
+        s /= 2;
+    }
+    d
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rects_from(boxes: Vec<[f64; 4]>) -> RectArray {
+        let mut values = Vec::with_capacity(boxes.len() * 4);
+        for b in boxes {
+            values.extend_from_slice(&b);
+        }
+        RectArray::new(values.into(), None)
+    }
+
+    #[test]
+    fn query_finds_only_intersecting_boxes() {
+        let rects = rects_from(vec![
+            [0.0, 0.0, 1.0, 1.0],
+            [10.0, 10.0, 11.0, 11.0],
+            [0.5, 0.5, 1.5, 1.5],
+            [100.0, 100.0, 101.0, 101.0],
+        ]);
+        let index = PackedHilbertRTree::new(&rects);
+
+        let mut hits = index.query(0.0, 0.0, 1.0, 1.0);
+        hits.sort_unstable();
+        assert_eq!(hits, vec![0, 2]);
+    }
+
+    #[test]
+    fn query_on_empty_index_returns_nothing() {
+        let rects = rects_from(vec![]);
+        let index = PackedHilbertRTree::new(&rects);
+        assert!(index.is_empty());
+        assert!(index.query(0.0, 0.0, 1.0, 1.0).is_empty());
+    }
+
+    #[test]
+    fn query_covers_every_item_with_many_items() {
+        let boxes: Vec<[f64; 4]> = (0..500)
+            .map(|i| {
+                let x = i as f64;
+                [x, x, x + 1.0, x + 1.0]
+            })
+            .collect();
+        let rects = rects_from(boxes);
+        let index = PackedHilbertRTree::new(&rects);
+        assert_eq!(index.len(), 500);
+
+        let mut hits = index.query(-1.0, -1.0, 1000.0, 1000.0);
+        hits.sort_unstable();
+        assert_eq!(hits, (0..500).collect::<Vec<u32>>());
+    }
+
+    #[test]
+    fn nearest_returns_closest_items_in_order() {
+        let rects = rects_from(vec![
+            [10.0, 10.0, 11.0, 11.0],
+            [0.0, 0.0, 1.0, 1.0],
+            [100.0, 100.0, 101.0, 101.0],
+            [2.0, 2.0, 3.0, 3.0],
+        ]);
+        let index = PackedHilbertRTree::new(&rects);
+
+        assert_eq!(index.nearest(0.0, 0.0, 2), vec![1, 3]);
+    }
+
+    #[test]
+    fn nearest_on_empty_index_returns_nothing() {
+        let rects = rects_from(vec![]);
+        let index = PackedHilbertRTree::new(&rects);
+        assert!(index.nearest(0.0, 0.0, 5).is_empty());
+    }
+}
@@ -3,26 +3,45 @@
 //! At some point in the future, this will likely become a public standalone geoarrow module, or be
 //! integrated into geozero
 
-pub use binary::{MutableWKBArray, WKBArray, WKB};
+pub use binary::{predicate_holds, MutableWKBArray, SpatialPredicate, WKBArray, WKB};
+#[cfg(feature = "binary_view")]
+pub use binary::{MutableWKBViewArray, WKBView, WKBViewArray};
+pub use coord::{CoordBuffer, CoordType, MutableCoordBuffer};
 pub use enum_::{GeometryArrayEnum, GeometryType};
+pub use geometrycollection::{
+    GeometryCollection, GeometryCollectionArray, MutableGeometryCollectionArray,
+};
+pub use index::PackedHilbertRTree;
 pub use linestring::{LineString, LineStringArray, MutableLineStringArray};
+pub use mixed::{MixedGeometryArray, MutableMixedGeometryArray};
 pub use multilinestring::{MultiLineString, MultiLineStringArray, MutableMultiLineStringArray};
 pub use multipoint::{MultiPoint, MultiPointArray, MutableMultiPointArray};
 pub use multipolygon::{MultiPolygon, MultiPolygonArray, MutableMultiPolygonArray};
 pub use point::{MutablePointArray, Point, PointArray};
 pub use polygon::{MutablePolygonArray, Polygon, PolygonArray};
+pub use rect::{MutableRectArray, Rect, RectArray, RectTrait};
 pub use trait_::GeometryArray;
+pub use wkt::{MutableWKTArray, WKTArray, WKT};
 
 pub mod algorithm;
 pub mod binary;
+pub mod coord;
 pub mod enum_;
 pub mod error;
+pub mod ffi;
 pub mod geo_traits;
+pub mod geometrycollection;
+pub mod geozero_ext;
+pub mod index;
 pub mod linestring;
+pub mod mixed;
 pub mod multilinestring;
 pub mod multipoint;
 pub mod multipolygon;
+pub mod offset;
 pub mod point;
 pub mod polygon;
+pub mod rect;
 pub mod trait_;
 pub mod util;
+pub mod wkt;
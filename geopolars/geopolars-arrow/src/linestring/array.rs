@@ -1,10 +1,11 @@
+use crate::coord::{CoordBuffer, MutableCoordBuffer};
 use crate::enum_::GeometryType;
 use crate::error::GeoArrowError;
 use crate::trait_::GeometryArray;
 use crate::MultiPointArray;
-use arrow2::array::{Array, ListArray, PrimitiveArray, StructArray};
+use arrow2::array::{Array, FixedSizeListArray, ListArray, PrimitiveArray, StructArray};
 use arrow2::bitmap::utils::{BitmapIter, ZipValidity};
-use arrow2::bitmap::Bitmap;
+use arrow2::bitmap::{Bitmap, MutableBitmap};
 use arrow2::buffer::Buffer;
 use arrow2::datatypes::{DataType, Field};
 use arrow2::offset::OffsetsBuffer;
@@ -15,13 +16,13 @@ use super::MutableLineStringArray;
 
 /// A [`GeometryArray`] semantically equivalent to `Vec<Option<LineString>>` using Arrow's
 /// in-memory representation.
+///
+/// Coordinates are stored in a [`CoordBuffer`], which may be either the separated `x`/`y`
+/// layout every producer in this crate used before [`CoordBuffer`] existed, or an interleaved
+/// `[x0, y0, x1, y1, ...]` layout ingested zero-copy from other GeoArrow producers.
 #[derive(Debug, Clone)]
 pub struct LineStringArray {
-    /// Buffer of x coordinates
-    x: Buffer<f64>,
-
-    /// Buffer of y coordinates
-    y: Buffer<f64>,
+    coords: CoordBuffer,
 
     /// Offsets into the coordinate array where each geometry starts
     geom_offsets: OffsetsBuffer<i64>,
@@ -31,28 +32,22 @@ pub struct LineStringArray {
 }
 
 pub(super) fn check(
-    x: &[f64],
-    y: &[f64],
+    coords: &CoordBuffer,
     validity_len: Option<usize>,
     geom_offsets: &OffsetsBuffer<i64>,
 ) -> Result<(), GeoArrowError> {
-    // TODO: check geom offsets?
+    // TODO: check geom offsets against coords.len()?
+    let _ = coords;
     if validity_len.map_or(false, |len| len != geom_offsets.len()) {
         return Err(GeoArrowError::General(
             "validity mask length must match the number of values".to_string(),
         ));
     }
-
-    if x.len() != y.len() {
-        return Err(GeoArrowError::General(
-            "x and y arrays must have the same length".to_string(),
-        ));
-    }
     Ok(())
 }
 
 impl LineStringArray {
-    /// Create a new LineStringArray from parts
+    /// Create a new LineStringArray from a separated `x`/`y` pair.
     /// # Implementation
     /// This function is `O(1)`.
     pub fn new(
@@ -61,16 +56,10 @@ impl LineStringArray {
         geom_offsets: OffsetsBuffer<i64>,
         validity: Option<Bitmap>,
     ) -> Self {
-        check(&x, &y, validity.as_ref().map(|v| v.len()), &geom_offsets).unwrap();
-        Self {
-            x,
-            y,
-            geom_offsets,
-            validity,
-        }
+        Self::try_new(x, y, geom_offsets, validity).unwrap()
     }
 
-    /// Create a new LineStringArray from parts
+    /// Create a new LineStringArray from a separated `x`/`y` pair.
     /// # Implementation
     /// This function is `O(1)`.
     pub fn try_new(
@@ -79,10 +68,21 @@ impl LineStringArray {
         geom_offsets: OffsetsBuffer<i64>,
         validity: Option<Bitmap>,
     ) -> Result<Self, GeoArrowError> {
-        check(&x, &y, validity.as_ref().map(|v| v.len()), &geom_offsets)?;
+        Self::try_new_from_coords(CoordBuffer::Separated(x, y), geom_offsets, validity)
+    }
+
+    /// Create a new LineStringArray from a [`CoordBuffer`] in either the separated or
+    /// interleaved layout.
+    /// # Implementation
+    /// This function is `O(1)`.
+    pub fn try_new_from_coords(
+        coords: CoordBuffer,
+        geom_offsets: OffsetsBuffer<i64>,
+        validity: Option<Bitmap>,
+    ) -> Result<Self, GeoArrowError> {
+        check(&coords, validity.as_ref().map(|v| v.len()), &geom_offsets)?;
         Ok(Self {
-            x,
-            y,
+            coords,
             geom_offsets,
             validity,
         })
@@ -99,6 +99,12 @@ impl LineStringArray {
         self.len() == 0
     }
 
+    /// The underlying [`CoordBuffer`], in whichever physical layout this array was built with.
+    #[inline]
+    pub fn coords(&self) -> &CoordBuffer {
+        &self.coords
+    }
+
     /// Returns the optional validity.
     #[inline]
     pub fn validity(&self) -> Option<&Bitmap> {
@@ -144,12 +150,53 @@ impl LineStringArray {
             .map(|bitmap| bitmap.slice_unchecked(offset, length))
             .and_then(|bitmap| (bitmap.unset_bits() > 0).then_some(bitmap));
         Self {
-            x: self.x.clone().slice_unchecked(offset, length),
-            y: self.y.clone().slice_unchecked(offset, length),
+            coords: self.coords.clone(),
             geom_offsets: self.geom_offsets.clone().slice_unchecked(offset, length),
             validity,
         }
     }
+
+    /// Returns an owned copy of the given slice, allocating fresh coordinate buffers that hold
+    /// only the vertices referenced by the sliced geometries and rebasing `geom_offsets` to
+    /// start at zero, unlike [`Self::slice`]/[`Self::slice_unchecked`] which keep the full parent
+    /// coordinate buffer alive behind an `O(1)` view. Useful when persisting a small filtered
+    /// subset or sending a slice across an FFI/serialization boundary where carrying the parent
+    /// buffer would be wasteful.
+    /// # Panic
+    /// This function panics iff `offset + length > self.len()`.
+    #[must_use]
+    pub fn owned_slice(&self, offset: usize, length: usize) -> Self {
+        assert!(
+            offset + length <= self.len(),
+            "offset + length may not exceed length of array"
+        );
+
+        let geom_offsets_slice = self.geom_offsets.as_slice();
+        let start_coord = geom_offsets_slice[offset] as usize;
+        let end_coord = geom_offsets_slice[offset + length] as usize;
+
+        let mut coords =
+            MutableCoordBuffer::with_capacity(self.coords.coord_type(), end_coord - start_coord);
+        for i in start_coord..end_coord {
+            let (x, y) = self.coords.value(i);
+            coords.push_xy(x, y);
+        }
+
+        let rebased_offsets: Vec<i64> = geom_offsets_slice[offset..=offset + length]
+            .iter()
+            .map(|&o| o - start_coord as i64)
+            .collect();
+
+        let validity = self.validity.as_ref().map(|bitmap| {
+            MutableBitmap::from_iter(bitmap.iter().skip(offset).take(length)).into()
+        });
+
+        Self {
+            coords: coords.into(),
+            geom_offsets: OffsetsBuffer::try_from(rebased_offsets).unwrap(),
+            validity,
+        }
+    }
 }
 
 // Implement geometry accessors
@@ -157,8 +204,7 @@ impl LineStringArray {
     /// Gets the value at slot `i`
     pub fn value(&self, i: usize) -> crate::LineString {
         crate::LineString {
-            x: &self.x,
-            y: &self.y,
+            coords: &self.coords,
             geom_offsets: &self.geom_offsets,
             geom_index: i,
         }
@@ -200,8 +246,6 @@ impl LineStringArray {
 
     /// Iterator over geo Geometry objects, not looking at validity
     pub fn iter_geo_values(&self) -> impl Iterator<Item = geo::LineString> + '_ {
-        println!("iter_geo_values");
-        dbg!(&self.len());
         (0..self.len()).map(|i| self.value_as_geo(i))
     }
 
@@ -242,33 +286,49 @@ impl LineStringArray {
         ZipValidity::new_with_validity(self.iter_geos_values(), self.validity())
     }
 
+    /// Converts this array into its Arrow representation: a `vertices` `StructArray` child when
+    /// backed by a separated [`CoordBuffer`], or a `FixedSizeList<f64>[2]` child when backed by
+    /// an interleaved one, so interleaved GeoArrow data round-trips without a re-striping copy.
     pub fn into_arrow(self) -> ListArray<i64> {
-        // Data type
-        let coord_field_x = Field::new("x", DataType::Float64, false);
-        let coord_field_y = Field::new("y", DataType::Float64, false);
-        let struct_data_type = DataType::Struct(vec![coord_field_x, coord_field_y]);
-        let list_data_type = DataType::LargeList(Box::new(Field::new(
-            "vertices",
-            struct_data_type.clone(),
-            true,
-        )));
-
-        // Validity
-        let validity: Option<Bitmap> = if let Some(validity) = self.validity {
-            validity.into()
-        } else {
-            None
-        };
-
-        // Array data
-        let array_x = PrimitiveArray::new(DataType::Float64, self.x, None).boxed();
-        let array_y = PrimitiveArray::new(DataType::Float64, self.y, None).boxed();
+        let validity: Option<Bitmap> = self.validity;
+        let coord_array = self.coords.into_arrow();
+        let coord_data_type = coord_array.data_type().clone();
 
-        let coord_array = StructArray::new(struct_data_type, vec![array_x, array_y], None).boxed();
+        let list_data_type =
+            DataType::LargeList(Box::new(Field::new("vertices", coord_data_type, true)));
 
         ListArray::new(list_data_type, self.geom_offsets, coord_array, validity)
     }
 
+    /// Convert to an Arrow [`ListArray`] backed by 32-bit (`List`, rather than `LargeList`)
+    /// offsets, halving the offset buffer size.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GeoArrowError::Overflow`] if any offset exceeds [`i32::MAX`], i.e. the array
+    /// has too many total coordinates to address with 32-bit offsets. Use [`Self::into_arrow`]
+    /// in that case.
+    pub fn into_arrow_small(self) -> Result<ListArray<i32>, GeoArrowError> {
+        if !crate::offset::fits_in_i32(&self.geom_offsets) {
+            return Err(GeoArrowError::Overflow);
+        }
+
+        let validity: Option<Bitmap> = self.validity;
+        let geom_offsets = crate::offset::narrow_offsets(&self.geom_offsets);
+        let coord_array = self.coords.into_arrow();
+        let coord_data_type = coord_array.data_type().clone();
+
+        let list_data_type =
+            DataType::List(Box::new(Field::new("vertices", coord_data_type, true)));
+
+        Ok(ListArray::new(
+            list_data_type,
+            geom_offsets,
+            coord_array,
+            validity,
+        ))
+    }
+
     /// Build a spatial index containing this array's geometries
     pub fn rstar_tree(&self) -> RTree<crate::LineString> {
         let mut tree = RTree::new();
@@ -282,28 +342,40 @@ impl TryFrom<ListArray<i64>> for LineStringArray {
 
     fn try_from(value: ListArray<i64>) -> Result<Self, Self::Error> {
         let inner_dyn_array = value.values();
-        let struct_array = inner_dyn_array
-            .as_any()
-            .downcast_ref::<StructArray>()
-            .unwrap();
         let geom_offsets = value.offsets();
         let validity = value.validity();
 
-        let x_array_values = struct_array.values()[0]
-            .as_any()
-            .downcast_ref::<PrimitiveArray<f64>>()
-            .unwrap();
-        let y_array_values = struct_array.values()[1]
-            .as_any()
-            .downcast_ref::<PrimitiveArray<f64>>()
-            .unwrap();
-
-        Ok(Self::new(
-            x_array_values.values().clone(),
-            y_array_values.values().clone(),
-            geom_offsets.clone(),
-            validity.cloned(),
-        ))
+        let coords = if let Some(struct_array) =
+            inner_dyn_array.as_any().downcast_ref::<StructArray>()
+        {
+            let x_array_values = struct_array.values()[0]
+                .as_any()
+                .downcast_ref::<PrimitiveArray<f64>>()
+                .unwrap();
+            let y_array_values = struct_array.values()[1]
+                .as_any()
+                .downcast_ref::<PrimitiveArray<f64>>()
+                .unwrap();
+
+            CoordBuffer::Separated(
+                x_array_values.values().clone(),
+                y_array_values.values().clone(),
+            )
+        } else {
+            let fixed_size_list_array = inner_dyn_array
+                .as_any()
+                .downcast_ref::<FixedSizeListArray>()
+                .unwrap();
+            let xy_values = fixed_size_list_array
+                .values()
+                .as_any()
+                .downcast_ref::<PrimitiveArray<f64>>()
+                .unwrap();
+
+            CoordBuffer::Interleaved(xy_values.values().clone())
+        };
+
+        Self::try_new_from_coords(coords, geom_offsets.clone(), validity.cloned())
     }
 }
 
@@ -311,8 +383,15 @@ impl TryFrom<Box<dyn Array>> for LineStringArray {
     type Error = GeoArrowError;
 
     fn try_from(value: Box<dyn Array>) -> Result<Self, Self::Error> {
-        let arr = value.as_any().downcast_ref::<ListArray<i64>>().unwrap();
-        arr.clone().try_into()
+        // Accept either `LargeList` (i64 offsets, our own native width) or `List` (i32 offsets,
+        // e.g. from a producer that didn't opt into large offsets) by widening the latter up
+        // front; everything past this point only ever deals with `ListArray<i64>`.
+        if let Some(arr) = value.as_any().downcast_ref::<ListArray<i64>>() {
+            arr.clone().try_into()
+        } else {
+            let arr = value.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+            crate::offset::widen_list_array(arr).try_into()
+        }
     }
 }
 
@@ -368,11 +447,20 @@ impl From<Vec<geo::LineString>> for LineStringArray {
     }
 }
 
+impl From<LineStringArray> for crate::WKBArray {
+    fn from(value: LineStringArray) -> Self {
+        let geoms: Vec<Option<geo::Geometry>> = (0..value.len())
+            .map(|i| value.get_as_geo(i).map(geo::Geometry::LineString))
+            .collect();
+        geoms.into()
+    }
+}
+
 /// LineString and MultiPoint have the same layout, so enable conversions between the two to change
-/// the semantic type
+/// the semantic type. This is `O(1)` regardless of [`CoordBuffer`] layout.
 impl From<LineStringArray> for MultiPointArray {
     fn from(value: LineStringArray) -> Self {
-        Self::new(value.x, value.y, value.geom_offsets, value.validity)
+        Self::try_new_from_coords(value.coords, value.geom_offsets, value.validity).unwrap()
     }
 }
 
@@ -390,11 +478,8 @@ impl GeozeroGeometry for LineStringArray {
             processor.linestring_begin(true, end_coord_idx - start_coord_idx, geom_idx)?;
 
             for coord_idx in start_coord_idx..end_coord_idx {
-                processor.xy(
-                    self.x[coord_idx],
-                    self.y[coord_idx],
-                    coord_idx - start_coord_idx,
-                )?;
+                let (x, y) = self.coords.value(coord_idx);
+                processor.xy(x, y, coord_idx - start_coord_idx)?;
             }
 
             processor.linestring_end(true, geom_idx)?;
@@ -465,4 +550,15 @@ mod test {
             "The second element in the LineStringArray should be found"
         );
     }
+
+    #[test]
+    fn owned_slice_compacts_coords_and_rebases_offsets() {
+        let arr: LineStringArray = vec![ls0(), ls1()].into();
+        let sliced = arr.owned_slice(1, 1);
+
+        assert_eq!(sliced.len(), 1);
+        assert_eq!(sliced.value_as_geo(0), ls1());
+        assert_eq!(sliced.coords.len(), 2);
+        assert_eq!(sliced.geom_offsets.as_slice(), &[0, 2]);
+    }
 }
@@ -1,3 +1,4 @@
+use crate::coord::{CoordType, MutableCoordBuffer};
 use crate::error::GeoArrowError;
 use crate::multipoint::MutableMultiPointArray;
 use crate::LineStringArray;
@@ -6,14 +7,16 @@ use arrow2::bitmap::{Bitmap, MutableBitmap};
 use arrow2::offset::Offsets;
 use arrow2::types::Index;
 use geo::{CoordsIter, LineString};
+use geozero::{GeomProcessor, GeozeroGeometry};
 use std::convert::From;
 
 /// The Arrow equivalent to `Vec<Option<LineString>>`.
-/// Converting a [`MutableLineStringArray`] into a [`LineStringArray`] is `O(1)`.
+/// Converting a [`MutableLineStringArray`] into a [`LineStringArray`] is `O(1)` when the
+/// coordinates are [`CoordType::Separated`]; building [`Self::into_arrow`] directly is `O(1)`
+/// regardless of [`CoordType`].
 #[derive(Debug, Clone)]
 pub struct MutableLineStringArray {
-    x: Vec<f64>,
-    y: Vec<f64>,
+    coords: MutableCoordBuffer,
 
     /// Offsets into the coordinate array where each geometry starts
     geom_offsets: Offsets<i64>,
@@ -23,22 +26,27 @@ pub struct MutableLineStringArray {
 }
 
 impl MutableLineStringArray {
-    /// Creates a new empty [`MutableLineStringArray`].
+    /// Creates a new empty [`MutableLineStringArray`] storing coordinates as [`CoordType::Separated`].
     pub fn new() -> Self {
-        Self::with_capacities(0, 0)
+        Self::with_capacities(CoordType::Separated, 0, 0)
     }
 
-    /// Creates a new [`MutableLineStringArray`] with a capacity.
-    pub fn with_capacities(coord_capacity: usize, geom_capacity: usize) -> Self {
+    /// Creates a new [`MutableLineStringArray`] with a capacity, storing coordinates in the
+    /// given [`CoordType`] layout.
+    pub fn with_capacities(
+        coord_type: CoordType,
+        coord_capacity: usize,
+        geom_capacity: usize,
+    ) -> Self {
         Self {
-            x: Vec::with_capacity(coord_capacity),
-            y: Vec::with_capacity(coord_capacity),
+            coords: MutableCoordBuffer::with_capacity(coord_type, coord_capacity),
             geom_offsets: Offsets::<i64>::with_capacity(geom_capacity),
             validity: None,
         }
     }
 
-    /// The canonical method to create a [`MutableLineStringArray`] out of its internal components.
+    /// The canonical method to create a [`MutableLineStringArray`] out of its internal
+    /// components, storing `x`/`y` as [`CoordType::Separated`].
     /// # Implementation
     /// This function is `O(1)`.
     ///
@@ -50,29 +58,53 @@ impl MutableLineStringArray {
         y: Vec<f64>,
         geom_offsets: Offsets<i64>,
         validity: Option<MutableBitmap>,
+    ) -> Result<Self, GeoArrowError> {
+        Self::try_new_with_coord_type(x, y, geom_offsets, validity, CoordType::Separated)
+    }
+
+    /// Like [`Self::try_new`], but storing `x`/`y` in the given [`CoordType`] layout.
+    /// # Implementation
+    /// This function is `O(1)` when `coord_type` is [`CoordType::Separated`]; otherwise it copies
+    /// `x` and `y` into a single interleaved buffer.
+    pub fn try_new_with_coord_type(
+        x: Vec<f64>,
+        y: Vec<f64>,
+        geom_offsets: Offsets<i64>,
+        validity: Option<MutableBitmap>,
+        coord_type: CoordType,
     ) -> Result<Self, GeoArrowError> {
         // Can't pass Offsets into the check, expected OffsetsBuffer
         // use crate::linestring::array::check;
         // check(&x, &y, validity.as_ref().map(|x| x.len()), &geom_offsets)?;
+        let coords = match coord_type {
+            CoordType::Separated => MutableCoordBuffer::Separated(x, y),
+            CoordType::Interleaved => {
+                let mut xy = Vec::with_capacity(x.len() * 2);
+                for (x, y) in x.into_iter().zip(y) {
+                    xy.push(x);
+                    xy.push(y);
+                }
+                MutableCoordBuffer::Interleaved(xy)
+            }
+        };
+
         Ok(Self {
-            x,
-            y,
+            coords,
             geom_offsets,
             validity,
         })
     }
 
     /// Extract the low-level APIs from the [`MutableLineStringArray`].
-    pub fn into_inner(self) -> (Vec<f64>, Vec<f64>, Offsets<i64>, Option<MutableBitmap>) {
-        (self.x, self.y, self.geom_offsets, self.validity)
+    pub fn into_inner(self) -> (MutableCoordBuffer, Offsets<i64>, Option<MutableBitmap>) {
+        (self.coords, self.geom_offsets, self.validity)
     }
 
     /// Adds a new value to the array.
     pub fn try_push_geo(&mut self, value: Option<LineString>) -> Result<(), GeoArrowError> {
         if let Some(line_string) = value {
             line_string.coords_iter().for_each(|c| {
-                self.x.push(c.x);
-                self.y.push(c.y);
+                self.coords.push_xy(c.x, c.y);
             });
             self.try_push_valid()?;
         } else {
@@ -85,7 +117,7 @@ impl MutableLineStringArray {
     /// Needs to be called when a valid value was extended to this array.
     /// This is a relatively low level function, prefer `try_push` when you can.
     pub fn try_push_valid(&mut self) -> Result<(), GeoArrowError> {
-        let total_length = self.x.len();
+        let total_length = self.coords.len();
         let offset = self.geom_offsets.last().to_usize();
         let length = total_length
             .checked_sub(offset)
@@ -117,9 +149,30 @@ impl MutableLineStringArray {
         self.validity = Some(validity)
     }
 
+    /// Converts this builder directly into Arrow's [`ListArray`] representation.
+    ///
+    /// Unlike converting into a [`LineStringArray`] first (a conversion that always copies the
+    /// coordinates into separated `x`/`y` buffers), this builds the coordinate child straight from this array's
+    /// [`CoordType`]: a `vertices` `StructArray` when separated, or a `FixedSizeListArray[2]` when
+    /// interleaved, so interleaved GeoArrow data round-trips without a re-striping copy.
     pub fn into_arrow(self) -> ListArray<i64> {
-        let linestring_arr: LineStringArray = self.into();
-        linestring_arr.into_arrow()
+        use arrow2::bitmap::Bitmap as ArrowBitmap;
+        use arrow2::datatypes::{DataType, Field};
+        use arrow2::offset::OffsetsBuffer;
+
+        let validity: Option<ArrowBitmap> = self.validity.and_then(|x| {
+            let bitmap: ArrowBitmap = x.into();
+            (bitmap.unset_bits() > 0).then_some(bitmap)
+        });
+
+        let geom_offsets: OffsetsBuffer<i64> = self.geom_offsets.into();
+        let coord_array = self.coords.into_arrow();
+        let coord_data_type = coord_array.data_type().clone();
+
+        let list_data_type =
+            DataType::LargeList(Box::new(Field::new("vertices", coord_data_type, true)));
+
+        ListArray::new(list_data_type, geom_offsets, coord_array, validity)
     }
 }
 
@@ -129,6 +182,10 @@ impl Default for MutableLineStringArray {
     }
 }
 
+/// Note that this always produces a [`LineStringArray`] storing separated `x`/`y` buffers,
+/// copying into that layout if `other` was interleaved. Callers that need to preserve an
+/// interleaved layout should call [`MutableLineStringArray::into_arrow`] directly instead of
+/// going through this conversion.
 impl From<MutableLineStringArray> for LineStringArray {
     fn from(other: MutableLineStringArray) -> Self {
         let validity = other.validity.and_then(|x| {
@@ -140,12 +197,9 @@ impl From<MutableLineStringArray> for LineStringArray {
             }
         });
 
-        Self::new(
-            other.x.into(),
-            other.y.into(),
-            other.geom_offsets.into(),
-            validity,
-        )
+        let (x, y) = other.coords.into_separated();
+
+        Self::new(x.into(), y.into(), other.geom_offsets.into(), validity)
     }
 }
 
@@ -160,25 +214,30 @@ impl From<MutableLineStringArray> for ListArray<i64> {
 /// Implement a converter that can be used for either Vec<LineString> or
 /// Vec<MultiPoint>
 pub(crate) fn line_string_from_geo_vec(geoms: Vec<LineString>) -> MutableLineStringArray {
+    line_string_from_geo_vec_with_coord_type(geoms, CoordType::Separated)
+}
+
+/// Like [`line_string_from_geo_vec`], storing coordinates in the given [`CoordType`] layout.
+pub(crate) fn line_string_from_geo_vec_with_coord_type(
+    geoms: Vec<LineString>,
+    coord_type: CoordType,
+) -> MutableLineStringArray {
     let mut geom_offsets = Offsets::<i64>::with_capacity(geoms.len());
 
     for geom in &geoms {
         geom_offsets.try_push_usize(geom.0.len()).unwrap();
     }
 
-    let mut x_arr = Vec::<f64>::with_capacity(geom_offsets.last().to_usize());
-    let mut y_arr = Vec::<f64>::with_capacity(geom_offsets.last().to_usize());
+    let mut coords = MutableCoordBuffer::with_capacity(coord_type, geom_offsets.last().to_usize());
 
     for geom in geoms {
         for coord in geom.coords_iter() {
-            x_arr.push(coord.x);
-            y_arr.push(coord.y);
+            coords.push_xy(coord.x, coord.y);
         }
     }
 
     MutableLineStringArray {
-        x: x_arr,
-        y: y_arr,
+        coords,
         geom_offsets,
         validity: None,
     }
@@ -188,6 +247,14 @@ pub(crate) fn line_string_from_geo_vec(geoms: Vec<LineString>) -> MutableLineStr
 /// Vec<Option<MultiPoint>>
 pub(crate) fn line_string_from_geo_option_vec(
     geoms: Vec<Option<LineString>>,
+) -> MutableLineStringArray {
+    line_string_from_geo_option_vec_with_coord_type(geoms, CoordType::Separated)
+}
+
+/// Like [`line_string_from_geo_option_vec`], storing coordinates in the given [`CoordType`] layout.
+pub(crate) fn line_string_from_geo_option_vec_with_coord_type(
+    geoms: Vec<Option<LineString>>,
+    coord_type: CoordType,
 ) -> MutableLineStringArray {
     let mut geom_offsets = Offsets::<i64>::with_capacity(geoms.len());
     let mut validity = MutableBitmap::with_capacity(geoms.len());
@@ -199,19 +266,16 @@ pub(crate) fn line_string_from_geo_option_vec(
             .unwrap();
     }
 
-    let mut x_arr = Vec::<f64>::with_capacity(geom_offsets.last().to_usize());
-    let mut y_arr = Vec::<f64>::with_capacity(geom_offsets.last().to_usize());
+    let mut coords = MutableCoordBuffer::with_capacity(coord_type, geom_offsets.last().to_usize());
 
     for geom in geoms.into_iter().flatten() {
         for coord in geom.coords_iter() {
-            x_arr.push(coord.x);
-            y_arr.push(coord.y);
+            coords.push_xy(coord.x, coord.y);
         }
     }
 
     MutableLineStringArray {
-        x: x_arr,
-        y: y_arr,
+        coords,
         geom_offsets,
         validity: Some(validity),
     }
@@ -230,9 +294,191 @@ impl From<Vec<Option<LineString>>> for MutableLineStringArray {
 }
 
 /// LineString and MultiPoint have the same layout, so enable conversions between the two to change
-/// the semantic type
+/// the semantic type. This always produces a [`MutableMultiPointArray`] storing separated `x`/`y`
+/// buffers, copying into that layout if `value` was interleaved.
 impl From<MutableLineStringArray> for MutableMultiPointArray {
     fn from(value: MutableLineStringArray) -> Self {
-        Self::try_new(value.x, value.y, value.geom_offsets, value.validity).unwrap()
+        let (x, y) = value.coords.into_separated();
+        Self::try_new(x, y, value.geom_offsets, value.validity, CoordType::Separated).unwrap()
+    }
+}
+
+/// Convert to GeoArrow LineStringArray
+pub trait ToGeoArrowLineString {
+    /// Convert to GeoArrow LineStringArray
+    fn to_geoarrow(&self) -> geozero::error::Result<LineStringArray>;
+
+    /// Convert to a GeoArrow MutableLineStringArray
+    fn to_mutable_geoarrow(&self) -> geozero::error::Result<MutableLineStringArray>;
+}
+
+impl<T: GeozeroGeometry> ToGeoArrowLineString for T {
+    fn to_geoarrow(&self) -> geozero::error::Result<LineStringArray> {
+        Ok(self.to_mutable_geoarrow()?.into())
+    }
+
+    fn to_mutable_geoarrow(&self) -> geozero::error::Result<MutableLineStringArray> {
+        let mut mutable_linestring_array = MutableLineStringArray::new();
+        self.process_geom(&mut mutable_linestring_array)?;
+        Ok(mutable_linestring_array)
+    }
+}
+
+#[allow(unused_variables)]
+impl GeomProcessor for MutableLineStringArray {
+    fn xy(&mut self, x: f64, y: f64, _idx: usize) -> geozero::error::Result<()> {
+        self.coords.push_xy(x, y);
+        Ok(())
+    }
+
+    fn linestring_begin(
+        &mut self,
+        tagged: bool,
+        size: usize,
+        idx: usize,
+    ) -> geozero::error::Result<()> {
+        Ok(())
+    }
+
+    fn linestring_end(&mut self, tagged: bool, idx: usize) -> geozero::error::Result<()> {
+        self.try_push_valid()
+            .map_err(|err| geozero::error::GeozeroError::Geometry(err.to_string()))
+    }
+
+    // Override all other trait _begin methods
+    fn circularstring_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        Err(geozero::error::GeozeroError::Geometry(
+            "Only linestring geometries allowed".to_string(),
+        ))
+    }
+
+    fn compoundcurve_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        Err(geozero::error::GeozeroError::Geometry(
+            "Only linestring geometries allowed".to_string(),
+        ))
+    }
+
+    fn tin_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        Err(geozero::error::GeozeroError::Geometry(
+            "Only linestring geometries allowed".to_string(),
+        ))
+    }
+
+    fn polygon_begin(
+        &mut self,
+        tagged: bool,
+        size: usize,
+        idx: usize,
+    ) -> geozero::error::Result<()> {
+        Err(geozero::error::GeozeroError::Geometry(
+            "Only linestring geometries allowed".to_string(),
+        ))
+    }
+
+    fn triangle_begin(
+        &mut self,
+        tagged: bool,
+        size: usize,
+        idx: usize,
+    ) -> geozero::error::Result<()> {
+        Err(geozero::error::GeozeroError::Geometry(
+            "Only linestring geometries allowed".to_string(),
+        ))
+    }
+
+    fn multicurve_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        Err(geozero::error::GeozeroError::Geometry(
+            "Only linestring geometries allowed".to_string(),
+        ))
+    }
+
+    fn multipoint_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        Err(geozero::error::GeozeroError::Geometry(
+            "Only linestring geometries allowed".to_string(),
+        ))
+    }
+
+    fn curvepolygon_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        Err(geozero::error::GeozeroError::Geometry(
+            "Only linestring geometries allowed".to_string(),
+        ))
+    }
+
+    fn multipolygon_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        Err(geozero::error::GeozeroError::Geometry(
+            "Only linestring geometries allowed".to_string(),
+        ))
+    }
+
+    fn multisurface_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        Err(geozero::error::GeozeroError::Geometry(
+            "Only linestring geometries allowed".to_string(),
+        ))
+    }
+
+    fn multilinestring_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        Err(geozero::error::GeozeroError::Geometry(
+            "Only linestring geometries allowed".to_string(),
+        ))
+    }
+
+    fn polyhedralsurface_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        Err(geozero::error::GeozeroError::Geometry(
+            "Only linestring geometries allowed".to_string(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ToGeoArrowLineString;
+    use crate::GeometryArrayTrait;
+    use geo::{line_string, Geometry, GeometryCollection, LineString, Point};
+
+    fn ls0() -> LineString {
+        line_string![
+            (x: 0., y: 1.),
+            (x: 1., y: 2.)
+        ]
+    }
+
+    fn ls1() -> LineString {
+        line_string![
+            (x: 2., y: 3.),
+            (x: 3., y: 4.)
+        ]
+    }
+
+    #[test]
+    fn from_geozero() {
+        let geo = Geometry::GeometryCollection(GeometryCollection(vec![
+            Geometry::LineString(ls0()),
+            Geometry::LineString(ls1()),
+        ]));
+        let linestring_array = geo.to_geoarrow().unwrap();
+        assert_eq!(linestring_array.value_as_geo(0), ls0());
+        assert_eq!(linestring_array.value_as_geo(1), ls1());
+    }
+
+    #[test]
+    fn from_geozero_error_multiple_geom_types() {
+        let geo = Geometry::GeometryCollection(GeometryCollection(vec![
+            Geometry::LineString(ls0()),
+            Geometry::Point(Point::new(0., 1.)),
+        ]));
+        let err = geo.to_geoarrow().unwrap_err();
+        assert!(matches!(err, geozero::error::GeozeroError::Geometry(..)));
+    }
+
+    #[test]
+    fn interleaved_coord_type_round_trips_through_arrow() {
+        use super::super::mutable::line_string_from_geo_vec_with_coord_type;
+        use crate::coord::CoordType;
+
+        let arr = line_string_from_geo_vec_with_coord_type(vec![ls0(), ls1()], CoordType::Interleaved);
+        let arrow_arr = arr.into_arrow();
+        let linestring_array: crate::LineStringArray = arrow_arr.try_into().unwrap();
+        assert_eq!(linestring_array.value_as_geo(0), ls0());
+        assert_eq!(linestring_array.value_as_geo(1), ls1());
     }
 }
@@ -1,16 +1,16 @@
+use crate::algorithm::bounding_rect::bounding_rect_linestring;
+use crate::coord::CoordBuffer;
 use crate::geo_traits::LineStringTrait;
 use crate::Point;
-use arrow2::buffer::Buffer;
 use arrow2::offset::OffsetsBuffer;
+use rstar::{RTreeObject, AABB};
 
 /// An arrow equivalent of a LineString
 #[derive(Debug, Clone)]
 pub struct LineString<'a> {
-    /// Buffer of x coordinates
-    pub x: &'a Buffer<f64>,
-
-    /// Buffer of y coordinates
-    pub y: &'a Buffer<f64>,
+    /// The [`CoordBuffer`] of the parent [`crate::LineStringArray`], in whichever physical
+    /// layout it was built with.
+    pub coords: &'a CoordBuffer,
 
     /// Offsets into the coordinate array where each geometry starts
     pub geom_offsets: &'a OffsetsBuffer<i64>,
@@ -39,14 +39,23 @@ impl<'a> LineStringTrait<'a> for LineString<'a> {
         }
 
         let point = Point {
-            x: self.x,
-            y: self.y,
+            coords: self.coords,
+            z: None,
             geom_index: start + i,
         };
         Some(point)
     }
 }
 
+impl RTreeObject for LineString<'_> {
+    type Envelope = AABB<[f64; 2]>;
+
+    fn envelope(&self) -> Self::Envelope {
+        let (lower, upper) = bounding_rect_linestring(self);
+        AABB::from_corners(lower, upper)
+    }
+}
+
 impl From<LineString<'_>> for geo::LineString {
     fn from(value: LineString<'_>) -> Self {
         (&value).into()
@@ -59,10 +68,8 @@ impl From<&LineString<'_>> for geo::LineString {
         let mut coords: Vec<geo::Coord> = Vec::with_capacity(end_idx - start_idx);
 
         for i in start_idx..end_idx {
-            coords.push(geo::Coord {
-                x: value.x[i],
-                y: value.y[i],
-            })
+            let (x, y) = value.coords.value(i);
+            coords.push(geo::Coord { x, y })
         }
 
         geo::LineString::new(coords)
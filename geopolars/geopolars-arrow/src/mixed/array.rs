@@ -0,0 +1,405 @@
+use crate::enum_::{Geometry, GeometryType};
+use crate::error::GeoArrowError;
+use crate::trait_::GeometryArray;
+use crate::{
+    GeometryArrayTrait, GeometryCollectionArray, LineStringArray, MultiLineStringArray,
+    MultiPointArray, MultiPolygonArray, PointArray, PolygonArray,
+};
+use arrow2::array::{Array, UnionArray};
+use arrow2::bitmap::Bitmap;
+use arrow2::buffer::Buffer;
+use arrow2::datatypes::{DataType, Field, UnionMode};
+use geozero::{GeomProcessor, GeozeroGeometry};
+
+const POINT: i8 = 0;
+const LINE_STRING: i8 = 1;
+const POLYGON: i8 = 2;
+const MULTI_POINT: i8 = 3;
+const MULTI_LINE_STRING: i8 = 4;
+const MULTI_POLYGON: i8 = 5;
+const GEOMETRY_COLLECTION: i8 = 6;
+
+/// A [`GeometryArray`] whose rows are not restricted to a single geometry type.
+///
+/// Every other geometry array in this crate is homogeneous (every row is, say, a `Polygon`);
+/// this one is the escape hatch for a column where rows genuinely differ, modeled after Arrow's
+/// own dense union layout: a `types` buffer says which geometry type each row is, an `offsets`
+/// buffer says which index into that type's own child array holds the row, and one child array
+/// per geometry type holds the actual values, densely packed (so a child array's length is the
+/// number of rows of that type, not [`Self::len`]).
+///
+/// A row's validity is tracked only at this top level, not in the child arrays - a null row's
+/// `types`/`offsets` entries are meaningless placeholders, never read.
+#[derive(Debug, Clone)]
+pub struct MixedGeometryArray {
+    types: Buffer<i8>,
+    offsets: Buffer<i32>,
+    points: PointArray,
+    line_strings: LineStringArray,
+    polygons: PolygonArray,
+    multi_points: MultiPointArray,
+    multi_line_strings: MultiLineStringArray,
+    multi_polygons: MultiPolygonArray,
+    geometry_collections: GeometryCollectionArray,
+    validity: Option<Bitmap>,
+}
+
+pub(super) fn check(
+    types_len: usize,
+    offsets_len: usize,
+    validity_len: Option<usize>,
+) -> Result<(), GeoArrowError> {
+    if types_len != offsets_len {
+        return Err(GeoArrowError::General(
+            "types and offsets must have the same length".to_string(),
+        ));
+    }
+    if validity_len.map_or(false, |len| len != types_len) {
+        return Err(GeoArrowError::General(
+            "validity mask length must match the number of rows".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+impl MixedGeometryArray {
+    /// Create a new [`MixedGeometryArray`] from its parts.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        types: Buffer<i8>,
+        offsets: Buffer<i32>,
+        points: PointArray,
+        line_strings: LineStringArray,
+        polygons: PolygonArray,
+        multi_points: MultiPointArray,
+        multi_line_strings: MultiLineStringArray,
+        multi_polygons: MultiPolygonArray,
+        geometry_collections: GeometryCollectionArray,
+        validity: Option<Bitmap>,
+    ) -> Self {
+        check(types.len(), offsets.len(), validity.as_ref().map(|v| v.len())).unwrap();
+        Self {
+            types,
+            offsets,
+            points,
+            line_strings,
+            polygons,
+            multi_points,
+            multi_line_strings,
+            multi_polygons,
+            geometry_collections,
+            validity,
+        }
+    }
+
+    /// Returns the number of rows in this array
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.types.len()
+    }
+
+    /// Returns true if the array is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the optional validity.
+    #[inline]
+    pub fn validity(&self) -> Option<&Bitmap> {
+        self.validity.as_ref()
+    }
+
+    #[inline]
+    fn is_null(&self, i: usize) -> bool {
+        self.validity
+            .as_ref()
+            .map(|v| !v.get_bit(i))
+            .unwrap_or(false)
+    }
+
+    /// Returns the value at slot `i`, dispatching to whichever child array that row's geometry
+    /// type lives in.
+    ///
+    /// # Panics
+    /// Panics iff `i >= self.len()`.
+    pub fn value(&self, i: usize) -> Geometry {
+        let offset = self.offsets[i] as usize;
+        match self.types[i] {
+            POINT => Geometry::Point(self.points.value(offset)),
+            LINE_STRING => Geometry::LineString(self.line_strings.value(offset)),
+            POLYGON => Geometry::Polygon(self.polygons.get(offset).unwrap()),
+            MULTI_POINT => Geometry::MultiPoint(self.multi_points.value(offset)),
+            MULTI_LINE_STRING => Geometry::MultiLineString(self.multi_line_strings.value(offset)),
+            MULTI_POLYGON => Geometry::MultiPolygon(self.multi_polygons.value(offset)),
+            GEOMETRY_COLLECTION => {
+                Geometry::GeometryCollection(self.geometry_collections.value(offset))
+            }
+            other => unreachable!("invalid MixedGeometryArray type code {other}"),
+        }
+    }
+
+    /// Gets the value at slot `i`, additionally checking the validity bitmap
+    pub fn get(&self, i: usize) -> Option<Geometry> {
+        if self.is_null(i) {
+            return None;
+        }
+
+        Some(self.value(i))
+    }
+
+    /// Returns the value at slot `i` as a geo object.
+    pub fn value_as_geo(&self, i: usize) -> geo::Geometry {
+        self.value(i).into()
+    }
+
+    /// Gets the value at slot `i` as a geo object, additionally checking the validity bitmap
+    pub fn get_as_geo(&self, i: usize) -> Option<geo::Geometry> {
+        if self.is_null(i) {
+            return None;
+        }
+
+        Some(self.value_as_geo(i))
+    }
+
+    /// Iterator over geo Geometry objects, not looking at validity
+    pub fn iter_geo_values(&self) -> impl Iterator<Item = geo::Geometry> + '_ {
+        (0..self.len()).map(|i| self.value_as_geo(i))
+    }
+
+    /// Iterator over geo Geometry objects, taking into account validity
+    pub fn iter_geo(&self) -> impl Iterator<Item = Option<geo::Geometry>> + '_ {
+        (0..self.len()).map(|i| self.get_as_geo(i))
+    }
+
+    /// Returns a clone of this array sliced by an offset and length.
+    ///
+    /// Unlike the list-backed arrays in this crate, slicing a dense union doesn't need to touch
+    /// the child arrays at all: `offsets` holds absolute indices into each (untouched) child, so
+    /// only `types`/`offsets`/`validity` need slicing.
+    /// # Panic
+    /// This function panics iff `offset + length > self.len()`.
+    #[must_use]
+    pub fn slice(&self, offset: usize, length: usize) -> Self {
+        assert!(
+            offset + length <= self.len(),
+            "offset + length may not exceed length of array"
+        );
+        unsafe { self.slice_unchecked(offset, length) }
+    }
+
+    /// # Safety
+    /// The caller must ensure that `offset + length <= self.len()`.
+    #[must_use]
+    pub unsafe fn slice_unchecked(&self, offset: usize, length: usize) -> Self {
+        let validity = self
+            .validity
+            .clone()
+            .map(|bitmap| bitmap.slice_unchecked(offset, length))
+            .and_then(|bitmap| (bitmap.unset_bits() > 0).then_some(bitmap));
+
+        Self {
+            types: self.types.clone().sliced_unchecked(offset, length),
+            offsets: self.offsets.clone().sliced_unchecked(offset, length),
+            points: self.points.clone(),
+            line_strings: self.line_strings.clone(),
+            polygons: self.polygons.clone(),
+            multi_points: self.multi_points.clone(),
+            multi_line_strings: self.multi_line_strings.clone(),
+            multi_polygons: self.multi_polygons.clone(),
+            geometry_collections: self.geometry_collections.clone(),
+            validity,
+        }
+    }
+
+    /// Converts this array into an Arrow dense `UnionArray`, one field per geometry type.
+    pub fn into_arrow(self) -> UnionArray {
+        let children: Vec<Box<dyn Array>> = vec![
+            self.points.into_arrow().boxed(),
+            self.line_strings.into_arrow().boxed(),
+            self.polygons.into_arrow().boxed(),
+            self.multi_points.into_arrow().boxed(),
+            self.multi_line_strings.into_arrow().boxed(),
+            self.multi_polygons.into_arrow().boxed(),
+            self.geometry_collections.into_arrow().boxed(),
+        ];
+
+        let names = [
+            "point",
+            "line_string",
+            "polygon",
+            "multi_point",
+            "multi_line_string",
+            "multi_polygon",
+            "geometry_collection",
+        ];
+        let fields = names
+            .iter()
+            .zip(children.iter())
+            .map(|(name, child)| Field::new(*name, child.data_type().clone(), true))
+            .collect();
+        let data_type = DataType::Union(fields, None, UnionMode::Dense);
+
+        UnionArray::new(data_type, self.types, children, Some(self.offsets))
+    }
+}
+
+impl TryFrom<UnionArray> for MixedGeometryArray {
+    type Error = GeoArrowError;
+
+    fn try_from(value: UnionArray) -> Result<Self, Self::Error> {
+        let (_fields, _type_ids, _mode) = match value.data_type() {
+            DataType::Union(fields, type_ids, mode) => (fields, type_ids, mode),
+            other => {
+                return Err(GeoArrowError::General(format!(
+                    "expected a Union data type, got {other:?}"
+                )))
+            }
+        };
+
+        let types = value.types().clone();
+        let offsets = value
+            .offsets()
+            .cloned()
+            .ok_or_else(|| GeoArrowError::General("only dense unions are supported".to_string()))?;
+        let fields = value.fields();
+
+        let points: PointArray = fields[POINT as usize].clone().try_into()?;
+        let line_strings: LineStringArray = fields[LINE_STRING as usize].clone().try_into()?;
+        let polygons: PolygonArray = fields[POLYGON as usize].clone().try_into()?;
+        let multi_points: MultiPointArray = fields[MULTI_POINT as usize].clone().try_into()?;
+        let multi_line_strings: MultiLineStringArray =
+            fields[MULTI_LINE_STRING as usize].clone().try_into()?;
+        let multi_polygons: MultiPolygonArray =
+            fields[MULTI_POLYGON as usize].clone().try_into()?;
+        let geometry_collections: GeometryCollectionArray =
+            fields[GEOMETRY_COLLECTION as usize].clone().try_into()?;
+
+        Ok(Self::new(
+            types,
+            offsets,
+            points,
+            line_strings,
+            polygons,
+            multi_points,
+            multi_line_strings,
+            multi_polygons,
+            geometry_collections,
+            None,
+        ))
+    }
+}
+
+impl TryFrom<Box<dyn Array>> for MixedGeometryArray {
+    type Error = GeoArrowError;
+
+    fn try_from(value: Box<dyn Array>) -> Result<Self, Self::Error> {
+        let arr = value
+            .as_any()
+            .downcast_ref::<UnionArray>()
+            .ok_or_else(|| GeoArrowError::General("expected a UnionArray".to_string()))?;
+        arr.clone().try_into()
+    }
+}
+
+impl GeometryArray for MixedGeometryArray {
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn geometry_type(&self) -> GeometryType {
+        GeometryType::WKB
+    }
+
+    fn validity(&self) -> Option<&Bitmap> {
+        self.validity()
+    }
+
+    fn slice(&self, offset: usize, length: usize) -> Box<dyn GeometryArray> {
+        Box::new(self.slice(offset, length))
+    }
+
+    unsafe fn slice_unchecked(&self, offset: usize, length: usize) -> Box<dyn GeometryArray> {
+        Box::new(self.slice_unchecked(offset, length))
+    }
+
+    fn to_boxed(&self) -> Box<dyn GeometryArray> {
+        Box::new(self.clone())
+    }
+}
+
+impl GeozeroGeometry for MixedGeometryArray {
+    /// Streams every row through `processor` as a single geometry collection, dispatching each
+    /// one to its own child array's already-decoded `geo::Geometry` rather than `self`'s own
+    /// type/offset bookkeeping - a row's geometry type is whatever [`Self::value_as_geo`] returns,
+    /// so this crate doesn't need a second, parallel way of walking each child's shape.
+    fn process_geom<P: GeomProcessor>(&self, processor: &mut P) -> geozero::error::Result<()>
+    where
+        Self: Sized,
+    {
+        let num_geometries = self.len();
+        processor.geometrycollection_begin(num_geometries, 0)?;
+
+        for idx in 0..num_geometries {
+            self.value_as_geo(idx).process_geom(processor)?;
+        }
+
+        processor.geometrycollection_end(num_geometries)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use super::mutable::MutableMixedGeometryArray;
+    use geo::{line_string, point, polygon};
+
+    fn geoms() -> Vec<Option<geo::Geometry>> {
+        vec![
+            Some(geo::Geometry::Point(point!(x: 0., y: 1.))),
+            None,
+            Some(geo::Geometry::LineString(line_string![
+                (x: 0., y: 0.), (x: 1., y: 1.)
+            ])),
+            Some(geo::Geometry::Polygon(polygon!(
+                exterior: [(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 0.)],
+                interiors: [],
+            ))),
+        ]
+    }
+
+    #[test]
+    fn round_trips_mixed_geometries() {
+        let mutable: MutableMixedGeometryArray = geoms().into();
+        let arr: MixedGeometryArray = mutable.into();
+
+        assert_eq!(arr.len(), 4);
+        assert_eq!(arr.get_as_geo(0), geoms()[0]);
+        assert_eq!(arr.get_as_geo(1), None);
+        assert_eq!(arr.get_as_geo(2), geoms()[2]);
+        assert_eq!(arr.get_as_geo(3), geoms()[3]);
+    }
+
+    #[test]
+    fn slice_does_not_touch_children() {
+        let mutable: MutableMixedGeometryArray = geoms().into();
+        let arr: MixedGeometryArray = mutable.into();
+
+        let sliced = arr.slice(2, 2);
+        assert_eq!(sliced.len(), 2);
+        assert_eq!(sliced.get_as_geo(0), geoms()[2]);
+        assert_eq!(sliced.get_as_geo(1), geoms()[3]);
+    }
+}
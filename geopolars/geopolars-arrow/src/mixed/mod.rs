@@ -0,0 +1,7 @@
+//! Helpers for storing a column whose rows are not all the same geometry type
+
+pub use array::MixedGeometryArray;
+pub use mutable::MutableMixedGeometryArray;
+
+mod array;
+mod mutable;
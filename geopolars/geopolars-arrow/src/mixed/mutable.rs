@@ -0,0 +1,196 @@
+use arrow2::bitmap::{Bitmap, MutableBitmap};
+
+use crate::{
+    GeometryCollectionArray, LineStringArray, MultiLineStringArray, MultiPointArray,
+    MultiPolygonArray, PointArray, PolygonArray,
+};
+
+use super::array::MixedGeometryArray;
+
+/// The Arrow equivalent to `Vec<Option<geo::Geometry>>`.
+///
+/// Unlike the single-type `Mutable*Array` builders in this crate, this one isn't built
+/// incrementally through a [`geozero::GeomProcessor`]: a `geo::Geometry::GeometryCollection`
+/// already nests its children as a plain `Vec`, so resolving a nested collection's contents
+/// doesn't need an explicit begin/end stack the way a push-based builder would. This mirrors
+/// [`MutableGeometryCollectionArray`](crate::MutableGeometryCollectionArray), which makes the
+/// same call for the same reason.
+#[derive(Debug, Clone, Default)]
+pub struct MutableMixedGeometryArray {
+    types: Vec<i8>,
+    offsets: Vec<i32>,
+    points: Vec<geo::Point>,
+    line_strings: Vec<geo::LineString>,
+    polygons: Vec<geo::Polygon>,
+    multi_points: Vec<geo::MultiPoint>,
+    multi_line_strings: Vec<geo::MultiLineString>,
+    multi_polygons: Vec<geo::MultiPolygon>,
+    geometry_collections: Vec<geo::GeometryCollection>,
+    validity: Option<MutableBitmap>,
+}
+
+const POINT: i8 = 0;
+const LINE_STRING: i8 = 1;
+const POLYGON: i8 = 2;
+const MULTI_POINT: i8 = 3;
+const MULTI_LINE_STRING: i8 = 4;
+const MULTI_POLYGON: i8 = 5;
+const GEOMETRY_COLLECTION: i8 = 6;
+
+impl MutableMixedGeometryArray {
+    /// Creates a new empty [`MutableMixedGeometryArray`].
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    fn init_validity(&mut self) {
+        let mut validity = MutableBitmap::with_capacity(self.types.len());
+        validity.extend_constant(self.types.len(), true);
+        validity.set(self.types.len() - 1, false);
+        self.validity = Some(validity);
+    }
+
+    /// Appends a single geometry, or a null if `geom` is `None`.
+    pub fn push_geo(&mut self, geom: Option<geo::Geometry>) {
+        let geom = match geom {
+            Some(geom) => geom,
+            None => {
+                // Placeholder row; `types`/`offsets` are never read back for a null slot.
+                self.types.push(POINT);
+                self.offsets.push(0);
+                match &mut self.validity {
+                    Some(validity) => validity.push(false),
+                    None => self.init_validity(),
+                }
+                return;
+            }
+        };
+
+        match geom {
+            geo::Geometry::Point(g) => {
+                self.types.push(POINT);
+                self.offsets.push(self.points.len() as i32);
+                self.points.push(g);
+            }
+            geo::Geometry::LineString(g) => {
+                self.types.push(LINE_STRING);
+                self.offsets.push(self.line_strings.len() as i32);
+                self.line_strings.push(g);
+            }
+            geo::Geometry::Polygon(g) => {
+                self.types.push(POLYGON);
+                self.offsets.push(self.polygons.len() as i32);
+                self.polygons.push(g);
+            }
+            geo::Geometry::MultiPoint(g) => {
+                self.types.push(MULTI_POINT);
+                self.offsets.push(self.multi_points.len() as i32);
+                self.multi_points.push(g);
+            }
+            geo::Geometry::MultiLineString(g) => {
+                self.types.push(MULTI_LINE_STRING);
+                self.offsets.push(self.multi_line_strings.len() as i32);
+                self.multi_line_strings.push(g);
+            }
+            geo::Geometry::MultiPolygon(g) => {
+                self.types.push(MULTI_POLYGON);
+                self.offsets.push(self.multi_polygons.len() as i32);
+                self.multi_polygons.push(g);
+            }
+            geo::Geometry::GeometryCollection(g) => {
+                self.types.push(GEOMETRY_COLLECTION);
+                self.offsets.push(self.geometry_collections.len() as i32);
+                self.geometry_collections.push(g);
+            }
+            other => panic!("unsupported geometry type in MixedGeometryArray: {other:?}"),
+        }
+
+        if let Some(validity) = &mut self.validity {
+            validity.push(true);
+        }
+    }
+}
+
+impl From<Vec<geo::Geometry>> for MutableMixedGeometryArray {
+    fn from(geoms: Vec<geo::Geometry>) -> Self {
+        let mut arr = Self::new();
+        for geom in geoms {
+            arr.push_geo(Some(geom));
+        }
+        arr
+    }
+}
+
+impl From<Vec<Option<geo::Geometry>>> for MutableMixedGeometryArray {
+    fn from(geoms: Vec<Option<geo::Geometry>>) -> Self {
+        let mut arr = Self::new();
+        for geom in geoms {
+            arr.push_geo(geom);
+        }
+        arr
+    }
+}
+
+impl From<MutableMixedGeometryArray> for MixedGeometryArray {
+    fn from(other: MutableMixedGeometryArray) -> Self {
+        let validity: Option<Bitmap> = other.validity.and_then(|x| {
+            let bitmap: Bitmap = x.into();
+            if bitmap.unset_bits() == 0 {
+                None
+            } else {
+                Some(bitmap)
+            }
+        });
+
+        let points: PointArray = other.points.into_iter().map(Some).collect::<Vec<_>>().into();
+        let line_strings: LineStringArray = other
+            .line_strings
+            .into_iter()
+            .map(Some)
+            .collect::<Vec<_>>()
+            .into();
+        let polygons: PolygonArray = other
+            .polygons
+            .into_iter()
+            .map(Some)
+            .collect::<Vec<_>>()
+            .into();
+        let multi_points: MultiPointArray = other
+            .multi_points
+            .into_iter()
+            .map(Some)
+            .collect::<Vec<_>>()
+            .into();
+        let multi_line_strings: MultiLineStringArray = other
+            .multi_line_strings
+            .into_iter()
+            .map(Some)
+            .collect::<Vec<_>>()
+            .into();
+        let multi_polygons: MultiPolygonArray = other
+            .multi_polygons
+            .into_iter()
+            .map(Some)
+            .collect::<Vec<_>>()
+            .into();
+        let geometry_collections: GeometryCollectionArray = other
+            .geometry_collections
+            .into_iter()
+            .map(Some)
+            .collect::<Vec<_>>()
+            .into();
+
+        MixedGeometryArray::new(
+            other.types.into(),
+            other.offsets.into(),
+            points,
+            line_strings,
+            polygons,
+            multi_points,
+            multi_line_strings,
+            multi_polygons,
+            geometry_collections,
+            validity,
+        )
+    }
+}
@@ -1,9 +1,10 @@
+use crate::coord::CoordBuffer;
 use crate::error::GeoArrowError;
 use crate::{GeometryArrayTrait, PolygonArray};
-use arrow2::array::{Array, ListArray, PrimitiveArray, StructArray};
+use arrow2::array::{Array, FixedSizeListArray, ListArray, PrimitiveArray, StructArray};
 use arrow2::bitmap::utils::{BitmapIter, ZipValidity};
 use arrow2::bitmap::Bitmap;
-use arrow2::buffer::Buffer;
+use arrow2::datatypes::{Field, Metadata};
 use arrow2::offset::OffsetsBuffer;
 use geozero::{GeomProcessor, GeozeroGeometry};
 use rstar::RTree;
@@ -12,13 +13,13 @@ use super::MutableMultiLineStringArray;
 
 /// A [`GeometryArray`] semantically equivalent to `Vec<Option<MultiLineString>>` using Arrow's
 /// in-memory representation.
+///
+/// Coordinates are stored in a [`CoordBuffer`], which may be either the separated `x`/`y`
+/// layout every producer in this crate used before [`CoordBuffer`] existed, or an interleaved
+/// `[x0, y0, x1, y1, ...]` layout ingested zero-copy from other GeoArrow producers.
 #[derive(Debug, Clone)]
 pub struct MultiLineStringArray {
-    /// Buffer of x coordinates
-    x: Buffer<f64>,
-
-    /// Buffer of y coordinates
-    y: Buffer<f64>,
+    coords: CoordBuffer,
 
     /// Offsets into the ring array where each geometry starts
     geom_offsets: OffsetsBuffer<i64>,
@@ -28,69 +29,146 @@ pub struct MultiLineStringArray {
 
     /// Validity bitmap
     validity: Option<Bitmap>,
+
+    /// The coordinate reference system of these geometries, if known.
+    crs: Option<String>,
 }
 
 pub(super) fn check(
-    x: &[f64],
-    y: &[f64],
+    coords: &CoordBuffer,
     validity_len: Option<usize>,
     geom_offsets: &OffsetsBuffer<i64>,
+    ring_offsets: &OffsetsBuffer<i64>,
 ) -> Result<(), GeoArrowError> {
-    // TODO: check geom offsets and ring_offsets?
     if validity_len.map_or(false, |len| len != geom_offsets.len()) {
         return Err(GeoArrowError::General(
             "validity mask length must match the number of values".to_string(),
         ));
     }
 
-    if x.len() != y.len() {
+    crate::offset::validate_offsets("geom_offsets", geom_offsets)?;
+    crate::offset::validate_offsets("ring_offsets", ring_offsets)?;
+
+    if geom_offsets.last() as usize != ring_offsets.len() {
+        return Err(GeoArrowError::General(
+            "the last offset in geom_offsets must equal the number of rings".to_string(),
+        ));
+    }
+    if ring_offsets.last() as usize != coords.len() {
         return Err(GeoArrowError::General(
-            "x and y arrays must have the same length".to_string(),
+            "the last offset in ring_offsets must equal the coordinate count".to_string(),
         ));
     }
+
     Ok(())
 }
 
 impl MultiLineStringArray {
-    /// Create a new MultiLineStringArray from parts
+    /// Create a new MultiLineStringArray from a [`CoordBuffer`] in either the separated or
+    /// interleaved layout.
     /// # Implementation
     /// This function is `O(1)`.
     pub fn new(
-        x: Buffer<f64>,
-        y: Buffer<f64>,
+        coords: CoordBuffer,
         geom_offsets: OffsetsBuffer<i64>,
         ring_offsets: OffsetsBuffer<i64>,
         validity: Option<Bitmap>,
     ) -> Self {
-        check(&x, &y, validity.as_ref().map(|v| v.len()), &geom_offsets).unwrap();
+        check(
+            &coords,
+            validity.as_ref().map(|v| v.len()),
+            &geom_offsets,
+            &ring_offsets,
+        )
+        .unwrap();
         Self {
-            x,
-            y,
+            coords,
             geom_offsets,
             ring_offsets,
             validity,
+            crs: None,
         }
     }
 
-    /// Create a new MultiLineStringArray from parts
+    /// Create a new MultiLineStringArray from a [`CoordBuffer`] in either the separated or
+    /// interleaved layout.
     /// # Implementation
     /// This function is `O(1)`.
     pub fn try_new(
-        x: Buffer<f64>,
-        y: Buffer<f64>,
+        coords: CoordBuffer,
         geom_offsets: OffsetsBuffer<i64>,
         ring_offsets: OffsetsBuffer<i64>,
         validity: Option<Bitmap>,
     ) -> Result<Self, GeoArrowError> {
-        check(&x, &y, validity.as_ref().map(|v| v.len()), &geom_offsets)?;
+        check(
+            &coords,
+            validity.as_ref().map(|v| v.len()),
+            &geom_offsets,
+            &ring_offsets,
+        )?;
         Ok(Self {
-            x,
-            y,
+            coords,
             geom_offsets,
             ring_offsets,
             validity,
+            crs: None,
         })
     }
+
+    /// The underlying [`CoordBuffer`], in whichever physical layout this array was built with.
+    /// Values on null slots are undetermined (they can be anything).
+    #[inline]
+    pub fn coords(&self) -> &CoordBuffer {
+        &self.coords
+    }
+
+    /// Offsets into [`Self::ring_offsets`] where each geometry's rings (i.e. its member
+    /// `LineString`s) start.
+    #[inline]
+    pub fn geom_offsets(&self) -> &OffsetsBuffer<i64> {
+        &self.geom_offsets
+    }
+
+    /// Offsets into [`Self::coords`] where each ring (i.e. each member `LineString`) starts.
+    #[inline]
+    pub fn ring_offsets(&self) -> &OffsetsBuffer<i64> {
+        &self.ring_offsets
+    }
+
+    /// The coordinate reference system of these geometries, if known.
+    pub fn crs(&self) -> Option<&str> {
+        self.crs.as_deref()
+    }
+
+    /// Sets the coordinate reference system of these geometries.
+    pub fn with_crs(mut self, crs: Option<String>) -> Self {
+        self.crs = crs;
+        self
+    }
+
+    /// Builds the Arrow [`Field`] describing this array as a GeoArrow extension column, carrying
+    /// the embedded CRS (if any) as `ARROW:extension:metadata`.
+    ///
+    /// Note this doesn't flow through [`GeometryArrayTrait::into_arrow`]: polars doesn't yet
+    /// carry extension-type metadata through a `Series`, so callers that write Arrow/Parquet
+    /// schemas directly are the ones that can make use of this today.
+    pub fn extension_field(&self, name: &str) -> Field {
+        let data_type = self.clone().into_arrow().data_type().clone();
+
+        let mut metadata = Metadata::new();
+        metadata.insert(
+            "ARROW:extension:name".to_string(),
+            "geoarrow.multilinestring".to_string(),
+        );
+        if let Some(crs) = &self.crs {
+            metadata.insert(
+                "ARROW:extension:metadata".to_string(),
+                format!(r#"{{"crs":"{crs}"}}"#),
+            );
+        }
+
+        Field::new(name, data_type, true).with_metadata(metadata)
+    }
 }
 
 impl<'a> GeometryArrayTrait<'a> for MultiLineStringArray {
@@ -100,8 +178,7 @@ impl<'a> GeometryArrayTrait<'a> for MultiLineStringArray {
 
     fn value(&'a self, i: usize) -> Self::Scalar {
         crate::MultiLineString {
-            x: &self.x,
-            y: &self.y,
+            coords: &self.coords,
             geom_offsets: &self.geom_offsets,
             ring_offsets: &self.ring_offsets,
             geom_index: i,
@@ -177,11 +254,11 @@ impl<'a> GeometryArrayTrait<'a> for MultiLineStringArray {
             .slice_unchecked(offset, length + 1);
 
         Self {
-            x: self.x.clone(),
-            y: self.y.clone(),
+            coords: self.coords.clone(),
             geom_offsets,
             ring_offsets: self.ring_offsets.clone(),
             validity,
+            crs: self.crs.clone(),
         }
     }
 
@@ -236,37 +313,69 @@ impl MultiLineStringArray {
         ZipValidity::new_with_validity(self.iter_geo_values(), self.validity())
     }
 
-    // GEOS from not implemented for MultiLineString I suppose
-    //
-    // /// Returns the value at slot `i` as a GEOS geometry.
-    // #[cfg(feature = "geos")]
-    // pub fn value_as_geos(&self, i: usize) -> geos::Geometry {
-    //     (&self.value_as_geo(i)).try_into().unwrap()
-    // }
-
-    // /// Gets the value at slot `i` as a GEOS geometry, additionally checking the validity bitmap
-    // #[cfg(feature = "geos")]
-    // pub fn get_as_geos(&self, i: usize) -> Option<geos::Geometry> {
-    //     if self.is_null(i) {
-    //         return None;
-    //     }
-
-    //     self.get_as_geo(i).as_ref().map(|g| g.try_into().unwrap())
-    // }
-
-    // /// Iterator over GEOS geometry objects
-    // #[cfg(feature = "geos")]
-    // pub fn iter_geos_values(&self) -> impl Iterator<Item = geos::Geometry> + '_ {
-    //     (0..self.len()).map(|i| self.value_as_geos(i))
-    // }
-
-    // /// Iterator over GEOS geometry objects, taking validity into account
-    // #[cfg(feature = "geos")]
-    // pub fn iter_geos(
-    //     &self,
-    // ) -> ZipValidity<geos::Geometry, impl Iterator<Item = geos::Geometry> + '_, BitmapIter> {
-    //     ZipValidity::new_with_validity(self.iter_geos_values(), self.validity())
-    // }
+    /// Returns the value at slot `i` as a GEOS geometry.
+    #[cfg(feature = "geos")]
+    pub fn value_as_geos(&self, i: usize) -> geos::Geometry {
+        (&self.value_as_geo(i)).try_into().unwrap()
+    }
+
+    /// Gets the value at slot `i` as a GEOS geometry, additionally checking the validity bitmap
+    #[cfg(feature = "geos")]
+    pub fn get_as_geos(&self, i: usize) -> Option<geos::Geometry> {
+        if self.is_null(i) {
+            return None;
+        }
+
+        self.get_as_geo(i).as_ref().map(|g| g.try_into().unwrap())
+    }
+
+    /// Iterator over GEOS geometry objects
+    #[cfg(feature = "geos")]
+    pub fn iter_geos_values(&self) -> impl Iterator<Item = geos::Geometry> + '_ {
+        (0..self.len()).map(|i| self.value_as_geos(i))
+    }
+
+    /// Iterator over GEOS geometry objects, taking validity into account
+    #[cfg(feature = "geos")]
+    pub fn iter_geos(
+        &self,
+    ) -> ZipValidity<geos::Geometry, impl Iterator<Item = geos::Geometry> + '_, BitmapIter> {
+        ZipValidity::new_with_validity(self.iter_geos_values(), self.validity())
+    }
+
+    /// Convert to an Arrow [`ListArray`] backed by 32-bit (`List`, rather than `LargeList`)
+    /// offsets, halving the offset buffer size.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GeoArrowError::Overflow`] if either offsets buffer exceeds [`i32::MAX`]. Use
+    /// [`Self::into_arrow`] (via [`GeometryArrayTrait`]) in that case.
+    pub fn into_arrow_small(self) -> Result<ListArray<i32>, GeoArrowError> {
+        let polygon_array: PolygonArray = self.into();
+        polygon_array.into_arrow_small()
+    }
+
+    /// Tessellates each geometry's rings into a triangle mesh via ear clipping, treating the
+    /// first ring as the exterior and any remaining rings as holes. See
+    /// [`crate::algorithm::triangulate::tessellate`].
+    pub fn tessellate(&self) -> crate::algorithm::triangulate::Tessellation {
+        crate::algorithm::triangulate::tessellate(&self.coords, &self.geom_offsets, &self.ring_offsets)
+    }
+}
+
+/// Converts every value in `value` to a GEOS geometry, going through [`geo::MultiLineString`]
+/// (itself built straight off this array's [`CoordBuffer`] and `geom_offsets`/`ring_offsets`
+/// via [`MultiLineStringArray::value_as_geo`]) rather than a WKB round-trip.
+#[cfg(feature = "geos")]
+impl TryFrom<&MultiLineStringArray> for Vec<geos::Geometry> {
+    type Error = geos::Error;
+
+    fn try_from(value: &MultiLineStringArray) -> Result<Self, Self::Error> {
+        value
+            .iter_geo_values()
+            .map(|geom| (&geom).try_into())
+            .collect()
+    }
 }
 
 impl TryFrom<ListArray<i64>> for MultiLineStringArray {
@@ -284,23 +393,37 @@ impl TryFrom<ListArray<i64>> for MultiLineStringArray {
 
         let ring_offsets = inner_array.offsets();
         let coords_dyn_array = inner_array.values();
-        let coords_array = coords_dyn_array
-            .as_any()
-            .downcast_ref::<StructArray>()
-            .unwrap();
 
-        let x_array_values = coords_array.values()[0]
-            .as_any()
-            .downcast_ref::<PrimitiveArray<f64>>()
-            .unwrap();
-        let y_array_values = coords_array.values()[1]
-            .as_any()
-            .downcast_ref::<PrimitiveArray<f64>>()
-            .unwrap();
+        let coords = if let Some(coords_array) =
+            coords_dyn_array.as_any().downcast_ref::<StructArray>()
+        {
+            let x_array_values = coords_array.values()[0]
+                .as_any()
+                .downcast_ref::<PrimitiveArray<f64>>()
+                .unwrap();
+            let y_array_values = coords_array.values()[1]
+                .as_any()
+                .downcast_ref::<PrimitiveArray<f64>>()
+                .unwrap();
+            CoordBuffer::Separated(
+                x_array_values.values().clone(),
+                y_array_values.values().clone(),
+            )
+        } else {
+            let coords_array = coords_dyn_array
+                .as_any()
+                .downcast_ref::<FixedSizeListArray>()
+                .unwrap();
+            let values = coords_array
+                .values()
+                .as_any()
+                .downcast_ref::<PrimitiveArray<f64>>()
+                .unwrap();
+            CoordBuffer::Interleaved(values.values().clone())
+        };
 
         Ok(Self::new(
-            x_array_values.values().clone(),
-            y_array_values.values().clone(),
+            coords,
             geom_offsets.clone(),
             ring_offsets.clone(),
             validity.cloned(),
@@ -312,8 +435,15 @@ impl TryFrom<Box<dyn Array>> for MultiLineStringArray {
     type Error = GeoArrowError;
 
     fn try_from(value: Box<dyn Array>) -> Result<Self, Self::Error> {
-        let arr = value.as_any().downcast_ref::<ListArray<i64>>().unwrap();
-        arr.clone().try_into()
+        // Accept either `LargeList` (i64 offsets, our own native width) or `List` (i32 offsets,
+        // e.g. from a producer that didn't opt into large offsets) by widening the latter up
+        // front; everything past this point only ever deals with `ListArray<i64>`.
+        if let Some(arr) = value.as_any().downcast_ref::<ListArray<i64>>() {
+            arr.clone().try_into()
+        } else {
+            let arr = value.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+            crate::offset::widen_list_array(arr).try_into()
+        }
     }
 }
 
@@ -331,17 +461,26 @@ impl From<Vec<geo::MultiLineString>> for MultiLineStringArray {
     }
 }
 
+impl From<MultiLineStringArray> for crate::WKBArray {
+    fn from(value: MultiLineStringArray) -> Self {
+        let geoms: Vec<Option<geo::Geometry>> = (0..value.len())
+            .map(|i| value.get_as_geo(i).map(geo::Geometry::MultiLineString))
+            .collect();
+        geoms.into()
+    }
+}
+
 /// Polygon and MultiLineString have the same layout, so enable conversions between the two to
-/// change the semantic type
+/// change the semantic type. Whichever [`CoordBuffer`] layout `value` was built with is preserved.
 impl From<MultiLineStringArray> for PolygonArray {
     fn from(value: MultiLineStringArray) -> Self {
-        Self::new(
-            value.x,
-            value.y,
+        Self::try_new_from_coords(
+            value.coords,
             value.geom_offsets,
             value.ring_offsets,
             value.validity,
         )
+        .unwrap()
     }
 }
 
@@ -369,11 +508,8 @@ impl GeozeroGeometry for MultiLineStringArray {
                 )?;
 
                 for coord_idx in start_coord_idx..end_coord_idx {
-                    processor.xy(
-                        self.x[coord_idx],
-                        self.y[coord_idx],
-                        coord_idx - start_coord_idx,
-                    )?;
+                    let (x, y) = self.coords.value(coord_idx);
+                    processor.xy(x, y, coord_idx - start_coord_idx)?;
                 }
 
                 processor.linestring_end(false, ring_idx - start_ring_idx)?;
@@ -450,4 +586,15 @@ mod test {
         assert_eq!(sliced.len(), 1);
         assert_eq!(sliced.get_as_geo(0), Some(ml1()));
     }
+
+    #[test]
+    fn extension_field_carries_crs() {
+        let arr: MultiLineStringArray = vec![ml0()].into();
+        let arr = arr.with_crs(Some("EPSG:4326".to_string()));
+        let field = arr.extension_field("geometry");
+        assert_eq!(
+            field.metadata.get("ARROW:extension:metadata").unwrap(),
+            r#"{"crs":"EPSG:4326"}"#,
+        );
+    }
 }
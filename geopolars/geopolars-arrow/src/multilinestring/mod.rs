@@ -0,0 +1,8 @@
+pub use array::MultiLineStringArray;
+pub use mutable::MutableMultiLineStringArray;
+pub use scalar::MultiLineString;
+
+mod array;
+mod iterator;
+mod mutable;
+mod scalar;
@@ -1,16 +1,33 @@
 use geo::MultiLineString;
+use geozero::{CoordDimensions, GeomProcessor, GeozeroGeometry};
 use polars::export::arrow::array::ListArray;
 use polars::export::arrow::bitmap::{Bitmap, MutableBitmap};
 use polars::export::arrow::offset::{Offsets, OffsetsBuffer};
+use polars::export::arrow::types::Index;
 
+use crate::coord::{CoordType, MutableCoordBuffer};
 use crate::error::GeoArrowError;
 use crate::polygon::MutablePolygonArray;
 use crate::MultiLineStringArray;
 
+/// Which coordinate dimensions a [`MutableMultiLineStringArray`] carries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Dimension {
+    /// `x`/`y` only. This is the layout every `MutableMultiLineStringArray` had before
+    /// [`Dimension`] existed.
+    #[default]
+    XY,
+    /// `x`/`y`/`z`, e.g. elevation carried through a WKB Z/EWKB Z input.
+    XYZ,
+}
+
 #[derive(Debug, Clone)]
 pub struct MutableMultiLineStringArray {
-    x: Vec<f64>,
-    y: Vec<f64>,
+    coords: MutableCoordBuffer,
+
+    /// An optional `z` buffer, one value per coordinate in [`Self::coords`]. Guarded by
+    /// [`Self::dimension`].
+    z: Option<Vec<f64>>,
 
     /// Offsets into the ring array where each geometry starts
     geom_offsets: Offsets<i64>,
@@ -20,29 +37,42 @@ pub struct MutableMultiLineStringArray {
 
     /// Validity is only defined at the geometry level
     validity: Option<MutableBitmap>,
+
+    /// The coordinate reference system of these geometries, if known. Populated by
+    /// [`MutableMultiLineStringArray::from_ewkb`] from the source EWKB's embedded SRID; `None`
+    /// for geometries built any other way.
+    crs: Option<String>,
 }
 
 pub type MultiLineStringInner = (
-    Vec<f64>,
-    Vec<f64>,
+    MutableCoordBuffer,
+    Option<Vec<f64>>,
     Offsets<i64>,
     Offsets<i64>,
     Option<MutableBitmap>,
 );
 
 impl MutableMultiLineStringArray {
-    /// Creates a new empty [`MutableLineStringArray`].
+    /// Creates a new empty [`MutableMultiLineStringArray`] storing coordinates as
+    /// [`CoordType::Separated`].
     pub fn new() -> Self {
         MutablePolygonArray::new().into()
     }
 
-    /// Creates a new [`MutableLineStringArray`] with a capacity.
+    /// Creates a new [`MutableMultiLineStringArray`] with a capacity, storing coordinates as
+    /// [`CoordType::Separated`].
     pub fn with_capacities(
         coord_capacity: usize,
         geom_capacity: usize,
         ring_capacity: usize,
     ) -> Self {
-        MutablePolygonArray::with_capacities(coord_capacity, geom_capacity, ring_capacity).into()
+        MutablePolygonArray::with_capacities(
+            CoordType::Separated,
+            coord_capacity,
+            geom_capacity,
+            ring_capacity,
+        )
+        .into()
     }
 
     /// The canonical method to create a [`MutableLineStringArray`] out of its internal components.
@@ -63,21 +93,81 @@ impl MutableMultiLineStringArray {
             .map(|result| result.into())
     }
 
+    /// Create a new [`MutableMultiLineStringArray`] from a [`MutableCoordBuffer`] in either the
+    /// separated or interleaved layout.
+    /// # Implementation
+    /// This function is `O(1)`.
+    pub fn try_new_from_coords(
+        coords: MutableCoordBuffer,
+        z: Option<Vec<f64>>,
+        geom_offsets: Offsets<i64>,
+        ring_offsets: Offsets<i64>,
+        validity: Option<MutableBitmap>,
+    ) -> Result<Self, GeoArrowError> {
+        Ok(Self {
+            coords,
+            z,
+            geom_offsets,
+            ring_offsets,
+            validity,
+            crs: None,
+        })
+    }
+
+    /// The optional `z` buffer, one value per coordinate. Values on null slots are undetermined
+    /// (they can be anything).
+    #[inline]
+    pub fn z(&self) -> Option<&[f64]> {
+        self.z.as_deref()
+    }
+
+    /// Which coordinate dimensions this array carries.
+    #[inline]
+    pub fn dimension(&self) -> Dimension {
+        if self.z.is_some() {
+            Dimension::XYZ
+        } else {
+            Dimension::XY
+        }
+    }
+
     /// Extract the low-level APIs from the [`MutableLineStringArray`].
     pub fn into_inner(self) -> MultiLineStringInner {
         (
-            self.x,
-            self.y,
+            self.coords,
+            self.z,
             self.geom_offsets,
             self.ring_offsets,
             self.validity,
         )
     }
 
+    /// Converts this builder directly into Arrow's [`ListArray`] representation, going through
+    /// [`MutablePolygonArray`] (Polygon and MultiLineString share the same layout) so the `z`
+    /// buffer, if any, is preserved.
     pub fn into_arrow(self) -> ListArray<i64> {
-        let arr: MultiLineStringArray = self.into();
+        let arr: MutablePolygonArray = self.into();
         arr.into_arrow()
     }
+
+    /// The coordinate reference system of these geometries, if known.
+    pub fn crs(&self) -> Option<&str> {
+        self.crs.as_deref()
+    }
+
+    /// Sets the coordinate reference system of these geometries.
+    pub fn with_crs(mut self, crs: Option<String>) -> Self {
+        self.crs = crs;
+        self
+    }
+
+    /// Parses Extended WKB (the PostGIS variant of WKB with an embedded SRID) into a
+    /// [`MutableMultiLineStringArray`], preserving the source SRID as [`Self::crs`].
+    pub fn from_ewkb(buf: &[u8]) -> geozero::error::Result<Self> {
+        let mut array = Self::new();
+        geozero::wkb::Ewkb(buf.to_vec()).process_geom(&mut array)?;
+        Ok(array)
+    }
 }
 
 impl Default for MutableMultiLineStringArray {
@@ -86,6 +176,9 @@ impl Default for MutableMultiLineStringArray {
     }
 }
 
+/// [`MultiLineStringArray`] has no `z` support yet, so a 3D [`MutableMultiLineStringArray`]
+/// drops its `z` buffer on this conversion; go through [`MutableMultiLineStringArray::into_arrow`]
+/// instead to keep it.
 impl From<MutableMultiLineStringArray> for MultiLineStringArray {
     fn from(other: MutableMultiLineStringArray) -> Self {
         let validity = other.validity.and_then(|x| {
@@ -100,63 +193,93 @@ impl From<MutableMultiLineStringArray> for MultiLineStringArray {
         let geom_offsets: OffsetsBuffer<i64> = other.geom_offsets.into();
         let ring_offsets: OffsetsBuffer<i64> = other.ring_offsets.into();
 
-        Self::new(
-            other.x.into(),
-            other.y.into(),
-            geom_offsets,
-            ring_offsets,
-            validity,
-        )
+        Self::new(other.coords.into(), geom_offsets, ring_offsets, validity).with_crs(other.crs)
     }
 }
 
-impl From<Vec<MultiLineString>> for MutableMultiLineStringArray {
-    fn from(geoms: Vec<MultiLineString>) -> Self {
-        use geo::coords_iter::CoordsIter;
-
-        // Offset into ring indexes for each geometry
-        let mut geom_offsets = Offsets::<i64>::with_capacity(geoms.len());
-
-        // Offset into coordinates for each ring
-        // This capacity will only be enough in the case where each geometry has only a single
-        // linestring
-        let mut ring_offsets = Offsets::<i64>::with_capacity(geoms.len());
-
-        // Current offset into ring array
-        let mut current_geom_offset = 0;
+/// Builds a [`MutableMultiLineStringArray`] from owned geometries, storing coordinates in the
+/// given [`CoordType`] layout.
+pub(crate) fn multilinestring_from_geo_vec_with_coord_type(
+    geoms: Vec<MultiLineString>,
+    coord_type: CoordType,
+) -> MutableMultiLineStringArray {
+    use geo::coords_iter::CoordsIter;
+
+    // Offset into ring indexes for each geometry
+    let mut geom_offsets = Offsets::<i64>::with_capacity(geoms.len());
+
+    // Offset into coordinates for each ring
+    // This capacity will only be enough in the case where each geometry has only a single
+    // linestring
+    let mut ring_offsets = Offsets::<i64>::with_capacity(geoms.len());
+
+    // Current offset into ring array
+    let mut current_geom_offset = 0;
+
+    // Current offset into coord array
+    let mut current_ring_offset = 0;
+
+    for geom in &geoms {
+        // Total number of linestrings in this multilinestring
+        current_geom_offset += geom.0.len();
+        geom_offsets.try_push_usize(geom.0.len()).unwrap();
+
+        // Number of coords for each ring
+        for linestring in geom.0.iter() {
+            current_ring_offset += linestring.coords_count();
+            ring_offsets.try_push_usize(linestring.coords_count()).unwrap();
+        }
+    }
 
-        // Current offset into coord array
-        let mut current_ring_offset = 0;
+    let mut coords = MutableCoordBuffer::with_capacity(coord_type, current_ring_offset);
 
-        for geom in &geoms {
-            // Total number of linestrings in this multilinestring
-            current_geom_offset += geom.0.len();
-            geom_offsets.try_push_usize(current_geom_offset).unwrap();
-
-            // Number of coords for each ring
-            for linestring in geom.0.iter() {
-                current_ring_offset += linestring.coords_count();
-                ring_offsets.try_push_usize(current_ring_offset).unwrap();
-            }
+    for geom in geoms {
+        for coord in geom.coords_iter() {
+            coords.push_xy(coord.x, coord.y);
         }
+    }
 
-        let mut x_arr = Vec::<f64>::with_capacity(current_ring_offset);
-        let mut y_arr = Vec::<f64>::with_capacity(current_ring_offset);
+    MutableMultiLineStringArray {
+        coords,
+        z: None,
+        geom_offsets,
+        ring_offsets,
+        validity: None,
+        crs: None,
+    }
+}
 
-        for geom in geoms {
-            for coord in geom.coords_iter() {
-                x_arr.push(coord.x);
-                y_arr.push(coord.y);
-            }
-        }
+impl From<Vec<MultiLineString>> for MutableMultiLineStringArray {
+    fn from(geoms: Vec<MultiLineString>) -> Self {
+        multilinestring_from_geo_vec_with_coord_type(geoms, CoordType::Separated)
+    }
+}
 
-        MutableMultiLineStringArray {
-            x: x_arr,
-            y: y_arr,
-            geom_offsets,
-            ring_offsets,
-            validity: None,
-        }
+/// Ingests a batch of GEOS result geometries (e.g. from a buffer or boolean overlay operation)
+/// back into a [`MutableMultiLineStringArray`] by converting each one to [`geo::MultiLineString`]
+/// and pushing its coordinates/offsets through the same path as [`From<Vec<MultiLineString>>`].
+#[cfg(feature = "geos")]
+impl TryFrom<Vec<geos::Geometry>> for MutableMultiLineStringArray {
+    type Error = GeoArrowError;
+
+    fn try_from(value: Vec<geos::Geometry>) -> Result<Self, Self::Error> {
+        let geoms = value
+            .iter()
+            .map(|geom| {
+                let geo_geom: geo::Geometry = geom
+                    .try_into()
+                    .map_err(|err: geos::Error| GeoArrowError::External(anyhow::Error::from(err)))?;
+                match geo_geom {
+                    geo::Geometry::MultiLineString(ml) => Ok(ml),
+                    geo::Geometry::LineString(ls) => Ok(MultiLineString(vec![ls])),
+                    _ => Err(GeoArrowError::General(
+                        "Expected a LineString or MultiLineString geometry from GEOS".to_string(),
+                    )),
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(geoms.into())
     }
 }
 
@@ -199,33 +322,33 @@ impl From<Vec<Option<MultiLineString>>> for MutableMultiLineStringArray {
             }
         }
 
-        let mut x_arr = Vec::<f64>::with_capacity(current_ring_offset);
-        let mut y_arr = Vec::<f64>::with_capacity(current_ring_offset);
+        let mut coords = MutableCoordBuffer::with_capacity(CoordType::Separated, current_ring_offset);
 
         for geom in geoms.into_iter().flatten() {
             for coord in geom.coords_iter() {
-                x_arr.push(coord.x);
-                y_arr.push(coord.y);
+                coords.push_xy(coord.x, coord.y);
             }
         }
 
         MutableMultiLineStringArray {
-            x: x_arr,
-            y: y_arr,
+            coords,
+            z: None,
             geom_offsets,
             ring_offsets,
             validity: Some(validity),
+            crs: None,
         }
     }
 }
 
 /// Polygon and MultiLineString have the same layout, so enable conversions between the two to
-/// change the semantic type
+/// change the semantic type. Whichever [`CoordType`] `value` was built with is preserved, and the
+/// `z` buffer (if any) carries over unchanged.
 impl From<MutableMultiLineStringArray> for MutablePolygonArray {
     fn from(value: MutableMultiLineStringArray) -> Self {
-        Self::try_new(
-            value.x,
-            value.y,
+        Self::try_new_from_coords(
+            value.coords,
+            value.z,
             value.geom_offsets,
             value.ring_offsets,
             value.validity,
@@ -233,3 +356,307 @@ impl From<MutableMultiLineStringArray> for MutablePolygonArray {
         .unwrap()
     }
 }
+
+/// Convert to GeoArrow MultiLineStringArray
+pub trait ToGeoArrowMultiLineString {
+    /// Convert to GeoArrow MultiLineStringArray
+    fn to_geoarrow(&self) -> geozero::error::Result<MultiLineStringArray>;
+
+    /// Convert to a GeoArrow MutableMultiLineStringArray
+    fn to_mutable_geoarrow(&self) -> geozero::error::Result<MutableMultiLineStringArray>;
+}
+
+impl<T: GeozeroGeometry> ToGeoArrowMultiLineString for T {
+    fn to_geoarrow(&self) -> geozero::error::Result<MultiLineStringArray> {
+        Ok(self.to_mutable_geoarrow()?.into())
+    }
+
+    fn to_mutable_geoarrow(&self) -> geozero::error::Result<MutableMultiLineStringArray> {
+        let mut mutable_multilinestring_array = MutableMultiLineStringArray::new();
+        self.process_geom(&mut mutable_multilinestring_array)?;
+        Ok(mutable_multilinestring_array)
+    }
+}
+
+#[allow(unused_variables)]
+impl GeomProcessor for MutableMultiLineStringArray {
+    /// Requests 3D coordinates from the source, so a WKB Z/EWKB Z input reaches [`Self::xyz`]
+    /// instead of being silently flattened to 2D through [`Self::xy`].
+    fn dimensions(&self) -> CoordDimensions {
+        CoordDimensions::xyz()
+    }
+
+    fn xy(&mut self, x: f64, y: f64, _idx: usize) -> geozero::error::Result<()> {
+        if self.z.is_some() {
+            return Err(geozero::error::GeozeroError::Geometry(
+                "cannot mix 2D and 3D coordinates in the same array".to_string(),
+            ));
+        }
+        self.coords.push_xy(x, y);
+        Ok(())
+    }
+
+    fn xyz(&mut self, x: f64, y: f64, z: f64, _idx: usize) -> geozero::error::Result<()> {
+        if self.z.is_none() {
+            if !self.coords.is_empty() {
+                return Err(geozero::error::GeozeroError::Geometry(
+                    "cannot mix 2D and 3D coordinates in the same array".to_string(),
+                ));
+            }
+            self.z = Some(Vec::new());
+        }
+        self.coords.push_xy(x, y);
+        self.z.as_mut().unwrap().push(z);
+        Ok(())
+    }
+
+    /// Captures the SRID embedded in EWKB input, if any, as [`Self::crs`].
+    fn srid(&mut self, srid: Option<i32>) -> geozero::error::Result<()> {
+        self.crs = srid.map(|srid| format!("EPSG:{srid}"));
+        Ok(())
+    }
+
+    fn linestring_begin(
+        &mut self,
+        tagged: bool,
+        size: usize,
+        idx: usize,
+    ) -> geozero::error::Result<()> {
+        Ok(())
+    }
+
+    fn linestring_end(&mut self, tagged: bool, idx: usize) -> geozero::error::Result<()> {
+        let total_length = self.coords.len();
+        let offset = self.ring_offsets.last().to_usize();
+        let length = total_length
+            .checked_sub(offset)
+            .ok_or(GeoArrowError::Overflow)
+            .map_err(|err| geozero::error::GeozeroError::Geometry(err.to_string()))?;
+
+        self.ring_offsets.try_push_usize(length).unwrap();
+        Ok(())
+    }
+
+    fn multilinestring_begin(
+        &mut self,
+        size: usize,
+        idx: usize,
+    ) -> geozero::error::Result<()> {
+        Ok(())
+    }
+
+    fn multilinestring_end(&mut self, idx: usize) -> geozero::error::Result<()> {
+        let num_linestrings =
+            self.ring_offsets.len_proxy() - self.geom_offsets.last().to_usize();
+        self.geom_offsets.try_push_usize(num_linestrings).unwrap();
+        if let Some(validity) = &mut self.validity {
+            validity.push(true);
+        }
+        Ok(())
+    }
+
+    // Override all other trait _begin methods
+    fn circularstring_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        Err(geozero::error::GeozeroError::Geometry(
+            "Only multilinestring geometries allowed".to_string(),
+        ))
+    }
+
+    fn compoundcurve_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        Err(geozero::error::GeozeroError::Geometry(
+            "Only multilinestring geometries allowed".to_string(),
+        ))
+    }
+
+    fn tin_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        Err(geozero::error::GeozeroError::Geometry(
+            "Only multilinestring geometries allowed".to_string(),
+        ))
+    }
+
+    fn triangle_begin(
+        &mut self,
+        tagged: bool,
+        size: usize,
+        idx: usize,
+    ) -> geozero::error::Result<()> {
+        Err(geozero::error::GeozeroError::Geometry(
+            "Only multilinestring geometries allowed".to_string(),
+        ))
+    }
+
+    fn multicurve_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        Err(geozero::error::GeozeroError::Geometry(
+            "Only multilinestring geometries allowed".to_string(),
+        ))
+    }
+
+    fn multipoint_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        Err(geozero::error::GeozeroError::Geometry(
+            "Only multilinestring geometries allowed".to_string(),
+        ))
+    }
+
+    fn curvepolygon_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        Err(geozero::error::GeozeroError::Geometry(
+            "Only multilinestring geometries allowed".to_string(),
+        ))
+    }
+
+    fn polygon_begin(&mut self, tagged: bool, size: usize, idx: usize) -> geozero::error::Result<()> {
+        Err(geozero::error::GeozeroError::Geometry(
+            "Only multilinestring geometries allowed".to_string(),
+        ))
+    }
+
+    fn multipolygon_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        Err(geozero::error::GeozeroError::Geometry(
+            "Only multilinestring geometries allowed".to_string(),
+        ))
+    }
+
+    fn multisurface_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        Err(geozero::error::GeozeroError::Geometry(
+            "Only multilinestring geometries allowed".to_string(),
+        ))
+    }
+
+    fn polyhedralsurface_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        Err(geozero::error::GeozeroError::Geometry(
+            "Only multilinestring geometries allowed".to_string(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ToGeoArrowMultiLineString;
+    use crate::GeometryArrayTrait;
+    use geo::{line_string, Geometry, GeometryCollection, MultiLineString, MultiPoint, Point};
+
+    fn ml0() -> MultiLineString {
+        MultiLineString::new(vec![
+            line_string![(x: 0., y: 0.), (x: 1., y: 1.)],
+            line_string![(x: 2., y: 2.), (x: 3., y: 3.)],
+        ])
+    }
+
+    fn ml1() -> MultiLineString {
+        MultiLineString::new(vec![line_string![(x: 10., y: 10.), (x: 11., y: 11.)]])
+    }
+
+    #[test]
+    fn from_geozero() {
+        let geo = Geometry::GeometryCollection(GeometryCollection(vec![
+            Geometry::MultiLineString(ml0()),
+            Geometry::MultiLineString(ml1()),
+        ]));
+        let multilinestring_array = geo.to_geoarrow().unwrap();
+        assert_eq!(multilinestring_array.value_as_geo(0), ml0());
+        assert_eq!(multilinestring_array.value_as_geo(1), ml1());
+    }
+
+    #[test]
+    fn from_geozero_error_multiple_geom_types() {
+        let geo = Geometry::GeometryCollection(GeometryCollection(vec![
+            Geometry::MultiLineString(ml0()),
+            Geometry::MultiPoint(MultiPoint(vec![Point::new(0., 0.)])),
+        ]));
+        let err = geo.to_geoarrow().unwrap_err();
+        assert!(matches!(err, geozero::error::GeozeroError::Geometry(..)));
+    }
+
+    #[test]
+    fn from_ewkb_preserves_srid() {
+        use geozero::{CoordDimensions, ToWkb};
+
+        let geo = Geometry::MultiLineString(ml0());
+        let ewkb = geo.to_ewkb(CoordDimensions::xy(), Some(4326)).unwrap();
+
+        let array = super::MutableMultiLineStringArray::from_ewkb(&ewkb).unwrap();
+        assert_eq!(array.crs(), Some("EPSG:4326"));
+        let array: super::MultiLineStringArray = array.into();
+        assert_eq!(array.value_as_geo(0), ml0());
+    }
+
+    #[test]
+    fn interleaved_coord_type_round_trips_through_arrow() {
+        use super::multilinestring_from_geo_vec_with_coord_type;
+        use crate::coord::CoordType;
+        use arrow2::array::{Array, FixedSizeListArray, ListArray};
+
+        let arr =
+            multilinestring_from_geo_vec_with_coord_type(vec![ml0(), ml1()], CoordType::Interleaved);
+        let arrow_arr = arr.into_arrow();
+
+        // The rings' coordinate child should be a FixedSizeList(2), not a StructArray, since
+        // the builder was constructed with CoordType::Interleaved.
+        let rings_arr = arrow_arr
+            .values()
+            .as_any()
+            .downcast_ref::<ListArray<i64>>()
+            .unwrap();
+        let coords_arr = rings_arr
+            .values()
+            .as_any()
+            .downcast_ref::<FixedSizeListArray>()
+            .unwrap();
+        assert_eq!(coords_arr.size(), 2);
+
+        let multilinestring_array: super::MultiLineStringArray = arrow_arr.try_into().unwrap();
+        assert_eq!(multilinestring_array.value_as_geo(0), ml0());
+        assert_eq!(multilinestring_array.value_as_geo(1), ml1());
+    }
+
+    #[test]
+    fn z_buffer_round_trips_as_fixed_size_list_3() {
+        use super::{Dimension, MutableMultiLineStringArray};
+        use crate::coord::MutableCoordBuffer;
+        use arrow2::array::{Array, FixedSizeListArray, ListArray};
+        use arrow2::offset::Offsets;
+
+        let coords = MutableCoordBuffer::Separated(vec![0., 1., 2., 3.], vec![0., 1., 2., 3.]);
+        let mut geom_offsets = Offsets::<i64>::with_capacity(1);
+        geom_offsets.try_push_usize(2).unwrap();
+        let mut ring_offsets = Offsets::<i64>::with_capacity(2);
+        ring_offsets.try_push_usize(2).unwrap();
+        ring_offsets.try_push_usize(4).unwrap();
+
+        let arr = MutableMultiLineStringArray::try_new_from_coords(
+            coords,
+            Some(vec![10., 20., 30., 40.]),
+            geom_offsets,
+            ring_offsets,
+            None,
+        )
+        .unwrap();
+        assert_eq!(arr.dimension(), Dimension::XYZ);
+        assert_eq!(arr.z(), Some([10., 20., 30., 40.].as_slice()));
+
+        let arrow_arr = arr.into_arrow();
+        let rings_arr = arrow_arr
+            .values()
+            .as_any()
+            .downcast_ref::<ListArray<i64>>()
+            .unwrap();
+        let coords_arr = rings_arr
+            .values()
+            .as_any()
+            .downcast_ref::<FixedSizeListArray>()
+            .unwrap();
+        assert_eq!(coords_arr.size(), 3);
+    }
+
+    #[test]
+    fn from_geozero_error_mixed_dimensions() {
+        use super::MutableMultiLineStringArray;
+        use geozero::GeomProcessor;
+
+        let mut arr = MutableMultiLineStringArray::new();
+        arr.multilinestring_begin(1, 0).unwrap();
+        arr.linestring_begin(false, 2, 0).unwrap();
+        arr.xyz(0., 0., 1., 0).unwrap();
+        let err = arr.xy(1., 1., 1).unwrap_err();
+        assert!(matches!(err, geozero::error::GeozeroError::Geometry(..)));
+    }
+}
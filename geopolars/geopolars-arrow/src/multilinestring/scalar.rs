@@ -1,18 +1,16 @@
 use crate::algorithm::bounding_rect::bounding_rect_multilinestring;
+use crate::coord::CoordBuffer;
 use crate::geo_traits::MultiLineStringTrait;
 use crate::LineString;
-use arrow2::buffer::Buffer;
 use arrow2::offset::OffsetsBuffer;
 use rstar::{RTreeObject, AABB};
 
 /// An arrow equivalent of a Polygon
 #[derive(Debug, Clone)]
 pub struct MultiLineString<'a> {
-    /// Buffer of x coordinates
-    pub x: &'a Buffer<f64>,
-
-    /// Buffer of y coordinates
-    pub y: &'a Buffer<f64>,
+    /// The [`CoordBuffer`] of the parent [`crate::MultiLineStringArray`], in whichever physical
+    /// layout it was built with.
+    pub coords: &'a CoordBuffer,
 
     /// Offsets into the ring array where each geometry starts
     pub geom_offsets: &'a OffsetsBuffer<i64>,
@@ -38,8 +36,7 @@ impl<'a> MultiLineStringTrait<'a> for MultiLineString<'a> {
         }
 
         Some(LineString {
-            x: self.x,
-            y: self.y,
+            coords: self.coords,
             geom_offsets: self.ring_offsets,
             geom_index: start + i,
         })
@@ -64,10 +61,8 @@ impl From<&MultiLineString<'_>> for geo::MultiLineString {
             let (start_coord_idx, end_coord_idx) = value.ring_offsets.start_end(ring_idx);
             let mut ring: Vec<geo::Coord> = Vec::with_capacity(end_coord_idx - start_coord_idx);
             for coord_idx in start_coord_idx..end_coord_idx {
-                ring.push(geo::Coord {
-                    x: value.x[coord_idx],
-                    y: value.y[coord_idx],
-                })
+                let (x, y) = value.coords.value(coord_idx);
+                ring.push(geo::Coord { x, y })
             }
             line_strings.push(ring.into());
         }
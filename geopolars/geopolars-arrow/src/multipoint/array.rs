@@ -1,23 +1,25 @@
 use super::MutableMultiPointArray;
+use crate::coord::CoordBuffer;
 use crate::error::GeoArrowError;
 use crate::{GeometryArrayTrait, LineStringArray};
-use arrow2::array::{Array, ListArray, PrimitiveArray, StructArray};
+use arrow2::array::{Array, FixedSizeListArray, ListArray, PrimitiveArray, StructArray};
 use arrow2::bitmap::utils::{BitmapIter, ZipValidity};
 use arrow2::bitmap::Bitmap;
 use arrow2::buffer::Buffer;
+use arrow2::datatypes::{DataType, Field};
 use arrow2::offset::OffsetsBuffer;
 use geozero::{GeomProcessor, GeozeroGeometry};
 use rstar::RTree;
 
 /// A [`GeometryArray`] semantically equivalent to `Vec<Option<MultiPoint>>` using Arrow's
 /// in-memory representation.
+///
+/// Coordinates are stored in a [`CoordBuffer`], which may be either the separated `x`/`y`
+/// layout every producer in this crate used before [`CoordBuffer`] existed, or an interleaved
+/// `[x0, y0, x1, y1, ...]` layout ingested zero-copy from other GeoArrow producers.
 #[derive(Debug, Clone)]
 pub struct MultiPointArray {
-    /// Buffer of x coordinates
-    x: Buffer<f64>,
-
-    /// Buffer of y coordinates
-    y: Buffer<f64>,
+    coords: CoordBuffer,
 
     /// Offsets into the coordinate array where each geometry starts
     geom_offsets: OffsetsBuffer<i64>,
@@ -27,28 +29,22 @@ pub struct MultiPointArray {
 }
 
 pub(super) fn check(
-    x: &[f64],
-    y: &[f64],
+    coords: &CoordBuffer,
     validity_len: Option<usize>,
     geom_offsets: &OffsetsBuffer<i64>,
 ) -> Result<(), GeoArrowError> {
-    // TODO: check geom offsets?
+    // TODO: check geom offsets against coords.len()?
+    let _ = coords;
     if validity_len.map_or(false, |len| len != geom_offsets.len()) {
         return Err(GeoArrowError::General(
             "validity mask length must match the number of values".to_string(),
         ));
     }
-
-    if x.len() != y.len() {
-        return Err(GeoArrowError::General(
-            "x and y arrays must have the same length".to_string(),
-        ));
-    }
     Ok(())
 }
 
 impl MultiPointArray {
-    /// Create a new MultiPointArray from parts
+    /// Create a new MultiPointArray from a separated `x`/`y` pair.
     /// # Implementation
     /// This function is `O(1)`.
     pub fn new(
@@ -57,16 +53,10 @@ impl MultiPointArray {
         geom_offsets: OffsetsBuffer<i64>,
         validity: Option<Bitmap>,
     ) -> Self {
-        check(&x, &y, validity.as_ref().map(|v| v.len()), &geom_offsets).unwrap();
-        Self {
-            x,
-            y,
-            geom_offsets,
-            validity,
-        }
+        Self::try_new(x, y, geom_offsets, validity).unwrap()
     }
 
-    /// Create a new MultiPointArray from parts
+    /// Create a new MultiPointArray from a separated `x`/`y` pair.
     /// # Implementation
     /// This function is `O(1)`.
     pub fn try_new(
@@ -75,14 +65,37 @@ impl MultiPointArray {
         geom_offsets: OffsetsBuffer<i64>,
         validity: Option<Bitmap>,
     ) -> Result<Self, GeoArrowError> {
-        check(&x, &y, validity.as_ref().map(|v| v.len()), &geom_offsets)?;
+        Self::try_new_from_coords(CoordBuffer::Separated(x, y), geom_offsets, validity)
+    }
+
+    /// Create a new MultiPointArray from a [`CoordBuffer`] in either the separated or
+    /// interleaved layout.
+    /// # Implementation
+    /// This function is `O(1)`.
+    pub fn try_new_from_coords(
+        coords: CoordBuffer,
+        geom_offsets: OffsetsBuffer<i64>,
+        validity: Option<Bitmap>,
+    ) -> Result<Self, GeoArrowError> {
+        check(&coords, validity.as_ref().map(|v| v.len()), &geom_offsets)?;
         Ok(Self {
-            x,
-            y,
+            coords,
             geom_offsets,
             validity,
         })
     }
+
+    /// The underlying [`CoordBuffer`], in whichever physical layout this array was built with.
+    #[inline]
+    pub fn coords(&self) -> &CoordBuffer {
+        &self.coords
+    }
+
+    /// Offsets into [`Self::coords`] where each geometry's points start.
+    #[inline]
+    pub fn geom_offsets(&self) -> &OffsetsBuffer<i64> {
+        &self.geom_offsets
+    }
 }
 
 impl<'a> GeometryArrayTrait<'a> for MultiPointArray {
@@ -92,16 +105,28 @@ impl<'a> GeometryArrayTrait<'a> for MultiPointArray {
 
     fn value(&'a self, i: usize) -> Self::Scalar {
         crate::MultiPoint {
-            x: &self.x,
-            y: &self.y,
+            coords: &self.coords,
             geom_offsets: &self.geom_offsets,
             geom_index: i,
         }
     }
 
+    /// Converts this array into its Arrow representation: a `points` `StructArray` child when
+    /// backed by a separated [`CoordBuffer`], or a `FixedSizeList<f64>[2]` child when backed by
+    /// an interleaved one, so interleaved GeoArrow data round-trips without a re-striping copy.
     fn into_arrow(self) -> Self::ArrowArray {
-        let linestring_array: LineStringArray = self.into();
-        linestring_array.into_arrow()
+        let validity = self.validity;
+        let geom_offsets = self.geom_offsets;
+        let coord_array = self.coords.into_arrow();
+        let coord_data_type = coord_array.data_type().clone();
+
+        let list_data_type = DataType::LargeList(Box::new(Field::new(
+            "points",
+            coord_data_type,
+            false,
+        )));
+
+        ListArray::new(list_data_type, geom_offsets, coord_array, validity)
     }
 
     fn rstar_tree(&'a self) -> RTree<Self::Scalar> {
@@ -167,8 +192,7 @@ impl<'a> GeometryArrayTrait<'a> for MultiPointArray {
             .slice_unchecked(offset, length + 1);
 
         Self {
-            x: self.x.clone(),
-            y: self.y.clone(),
+            coords: self.coords.clone(),
             geom_offsets,
             validity,
         }
@@ -218,37 +242,63 @@ impl MultiPointArray {
         ZipValidity::new_with_validity(self.iter_geo_values(), self.validity())
     }
 
-    // GEOS from not implemented for MultiPoint?!?
-    //
-    // /// Returns the value at slot `i` as a GEOS geometry.
-    // #[cfg(feature = "geos")]
-    // pub fn value_as_geos(&self, i: usize) -> geos::Geometry {
-    //     (&self.value_as_geo(i)).try_into().unwrap()
-    // }
-
-    // /// Gets the value at slot `i` as a GEOS geometry, additionally checking the validity bitmap
-    // #[cfg(feature = "geos")]
-    // pub fn get_as_geos(&self, i: usize) -> Option<geos::Geometry> {
-    //     if self.is_null(i) {
-    //         return None;
-    //     }
-
-    //     self.get_as_geo(i).as_ref().map(|g| g.try_into().unwrap())
-    // }
-
-    // /// Iterator over GEOS geometry objects
-    // #[cfg(feature = "geos")]
-    // pub fn iter_geos_values(&self) -> impl Iterator<Item = geos::Geometry> + '_ {
-    //     (0..self.len()).map(|i| self.value_as_geos(i))
-    // }
-
-    // /// Iterator over GEOS geometry objects, taking validity into account
-    // #[cfg(feature = "geos")]
-    // pub fn iter_geos(
-    //     &self,
-    // ) -> ZipValidity<geos::Geometry, impl Iterator<Item = geos::Geometry> + '_, BitmapIter> {
-    //     ZipValidity::new_with_validity(self.iter_geos_values(), self.validity())
-    // }
+    /// Returns the value at slot `i` as a GEOS geometry.
+    #[cfg(feature = "geos")]
+    pub fn value_as_geos(&self, i: usize) -> geos::Geometry {
+        (&self.value_as_geo(i)).try_into().unwrap()
+    }
+
+    /// Gets the value at slot `i` as a GEOS geometry, additionally checking the validity bitmap
+    #[cfg(feature = "geos")]
+    pub fn get_as_geos(&self, i: usize) -> Option<geos::Geometry> {
+        if self.is_null(i) {
+            return None;
+        }
+
+        self.get_as_geo(i).as_ref().map(|g| g.try_into().unwrap())
+    }
+
+    /// Iterator over GEOS geometry objects
+    #[cfg(feature = "geos")]
+    pub fn iter_geos_values(&self) -> impl Iterator<Item = geos::Geometry> + '_ {
+        (0..self.len()).map(|i| self.value_as_geos(i))
+    }
+
+    /// Iterator over GEOS geometry objects, taking validity into account
+    #[cfg(feature = "geos")]
+    pub fn iter_geos(
+        &self,
+    ) -> ZipValidity<geos::Geometry, impl Iterator<Item = geos::Geometry> + '_, BitmapIter> {
+        ZipValidity::new_with_validity(self.iter_geos_values(), self.validity())
+    }
+
+    /// Convert to an Arrow [`ListArray`] backed by 32-bit (`List`, rather than `LargeList`)
+    /// offsets, halving the offset buffer size.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GeoArrowError::Overflow`] if any offset exceeds [`i32::MAX`]. Use
+    /// [`Self::into_arrow`] (via [`GeometryArrayTrait`]) in that case.
+    ///
+    /// Note this always produces separated `x`/`y` buffers, copying into that layout if `self`
+    /// was interleaved, since it goes through the [`LineStringArray`] conversion below.
+    pub fn into_arrow_small(self) -> Result<ListArray<i32>, GeoArrowError> {
+        let linestring_array: LineStringArray = self.into();
+        linestring_array.into_arrow_small()
+    }
+}
+
+impl crate::algorithm::affine::AffineOps for MultiPointArray {
+    /// Applies `transform` to every coordinate in one pass over [`Self::coords`], leaving
+    /// `geom_offsets` and the validity bitmap untouched.
+    fn affine_transform(&self, transform: &crate::algorithm::affine::AffineTransform) -> Self {
+        let coords = crate::algorithm::affine::affine_transform_coords(&self.coords, transform);
+        Self {
+            coords,
+            geom_offsets: self.geom_offsets.clone(),
+            validity: self.validity.clone(),
+        }
+    }
 }
 
 impl TryFrom<ListArray<i64>> for MultiPointArray {
@@ -256,28 +306,40 @@ impl TryFrom<ListArray<i64>> for MultiPointArray {
 
     fn try_from(value: ListArray<i64>) -> Result<Self, Self::Error> {
         let inner_dyn_array = value.values();
-        let struct_array = inner_dyn_array
-            .as_any()
-            .downcast_ref::<StructArray>()
-            .unwrap();
         let geom_offsets = value.offsets();
         let validity = value.validity();
 
-        let x_array_values = struct_array.values()[0]
-            .as_any()
-            .downcast_ref::<PrimitiveArray<f64>>()
-            .unwrap();
-        let y_array_values = struct_array.values()[1]
-            .as_any()
-            .downcast_ref::<PrimitiveArray<f64>>()
-            .unwrap();
-
-        Ok(Self::new(
-            x_array_values.values().clone(),
-            y_array_values.values().clone(),
-            geom_offsets.clone(),
-            validity.cloned(),
-        ))
+        let coords = if let Some(struct_array) =
+            inner_dyn_array.as_any().downcast_ref::<StructArray>()
+        {
+            let x_array_values = struct_array.values()[0]
+                .as_any()
+                .downcast_ref::<PrimitiveArray<f64>>()
+                .unwrap();
+            let y_array_values = struct_array.values()[1]
+                .as_any()
+                .downcast_ref::<PrimitiveArray<f64>>()
+                .unwrap();
+
+            CoordBuffer::Separated(
+                x_array_values.values().clone(),
+                y_array_values.values().clone(),
+            )
+        } else {
+            let fixed_size_list_array = inner_dyn_array
+                .as_any()
+                .downcast_ref::<FixedSizeListArray>()
+                .unwrap();
+            let xy_values = fixed_size_list_array
+                .values()
+                .as_any()
+                .downcast_ref::<PrimitiveArray<f64>>()
+                .unwrap();
+
+            CoordBuffer::Interleaved(xy_values.values().clone())
+        };
+
+        Self::try_new_from_coords(coords, geom_offsets.clone(), validity.cloned())
     }
 }
 
@@ -285,8 +347,15 @@ impl TryFrom<Box<dyn Array>> for MultiPointArray {
     type Error = GeoArrowError;
 
     fn try_from(value: Box<dyn Array>) -> Result<Self, Self::Error> {
-        let arr = value.as_any().downcast_ref::<ListArray<i64>>().unwrap();
-        arr.clone().try_into()
+        // Accept either `LargeList` (i64 offsets, our own native width) or `List` (i32 offsets,
+        // e.g. from a producer that didn't opt into large offsets) by widening the latter up
+        // front; everything past this point only ever deals with `ListArray<i64>`.
+        if let Some(arr) = value.as_any().downcast_ref::<ListArray<i64>>() {
+            arr.clone().try_into()
+        } else {
+            let arr = value.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+            crate::offset::widen_list_array(arr).try_into()
+        }
     }
 }
 
@@ -304,11 +373,22 @@ impl From<Vec<geo::MultiPoint>> for MultiPointArray {
     }
 }
 
+impl From<MultiPointArray> for crate::WKBArray {
+    fn from(value: MultiPointArray) -> Self {
+        let geoms: Vec<Option<geo::Geometry>> = (0..value.len())
+            .map(|i| value.get_as_geo(i).map(geo::Geometry::MultiPoint))
+            .collect();
+        geoms.into()
+    }
+}
+
 /// LineString and MultiPoint have the same layout, so enable conversions between the two to change
-/// the semantic type
+/// the semantic type. This always produces separated `x`/`y` buffers, copying into that layout
+/// if `value` was interleaved.
 impl From<MultiPointArray> for LineStringArray {
     fn from(value: MultiPointArray) -> Self {
-        Self::new(value.x, value.y, value.geom_offsets, value.validity)
+        let (x, y) = value.coords.into_separated();
+        Self::new(x.into(), y.into(), value.geom_offsets, value.validity)
     }
 }
 
@@ -326,11 +406,8 @@ impl GeozeroGeometry for MultiPointArray {
             processor.multipoint_begin(end_coord_idx - start_coord_idx, geom_idx)?;
 
             for coord_idx in start_coord_idx..end_coord_idx {
-                processor.xy(
-                    self.x[coord_idx],
-                    self.y[coord_idx],
-                    coord_idx - start_coord_idx,
-                )?;
+                let (x, y) = self.coords.value(coord_idx);
+                processor.xy(x, y, coord_idx - start_coord_idx)?;
             }
 
             processor.multipoint_end(geom_idx)?;
@@ -3,7 +3,9 @@ use arrow2::bitmap::{Bitmap, MutableBitmap};
 use arrow2::offset::Offsets;
 use arrow2::types::Index;
 use geo::MultiPoint;
+use geozero::{GeomProcessor, GeozeroGeometry};
 
+use crate::coord::{CoordType, MutableCoordBuffer};
 use crate::enum_::GeometryType;
 use crate::error::GeoArrowError;
 use crate::linestring::MutableLineStringArray;
@@ -12,39 +14,43 @@ use crate::trait_::MutableGeometryArray;
 use super::array::MultiPointArray;
 
 /// The Arrow equivalent to `Vec<Option<MultiPoint>>`.
-/// Converting a [`MutableMultiPointArray`] into a [`MultiPointArray`] is `O(1)`.
+/// Converting a [`MutableMultiPointArray`] into a [`MultiPointArray`] is `O(1)` when the
+/// coordinates are [`CoordType::Separated`]; building [`Self::into_arrow`] directly is `O(1)`
+/// regardless of [`CoordType`].
 #[derive(Debug, Clone)]
 pub struct MutableMultiPointArray {
-    x: Vec<f64>,
-    y: Vec<f64>,
+    coords: MutableCoordBuffer,
     geom_offsets: Offsets<i64>,
 
     /// Validity is only defined at the geometry level
     validity: Option<MutableBitmap>,
 }
 
-// Many of the methods here use the From impl from MutableLineStringArray to MutableMultiPointArray
-// to DRY
-
 impl MutableMultiPointArray {
-    /// Creates a new empty [`MutableMultiPointArray`].
+    /// Creates a new empty [`MutableMultiPointArray`] storing coordinates as [`CoordType::Separated`].
     pub fn new() -> Self {
-        MutableLineStringArray::new().into()
+        Self::with_capacities(CoordType::Separated, 0, 0)
     }
 
-    /// Creates a new [`MutableMultiPointArray`] with a capacity.
-    pub fn with_capacities(coord_capacity: usize, geom_capacity: usize) -> Self {
+    /// Creates a new [`MutableMultiPointArray`] with a capacity, storing coordinates in the
+    /// given [`CoordType`] layout.
+    pub fn with_capacities(
+        coord_type: CoordType,
+        coord_capacity: usize,
+        geom_capacity: usize,
+    ) -> Self {
         Self {
-            x: Vec::with_capacity(coord_capacity),
-            y: Vec::with_capacity(coord_capacity),
+            coords: MutableCoordBuffer::with_capacity(coord_type, coord_capacity),
             geom_offsets: Offsets::<i64>::with_capacity(geom_capacity),
             validity: None,
         }
     }
 
-    /// The canonical method to create a [`MutableMultiPointArray`] out of its internal components.
+    /// The canonical method to create a [`MutableMultiPointArray`] out of its internal components,
+    /// storing `x`/`y` in the given [`CoordType`] layout.
     /// # Implementation
-    /// This function is `O(1)`.
+    /// This function is `O(1)` when `coord_type` is [`CoordType::Separated`]; otherwise it copies
+    /// `x` and `y` into a single interleaved buffer.
     ///
     /// # Errors
     /// This function errors iff:
@@ -54,26 +60,69 @@ impl MutableMultiPointArray {
         y: Vec<f64>,
         geom_offsets: Offsets<i64>,
         validity: Option<MutableBitmap>,
+        coord_type: CoordType,
     ) -> Result<Self, GeoArrowError> {
-        MutableLineStringArray::try_new(x, y, geom_offsets, validity).map(|result| result.into())
+        // Can't pass Offsets into the check, expected OffsetsBuffer
+        // use crate::multipoint::array::check;
+        // check(&x, &y, validity.as_ref().map(|x| x.len()), &geom_offsets)?;
+        let coords = match coord_type {
+            CoordType::Separated => MutableCoordBuffer::Separated(x, y),
+            CoordType::Interleaved => {
+                let mut xy = Vec::with_capacity(x.len() * 2);
+                for (x, y) in x.into_iter().zip(y) {
+                    xy.push(x);
+                    xy.push(y);
+                }
+                MutableCoordBuffer::Interleaved(xy)
+            }
+        };
+
+        Ok(Self {
+            coords,
+            geom_offsets,
+            validity,
+        })
     }
 
     /// Extract the low-level APIs from the [`MutableMultiPointArray`].
-    pub fn into_inner(self) -> (Vec<f64>, Vec<f64>, Offsets<i64>, Option<MutableBitmap>) {
-        (self.x, self.y, self.geom_offsets, self.validity)
+    pub fn into_inner(self) -> (MutableCoordBuffer, Offsets<i64>, Option<MutableBitmap>) {
+        (self.coords, self.geom_offsets, self.validity)
     }
 
+    /// Converts this builder directly into Arrow's [`ListArray`] representation.
+    ///
+    /// Unlike converting into a [`MultiPointArray`] first (a conversion that always copies the
+    /// coordinates into separated `x`/`y` buffers), this builds the coordinate child straight from
+    /// this array's [`CoordType`]: a `StructArray` when separated, or a `FixedSizeListArray[2]` when
+    /// interleaved, so interleaved GeoArrow data round-trips without a re-striping copy.
     pub fn into_arrow(self) -> ListArray<i64> {
-        let arr: MultiPointArray = self.into();
-        arr.into_arrow()
+        use arrow2::bitmap::Bitmap as ArrowBitmap;
+        use arrow2::datatypes::{DataType, Field};
+        use arrow2::offset::OffsetsBuffer;
+
+        let validity: Option<ArrowBitmap> = self.validity.and_then(|x| {
+            let bitmap: ArrowBitmap = x.into();
+            (bitmap.unset_bits() > 0).then_some(bitmap)
+        });
+
+        let geom_offsets: OffsetsBuffer<i64> = self.geom_offsets.into();
+        let coord_array = self.coords.into_arrow();
+        let coord_data_type = coord_array.data_type().clone();
+
+        let list_data_type = DataType::LargeList(Box::new(Field::new(
+            "points",
+            coord_data_type,
+            false,
+        )));
+
+        ListArray::new(list_data_type, geom_offsets, coord_array, validity)
     }
 
     /// Adds a new value to the array.
     pub fn try_push_geo(&mut self, value: Option<MultiPoint>) -> Result<(), GeoArrowError> {
         if let Some(multipoint) = value {
             multipoint.0.iter().for_each(|point| {
-                self.x.push(point.x());
-                self.y.push(point.y());
+                self.coords.push_xy(point.x(), point.y());
             });
             self.try_push_valid()?;
         } else {
@@ -86,7 +135,7 @@ impl MutableMultiPointArray {
     /// Needs to be called when a valid value was extended to this array.
     /// This is a relatively low level function, prefer `try_push` when you can.
     pub fn try_push_valid(&mut self) -> Result<(), GeoArrowError> {
-        let total_length = self.x.len();
+        let total_length = self.coords.len();
         let offset = self.geom_offsets.last().to_usize();
         let length = total_length
             .checked_sub(offset)
@@ -131,7 +180,7 @@ impl MutableGeometryArray for MutableMultiPointArray {
     }
 
     fn len(&self) -> usize {
-        self.x.len()
+        self.coords.len()
     }
 
     fn validity(&self) -> Option<&MutableBitmap> {
@@ -147,6 +196,10 @@ impl MutableGeometryArray for MutableMultiPointArray {
     }
 }
 
+/// Note that this always produces a [`MultiPointArray`] storing separated `x`/`y` buffers,
+/// copying into that layout if `other` was interleaved. Callers that need to preserve an
+/// interleaved layout should call [`MutableMultiPointArray::into_arrow`] directly instead of
+/// going through this conversion.
 impl From<MutableMultiPointArray> for MultiPointArray {
     fn from(other: MutableMultiPointArray) -> Self {
         let validity = other.validity.and_then(|x| {
@@ -158,12 +211,9 @@ impl From<MutableMultiPointArray> for MultiPointArray {
             }
         });
 
-        Self::new(
-            other.x.into(),
-            other.y.into(),
-            other.geom_offsets.into(),
-            validity,
-        )
+        let (x, y) = other.coords.into_separated();
+
+        Self::new(x.into(), y.into(), other.geom_offsets.into(), validity)
     }
 }
 
@@ -175,37 +225,36 @@ impl From<MutableMultiPointArray> for ListArray<i64> {
 
 // TODO: in the future it would be useful to DRY the functions here and for LineString
 
-/// Implement a converter that can be used for either Vec<LineString> or
-/// Vec<MultiPoint>
-pub(crate) fn line_string_from_geo_vec(geoms: Vec<MultiPoint>) -> MutableMultiPointArray {
+/// Builds a [`MutableMultiPointArray`] from owned geometries, storing coordinates in the given
+/// [`CoordType`] layout.
+pub fn from_multi_points(geoms: Vec<MultiPoint>, coord_type: CoordType) -> MutableMultiPointArray {
     let mut geom_offsets = Offsets::<i64>::with_capacity(geoms.len());
 
     for geom in &geoms {
         geom_offsets.try_push_usize(geom.0.len()).unwrap();
     }
 
-    let mut x_arr = Vec::<f64>::with_capacity(geom_offsets.last().to_usize());
-    let mut y_arr = Vec::<f64>::with_capacity(geom_offsets.last().to_usize());
+    let mut coords =
+        MutableCoordBuffer::with_capacity(coord_type, geom_offsets.last().to_usize());
 
     for geom in geoms {
         for point in geom.iter() {
-            x_arr.push(point.x());
-            y_arr.push(point.y());
+            coords.push_xy(point.x(), point.y());
         }
     }
 
     MutableMultiPointArray {
-        x: x_arr,
-        y: y_arr,
+        coords,
         geom_offsets,
         validity: None,
     }
 }
 
-/// Implement a converter that can be used for either Vec<Option<LineString>> or
-/// Vec<Option<MultiPoint>>
-pub(crate) fn line_string_from_geo_option_vec(
+/// Builds a [`MutableMultiPointArray`] from owned, nullable geometries, storing coordinates in
+/// the given [`CoordType`] layout.
+pub fn from_nullable_multi_points(
     geoms: Vec<Option<MultiPoint>>,
+    coord_type: CoordType,
 ) -> MutableMultiPointArray {
     let mut geom_offsets = Offsets::<i64>::with_capacity(geoms.len());
     let mut validity = MutableBitmap::with_capacity(geoms.len());
@@ -217,19 +266,17 @@ pub(crate) fn line_string_from_geo_option_vec(
             .unwrap();
     }
 
-    let mut x_arr = Vec::<f64>::with_capacity(geom_offsets.last().to_usize());
-    let mut y_arr = Vec::<f64>::with_capacity(geom_offsets.last().to_usize());
+    let mut coords =
+        MutableCoordBuffer::with_capacity(coord_type, geom_offsets.last().to_usize());
 
     for geom in geoms.into_iter().flatten() {
         for point in geom.iter() {
-            x_arr.push(point.x());
-            y_arr.push(point.y());
+            coords.push_xy(point.x(), point.y());
         }
     }
 
     MutableMultiPointArray {
-        x: x_arr,
-        y: y_arr,
+        coords,
         geom_offsets,
         validity: Some(validity),
     }
@@ -237,20 +284,197 @@ pub(crate) fn line_string_from_geo_option_vec(
 
 impl From<Vec<MultiPoint>> for MutableMultiPointArray {
     fn from(geoms: Vec<MultiPoint>) -> Self {
-        line_string_from_geo_vec(geoms)
+        from_multi_points(geoms, CoordType::Separated)
     }
 }
 
 impl From<Vec<Option<MultiPoint>>> for MutableMultiPointArray {
     fn from(geoms: Vec<Option<MultiPoint>>) -> Self {
-        line_string_from_geo_option_vec(geoms)
+        from_nullable_multi_points(geoms, CoordType::Separated)
     }
 }
 
 /// LineString and MultiPoint have the same layout, so enable conversions between the two to change
-/// the semantic type
+/// the semantic type. This always produces separated `x`/`y` buffers, copying into that layout if
+/// `value` was interleaved.
 impl From<MutableMultiPointArray> for MutableLineStringArray {
     fn from(value: MutableMultiPointArray) -> Self {
-        Self::try_new(value.x, value.y, value.geom_offsets, value.validity).unwrap()
+        let (x, y) = value.coords.into_separated();
+        Self::try_new(x, y, value.geom_offsets, value.validity).unwrap()
+    }
+}
+
+/// Convert to GeoArrow MultiPointArray
+pub trait ToGeoArrowMultiPoint {
+    /// Convert to GeoArrow MultiPointArray
+    fn to_geoarrow(&self) -> geozero::error::Result<MultiPointArray>;
+
+    /// Convert to a GeoArrow MutableMultiPointArray
+    fn to_mutable_geoarrow(&self) -> geozero::error::Result<MutableMultiPointArray>;
+}
+
+impl<T: GeozeroGeometry> ToGeoArrowMultiPoint for T {
+    fn to_geoarrow(&self) -> geozero::error::Result<MultiPointArray> {
+        Ok(self.to_mutable_geoarrow()?.into())
+    }
+
+    fn to_mutable_geoarrow(&self) -> geozero::error::Result<MutableMultiPointArray> {
+        let mut mutable_multipoint_array = MutableMultiPointArray::new();
+        self.process_geom(&mut mutable_multipoint_array)?;
+        Ok(mutable_multipoint_array)
+    }
+}
+
+#[allow(unused_variables)]
+impl GeomProcessor for MutableMultiPointArray {
+    fn xy(&mut self, x: f64, y: f64, _idx: usize) -> geozero::error::Result<()> {
+        self.coords.push_xy(x, y);
+        Ok(())
+    }
+
+    fn multipoint_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        Ok(())
+    }
+
+    fn multipoint_end(&mut self, idx: usize) -> geozero::error::Result<()> {
+        self.try_push_valid()
+            .map_err(|err| geozero::error::GeozeroError::Geometry(err.to_string()))
+    }
+
+    // Override all other trait _begin methods
+    fn circularstring_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        Err(geozero::error::GeozeroError::Geometry(
+            "Only multipoint geometries allowed".to_string(),
+        ))
+    }
+
+    fn compoundcurve_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        Err(geozero::error::GeozeroError::Geometry(
+            "Only multipoint geometries allowed".to_string(),
+        ))
+    }
+
+    fn tin_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        Err(geozero::error::GeozeroError::Geometry(
+            "Only multipoint geometries allowed".to_string(),
+        ))
+    }
+
+    fn polygon_begin(
+        &mut self,
+        tagged: bool,
+        size: usize,
+        idx: usize,
+    ) -> geozero::error::Result<()> {
+        Err(geozero::error::GeozeroError::Geometry(
+            "Only multipoint geometries allowed".to_string(),
+        ))
+    }
+
+    fn triangle_begin(
+        &mut self,
+        tagged: bool,
+        size: usize,
+        idx: usize,
+    ) -> geozero::error::Result<()> {
+        Err(geozero::error::GeozeroError::Geometry(
+            "Only multipoint geometries allowed".to_string(),
+        ))
+    }
+
+    fn linestring_begin(
+        &mut self,
+        tagged: bool,
+        size: usize,
+        idx: usize,
+    ) -> geozero::error::Result<()> {
+        Err(geozero::error::GeozeroError::Geometry(
+            "Only multipoint geometries allowed".to_string(),
+        ))
+    }
+
+    fn multicurve_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        Err(geozero::error::GeozeroError::Geometry(
+            "Only multipoint geometries allowed".to_string(),
+        ))
+    }
+
+    fn curvepolygon_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        Err(geozero::error::GeozeroError::Geometry(
+            "Only multipoint geometries allowed".to_string(),
+        ))
+    }
+
+    fn multipolygon_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        Err(geozero::error::GeozeroError::Geometry(
+            "Only multipoint geometries allowed".to_string(),
+        ))
+    }
+
+    fn multisurface_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        Err(geozero::error::GeozeroError::Geometry(
+            "Only multipoint geometries allowed".to_string(),
+        ))
+    }
+
+    fn multilinestring_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        Err(geozero::error::GeozeroError::Geometry(
+            "Only multipoint geometries allowed".to_string(),
+        ))
+    }
+
+    fn polyhedralsurface_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        Err(geozero::error::GeozeroError::Geometry(
+            "Only multipoint geometries allowed".to_string(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ToGeoArrowMultiPoint;
+    use crate::GeometryArrayTrait;
+    use geo::{point, Geometry, GeometryCollection, MultiPoint, Point};
+
+    fn mp0() -> MultiPoint {
+        MultiPoint(vec![point!(x: 0., y: 1.), point!(x: 1., y: 2.)])
+    }
+
+    fn mp1() -> MultiPoint {
+        MultiPoint(vec![point!(x: 2., y: 3.), point!(x: 3., y: 4.)])
+    }
+
+    #[test]
+    fn from_geozero() {
+        let geo = Geometry::GeometryCollection(GeometryCollection(vec![
+            Geometry::MultiPoint(mp0()),
+            Geometry::MultiPoint(mp1()),
+        ]));
+        let multipoint_array = geo.to_geoarrow().unwrap();
+        assert_eq!(multipoint_array.value_as_geo(0), mp0());
+        assert_eq!(multipoint_array.value_as_geo(1), mp1());
+    }
+
+    #[test]
+    fn from_geozero_error_multiple_geom_types() {
+        let geo = Geometry::GeometryCollection(GeometryCollection(vec![
+            Geometry::MultiPoint(mp0()),
+            Geometry::Point(Point::new(0., 1.)),
+        ]));
+        let err = geo.to_geoarrow().unwrap_err();
+        assert!(matches!(err, geozero::error::GeozeroError::Geometry(..)));
+    }
+
+    #[test]
+    fn interleaved_coord_type_round_trips_through_arrow() {
+        use super::super::mutable::from_multi_points;
+        use crate::coord::CoordType;
+        use crate::GeometryArrayTrait;
+
+        let arr = from_multi_points(vec![mp0(), mp1()], CoordType::Interleaved);
+        let arrow_arr = arr.into_arrow();
+        let multipoint_array: crate::MultiPointArray = arrow_arr.try_into().unwrap();
+        assert_eq!(multipoint_array.value_as_geo(0), mp0());
+        assert_eq!(multipoint_array.value_as_geo(1), mp1());
     }
 }
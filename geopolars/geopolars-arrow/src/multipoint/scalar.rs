@@ -1,7 +1,7 @@
 use crate::algorithm::bounding_rect::bounding_rect_multipoint;
+use crate::coord::CoordBuffer;
 use crate::geo_traits::MultiPointTrait;
 use crate::Point;
-use arrow2::buffer::Buffer;
 use arrow2::offset::OffsetsBuffer;
 use rstar::{RTreeObject, AABB};
 use std::slice::Iter;
@@ -9,11 +9,9 @@ use std::slice::Iter;
 /// An arrow equivalent of a MultiPoint
 #[derive(Debug, Clone)]
 pub struct MultiPoint<'a> {
-    /// Buffer of x coordinates
-    pub x: &'a Buffer<f64>,
-
-    /// Buffer of y coordinates
-    pub y: &'a Buffer<f64>,
+    /// The [`CoordBuffer`] of the parent [`crate::MultiPointArray`], in whichever physical
+    /// layout it was built with.
+    pub coords: &'a CoordBuffer,
 
     /// Offsets into the coordinate array where each geometry starts
     pub geom_offsets: &'a OffsetsBuffer<i64>,
@@ -43,8 +41,8 @@ impl<'a> MultiPointTrait<'a> for MultiPoint<'a> {
         }
 
         let point = Point {
-            x: self.x,
-            y: self.y,
+            coords: self.coords,
+            z: None,
             geom_index: start + i,
         };
         Some(point)
@@ -63,7 +61,8 @@ impl From<&MultiPoint<'_>> for geo::MultiPoint {
         let mut coords: Vec<geo::Point> = Vec::with_capacity(end_idx - start_idx);
 
         for i in start_idx..end_idx {
-            coords.push(geo::Point::new(value.x[i], value.y[i]))
+            let (x, y) = value.coords.value(i);
+            coords.push(geo::Point::new(x, y))
         }
 
         geo::MultiPoint::new(coords)
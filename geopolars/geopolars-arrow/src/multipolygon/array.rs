@@ -1,26 +1,28 @@
+use crate::coord::CoordBuffer;
 use crate::enum_::GeometryType;
 use crate::error::GeoArrowError;
+use crate::rect::RectArray;
 use crate::trait_::GeometryArray;
-use arrow2::array::{Array, ListArray, PrimitiveArray, StructArray};
+use arrow2::array::{Array, FixedSizeListArray, ListArray, PrimitiveArray, StructArray};
 use arrow2::bitmap::utils::{BitmapIter, ZipValidity};
 use arrow2::bitmap::Bitmap;
 use arrow2::buffer::Buffer;
 use arrow2::datatypes::{DataType, Field};
 use arrow2::offset::OffsetsBuffer;
 use geozero::{GeomProcessor, GeozeroGeometry};
-use rstar::RTree;
+use rstar::{RTree, RTreeObject};
 
 use super::MutableMultiPolygonArray;
 
 /// A [`GeometryArray`] semantically equivalent to `Vec<Option<MultiPolygon>>` using Arrow's
 /// in-memory representation.
+///
+/// Coordinates are stored in a [`CoordBuffer`], which may be either the separated `x`/`y`
+/// layout every producer in this crate used before [`CoordBuffer`] existed, or an interleaved
+/// `[x0, y0, x1, y1, ...]` layout ingested zero-copy from other GeoArrow producers.
 #[derive(Debug, Clone)]
 pub struct MultiPolygonArray {
-    /// Buffer of x coordinates
-    x: Buffer<f64>,
-
-    /// Buffer of y coordinates
-    y: Buffer<f64>,
+    coords: CoordBuffer,
 
     /// Offsets into the polygon array where each geometry starts
     geom_offsets: OffsetsBuffer<i64>,
@@ -56,8 +58,21 @@ pub(super) fn check(
     Ok(())
 }
 
+pub(super) fn check_coords(
+    validity_len: Option<usize>,
+    geom_offsets: &OffsetsBuffer<i64>,
+) -> Result<(), GeoArrowError> {
+    // TODO: check geom offsets and ring_offsets?
+    if validity_len.map_or(false, |len| len != geom_offsets.len()) {
+        return Err(GeoArrowError::General(
+            "validity mask length must match the number of values".to_string(),
+        ));
+    }
+    Ok(())
+}
+
 impl MultiPolygonArray {
-    /// Create a new MultiPolygonArray from parts
+    /// Create a new MultiPolygonArray from a separated `x`/`y` pair.
     /// # Implementation
     /// This function is `O(1)`.
     pub fn new(
@@ -70,8 +85,7 @@ impl MultiPolygonArray {
     ) -> Self {
         check(&x, &y, validity.as_ref().map(|v| v.len()), &geom_offsets).unwrap();
         Self {
-            x,
-            y,
+            coords: CoordBuffer::Separated(x, y),
             geom_offsets,
             polygon_offsets,
             ring_offsets,
@@ -79,7 +93,7 @@ impl MultiPolygonArray {
         }
     }
 
-    /// Create a new MultiPolygonArray from parts
+    /// Create a new MultiPolygonArray from a separated `x`/`y` pair.
     /// # Implementation
     /// This function is `O(1)`.
     pub fn try_new(
@@ -92,8 +106,28 @@ impl MultiPolygonArray {
     ) -> Result<Self, GeoArrowError> {
         check(&x, &y, validity.as_ref().map(|v| v.len()), &geom_offsets)?;
         Ok(Self {
-            x,
-            y,
+            coords: CoordBuffer::Separated(x, y),
+            geom_offsets,
+            polygon_offsets,
+            ring_offsets,
+            validity,
+        })
+    }
+
+    /// Create a new MultiPolygonArray from a [`CoordBuffer`] in either the separated or
+    /// interleaved layout.
+    /// # Implementation
+    /// This function is `O(1)`.
+    pub fn try_new_from_coords(
+        coords: CoordBuffer,
+        geom_offsets: OffsetsBuffer<i64>,
+        polygon_offsets: OffsetsBuffer<i64>,
+        ring_offsets: OffsetsBuffer<i64>,
+        validity: Option<Bitmap>,
+    ) -> Result<Self, GeoArrowError> {
+        check_coords(validity.as_ref().map(|v| v.len()), &geom_offsets)?;
+        Ok(Self {
+            coords,
             geom_offsets,
             polygon_offsets,
             ring_offsets,
@@ -112,6 +146,31 @@ impl MultiPolygonArray {
         self.len() == 0
     }
 
+    /// The underlying [`CoordBuffer`], in whichever physical layout this array was built with.
+    /// Values on null slots are undetermined (they can be anything).
+    #[inline]
+    pub fn coords(&self) -> &CoordBuffer {
+        &self.coords
+    }
+
+    /// Offsets into [`Self::polygon_offsets`] where each geometry's member polygons start.
+    #[inline]
+    pub fn geom_offsets(&self) -> &OffsetsBuffer<i64> {
+        &self.geom_offsets
+    }
+
+    /// Offsets into [`Self::ring_offsets`] where each polygon's rings start.
+    #[inline]
+    pub fn polygon_offsets(&self) -> &OffsetsBuffer<i64> {
+        &self.polygon_offsets
+    }
+
+    /// Offsets into [`Self::coords`] where each ring starts.
+    #[inline]
+    pub fn ring_offsets(&self) -> &OffsetsBuffer<i64> {
+        &self.ring_offsets
+    }
+
     /// Returns the optional validity.
     #[inline]
     pub fn validity(&self) -> Option<&Bitmap> {
@@ -163,8 +222,7 @@ impl MultiPolygonArray {
             .slice_unchecked(offset, length + 1);
 
         Self {
-            x: self.x.clone(),
-            y: self.y.clone(),
+            coords: self.coords.clone(),
             geom_offsets,
             polygon_offsets: self.polygon_offsets.clone(),
             ring_offsets: self.ring_offsets.clone(),
@@ -177,8 +235,7 @@ impl MultiPolygonArray {
 impl MultiPolygonArray {
     pub fn value(&self, i: usize) -> crate::MultiPolygon {
         crate::MultiPolygon {
-            x: &self.x,
-            y: &self.y,
+            coords: &self.coords,
             geom_offsets: &self.geom_offsets,
             polygon_offsets: &self.polygon_offsets,
             ring_offsets: &self.ring_offsets,
@@ -233,48 +290,50 @@ impl MultiPolygonArray {
         ZipValidity::new_with_validity(self.iter_geo_values(), self.validity())
     }
 
-    // GEOS from not implemented for MultiLineString I suppose
-    //
-    // /// Returns the value at slot `i` as a GEOS geometry.
-    // #[cfg(feature = "geos")]
-    // pub fn value_as_geos(&self, i: usize) -> geos::Geometry {
-    //     (&self.value_as_geo(i)).try_into().unwrap()
-    // }
-
-    // /// Gets the value at slot `i` as a GEOS geometry, additionally checking the validity bitmap
-    // #[cfg(feature = "geos")]
-    // pub fn get_as_geos(&self, i: usize) -> Option<geos::Geometry> {
-    //     if self.is_null(i) {
-    //         return None;
-    //     }
-
-    //     self.get_as_geo(i).as_ref().map(|g| g.try_into().unwrap())
-    // }
-
-    // /// Iterator over GEOS geometry objects
-    // #[cfg(feature = "geos")]
-    // pub fn iter_geos_values(&self) -> impl Iterator<Item = geos::Geometry> + '_ {
-    //     (0..self.len()).map(|i| self.value_as_geos(i))
-    // }
-
-    // /// Iterator over GEOS geometry objects, taking validity into account
-    // #[cfg(feature = "geos")]
-    // pub fn iter_geos(
-    //     &self,
-    // ) -> ZipValidity<geos::Geometry, impl Iterator<Item = geos::Geometry> + '_, BitmapIter> {
-    //     ZipValidity::new_with_validity(self.iter_geos_values(), self.validity())
-    // }
+    /// Returns the value at slot `i` as a GEOS geometry.
+    #[cfg(feature = "geos")]
+    pub fn value_as_geos(&self, i: usize) -> geos::Geometry {
+        (&self.value_as_geo(i)).try_into().unwrap()
+    }
+
+    /// Gets the value at slot `i` as a GEOS geometry, additionally checking the validity bitmap
+    #[cfg(feature = "geos")]
+    pub fn get_as_geos(&self, i: usize) -> Option<geos::Geometry> {
+        if self.is_null(i) {
+            return None;
+        }
+
+        self.get_as_geo(i).as_ref().map(|g| g.try_into().unwrap())
+    }
+
+    /// Iterator over GEOS geometry objects
+    #[cfg(feature = "geos")]
+    pub fn iter_geos_values(&self) -> impl Iterator<Item = geos::Geometry> + '_ {
+        (0..self.len()).map(|i| self.value_as_geos(i))
+    }
+
+    /// Iterator over GEOS geometry objects, taking validity into account
+    #[cfg(feature = "geos")]
+    pub fn iter_geos(
+        &self,
+    ) -> ZipValidity<geos::Geometry, impl Iterator<Item = geos::Geometry> + '_, BitmapIter> {
+        ZipValidity::new_with_validity(self.iter_geos_values(), self.validity())
+    }
 
     pub fn into_arrow(self) -> ListArray<i64> {
+        // Validity
+        let validity: Option<Bitmap> = if let Some(validity) = self.validity {
+            validity.into()
+        } else {
+            None
+        };
+
+        let coord_array = self.coords.into_arrow();
+        let coord_data_type = coord_array.data_type().clone();
+
         // Data type
-        let coord_field_x = Field::new("x", DataType::Float64, false);
-        let coord_field_y = Field::new("y", DataType::Float64, false);
-        let struct_data_type = DataType::Struct(vec![coord_field_x, coord_field_y]);
-        let inner_list_data_type = DataType::LargeList(Box::new(Field::new(
-            "vertices",
-            struct_data_type.clone(),
-            false,
-        )));
+        let inner_list_data_type =
+            DataType::LargeList(Box::new(Field::new("vertices", coord_data_type, false)));
         let middle_list_data_type = DataType::LargeList(Box::new(Field::new(
             "rings",
             inner_list_data_type.clone(),
@@ -286,20 +345,6 @@ impl MultiPolygonArray {
             true,
         )));
 
-        // Validity
-        let validity: Option<Bitmap> = if let Some(validity) = self.validity {
-            validity.into()
-        } else {
-            None
-        };
-
-        // Array data
-        let array_x = PrimitiveArray::new(DataType::Float64, self.x, None).boxed();
-        let array_y = PrimitiveArray::new(DataType::Float64, self.y, None).boxed();
-
-        // Coord struct array
-        let coord_array = StructArray::new(struct_data_type, vec![array_x, array_y], None).boxed();
-
         // Rings array
         let inner_list_array =
             ListArray::new(inner_list_data_type, self.ring_offsets, coord_array, None).boxed();
@@ -322,12 +367,125 @@ impl MultiPolygonArray {
         )
     }
 
+    /// Convert to an Arrow [`ListArray`] backed by 32-bit (`List`, rather than `LargeList`)
+    /// offsets, halving the offset buffer size.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GeoArrowError::Overflow`] if any of the three offsets buffers exceeds
+    /// [`i32::MAX`]. Use [`Self::into_arrow`] in that case.
+    pub fn into_arrow_small(self) -> Result<ListArray<i32>, GeoArrowError> {
+        if !crate::offset::fits_in_i32(&self.geom_offsets)
+            || !crate::offset::fits_in_i32(&self.polygon_offsets)
+            || !crate::offset::fits_in_i32(&self.ring_offsets)
+        {
+            return Err(GeoArrowError::Overflow);
+        }
+
+        let validity: Option<Bitmap> = if let Some(validity) = self.validity {
+            validity.into()
+        } else {
+            None
+        };
+
+        let coord_array = self.coords.into_arrow();
+        let coord_data_type = coord_array.data_type().clone();
+
+        let inner_list_data_type =
+            DataType::List(Box::new(Field::new("vertices", coord_data_type, false)));
+        let middle_list_data_type = DataType::List(Box::new(Field::new(
+            "rings",
+            inner_list_data_type.clone(),
+            false,
+        )));
+        let outer_list_data_type = DataType::List(Box::new(Field::new(
+            "polygons",
+            middle_list_data_type.clone(),
+            true,
+        )));
+
+        let ring_offsets = crate::offset::narrow_offsets(&self.ring_offsets);
+        let polygon_offsets = crate::offset::narrow_offsets(&self.polygon_offsets);
+        let geom_offsets = crate::offset::narrow_offsets(&self.geom_offsets);
+
+        let inner_list_array =
+            ListArray::new(inner_list_data_type, ring_offsets, coord_array, None).boxed();
+
+        let middle_list_array = ListArray::new(
+            middle_list_data_type,
+            polygon_offsets,
+            inner_list_array,
+            None,
+        )
+        .boxed();
+
+        Ok(ListArray::new(
+            outer_list_data_type,
+            geom_offsets,
+            middle_list_array,
+            validity,
+        ))
+    }
+
     /// Build a spatial index containing this array's geometries
     pub fn rstar_tree(&self) -> RTree<crate::MultiPolygon> {
         let mut tree = RTree::new();
         self.iter().flatten().for_each(|geom| tree.insert(geom));
         tree
     }
+
+    /// Computes the envelope of every multipolygon in this array, returning a [`RectArray`] of
+    /// the same length.
+    ///
+    /// Each envelope is derived from [`crate::MultiPolygon`]'s `RTreeObject` impl, which reads
+    /// directly out of this array's coordinate buffers via
+    /// `geom_offsets`/`polygon_offsets`/`ring_offsets`, so this never materializes an owned
+    /// `geo::MultiPolygon`. Values at null slots are undetermined (they can be anything).
+    pub fn bounds(&self) -> RectArray {
+        let mut values = Vec::with_capacity(self.len() * 4);
+        for i in 0..self.len() {
+            let geom = crate::MultiPolygon {
+                coords: &self.coords,
+                geom_offsets: &self.geom_offsets,
+                polygon_offsets: &self.polygon_offsets,
+                ring_offsets: &self.ring_offsets,
+                geom_index: i,
+            };
+            let envelope = geom.envelope();
+            let lower = envelope.lower();
+            let upper = envelope.upper();
+            values.push(lower[0]);
+            values.push(lower[1]);
+            values.push(upper[0]);
+            values.push(upper[1]);
+        }
+        RectArray::new(values.into(), self.validity.clone())
+    }
+
+    /// Reduces [`Self::bounds`] down to the single box covering every non-null multipolygon in
+    /// this array, analogous to Shapely/GeoPandas' `total_bounds`.
+    ///
+    /// # Panics
+    /// Panics if the array has no non-null geometries, since there is then no box to return.
+    pub fn total_bounds(&self) -> geo::Rect {
+        let bounds = self.bounds();
+        let mut rects = bounds.iter_geo().flatten();
+        let first = rects
+            .next()
+            .expect("total_bounds of an array with no non-null geometries");
+        rects.fold(first, |acc, rect| {
+            geo::Rect::new(
+                geo::coord! {
+                    x: acc.min().x.min(rect.min().x),
+                    y: acc.min().y.min(rect.min().y),
+                },
+                geo::coord! {
+                    x: acc.max().x.max(rect.max().x),
+                    y: acc.max().y.max(rect.max().y),
+                },
+            )
+        })
+    }
 }
 
 impl TryFrom<ListArray<i64>> for MultiPolygonArray {
@@ -338,42 +496,74 @@ impl TryFrom<ListArray<i64>> for MultiPolygonArray {
         let validity = value.validity();
 
         let first_level_dyn_array = value.values();
-        let first_level_array = first_level_dyn_array
-            .as_any()
-            .downcast_ref::<ListArray<i64>>()
-            .unwrap();
+        let first_level_array_i64;
+        let first_level_array = if let Some(arr) =
+            first_level_dyn_array.as_any().downcast_ref::<ListArray<i64>>()
+        {
+            arr
+        } else {
+            let arr_i32 = first_level_dyn_array
+                .as_any()
+                .downcast_ref::<ListArray<i32>>()
+                .unwrap();
+            first_level_array_i64 = crate::offset::widen_list_array(arr_i32);
+            &first_level_array_i64
+        };
 
         let polygon_offsets = first_level_array.offsets();
         let second_level_dyn_array = first_level_array.values();
-        let second_level_array = second_level_dyn_array
-            .as_any()
-            .downcast_ref::<ListArray<i64>>()
-            .unwrap();
+        let second_level_array_i64;
+        let second_level_array = if let Some(arr) =
+            second_level_dyn_array.as_any().downcast_ref::<ListArray<i64>>()
+        {
+            arr
+        } else {
+            let arr_i32 = second_level_dyn_array
+                .as_any()
+                .downcast_ref::<ListArray<i32>>()
+                .unwrap();
+            second_level_array_i64 = crate::offset::widen_list_array(arr_i32);
+            &second_level_array_i64
+        };
 
         let ring_offsets = second_level_array.offsets();
         let coords_dyn_array = second_level_array.values();
-        let coords_array = coords_dyn_array
-            .as_any()
-            .downcast_ref::<StructArray>()
-            .unwrap();
-
-        let x_array_values = coords_array.values()[0]
-            .as_any()
-            .downcast_ref::<PrimitiveArray<f64>>()
-            .unwrap();
-        let y_array_values = coords_array.values()[1]
-            .as_any()
-            .downcast_ref::<PrimitiveArray<f64>>()
-            .unwrap();
-
-        Ok(Self::new(
-            x_array_values.values().clone(),
-            y_array_values.values().clone(),
+
+        let coords = if let Some(coords_array) =
+            coords_dyn_array.as_any().downcast_ref::<StructArray>()
+        {
+            let x_array_values = coords_array.values()[0]
+                .as_any()
+                .downcast_ref::<PrimitiveArray<f64>>()
+                .unwrap();
+            let y_array_values = coords_array.values()[1]
+                .as_any()
+                .downcast_ref::<PrimitiveArray<f64>>()
+                .unwrap();
+            CoordBuffer::Separated(
+                x_array_values.values().clone(),
+                y_array_values.values().clone(),
+            )
+        } else {
+            let coords_array = coords_dyn_array
+                .as_any()
+                .downcast_ref::<FixedSizeListArray>()
+                .unwrap();
+            let values = coords_array
+                .values()
+                .as_any()
+                .downcast_ref::<PrimitiveArray<f64>>()
+                .unwrap();
+            CoordBuffer::Interleaved(values.values().clone())
+        };
+
+        Self::try_new_from_coords(
+            coords,
             geom_offsets.clone(),
             polygon_offsets.clone(),
             ring_offsets.clone(),
             validity.cloned(),
-        ))
+        )
     }
 }
 
@@ -381,8 +571,15 @@ impl TryFrom<Box<dyn Array>> for MultiPolygonArray {
     type Error = GeoArrowError;
 
     fn try_from(value: Box<dyn Array>) -> Result<Self, Self::Error> {
-        let arr = value.as_any().downcast_ref::<ListArray<i64>>().unwrap();
-        arr.clone().try_into()
+        // Accept either `LargeList` (i64 offsets, our own native width) or `List` (i32 offsets,
+        // e.g. from a producer that didn't opt into large offsets) by widening the latter up
+        // front; everything past this point only ever deals with `ListArray<i64>`.
+        if let Some(arr) = value.as_any().downcast_ref::<ListArray<i64>>() {
+            arr.clone().try_into()
+        } else {
+            let arr = value.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+            crate::offset::widen_list_array(arr).try_into()
+        }
     }
 }
 
@@ -438,6 +635,15 @@ impl From<Vec<geo::MultiPolygon>> for MultiPolygonArray {
     }
 }
 
+impl From<MultiPolygonArray> for crate::WKBArray {
+    fn from(value: MultiPolygonArray) -> Self {
+        let geoms: Vec<Option<geo::Geometry>> = (0..value.len())
+            .map(|i| value.get_as_geo(i).map(geo::Geometry::MultiPolygon))
+            .collect();
+        geoms.into()
+    }
+}
+
 impl GeozeroGeometry for MultiPolygonArray {
     fn process_geom<P: GeomProcessor>(&self, processor: &mut P) -> geozero::error::Result<()>
     where
@@ -470,11 +676,8 @@ impl GeozeroGeometry for MultiPolygonArray {
                     )?;
 
                     for coord_idx in start_coord_idx..end_coord_idx {
-                        processor.xy(
-                            self.x[coord_idx],
-                            self.y[coord_idx],
-                            coord_idx - start_coord_idx,
-                        )?;
+                        let (x, y) = self.coords.value(coord_idx);
+                        processor.xy(x, y, coord_idx - start_coord_idx)?;
                     }
 
                     processor.linestring_end(false, ring_idx - start_ring_idx)?;
@@ -566,6 +769,31 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn bounds_and_total_bounds() {
+        let arr: MultiPolygonArray = vec![mp0(), mp1()].into();
+
+        let bounds = arr.bounds();
+        assert_eq!(
+            bounds.value_as_geo(0),
+            geo::Rect::new(
+                geo::coord! { x: -111., y: 41. },
+                geo::coord! { x: -104., y: 45. }
+            )
+        );
+        assert_eq!(
+            bounds.value_as_geo(1),
+            geo::Rect::new(
+                geo::coord! { x: -111., y: 41. },
+                geo::coord! { x: -104., y: 45. }
+            )
+        );
+
+        let total = arr.total_bounds();
+        assert_eq!(total.min(), geo::coord! { x: -111., y: 41. });
+        assert_eq!(total.max(), geo::coord! { x: -104., y: 45. });
+    }
+
     #[test]
     fn slice() {
         let arr: MultiPolygonArray = vec![mp0(), mp1()].into();
@@ -737,10 +965,12 @@ mod test {
         let mut_arr = MutableMultiPolygonArray::try_new(
             x,
             y,
+            None,
             geom_offsets,
             polygon_offsets,
             ring_offsets,
             None,
+            crate::coord::CoordType::Separated,
         )
         .unwrap();
         let arr: MultiPolygonArray = mut_arr.into();
@@ -1,15 +1,19 @@
 use super::array::check;
-use arrow2::array::ListArray;
+use arrow2::array::{Array, ListArray, PrimitiveArray, StructArray};
 use arrow2::bitmap::{Bitmap, MutableBitmap};
+use arrow2::datatypes::{DataType, Field};
 use arrow2::offset::{Offsets, OffsetsBuffer};
+use arrow2::types::Index;
 use geo::MultiPolygon;
+use geozero::{GeomProcessor, GeozeroGeometry};
 
+use crate::coord::{CoordType, MutableCoordBuffer};
 use crate::error::GeoArrowError;
 use crate::MultiPolygonArray;
 
 pub type MutableMultiPolygonParts = (
-    Vec<f64>,
-    Vec<f64>,
+    MutableCoordBuffer,
+    Option<Vec<f64>>,
     Offsets<i64>,
     Offsets<i64>,
     Offsets<i64>,
@@ -17,11 +21,16 @@ pub type MutableMultiPolygonParts = (
 );
 
 /// The Arrow equivalent to `Vec<Option<MultiPolygon>>`.
-/// Converting a [`MutableMultiPolygonArray`] into a [`MultiPolygonArray`] is `O(1)`.
+/// Converting a [`MutableMultiPolygonArray`] into a [`MultiPolygonArray`], or building
+/// [`Self::into_arrow`] directly, is `O(1)` regardless of [`CoordType`]. A `z` buffer, if
+/// present, only affects [`Self::into_arrow`] (which folds it into a three-field `x`/`y`/`z`
+/// `StructArray`); [`MultiPolygonArray`] has no elevation field, so converting to it drops `z`.
 #[derive(Debug, Clone)]
 pub struct MutableMultiPolygonArray {
-    x: Vec<f64>,
-    y: Vec<f64>,
+    coords: MutableCoordBuffer,
+
+    /// An optional elevation buffer, parallel to `coords`. `None` means every coordinate is 2D.
+    z: Option<Vec<f64>>,
 
     /// Offsets into the ring array where each geometry starts
     geom_offsets: Offsets<i64>,
@@ -37,21 +46,23 @@ pub struct MutableMultiPolygonArray {
 }
 
 impl MutableMultiPolygonArray {
-    /// Creates a new empty [`MutableLineStringArray`].
+    /// Creates a new empty [`MutableMultiPolygonArray`] storing coordinates as [`CoordType::Separated`].
     pub fn new() -> Self {
-        Self::with_capacities(0, 0, 0, 0)
+        Self::with_capacities(CoordType::Separated, 0, 0, 0, 0)
     }
 
-    /// Creates a new [`MutableLineStringArray`] with a capacity.
+    /// Creates a new [`MutableMultiPolygonArray`] with a capacity, storing coordinates in the
+    /// given [`CoordType`] layout.
     pub fn with_capacities(
+        coord_type: CoordType,
         coord_capacity: usize,
         geom_capacity: usize,
         polygon_capacity: usize,
         ring_capacity: usize,
     ) -> Self {
         Self {
-            x: Vec::with_capacity(coord_capacity),
-            y: Vec::with_capacity(coord_capacity),
+            coords: MutableCoordBuffer::with_capacity(coord_type, coord_capacity),
+            z: None,
             geom_offsets: Offsets::<i64>::with_capacity(geom_capacity),
             polygon_offsets: Offsets::<i64>::with_capacity(polygon_capacity),
             ring_offsets: Offsets::<i64>::with_capacity(ring_capacity),
@@ -59,25 +70,50 @@ impl MutableMultiPolygonArray {
         }
     }
 
-    /// The canonical method to create a [`MutableLineStringArray`] out of its internal components.
+    /// The canonical method to create a [`MutableMultiPolygonArray`] out of its internal
+    /// components, storing `x`/`y` in the given [`CoordType`] layout.
     /// # Implementation
-    /// This function is `O(1)`.
+    /// This function is `O(1)` when `coord_type` is [`CoordType::Separated`]; otherwise it copies
+    /// `x` and `y` into a single interleaved buffer.
     ///
     /// # Errors
     /// This function errors iff:
     /// * The validity is not `None` and its length is different from `values`'s length
+    /// * `z` is `Some` and its length is different from `x`/`y`'s length
     pub fn try_new(
         x: Vec<f64>,
         y: Vec<f64>,
+        z: Option<Vec<f64>>,
         geom_offsets: Offsets<i64>,
         polygon_offsets: Offsets<i64>,
         ring_offsets: Offsets<i64>,
         validity: Option<MutableBitmap>,
+        coord_type: CoordType,
     ) -> Result<Self, GeoArrowError> {
         check(&x, &y, validity.as_ref().map(|x| x.len()))?;
+        if let Some(z) = &z {
+            if z.len() != x.len() {
+                return Err(GeoArrowError::General(
+                    "z must have the same length as x and y".to_string(),
+                ));
+            }
+        }
+
+        let coords = match coord_type {
+            CoordType::Separated => MutableCoordBuffer::Separated(x, y),
+            CoordType::Interleaved => {
+                let mut xy = Vec::with_capacity(x.len() * 2);
+                for (x, y) in x.into_iter().zip(y) {
+                    xy.push(x);
+                    xy.push(y);
+                }
+                MutableCoordBuffer::Interleaved(xy)
+            }
+        };
+
         Ok(Self {
-            x,
-            y,
+            coords,
+            z,
             geom_offsets,
             polygon_offsets,
             ring_offsets,
@@ -85,11 +121,11 @@ impl MutableMultiPolygonArray {
         })
     }
 
-    /// Extract the low-level APIs from the [`MutableLineStringArray`].
+    /// Extract the low-level APIs from the [`MutableMultiPolygonArray`].
     pub fn into_inner(self) -> MutableMultiPolygonParts {
         (
-            self.x,
-            self.y,
+            self.coords,
+            self.z,
             self.geom_offsets,
             self.polygon_offsets,
             self.ring_offsets,
@@ -97,46 +133,81 @@ impl MutableMultiPolygonArray {
         )
     }
 
+    /// Converts this builder directly into Arrow's [`ListArray`] representation.
+    ///
+    /// Unlike going through [`MultiPolygonArray`] (which has no elevation field and so drops
+    /// `z`), this builds the coordinate child straight from this array's
+    /// [`CoordType`]: a `vertices` `StructArray` when separated, or a `FixedSizeListArray[2]` when
+    /// interleaved, so interleaved GeoArrow data round-trips without a re-striping copy. When a
+    /// `z` buffer is present, the coordinate child is instead a three-field `x`/`y`/`z`
+    /// `StructArray`, regardless of [`CoordType`].
     pub fn into_arrow(self) -> ListArray<i64> {
-        let arr: MultiPolygonArray = self.into();
-        arr.into_arrow()
-    }
-}
-
-impl Default for MutableMultiPolygonArray {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
-impl From<MutableMultiPolygonArray> for MultiPolygonArray {
-    fn from(other: MutableMultiPolygonArray) -> Self {
-        let validity = other.validity.and_then(|x| {
+        let validity: Option<Bitmap> = self.validity.and_then(|x| {
             let bitmap: Bitmap = x.into();
-            if bitmap.unset_bits() == 0 {
-                None
-            } else {
-                Some(bitmap)
-            }
+            (bitmap.unset_bits() > 0).then_some(bitmap)
         });
 
-        let geom_offsets: OffsetsBuffer<i64> = other.geom_offsets.into();
-        let polygon_offsets: OffsetsBuffer<i64> = other.polygon_offsets.into();
-        let ring_offsets: OffsetsBuffer<i64> = other.ring_offsets.into();
+        let geom_offsets: OffsetsBuffer<i64> = self.geom_offsets.into();
+        let polygon_offsets: OffsetsBuffer<i64> = self.polygon_offsets.into();
+        let ring_offsets: OffsetsBuffer<i64> = self.ring_offsets.into();
 
-        Self::new(
-            other.x.into(),
-            other.y.into(),
-            geom_offsets,
+        let coord_array: Box<dyn Array> = match self.z {
+            Some(z) => {
+                let (x, y) = self.coords.into_separated();
+
+                let field_x = Field::new("x", DataType::Float64, false);
+                let field_y = Field::new("y", DataType::Float64, false);
+                let field_z = Field::new("z", DataType::Float64, false);
+                let struct_data_type = DataType::Struct(vec![field_x, field_y, field_z]);
+
+                let array_x = PrimitiveArray::new(DataType::Float64, x.into(), None).boxed();
+                let array_y = PrimitiveArray::new(DataType::Float64, y.into(), None).boxed();
+                let array_z = PrimitiveArray::new(DataType::Float64, z.into(), None).boxed();
+
+                StructArray::new(struct_data_type, vec![array_x, array_y, array_z], None).boxed()
+            }
+            None => self.coords.into_arrow(),
+        };
+        let coord_data_type = coord_array.data_type().clone();
+
+        let inner_list_data_type = DataType::LargeList(Box::new(Field::new(
+            "vertices",
+            coord_data_type,
+            false,
+        )));
+        let middle_list_data_type = DataType::LargeList(Box::new(Field::new(
+            "rings",
+            inner_list_data_type.clone(),
+            false,
+        )));
+        let outer_list_data_type = DataType::LargeList(Box::new(Field::new(
+            "polygons",
+            middle_list_data_type.clone(),
+            true,
+        )));
+
+        let inner_list_array =
+            ListArray::new(inner_list_data_type, ring_offsets, coord_array, None).boxed();
+
+        let middle_list_array = ListArray::new(
+            middle_list_data_type,
             polygon_offsets,
-            ring_offsets,
+            inner_list_array,
+            None,
+        )
+        .boxed();
+
+        ListArray::new(
+            outer_list_data_type,
+            geom_offsets,
+            middle_list_array,
             validity,
         )
     }
-}
 
-impl From<Vec<MultiPolygon>> for MutableMultiPolygonArray {
-    fn from(geoms: Vec<MultiPolygon>) -> Self {
+    /// Builds a [`MutableMultiPolygonArray`] from owned geometries, storing coordinates in the
+    /// given [`CoordType`] layout.
+    pub fn from_multi_polygons(geoms: Vec<MultiPolygon>, coord_type: CoordType) -> Self {
         use geo::coords_iter::CoordsIter;
 
         // Offset into polygon indexes for each geometry
@@ -183,39 +254,41 @@ impl From<Vec<MultiPolygon>> for MutableMultiPolygonArray {
             }
         }
 
-        let mut x_arr = Vec::<f64>::with_capacity(current_ring_offset);
-        let mut y_arr = Vec::<f64>::with_capacity(current_ring_offset);
+        let mut coords = MutableCoordBuffer::with_capacity(coord_type, current_ring_offset);
 
         for multipolygon in geoms {
             for polygon in multipolygon {
                 let ext_ring = polygon.exterior();
                 for coord in ext_ring.coords_iter() {
-                    x_arr.push(coord.x);
-                    y_arr.push(coord.y);
+                    coords.push_xy(coord.x, coord.y);
                 }
 
                 for int_ring in polygon.interiors() {
                     for coord in int_ring.coords_iter() {
-                        x_arr.push(coord.x);
-                        y_arr.push(coord.y);
+                        coords.push_xy(coord.x, coord.y);
                     }
                 }
             }
         }
 
+        // `geo::MultiPolygon` has no Z coordinate in this crate, so there's nothing to populate
+        // the elevation buffer from here; callers who need Z must build via `try_new` directly.
         MutableMultiPolygonArray {
-            x: x_arr,
-            y: y_arr,
+            coords,
+            z: None,
             geom_offsets,
             polygon_offsets,
             ring_offsets,
             validity: None,
         }
     }
-}
 
-impl From<Vec<Option<MultiPolygon>>> for MutableMultiPolygonArray {
-    fn from(geoms: Vec<Option<MultiPolygon>>) -> Self {
+    /// Builds a [`MutableMultiPolygonArray`] from owned, nullable geometries, storing
+    /// coordinates in the given [`CoordType`] layout.
+    pub fn from_nullable_multi_polygons(
+        geoms: Vec<Option<MultiPolygon>>,
+        coord_type: CoordType,
+    ) -> Self {
         use geo::coords_iter::CoordsIter;
 
         let mut validity = MutableBitmap::with_capacity(geoms.len());
@@ -271,29 +344,27 @@ impl From<Vec<Option<MultiPolygon>>> for MutableMultiPolygonArray {
             }
         }
 
-        let mut x_arr = Vec::<f64>::with_capacity(current_ring_offset);
-        let mut y_arr = Vec::<f64>::with_capacity(current_ring_offset);
+        let mut coords = MutableCoordBuffer::with_capacity(coord_type, current_ring_offset);
 
         for multipolygon in geoms.into_iter().flatten() {
             for polygon in multipolygon {
                 let ext_ring = polygon.exterior();
                 for coord in ext_ring.coords_iter() {
-                    x_arr.push(coord.x);
-                    y_arr.push(coord.y);
+                    coords.push_xy(coord.x, coord.y);
                 }
 
                 for int_ring in polygon.interiors() {
                     for coord in int_ring.coords_iter() {
-                        x_arr.push(coord.x);
-                        y_arr.push(coord.y);
+                        coords.push_xy(coord.x, coord.y);
                     }
                 }
             }
         }
 
+        // Same as `from_multi_polygons`: `geo::MultiPolygon` carries no Z, so `z` stays `None`.
         MutableMultiPolygonArray {
-            x: x_arr,
-            y: y_arr,
+            coords,
+            z: None,
             geom_offsets,
             polygon_offsets,
             ring_offsets,
@@ -301,3 +372,277 @@ impl From<Vec<Option<MultiPolygon>>> for MutableMultiPolygonArray {
         }
     }
 }
+
+impl Default for MutableMultiPolygonArray {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Note that [`MultiPolygonArray`] has no elevation field, so if `other` carries a `z` buffer it
+/// is silently dropped here. Callers that need to preserve elevation should call
+/// [`MutableMultiPolygonArray::into_arrow`] directly instead of going through this conversion.
+/// Otherwise this preserves whichever [`CoordType`] `other` was built with, so converting a
+/// [`MutableMultiPolygonArray`] built as [`CoordType::Interleaved`] is `O(1)`, with no
+/// re-striping copy.
+impl From<MutableMultiPolygonArray> for MultiPolygonArray {
+    fn from(other: MutableMultiPolygonArray) -> Self {
+        let validity = other.validity.and_then(|x| {
+            let bitmap: Bitmap = x.into();
+            if bitmap.unset_bits() == 0 {
+                None
+            } else {
+                Some(bitmap)
+            }
+        });
+
+        let geom_offsets: OffsetsBuffer<i64> = other.geom_offsets.into();
+        let polygon_offsets: OffsetsBuffer<i64> = other.polygon_offsets.into();
+        let ring_offsets: OffsetsBuffer<i64> = other.ring_offsets.into();
+
+        Self::try_new_from_coords(
+            other.coords.into(),
+            geom_offsets,
+            polygon_offsets,
+            ring_offsets,
+            validity,
+        )
+        .unwrap()
+    }
+}
+
+impl From<Vec<MultiPolygon>> for MutableMultiPolygonArray {
+    fn from(geoms: Vec<MultiPolygon>) -> Self {
+        Self::from_multi_polygons(geoms, CoordType::Separated)
+    }
+}
+
+impl From<Vec<Option<MultiPolygon>>> for MutableMultiPolygonArray {
+    fn from(geoms: Vec<Option<MultiPolygon>>) -> Self {
+        Self::from_nullable_multi_polygons(geoms, CoordType::Separated)
+    }
+}
+
+/// Convert to GeoArrow MultiPolygonArray
+pub trait ToGeoArrowMultiPolygon {
+    /// Convert to GeoArrow MultiPolygonArray
+    fn to_geoarrow(&self) -> geozero::error::Result<MultiPolygonArray>;
+
+    /// Convert to a GeoArrow MutableMultiPolygonArray
+    fn to_mutable_geoarrow(&self) -> geozero::error::Result<MutableMultiPolygonArray>;
+}
+
+impl<T: GeozeroGeometry> ToGeoArrowMultiPolygon for T {
+    fn to_geoarrow(&self) -> geozero::error::Result<MultiPolygonArray> {
+        Ok(self.to_mutable_geoarrow()?.into())
+    }
+
+    fn to_mutable_geoarrow(&self) -> geozero::error::Result<MutableMultiPolygonArray> {
+        let mut mutable_multipolygon_array = MutableMultiPolygonArray::new();
+        self.process_geom(&mut mutable_multipolygon_array)?;
+        Ok(mutable_multipolygon_array)
+    }
+}
+
+#[allow(unused_variables)]
+impl GeomProcessor for MutableMultiPolygonArray {
+    fn xy(&mut self, x: f64, y: f64, _idx: usize) -> geozero::error::Result<()> {
+        self.coords.push_xy(x, y);
+        Ok(())
+    }
+
+    fn linestring_begin(
+        &mut self,
+        tagged: bool,
+        size: usize,
+        idx: usize,
+    ) -> geozero::error::Result<()> {
+        Ok(())
+    }
+
+    fn linestring_end(&mut self, tagged: bool, idx: usize) -> geozero::error::Result<()> {
+        let total_length = self.coords.len();
+        let offset = self.ring_offsets.last().to_usize();
+        let length = total_length
+            .checked_sub(offset)
+            .ok_or(GeoArrowError::Overflow)
+            .map_err(|err| geozero::error::GeozeroError::Geometry(err.to_string()))?;
+
+        self.ring_offsets.try_push_usize(length).unwrap();
+        Ok(())
+    }
+
+    fn polygon_begin(&mut self, tagged: bool, size: usize, idx: usize) -> geozero::error::Result<()> {
+        Ok(())
+    }
+
+    fn polygon_end(&mut self, tagged: bool, idx: usize) -> geozero::error::Result<()> {
+        let num_rings = self.ring_offsets.len_proxy() - self.polygon_offsets.last().to_usize();
+        self.polygon_offsets.try_push_usize(num_rings).unwrap();
+
+        // A `tagged` polygon arrived on its own, with no enclosing `multipolygon_begin`/`_end`
+        // pair to close out `geom_offsets` for us. Treat it as a length-1 multipolygon.
+        if tagged {
+            self.geom_offsets.try_push_usize(1).unwrap();
+            if let Some(validity) = &mut self.validity {
+                validity.push(true);
+            }
+        }
+        Ok(())
+    }
+
+    fn multipolygon_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        Ok(())
+    }
+
+    fn multipolygon_end(&mut self, idx: usize) -> geozero::error::Result<()> {
+        let num_polygons = self.polygon_offsets.len_proxy() - self.geom_offsets.last().to_usize();
+        self.geom_offsets.try_push_usize(num_polygons).unwrap();
+        if let Some(validity) = &mut self.validity {
+            validity.push(true);
+        }
+        Ok(())
+    }
+
+    // Override all other trait _begin methods
+    fn circularstring_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        Err(geozero::error::GeozeroError::Geometry(
+            "Only multipolygon geometries allowed".to_string(),
+        ))
+    }
+
+    fn compoundcurve_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        Err(geozero::error::GeozeroError::Geometry(
+            "Only multipolygon geometries allowed".to_string(),
+        ))
+    }
+
+    fn tin_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        Err(geozero::error::GeozeroError::Geometry(
+            "Only multipolygon geometries allowed".to_string(),
+        ))
+    }
+
+    fn triangle_begin(
+        &mut self,
+        tagged: bool,
+        size: usize,
+        idx: usize,
+    ) -> geozero::error::Result<()> {
+        Err(geozero::error::GeozeroError::Geometry(
+            "Only multipolygon geometries allowed".to_string(),
+        ))
+    }
+
+    fn multicurve_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        Err(geozero::error::GeozeroError::Geometry(
+            "Only multipolygon geometries allowed".to_string(),
+        ))
+    }
+
+    fn multipoint_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        Err(geozero::error::GeozeroError::Geometry(
+            "Only multipolygon geometries allowed".to_string(),
+        ))
+    }
+
+    fn curvepolygon_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        Err(geozero::error::GeozeroError::Geometry(
+            "Only multipolygon geometries allowed".to_string(),
+        ))
+    }
+
+    fn multisurface_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        Err(geozero::error::GeozeroError::Geometry(
+            "Only multipolygon geometries allowed".to_string(),
+        ))
+    }
+
+    fn multilinestring_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        Err(geozero::error::GeozeroError::Geometry(
+            "Only multipolygon geometries allowed".to_string(),
+        ))
+    }
+
+    fn polyhedralsurface_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        Err(geozero::error::GeozeroError::Geometry(
+            "Only multipolygon geometries allowed".to_string(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ToGeoArrowMultiPolygon;
+    use crate::GeometryArrayTrait;
+    use geo::{polygon, Geometry, GeometryCollection, MultiPoint, MultiPolygon, Point};
+
+    fn mp0() -> MultiPolygon {
+        MultiPolygon::new(vec![
+            polygon![
+                (x: 0., y: 0.),
+                (x: 4., y: 0.),
+                (x: 4., y: 4.),
+                (x: 0., y: 4.),
+                (x: 0., y: 0.),
+            ],
+            polygon![
+                (x: 10., y: 10.),
+                (x: 14., y: 10.),
+                (x: 14., y: 14.),
+                (x: 10., y: 14.),
+                (x: 10., y: 10.),
+            ],
+        ])
+    }
+
+    fn mp1() -> MultiPolygon {
+        MultiPolygon::new(vec![polygon![
+            (x: 20., y: 20.),
+            (x: 24., y: 20.),
+            (x: 24., y: 24.),
+            (x: 20., y: 24.),
+            (x: 20., y: 20.),
+        ]])
+    }
+
+    #[test]
+    fn from_geozero() {
+        let geo = Geometry::GeometryCollection(GeometryCollection(vec![
+            Geometry::MultiPolygon(mp0()),
+            Geometry::MultiPolygon(mp1()),
+        ]));
+        let multipolygon_array = geo.to_geoarrow().unwrap();
+        assert_eq!(multipolygon_array.value_as_geo(0), mp0());
+        assert_eq!(multipolygon_array.value_as_geo(1), mp1());
+    }
+
+    #[test]
+    fn from_geozero_error_multiple_geom_types() {
+        let geo = Geometry::GeometryCollection(GeometryCollection(vec![
+            Geometry::MultiPolygon(mp0()),
+            Geometry::MultiPoint(MultiPoint(vec![Point::new(0., 0.)])),
+        ]));
+        let err = geo.to_geoarrow().unwrap_err();
+        assert!(matches!(err, geozero::error::GeozeroError::Geometry(..)));
+    }
+
+    #[test]
+    fn from_geozero_bare_polygon_no_multipolygon_wrapper() {
+        let p0 = polygon![
+            (x: 0., y: 0.),
+            (x: 4., y: 0.),
+            (x: 4., y: 4.),
+            (x: 0., y: 4.),
+            (x: 0., y: 0.),
+        ];
+        let geo = Geometry::GeometryCollection(GeometryCollection(vec![Geometry::Polygon(
+            p0.clone(),
+        )]));
+        let multipolygon_array = geo.to_geoarrow().unwrap();
+        assert_eq!(
+            multipolygon_array.value_as_geo(0),
+            MultiPolygon::new(vec![p0])
+        );
+    }
+}
@@ -0,0 +1,156 @@
+//! Helpers for converting between 32-bit and 64-bit Arrow list offsets.
+//!
+//! Every list-backed array in this crate is currently hardcoded to `i64` offsets (`Offsets<i64>`
+//! while mutable, `OffsetsBuffer<i64>` once frozen), which doubles offset memory for the common
+//! case where coordinate counts fit comfortably in 32 bits and blocks zero-copy interop with
+//! producers that emit Arrow's plain `List` (`i32` offsets) rather than `LargeList` (`i64`
+//! offsets). These helpers convert between the two so callers can accept either without every
+//! array module needing its own copy of the conversion logic.
+
+use crate::error::GeoArrowError;
+use arrow2::array::ListArray;
+use arrow2::datatypes::DataType;
+use arrow2::offset::{Offsets, OffsetsBuffer};
+
+/// Widen a buffer of 32-bit offsets into 64-bit offsets.
+pub fn widen_offsets(offsets: &OffsetsBuffer<i32>) -> OffsetsBuffer<i64> {
+    let widened: Vec<i64> = offsets.as_slice().iter().map(|&o| o as i64).collect();
+    OffsetsBuffer::try_from(widened).unwrap()
+}
+
+/// Narrow a buffer of 64-bit offsets into 32-bit offsets.
+///
+/// # Panics
+///
+/// Panics if any offset exceeds `i32::MAX`.
+pub fn narrow_offsets(offsets: &OffsetsBuffer<i64>) -> OffsetsBuffer<i32> {
+    let narrowed: Vec<i32> = offsets
+        .as_slice()
+        .iter()
+        .map(|&o| i32::try_from(o).expect("offset overflows i32"))
+        .collect();
+    OffsetsBuffer::try_from(narrowed).unwrap()
+}
+
+/// Returns `true` if every offset in `offsets` fits in an `i32`, i.e. [`narrow_offsets`] would
+/// not panic on it.
+pub fn fits_in_i32(offsets: &OffsetsBuffer<i64>) -> bool {
+    offsets.last() <= i32::MAX as i64
+}
+
+/// Validates that `offsets` is a well-formed Arrow offsets buffer: it starts at `0` and is
+/// monotonically non-decreasing. `name` identifies the buffer in the returned error so callers
+/// can tell which of several offset buffers (e.g. `geom_offsets` vs `ring_offsets`) failed.
+pub(crate) fn validate_offsets(name: &str, offsets: &OffsetsBuffer<i64>) -> Result<(), GeoArrowError> {
+    let slice = offsets.as_slice();
+    if slice.first() != Some(&0) {
+        return Err(GeoArrowError::General(format!(
+            "{name} must start at offset 0"
+        )));
+    }
+    if !slice.windows(2).all(|pair| pair[0] <= pair[1]) {
+        return Err(GeoArrowError::General(format!(
+            "{name} must be monotonically non-decreasing"
+        )));
+    }
+    Ok(())
+}
+
+/// Widen a mutable buffer of 32-bit offsets into 64-bit offsets.
+pub fn widen_mutable_offsets(offsets: &Offsets<i32>) -> Offsets<i64> {
+    let widened: Vec<i64> = offsets.as_slice().iter().map(|&o| o as i64).collect();
+    Offsets::try_from(widened).unwrap()
+}
+
+/// Narrow a mutable buffer of 64-bit offsets into 32-bit offsets.
+///
+/// # Panics
+///
+/// Panics if any offset exceeds `i32::MAX`.
+pub fn narrow_mutable_offsets(offsets: &Offsets<i64>) -> Offsets<i32> {
+    let narrowed: Vec<i32> = offsets
+        .as_slice()
+        .iter()
+        .map(|&o| i32::try_from(o).expect("offset overflows i32"))
+        .collect();
+    Offsets::try_from(narrowed).unwrap()
+}
+
+/// Widens a 32-bit offset `List` array into the equivalent 64-bit offset `LargeList` array,
+/// preserving its values and validity untouched. This lets a `TryFrom<ListArray<i64>>` impl
+/// accept either list width by widening a `List` input up front and otherwise proceeding as if
+/// it had always been a `LargeList`.
+pub(crate) fn widen_list_array(array: &ListArray<i32>) -> ListArray<i64> {
+    let field = match array.data_type() {
+        DataType::List(field) => field.clone(),
+        other => unreachable!("ListArray<i32> must have a List data type, got {other:?}"),
+    };
+    ListArray::new(
+        DataType::LargeList(field),
+        widen_offsets(array.offsets()),
+        array.values().clone(),
+        array.validity().cloned(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrips_through_i32_and_i64() {
+        let offsets = OffsetsBuffer::try_from(vec![0i64, 2, 5, 9]).unwrap();
+        let narrowed = narrow_offsets(&offsets);
+        let widened = widen_offsets(&narrowed);
+        assert_eq!(offsets.as_slice(), widened.as_slice());
+    }
+
+    #[test]
+    fn fits_in_i32_detects_overflow() {
+        let small = OffsetsBuffer::try_from(vec![0i64, 2, 5]).unwrap();
+        assert!(fits_in_i32(&small));
+
+        let huge = OffsetsBuffer::try_from(vec![0i64, i32::MAX as i64 + 1]).unwrap();
+        assert!(!fits_in_i32(&huge));
+    }
+
+    #[test]
+    #[should_panic(expected = "overflows i32")]
+    fn narrow_offsets_panics_on_overflow() {
+        let huge = OffsetsBuffer::try_from(vec![0i64, i32::MAX as i64 + 1]).unwrap();
+        narrow_offsets(&huge);
+    }
+
+    #[test]
+    fn validate_offsets_accepts_well_formed_buffer() {
+        let offsets = OffsetsBuffer::try_from(vec![0i64, 2, 5, 9]).unwrap();
+        assert!(validate_offsets("geom_offsets", &offsets).is_ok());
+    }
+
+    #[test]
+    fn validate_offsets_rejects_non_zero_start() {
+        let offsets = OffsetsBuffer::try_from(vec![1i64, 2, 5]).unwrap();
+        let err = validate_offsets("geom_offsets", &offsets).unwrap_err();
+        assert!(matches!(err, GeoArrowError::General(msg) if msg.contains("geom_offsets")));
+    }
+
+    #[test]
+    fn widen_list_array_preserves_values_and_validity() {
+        use arrow2::array::{ListArray, PrimitiveArray};
+        use arrow2::datatypes::{DataType, Field};
+
+        let values = PrimitiveArray::<i32>::from_slice([1, 2, 3, 4, 5]).boxed();
+        let data_type = DataType::List(Box::new(Field::new(
+            "item",
+            DataType::Int32,
+            true,
+        )));
+        let offsets = OffsetsBuffer::try_from(vec![0i32, 2, 5]).unwrap();
+        let list = ListArray::<i32>::new(data_type, offsets, values.clone(), None);
+
+        let widened = widen_list_array(&list);
+        assert_eq!(widened.offsets().as_slice(), &[0i64, 2, 5]);
+        assert_eq!(widened.values(), &values);
+        assert_eq!(widened.data_type(), &DataType::LargeList(Box::new(Field::new("item", DataType::Int32, true))));
+    }
+}
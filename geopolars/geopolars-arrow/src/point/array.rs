@@ -1,3 +1,4 @@
+use crate::coord::CoordBuffer;
 use crate::enum_::GeometryType;
 use crate::error::GeoArrowError;
 use crate::trait_::GeometryArray;
@@ -6,15 +7,30 @@ use arrow2::array::{Array, PrimitiveArray, StructArray};
 use arrow2::bitmap::utils::{BitmapIter, ZipValidity};
 use arrow2::bitmap::Bitmap;
 use arrow2::buffer::Buffer;
-use arrow2::datatypes::{DataType, Field};
+use arrow2::datatypes::{DataType, Field, Metadata};
 use geozero::{GeomProcessor, GeozeroGeometry};
 
+/// Which coordinate dimensions a [`PointArray`] carries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Dimension {
+    /// `x`/`y` only. This is the layout every `PointArray` had before [`Dimension`] existed.
+    #[default]
+    XY,
+    /// `x`/`y`/`z`, e.g. elevation data or 3D point clouds.
+    XYZ,
+}
+
 /// A [`GeometryArray`] semantically equivalent to `Vec<Option<Point>>` using Arrow's
 /// in-memory representation.
+///
+/// Coordinates are stored in a [`CoordBuffer`], which may be either the separated `x`/`y`
+/// layout every producer in this crate used before [`CoordBuffer`] existed, or an interleaved
+/// `[x0, y0, x1, y1, ...]` layout ingested zero-copy from other GeoArrow producers. An optional
+/// `z` buffer, guarded by [`Dimension`], adds a third coordinate on top of either layout.
 #[derive(Debug, Clone)]
 pub struct PointArray {
-    x: Buffer<f64>,
-    y: Buffer<f64>,
+    coords: CoordBuffer,
+    z: Option<Buffer<f64>>,
     validity: Option<Bitmap>,
 }
 
@@ -37,16 +53,40 @@ pub(super) fn check(
     Ok(())
 }
 
+pub(super) fn check_coords(
+    coords: &CoordBuffer,
+    z: Option<&Buffer<f64>>,
+    validity_len: Option<usize>,
+) -> Result<(), GeoArrowError> {
+    if validity_len.map_or(false, |len| len != coords.len()) {
+        return Err(GeoArrowError::General(
+            "validity mask length must match the number of values".to_string(),
+        ));
+    }
+    if let Some(z) = z {
+        if z.len() != coords.len() {
+            return Err(GeoArrowError::General(
+                "z buffer length must match the number of values".to_string(),
+            ));
+        }
+    }
+    Ok(())
+}
+
 impl PointArray {
-    /// Create a new PointArray from parts
+    /// Create a new PointArray from a separated `x`/`y` pair.
     /// # Implementation
     /// This function is `O(1)`.
     pub fn new(x: Buffer<f64>, y: Buffer<f64>, validity: Option<Bitmap>) -> Self {
         check(&x, &y, validity.as_ref().map(|v| v.len())).unwrap();
-        Self { x, y, validity }
+        Self {
+            coords: CoordBuffer::Separated(x, y),
+            z: None,
+            validity,
+        }
     }
 
-    /// Create a new PointArray from parts
+    /// Create a new PointArray from a separated `x`/`y` pair.
     /// # Implementation
     /// This function is `O(1)`.
     pub fn try_new(
@@ -55,13 +95,53 @@ impl PointArray {
         validity: Option<Bitmap>,
     ) -> Result<Self, GeoArrowError> {
         check(&x, &y, validity.as_ref().map(|v| v.len()))?;
-        Ok(Self { x, y, validity })
+        Ok(Self {
+            coords: CoordBuffer::Separated(x, y),
+            z: None,
+            validity,
+        })
+    }
+
+    /// Create a new 3D PointArray from a separated `x`/`y`/`z` triple.
+    /// # Implementation
+    /// This function is `O(1)`.
+    pub fn try_new_xyz(
+        x: Buffer<f64>,
+        y: Buffer<f64>,
+        z: Buffer<f64>,
+        validity: Option<Bitmap>,
+    ) -> Result<Self, GeoArrowError> {
+        check(&x, &y, validity.as_ref().map(|v| v.len()))?;
+        let coords = CoordBuffer::Separated(x, y);
+        check_coords(&coords, Some(&z), validity.as_ref().map(|v| v.len()))?;
+        Ok(Self {
+            coords,
+            z: Some(z),
+            validity,
+        })
+    }
+
+    /// Create a new PointArray from a [`CoordBuffer`] in either the separated or interleaved
+    /// layout, with an optional `z` buffer.
+    /// # Implementation
+    /// This function is `O(1)`.
+    pub fn try_new_from_coords(
+        coords: CoordBuffer,
+        z: Option<Buffer<f64>>,
+        validity: Option<Bitmap>,
+    ) -> Result<Self, GeoArrowError> {
+        check_coords(&coords, z.as_ref(), validity.as_ref().map(|v| v.len()))?;
+        Ok(Self {
+            coords,
+            z,
+            validity,
+        })
     }
 
     /// Returns the number of geometries in this array
     #[inline]
     pub fn len(&self) -> usize {
-        self.x.len()
+        self.coords.len()
     }
 
     /// Returns true if the array is empty
@@ -69,18 +149,27 @@ impl PointArray {
         self.len() == 0
     }
 
-    /// The values [`Buffer`].
+    /// The underlying [`CoordBuffer`], in whichever physical layout this array was built with.
     /// Values on null slots are undetermined (they can be anything).
     #[inline]
-    pub fn values_x(&self) -> &Buffer<f64> {
-        &self.x
+    pub fn coords(&self) -> &CoordBuffer {
+        &self.coords
     }
 
-    /// The values [`Buffer`].
-    /// Values on null slots are undetermined (they can be anything).
+    /// The optional `z` buffer. Values on null slots are undetermined (they can be anything).
+    #[inline]
+    pub fn z(&self) -> Option<&Buffer<f64>> {
+        self.z.as_ref()
+    }
+
+    /// Which coordinate dimensions this array carries.
     #[inline]
-    pub fn values_y(&self) -> &Buffer<f64> {
-        &self.y
+    pub fn dimension(&self) -> Dimension {
+        if self.z.is_some() {
+            Dimension::XYZ
+        } else {
+            Dimension::XY
+        }
     }
 
     /// Returns the optional validity.
@@ -128,8 +217,11 @@ impl PointArray {
             .map(|bitmap| bitmap.slice_unchecked(offset, length))
             .and_then(|bitmap| (bitmap.unset_bits() > 0).then_some(bitmap));
         Self {
-            x: self.x.clone().slice_unchecked(offset, length),
-            y: self.y.clone().slice_unchecked(offset, length),
+            coords: self.coords.slice(offset, length),
+            z: self
+                .z
+                .clone()
+                .map(|z| z.slice_unchecked(offset, length)),
             validity,
         }
     }
@@ -139,8 +231,8 @@ impl PointArray {
 impl PointArray {
     pub fn value(&self, i: usize) -> crate::Point {
         crate::Point {
-            x: &self.x,
-            y: &self.y,
+            coords: &self.coords,
+            z: self.z.as_ref(),
             geom_index: i,
         }
     }
@@ -209,23 +301,63 @@ impl PointArray {
         ZipValidity::new_with_validity(self.iter_geos_values(), self.validity())
     }
 
-    pub fn into_arrow(self) -> StructArray {
-        let field_x = Field::new("x", DataType::Float64, false);
-        let field_y = Field::new("y", DataType::Float64, false);
-
-        let array_x = PrimitiveArray::new(DataType::Float64, self.x, None).boxed();
-        let array_y = PrimitiveArray::new(DataType::Float64, self.y, None).boxed();
-
-        let struct_data_type = DataType::Struct(vec![field_x, field_y]);
-        let struct_values = vec![array_x, array_y];
+    /// Converts this array into its Arrow representation: a `x`/`y` (and, when 3D, `z`)
+    /// [`StructArray`] when backed by a separated [`CoordBuffer`], or a `FixedSizeList<f64>[2]`
+    /// when backed by an interleaved one (interleaved `z` is not yet supported).
+    pub fn into_arrow(self) -> Box<dyn Array> {
+        match self.coords {
+            CoordBuffer::Separated(x, y) => {
+                let field_x = Field::new("x", DataType::Float64, false);
+                let field_y = Field::new("y", DataType::Float64, false);
+
+                let array_x = PrimitiveArray::new(DataType::Float64, x, None).boxed();
+                let array_y = PrimitiveArray::new(DataType::Float64, y, None).boxed();
+
+                let (fields, values): (Vec<Field>, Vec<Box<dyn Array>>) = match self.z {
+                    Some(z) => {
+                        let field_z = Field::new("z", DataType::Float64, false);
+                        let array_z = PrimitiveArray::new(DataType::Float64, z, None).boxed();
+                        (
+                            vec![field_x, field_y, field_z],
+                            vec![array_x, array_y, array_z],
+                        )
+                    }
+                    None => (vec![field_x, field_y], vec![array_x, array_y]),
+                };
+
+                let struct_data_type = DataType::Struct(fields);
+                StructArray::new(struct_data_type, values, self.validity).boxed()
+            }
+            CoordBuffer::Interleaved(xy) => {
+                let values_field = Field::new("xy", DataType::Float64, false);
+                let values = PrimitiveArray::new(DataType::Float64, xy, None).boxed();
+
+                arrow2::array::FixedSizeListArray::new(
+                    DataType::FixedSizeList(Box::new(values_field), 2),
+                    values,
+                    self.validity,
+                )
+                .boxed()
+            }
+        }
+    }
 
-        let validity: Option<Bitmap> = if let Some(validity) = self.validity {
-            validity.into()
-        } else {
-            None
-        };
+    /// Builds the Arrow [`Field`] describing this array as a GeoArrow extension column.
+    ///
+    /// See [`crate::MultiLineStringArray::extension_field`] for why this doesn't flow through
+    /// [`into_arrow`](Self::into_arrow): polars doesn't yet carry extension-type metadata through
+    /// a `Series`, so this is for callers that write Arrow/Parquet schemas, or hand a column
+    /// across the C Data Interface via [`crate::ffi`], directly.
+    pub fn extension_field(&self, name: &str) -> Field {
+        let data_type = self.clone().into_arrow().data_type().clone();
+
+        let mut metadata = Metadata::new();
+        metadata.insert(
+            "ARROW:extension:name".to_string(),
+            "geoarrow.point".to_string(),
+        );
 
-        StructArray::new(struct_data_type, struct_values, validity)
+        Field::new(name, data_type, true).with_metadata(metadata)
     }
 }
 
@@ -236,9 +368,9 @@ impl TryFrom<StructArray> for PointArray {
         let arrays = value.values();
         let validity = value.validity();
 
-        if !arrays.len() == 2 {
+        if arrays.len() != 2 && arrays.len() != 3 {
             return Err(GeoArrowError::General(
-                "Expected two child arrays of this StructArray.".to_string(),
+                "Expected two or three (x/y[/z]) child arrays of this StructArray.".to_string(),
             ));
         }
 
@@ -251,6 +383,16 @@ impl TryFrom<StructArray> for PointArray {
             .downcast_ref::<PrimitiveArray<f64>>()
             .unwrap();
 
+        if let Some(z_array) = arrays.get(2) {
+            let z_array_values = z_array.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+            return Self::try_new_xyz(
+                x_array_values.values().clone(),
+                y_array_values.values().clone(),
+                z_array_values.values().clone(),
+                validity.cloned(),
+            );
+        }
+
         Ok(Self::new(
             x_array_values.values().clone(),
             y_array_values.values().clone(),
@@ -259,33 +401,37 @@ impl TryFrom<StructArray> for PointArray {
     }
 }
 
-impl TryFrom<Box<dyn Array>> for PointArray {
+impl TryFrom<arrow2::array::FixedSizeListArray> for PointArray {
     type Error = GeoArrowError;
 
-    fn try_from(value: Box<dyn Array>) -> Result<Self, Self::Error> {
-        let arr = value.as_any().downcast_ref::<StructArray>().unwrap();
-        arr.clone().try_into()
+    fn try_from(value: arrow2::array::FixedSizeListArray) -> Result<Self, Self::Error> {
+        let validity = value.validity().cloned();
+        let values = value
+            .values()
+            .as_any()
+            .downcast_ref::<PrimitiveArray<f64>>()
+            .unwrap();
+
+        Self::try_new_from_coords(
+            CoordBuffer::Interleaved(values.values().clone()),
+            None,
+            validity,
+        )
     }
 }
 
-impl From<PointArray> for StructArray {
-    fn from(value: PointArray) -> Self {
-        let field_x = Field::new("x", DataType::Float64, false);
-        let field_y = Field::new("y", DataType::Float64, false);
-
-        let array_x = PrimitiveArray::<f64>::new(DataType::Float64, value.x, None);
-        let array_y = PrimitiveArray::<f64>::new(DataType::Float64, value.y, None);
-
-        let struct_data_type = DataType::Struct(vec![field_x, field_y]);
-        let struct_values: Vec<Box<dyn Array>> = vec![array_x.boxed(), array_y.boxed()];
-
-        let validity: Option<Bitmap> = if let Some(validity) = value.validity {
-            validity.into()
-        } else {
-            None
-        };
+impl TryFrom<Box<dyn Array>> for PointArray {
+    type Error = GeoArrowError;
 
-        StructArray::new(struct_data_type, struct_values, validity)
+    fn try_from(value: Box<dyn Array>) -> Result<Self, Self::Error> {
+        if let Some(arr) = value.as_any().downcast_ref::<StructArray>() {
+            return arr.clone().try_into();
+        }
+        let arr = value
+            .as_any()
+            .downcast_ref::<arrow2::array::FixedSizeListArray>()
+            .unwrap();
+        arr.clone().try_into()
     }
 }
 
@@ -341,6 +487,15 @@ impl From<Vec<geo::Point>> for PointArray {
     }
 }
 
+impl From<PointArray> for crate::WKBArray {
+    fn from(value: PointArray) -> Self {
+        let geoms: Vec<Option<geo::Geometry>> = (0..value.len())
+            .map(|i| value.get_as_geo(i).map(geo::Geometry::Point))
+            .collect();
+        geoms.into()
+    }
+}
+
 impl GeozeroGeometry for PointArray {
     fn process_geom<P: GeomProcessor>(&self, processor: &mut P) -> geozero::error::Result<()>
     where
@@ -351,7 +506,11 @@ impl GeozeroGeometry for PointArray {
 
         for idx in 0..num_geometries {
             processor.point_begin(idx)?;
-            processor.xy(self.x[idx], self.y[idx], 0)?;
+            let (x, y) = self.coords.value(idx);
+            match self.z.as_ref() {
+                Some(z) => processor.coordinate(x, y, Some(z[idx]), None, None, None, 0)?,
+                None => processor.xy(x, y, 0)?,
+            }
             processor.point_end(idx)?;
         }
 
@@ -363,6 +522,7 @@ impl GeozeroGeometry for PointArray {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::coord::CoordType;
     use geo::{point, Point};
     use geozero::ToWkt;
 
@@ -393,4 +553,40 @@ mod test {
         assert_eq!(wkt, expected);
         Ok(())
     }
+
+    #[test]
+    fn interleaved_coords_round_trip_through_arrow() {
+        let coords = CoordBuffer::Interleaved(vec![0., 1., 1., 2., 2., 3.].into());
+        let point_array = PointArray::try_new_from_coords(coords, None, None).unwrap();
+        assert_eq!(point_array.value_as_geo(1), p1());
+
+        let arrow_array = point_array.into_arrow();
+        let round_tripped: PointArray = arrow_array.try_into().unwrap();
+        assert_eq!(round_tripped.coords().coord_type(), CoordType::Interleaved);
+        assert_eq!(round_tripped.value_as_geo(0), p0());
+        assert_eq!(round_tripped.value_as_geo(2), p2());
+    }
+
+    #[test]
+    fn xyz_round_trips_through_arrow_and_emits_coordinate() -> geozero::error::Result<()> {
+        let point_array = PointArray::try_new_xyz(
+            vec![0., 1.].into(),
+            vec![1., 2.].into(),
+            vec![10., 20.].into(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(point_array.dimension(), Dimension::XYZ);
+        assert_eq!(point_array.value(0).z(), Some(10.));
+
+        let arrow_array = point_array.into_arrow();
+        let round_tripped: PointArray = arrow_array.try_into().unwrap();
+        assert_eq!(round_tripped.dimension(), Dimension::XYZ);
+        assert_eq!(round_tripped.value(1).z(), Some(20.));
+
+        // Just exercise the `coordinate()` path through a geozero consumer; the exact WKT
+        // dimensionality formatting is geozero's concern, not this array's.
+        round_tripped.to_wkt()?;
+        Ok(())
+    }
 }
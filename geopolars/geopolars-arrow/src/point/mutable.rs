@@ -1,37 +1,48 @@
+use crate::coord::{CoordBuffer, CoordType, MutableCoordBuffer};
 use crate::error::GeoArrowError;
+use crate::point::array::Dimension;
 use crate::trait_::{GeometryArrayTrait, MutableGeometryArray};
-use arrow2::array::StructArray;
+use arrow2::array::{Array, StructArray};
 use arrow2::bitmap::{Bitmap, MutableBitmap};
+use arrow2::buffer::Buffer;
 use geo::Point;
-use geozero::{GeomProcessor, GeozeroGeometry};
+use geozero::{CoordDimensions, GeomProcessor, GeozeroGeometry};
 
 use super::array::{check, PointArray};
 
 /// The Arrow equivalent to `Vec<Option<Point>>`.
-/// Converting a [`MutablePointArray`] into a [`PointArray`] is `O(1)`.
+/// Converting a [`MutablePointArray`] into a [`PointArray`] is `O(1)` when the coordinates are
+/// [`CoordType::Separated`]; building [`Self::into_arrow`] directly is `O(1)` regardless of
+/// [`CoordType`].
 #[derive(Debug, Clone)]
 pub struct MutablePointArray {
-    x: Vec<f64>,
-    y: Vec<f64>,
+    coords: MutableCoordBuffer,
+
+    /// An optional `z` buffer, one value per coordinate in [`Self::coords`]. Guarded by
+    /// [`Self::dimension`].
+    z: Option<Vec<f64>>,
+
     validity: Option<MutableBitmap>,
 }
 
 impl MutablePointArray {
-    /// Creates a new empty [`MutablePointArray`].
+    /// Creates a new empty [`MutablePointArray`] storing coordinates as [`CoordType::Separated`].
     pub fn new() -> Self {
-        Self::with_capacity(0)
+        Self::with_capacity(CoordType::Separated, 0)
     }
 
-    /// Creates a new [`MutablePointArray`] with a capacity.
-    pub fn with_capacity(capacity: usize) -> Self {
+    /// Creates a new [`MutablePointArray`] with a capacity, storing coordinates in the given
+    /// [`CoordType`] layout.
+    pub fn with_capacity(coord_type: CoordType, capacity: usize) -> Self {
         Self {
-            x: Vec::with_capacity(capacity),
-            y: Vec::with_capacity(capacity),
+            coords: MutableCoordBuffer::with_capacity(coord_type, capacity),
+            z: None,
             validity: None,
         }
     }
 
-    /// The canonical method to create a [`MutablePointArray`] out of its internal components.
+    /// The canonical method to create a [`MutablePointArray`] out of its internal components,
+    /// storing `x`/`y` as [`CoordType::Separated`].
     /// # Implementation
     /// This function is `O(1)`.
     ///
@@ -43,30 +54,71 @@ impl MutablePointArray {
         x: Vec<f64>,
         y: Vec<f64>,
         validity: Option<MutableBitmap>,
+    ) -> Result<Self, GeoArrowError> {
+        Self::try_new_with_coord_type(x, y, validity, CoordType::Separated)
+    }
+
+    /// Like [`Self::try_new`], but storing `x`/`y` in the given [`CoordType`] layout.
+    pub fn try_new_with_coord_type(
+        x: Vec<f64>,
+        y: Vec<f64>,
+        validity: Option<MutableBitmap>,
+        coord_type: CoordType,
     ) -> Result<Self, GeoArrowError> {
         check(&x, &y, validity.as_ref().map(|x| x.len()))?;
-        Ok(Self { x, y, validity })
+        let coords = match coord_type {
+            CoordType::Separated => MutableCoordBuffer::Separated(x, y),
+            CoordType::Interleaved => {
+                let mut xy = Vec::with_capacity(x.len() * 2);
+                for (x, y) in x.into_iter().zip(y) {
+                    xy.push(x);
+                    xy.push(y);
+                }
+                MutableCoordBuffer::Interleaved(xy)
+            }
+        };
+        Ok(Self {
+            coords,
+            z: None,
+            validity,
+        })
     }
 
     /// Extract the low-level APIs from the [`MutablePointArray`].
-    pub fn into_inner(self) -> (Vec<f64>, Vec<f64>, Option<MutableBitmap>) {
-        (self.x, self.y, self.validity)
+    pub fn into_inner(self) -> (MutableCoordBuffer, Option<Vec<f64>>, Option<MutableBitmap>) {
+        (self.coords, self.z, self.validity)
+    }
+
+    /// The optional `z` buffer, one value per coordinate. Values on null slots are undetermined
+    /// (they can be anything).
+    #[inline]
+    pub fn z(&self) -> Option<&[f64]> {
+        self.z.as_deref()
+    }
+
+    /// Which coordinate dimensions this array carries.
+    #[inline]
+    pub fn dimension(&self) -> Dimension {
+        if self.z.is_some() {
+            Dimension::XYZ
+        } else {
+            Dimension::XY
+        }
     }
 
-    /// Adds a new value to the array.
+    /// Adds a new value to the array. `geo::Point` has no `z` ordinate, so this always leaves
+    /// [`Self::z`] untouched; it is only populated by ingesting XYZ WKB through [`GeomProcessor`].
     pub fn push_geo(&mut self, value: Option<Point>) {
         match value {
             Some(value) => {
-                self.x.push(value.x());
-                self.y.push(value.y());
+                self.coords.push_xy(value.x(), value.y());
                 match &mut self.validity {
                     Some(validity) => validity.push(true),
                     None => {}
                 }
             }
             None => {
-                self.x.push(f64::default());
-                self.y.push(f64::default());
+                self.coords.push_xy(f64::default(), f64::default());
                 match &mut self.validity {
                     Some(validity) => validity.push(false),
                     None => {
@@ -80,8 +132,7 @@ impl MutablePointArray {
     /// Pop a value from the array.
     /// Note if the values is empty, this method will return None.
     pub fn pop_geo(&mut self) -> Option<Point> {
-        let x = self.x.pop()?;
-        let y = self.y.pop()?;
+        let (x, y) = self.coords.pop_xy()?;
         let pt = Point::new(x, y);
 
         self.validity
@@ -91,7 +142,7 @@ impl MutablePointArray {
     }
 
     fn init_validity(&mut self) {
-        let mut validity = MutableBitmap::with_capacity(self.x.capacity());
+        let mut validity = MutableBitmap::with_capacity(self.coords.len());
         validity.extend_constant(self.len(), true);
         validity.set(self.len() - 1, false);
         self.validity = Some(validity)
@@ -100,18 +151,28 @@ impl MutablePointArray {
 
 impl MutablePointArray {
     fn len(&self) -> usize {
-        self.x.len()
+        self.coords.len()
     }
 
-    pub fn into_arrow(self) -> StructArray {
-        let point_array: PointArray = self.into();
-        point_array.into_arrow()
+    /// Converts this builder directly into Arrow's representation: an `x`/`y` [`StructArray`]
+    /// when storing coordinates as [`CoordType::Separated`], or a `FixedSizeList<f64>[2]` when
+    /// [`CoordType::Interleaved`], so interleaved GeoArrow data round-trips without a
+    /// re-striping copy.
+    pub fn into_arrow(self) -> Box<dyn Array> {
+        let validity = self.validity.and_then(|x| {
+            let bitmap: Bitmap = x.into();
+            (bitmap.unset_bits() > 0).then_some(bitmap)
+        });
+        let z = self.z.map(Buffer::from);
+        PointArray::try_new_from_coords(self.coords.into(), z, validity)
+            .unwrap()
+            .into_arrow()
     }
 }
 
 impl MutableGeometryArray for MutablePointArray {
     fn len(&self) -> usize {
-        self.x.len()
+        self.coords.len()
     }
 
     fn validity(&self) -> Option<&MutableBitmap> {
@@ -133,6 +194,10 @@ impl Default for MutablePointArray {
     }
 }
 
+/// Note that this always produces a [`PointArray`] storing separated `x`/`y` buffers, copying
+/// into that layout if `other` was interleaved. Callers that need to preserve an interleaved
+/// layout should call [`MutablePointArray::into_arrow`] directly instead of going through this
+/// conversion. The `z` buffer, if any, carries over unchanged.
 impl From<MutablePointArray> for PointArray {
     fn from(other: MutablePointArray) -> Self {
         let validity = other.validity.and_then(|x| {
@@ -144,29 +209,34 @@ impl From<MutablePointArray> for PointArray {
             }
         });
 
-        Self::new(other.x.into(), other.y.into(), validity)
+        let z = other.z.map(Buffer::from);
+        let (x, y) = other.coords.into_separated();
+
+        Self::try_new_from_coords(CoordBuffer::Separated(x.into(), y.into()), z, validity).unwrap()
     }
 }
 
 impl From<MutablePointArray> for StructArray {
     fn from(arr: MutablePointArray) -> Self {
         arr.into_arrow()
+            .as_any()
+            .downcast_ref::<StructArray>()
+            .unwrap()
+            .clone()
     }
 }
 
 impl From<Vec<Point>> for MutablePointArray {
     fn from(geoms: Vec<Point>) -> Self {
-        let mut x_arr = Vec::<f64>::with_capacity(geoms.len());
-        let mut y_arr = Vec::<f64>::with_capacity(geoms.len());
+        let mut coords = MutableCoordBuffer::with_capacity(CoordType::Separated, geoms.len());
 
         for geom in geoms {
-            x_arr.push(geom.x());
-            y_arr.push(geom.y());
+            coords.push_xy(geom.x(), geom.y());
         }
 
         MutablePointArray {
-            x: x_arr,
-            y: y_arr,
+            coords,
+            z: None,
             validity: None,
         }
     }
@@ -174,23 +244,22 @@ impl From<Vec<Point>> for MutablePointArray {
 
 impl From<Vec<Option<Point>>> for MutablePointArray {
     fn from(geoms: Vec<Option<Point>>) -> Self {
-        let mut x_arr = vec![0.0_f64; geoms.len()];
-        let mut y_arr = vec![0.0_f64; geoms.len()];
+        let mut coords = MutableCoordBuffer::with_capacity(CoordType::Separated, geoms.len());
         let mut validity = MutableBitmap::with_capacity(geoms.len());
 
-        for i in 0..geoms.len() {
-            if let Some(geom) = geoms[i] {
-                x_arr[i] = geom.x();
-                y_arr[i] = geom.y();
+        for geom in &geoms {
+            if let Some(geom) = geom {
+                coords.push_xy(geom.x(), geom.y());
                 validity.push(true);
             } else {
+                coords.push_xy(0.0, 0.0);
                 validity.push(false);
             }
         }
 
         MutablePointArray {
-            x: x_arr,
-            y: y_arr,
+            coords,
+            z: None,
             validity: Some(validity),
         }
     }
@@ -219,15 +288,37 @@ impl<T: GeozeroGeometry> ToGeoArrowPoint for T {
 
 #[allow(unused_variables)]
 impl GeomProcessor for MutablePointArray {
+    /// Requests 3D coordinates from the source, so a WKB Z/EWKB Z input reaches [`Self::xyz`]
+    /// instead of being silently flattened to 2D through [`Self::xy`].
+    fn dimensions(&self) -> CoordDimensions {
+        CoordDimensions::xyz()
+    }
+
     fn xy(&mut self, x: f64, y: f64, _idx: usize) -> geozero::error::Result<()> {
-        self.x.push(x);
-        self.y.push(y);
+        if self.z.is_some() {
+            return Err(geozero::error::GeozeroError::Geometry(
+                "cannot mix 2D and 3D coordinates in the same array".to_string(),
+            ));
+        }
+        self.coords.push_xy(x, y);
+        Ok(())
+    }
+
+    fn xyz(&mut self, x: f64, y: f64, z: f64, _idx: usize) -> geozero::error::Result<()> {
+        if self.z.is_none() {
+            if !self.coords.is_empty() {
+                return Err(geozero::error::GeozeroError::Geometry(
+                    "cannot mix 2D and 3D coordinates in the same array".to_string(),
+                ));
+            }
+            self.z = Some(Vec::new());
+        }
+        self.coords.push_xy(x, y);
+        self.z.as_mut().unwrap().push(z);
         Ok(())
     }
 
     fn geometrycollection_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
-        self.x.reserve_exact(size);
-        self.y.reserve_exact(size);
         Ok(())
     }
 
@@ -379,4 +470,55 @@ mod test {
         let err = geo.to_geoarrow().unwrap_err();
         assert!(matches!(err, geozero::error::GeozeroError::Geometry(..)));
     }
+
+    #[test]
+    fn interleaved_coord_type_round_trips_through_arrow() {
+        use crate::coord::CoordType;
+
+        let mut arr = super::MutablePointArray::with_capacity(CoordType::Interleaved, 2);
+        arr.push_geo(Some(p0()));
+        arr.push_geo(Some(p1()));
+
+        let arrow_arr = arr.into_arrow();
+        let point_array: crate::PointArray = arrow_arr.try_into().unwrap();
+        assert_eq!(point_array.value_as_geo(0), p0());
+        assert_eq!(point_array.value_as_geo(1), p1());
+    }
+
+    #[test]
+    fn xyz_coordinates_land_in_the_z_buffer() {
+        use super::super::array::Dimension;
+        use geozero::GeomProcessor;
+
+        let mut arr = super::MutablePointArray::new();
+        arr.geometrycollection_begin(2, 0).unwrap();
+        arr.point_begin(0).unwrap();
+        arr.xyz(0., 1., 10., 0).unwrap();
+        arr.point_end(0).unwrap();
+        arr.point_begin(1).unwrap();
+        arr.xyz(1., 2., 20., 1).unwrap();
+        arr.point_end(1).unwrap();
+        arr.geometrycollection_end(2).unwrap();
+
+        assert_eq!(arr.dimension(), Dimension::XYZ);
+        assert_eq!(arr.z(), Some([10., 20.].as_slice()));
+
+        let point_array: crate::PointArray = arr.into();
+        assert_eq!(point_array.dimension(), Dimension::XYZ);
+        assert_eq!(point_array.value(1).z(), Some(20.));
+    }
+
+    #[test]
+    fn from_geozero_error_mixed_dimensions() {
+        use geozero::GeomProcessor;
+
+        let mut arr = super::MutablePointArray::new();
+        arr.geometrycollection_begin(2, 0).unwrap();
+        arr.point_begin(0).unwrap();
+        arr.xyz(0., 1., 10., 0).unwrap();
+        arr.point_end(0).unwrap();
+        arr.point_begin(1).unwrap();
+        let err = arr.xy(1., 2., 1).unwrap_err();
+        assert!(matches!(err, geozero::error::GeozeroError::Geometry(..)));
+    }
 }
@@ -1,4 +1,5 @@
 use crate::algorithm::bounding_rect::bounding_rect_point;
+use crate::coord::CoordBuffer;
 use crate::geo_traits::PointTrait;
 use arrow2::buffer::Buffer;
 use rstar::{RTreeObject, AABB};
@@ -6,36 +7,44 @@ use rstar::{RTreeObject, AABB};
 /// An arrow equivalent of a Point
 #[derive(Debug, Clone)]
 pub struct Point<'a> {
-    pub x: &'a Buffer<f64>,
-    pub y: &'a Buffer<f64>,
+    pub coords: &'a CoordBuffer,
+    /// The `z` coordinate, present when the backing [`crate::PointArray`] is 3D.
+    pub z: Option<&'a Buffer<f64>>,
     pub geom_index: usize,
 }
 
+impl Point<'_> {
+    /// Returns the `z` coordinate, if this point carries one.
+    pub fn z(&self) -> Option<f64> {
+        self.z.map(|z| z[self.geom_index])
+    }
+}
+
 impl PointTrait for Point<'_> {
     fn x(&self) -> f64 {
-        self.x[self.geom_index]
+        self.coords.get_x(self.geom_index)
     }
 
     fn y(&self) -> f64 {
-        self.y[self.geom_index]
+        self.coords.get_y(self.geom_index)
     }
 
     fn x_y(&self) -> (f64, f64) {
-        (self.x[self.geom_index], self.y[self.geom_index])
+        self.coords.value(self.geom_index)
     }
 }
 
 impl PointTrait for &Point<'_> {
     fn x(&self) -> f64 {
-        self.x[self.geom_index]
+        self.coords.get_x(self.geom_index)
     }
 
     fn y(&self) -> f64 {
-        self.y[self.geom_index]
+        self.coords.get_y(self.geom_index)
     }
 
     fn x_y(&self) -> (f64, f64) {
-        (self.x[self.geom_index], self.y[self.geom_index])
+        self.coords.value(self.geom_index)
     }
 }
 
@@ -1,27 +1,31 @@
+use crate::coord::CoordBuffer;
 use crate::enum_::GeometryType;
 use crate::error::GeoArrowError;
 use crate::trait_::GeometryArray;
+use crate::rect::RectArray;
 use crate::MultiLineStringArray;
 use arrow2::array::Array;
-use arrow2::array::{ListArray, PrimitiveArray, StructArray};
+use arrow2::array::{FixedSizeListArray, ListArray, PrimitiveArray, StructArray};
 use arrow2::bitmap::utils::{BitmapIter, ZipValidity};
 use arrow2::bitmap::Bitmap;
 use arrow2::buffer::Buffer;
 use arrow2::datatypes::{DataType, Field};
 use arrow2::offset::OffsetsBuffer;
-use geo::{Coord, LineString, Polygon};
+use geo::Polygon;
+use geozero::{GeomProcessor, GeozeroGeometry};
+use rstar::RTreeObject;
 
 use super::MutablePolygonArray;
 
 /// A [`GeometryArray`] semantically equivalent to `Vec<Option<Polygon>>` using Arrow's
 /// in-memory representation.
+///
+/// Coordinates are stored in a [`CoordBuffer`], which may be either the separated `x`/`y`
+/// layout every producer in this crate used before [`CoordBuffer`] existed, or an interleaved
+/// `[x0, y0, x1, y1, ...]` layout ingested zero-copy from other GeoArrow producers.
 #[derive(Debug, Clone)]
 pub struct PolygonArray {
-    /// Buffer of x coordinates
-    x: Buffer<f64>,
-
-    /// Buffer of y coordinates
-    y: Buffer<f64>,
+    coords: CoordBuffer,
 
     /// Offsets into the ring array where each geometry starts
     geom_offsets: OffsetsBuffer<i64>,
@@ -37,9 +41,10 @@ pub(super) fn check(
     x: &[f64],
     y: &[f64],
     validity_len: Option<usize>,
+    geom_offsets: &OffsetsBuffer<i64>,
+    ring_offsets: &OffsetsBuffer<i64>,
 ) -> Result<(), GeoArrowError> {
-    // TODO: check geom offsets and ring_offsets?
-    if validity_len.map_or(false, |len| len != x.len()) {
+    if validity_len.map_or(false, |len| len != geom_offsets.len()) {
         return Err(GeoArrowError::General(
             "validity mask length must match the number of values".to_string(),
         ));
@@ -50,11 +55,57 @@ pub(super) fn check(
             "x and y arrays must have the same length".to_string(),
         ));
     }
+
+    crate::offset::validate_offsets("geom_offsets", geom_offsets)?;
+    crate::offset::validate_offsets("ring_offsets", ring_offsets)?;
+
+    if geom_offsets.last() as usize != ring_offsets.len() {
+        return Err(GeoArrowError::General(
+            "the last offset in geom_offsets must equal the number of rings".to_string(),
+        ));
+    }
+
+    if ring_offsets.last() as usize != x.len() {
+        return Err(GeoArrowError::General(
+            "the last offset in ring_offsets must equal the coordinate count".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+pub(super) fn check_coords(
+    coords: &CoordBuffer,
+    validity_len: Option<usize>,
+    geom_offsets: &OffsetsBuffer<i64>,
+    ring_offsets: &OffsetsBuffer<i64>,
+) -> Result<(), GeoArrowError> {
+    if validity_len.map_or(false, |len| len != geom_offsets.len()) {
+        return Err(GeoArrowError::General(
+            "validity mask length must match the number of values".to_string(),
+        ));
+    }
+
+    crate::offset::validate_offsets("geom_offsets", geom_offsets)?;
+    crate::offset::validate_offsets("ring_offsets", ring_offsets)?;
+
+    if geom_offsets.last() as usize != ring_offsets.len() {
+        return Err(GeoArrowError::General(
+            "the last offset in geom_offsets must equal the number of rings".to_string(),
+        ));
+    }
+
+    if ring_offsets.last() as usize != coords.len() {
+        return Err(GeoArrowError::General(
+            "the last offset in ring_offsets must equal the coordinate count".to_string(),
+        ));
+    }
+
     Ok(())
 }
 
 impl PolygonArray {
-    /// Create a new PolygonArray from parts
+    /// Create a new PolygonArray from a separated `x`/`y` pair.
     /// # Implementation
     /// This function is `O(1)`.
     pub fn new(
@@ -64,17 +115,23 @@ impl PolygonArray {
         ring_offsets: OffsetsBuffer<i64>,
         validity: Option<Bitmap>,
     ) -> Self {
-        check(&x, &y, validity.as_ref().map(|v| v.len())).unwrap();
+        check(
+            &x,
+            &y,
+            validity.as_ref().map(|v| v.len()),
+            &geom_offsets,
+            &ring_offsets,
+        )
+        .unwrap();
         Self {
-            x,
-            y,
+            coords: CoordBuffer::Separated(x, y),
             geom_offsets,
             ring_offsets,
             validity,
         }
     }
 
-    /// Create a new PolygonArray from parts
+    /// Create a new PolygonArray from a separated `x`/`y` pair.
     /// # Implementation
     /// This function is `O(1)`.
     pub fn try_new(
@@ -84,10 +141,39 @@ impl PolygonArray {
         ring_offsets: OffsetsBuffer<i64>,
         validity: Option<Bitmap>,
     ) -> Result<Self, GeoArrowError> {
-        check(&x, &y, validity.as_ref().map(|v| v.len()))?;
+        check(
+            &x,
+            &y,
+            validity.as_ref().map(|v| v.len()),
+            &geom_offsets,
+            &ring_offsets,
+        )?;
+        Ok(Self {
+            coords: CoordBuffer::Separated(x, y),
+            geom_offsets,
+            ring_offsets,
+            validity,
+        })
+    }
+
+    /// Create a new PolygonArray from a [`CoordBuffer`] in either the separated or interleaved
+    /// layout.
+    /// # Implementation
+    /// This function is `O(1)`.
+    pub fn try_new_from_coords(
+        coords: CoordBuffer,
+        geom_offsets: OffsetsBuffer<i64>,
+        ring_offsets: OffsetsBuffer<i64>,
+        validity: Option<Bitmap>,
+    ) -> Result<Self, GeoArrowError> {
+        check_coords(
+            &coords,
+            validity.as_ref().map(|v| v.len()),
+            &geom_offsets,
+            &ring_offsets,
+        )?;
         Ok(Self {
-            x,
-            y,
+            coords,
             geom_offsets,
             ring_offsets,
             validity,
@@ -105,6 +191,13 @@ impl PolygonArray {
         self.len() == 0
     }
 
+    /// The underlying [`CoordBuffer`], in whichever physical layout this array was built with.
+    /// Values on null slots are undetermined (they can be anything).
+    #[inline]
+    pub fn coords(&self) -> &CoordBuffer {
+        &self.coords
+    }
+
     /// Returns the optional validity.
     #[inline]
     pub fn validity(&self) -> Option<&Bitmap> {
@@ -150,8 +243,7 @@ impl PolygonArray {
             .map(|bitmap| bitmap.slice_unchecked(offset, length))
             .and_then(|bitmap| (bitmap.unset_bits() > 0).then_some(bitmap));
         Self {
-            x: self.x.clone().slice_unchecked(offset, length),
-            y: self.y.clone().slice_unchecked(offset, length),
+            coords: self.coords.slice(offset, length),
             geom_offsets: self.geom_offsets.clone().slice_unchecked(offset, length),
             ring_offsets: self.ring_offsets.clone().slice_unchecked(offset, length),
             validity,
@@ -159,49 +251,6 @@ impl PolygonArray {
     }
 }
 
-pub(crate) fn parse_polygon(
-    x: &Buffer<f64>,
-    y: &Buffer<f64>,
-    polygon_offsets: &OffsetsBuffer<i64>,
-    ring_offsets: &OffsetsBuffer<i64>,
-    i: usize,
-) -> Polygon {
-    // Start and end indices into the ring_offsets buffer
-    let (start_geom_idx, end_geom_idx) = polygon_offsets.start_end(i);
-
-    // Parse exterior ring first
-    let (start_ext_ring_idx, end_ext_ring_idx) = ring_offsets.start_end(start_geom_idx);
-    let mut exterior_coords: Vec<Coord> = Vec::with_capacity(end_ext_ring_idx - start_ext_ring_idx);
-
-    for i in start_ext_ring_idx..end_ext_ring_idx {
-        exterior_coords.push(Coord { x: x[i], y: y[i] })
-    }
-    let exterior_ring: LineString = exterior_coords.into();
-
-    // Parse any interior rings
-    // Note: need to check if interior rings exist otherwise the subtraction below can overflow
-    let has_interior_rings = end_geom_idx - start_geom_idx > 1;
-    let n_interior_rings = if has_interior_rings {
-        end_geom_idx - start_geom_idx - 2
-    } else {
-        0
-    };
-    let mut interior_rings: Vec<LineString<f64>> = Vec::with_capacity(n_interior_rings);
-    for ring_idx in start_geom_idx + 1..end_geom_idx {
-        let (start_coord_idx, end_coord_idx) = ring_offsets.start_end(ring_idx);
-        let mut ring: Vec<Coord> = Vec::with_capacity(end_coord_idx - start_coord_idx);
-        for coord_idx in start_coord_idx..end_coord_idx {
-            ring.push(Coord {
-                x: x[coord_idx],
-                y: y[coord_idx],
-            })
-        }
-        interior_rings.push(ring.into());
-    }
-
-    Polygon::new(exterior_ring, interior_rings)
-}
-
 // Implement geometry accessors
 impl PolygonArray {
     pub fn get(&self, i: usize) -> Option<crate::Polygon> {
@@ -210,8 +259,7 @@ impl PolygonArray {
         }
 
         Some(crate::Polygon {
-            x: &self.x,
-            y: &self.y,
+            coords: &self.coords,
             geom_offsets: &self.geom_offsets,
             ring_offsets: &self.ring_offsets,
             geom_index: i,
@@ -220,7 +268,7 @@ impl PolygonArray {
 
     /// Returns the value at slot `i` as a geo object.
     pub fn value_as_geo(&self, i: usize) -> Polygon {
-        parse_polygon(&self.x, &self.y, &self.geom_offsets, &self.ring_offsets, i)
+        super::parse_polygon(&self.coords, &self.geom_offsets, &self.ring_offsets, i)
     }
 
     /// Gets the value at slot `i` as a geo object, additionally checking the validity bitmap
@@ -272,44 +320,168 @@ impl PolygonArray {
         ZipValidity::new_with_validity(self.iter_geos_values(), self.validity())
     }
 
+    /// Converts this array into its Arrow representation: a `ListArray<i64>` of rings, each
+    /// ring itself a `ListArray<i64>` of vertices backed by this array's [`CoordBuffer`] (a
+    /// `x`/`y` [`StructArray`] when separated, or a `FixedSizeList<f64>[2]` when interleaved).
     pub fn into_arrow(self) -> ListArray<i64> {
-        // Data type
-        let coord_field_x = Field::new("x", DataType::Float64, false);
-        let coord_field_y = Field::new("y", DataType::Float64, false);
-        let struct_data_type = DataType::Struct(vec![coord_field_x, coord_field_y]);
-        let inner_list_data_type = DataType::LargeList(Box::new(Field::new(
-            "vertices",
-            struct_data_type.clone(),
-            false,
-        )));
+        let validity: Option<Bitmap> = if let Some(validity) = self.validity {
+            validity.into()
+        } else {
+            None
+        };
+
+        let coord_array = self.coords.into_arrow();
+        let coord_data_type = coord_array.data_type().clone();
+
+        let inner_list_data_type =
+            DataType::LargeList(Box::new(Field::new("vertices", coord_data_type, false)));
         let outer_list_data_type = DataType::LargeList(Box::new(Field::new(
             "rings",
             inner_list_data_type.clone(),
             true,
         )));
 
-        // Validity
+        let inner_list_array =
+            ListArray::new(inner_list_data_type, self.ring_offsets, coord_array, None).boxed();
+
+        ListArray::new(
+            outer_list_data_type,
+            self.geom_offsets,
+            inner_list_array,
+            validity,
+        )
+    }
+
+    /// Convert to an Arrow [`ListArray`] backed by 32-bit (`List`, rather than `LargeList`)
+    /// offsets, halving the offset buffer size.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GeoArrowError::Overflow`] if either offsets buffer exceeds [`i32::MAX`]. Use
+    /// [`Self::into_arrow`] in that case.
+    pub fn into_arrow_small(self) -> Result<ListArray<i32>, GeoArrowError> {
+        if !crate::offset::fits_in_i32(&self.geom_offsets)
+            || !crate::offset::fits_in_i32(&self.ring_offsets)
+        {
+            return Err(GeoArrowError::Overflow);
+        }
+
         let validity: Option<Bitmap> = if let Some(validity) = self.validity {
             validity.into()
         } else {
             None
         };
 
-        // Array data
-        let array_x = PrimitiveArray::new(DataType::Float64, self.x, None).boxed();
-        let array_y = PrimitiveArray::new(DataType::Float64, self.y, None).boxed();
+        let coord_array = self.coords.into_arrow();
+        let coord_data_type = coord_array.data_type().clone();
 
-        let coord_array = StructArray::new(struct_data_type, vec![array_x, array_y], None).boxed();
+        let inner_list_data_type =
+            DataType::List(Box::new(Field::new("vertices", coord_data_type, false)));
+        let outer_list_data_type = DataType::List(Box::new(Field::new(
+            "rings",
+            inner_list_data_type.clone(),
+            true,
+        )));
+
+        let ring_offsets = crate::offset::narrow_offsets(&self.ring_offsets);
+        let geom_offsets = crate::offset::narrow_offsets(&self.geom_offsets);
 
         let inner_list_array =
-            ListArray::new(inner_list_data_type, self.ring_offsets, coord_array, None).boxed();
+            ListArray::new(inner_list_data_type, ring_offsets, coord_array, None).boxed();
 
-        ListArray::new(
+        Ok(ListArray::new(
             outer_list_data_type,
-            self.geom_offsets,
+            geom_offsets,
             inner_list_array,
             validity,
-        )
+        ))
+    }
+
+    /// Tessellates every polygon in this array into a triangle mesh via ear clipping, honoring
+    /// interior rings (holes). See [`crate::algorithm::triangulate::tessellate`].
+    pub fn tessellate(&self) -> crate::algorithm::triangulate::Tessellation {
+        crate::algorithm::triangulate::tessellate(&self.coords, &self.geom_offsets, &self.ring_offsets)
+    }
+
+    /// Computes the pole of inaccessibility (the interior point farthest from any edge) of
+    /// every polygon in this array, to within `precision`. Unlike a centroid, the result is
+    /// always inside its polygon, which is what makes it useful for label placement on concave
+    /// shapes. See [`crate::algorithm::label_point::label_point`]. Null slots come back null.
+    pub fn polylabel(&self, precision: f64) -> crate::PointArray {
+        let points: Vec<Option<geo::Point>> = (0..self.len())
+            .map(|i| {
+                self.get(i)
+                    .map(|polygon| crate::algorithm::label_point::label_point(&polygon, precision))
+            })
+            .collect();
+        points.into()
+    }
+
+    /// Computes the envelope of every polygon in this array, returning a [`RectArray`] of the
+    /// same length.
+    ///
+    /// Each envelope is derived from [`crate::Polygon`]'s `RTreeObject` impl, which reads
+    /// directly out of this array's coordinate buffers via `geom_offsets`/`ring_offsets`, so
+    /// this never materializes an owned `geo::Polygon`. Values at null slots are undetermined
+    /// (they can be anything).
+    pub fn bounds(&self) -> RectArray {
+        let mut values = Vec::with_capacity(self.len() * 4);
+        for i in 0..self.len() {
+            let geom = crate::Polygon {
+                coords: &self.coords,
+                geom_offsets: &self.geom_offsets,
+                ring_offsets: &self.ring_offsets,
+                geom_index: i,
+            };
+            let envelope = geom.envelope();
+            let lower = envelope.lower();
+            let upper = envelope.upper();
+            values.push(lower[0]);
+            values.push(lower[1]);
+            values.push(upper[0]);
+            values.push(upper[1]);
+        }
+        RectArray::new(values.into(), self.validity.clone())
+    }
+
+    /// Reduces [`Self::bounds`] down to the single box covering every non-null polygon in this
+    /// array, analogous to Shapely/GeoPandas' `total_bounds`.
+    ///
+    /// # Panics
+    /// Panics if the array has no non-null geometries, since there is then no box to return.
+    pub fn total_bounds(&self) -> geo::Rect {
+        let bounds = self.bounds();
+        let mut rects = bounds.iter_geo().flatten();
+        let first = rects
+            .next()
+            .expect("total_bounds of an array with no non-null geometries");
+        rects.fold(first, |acc, rect| {
+            geo::Rect::new(
+                geo::coord! {
+                    x: acc.min().x.min(rect.min().x),
+                    y: acc.min().y.min(rect.min().y),
+                },
+                geo::coord! {
+                    x: acc.max().x.max(rect.max().x),
+                    y: acc.max().y.max(rect.max().y),
+                },
+            )
+        })
+    }
+}
+
+/// Converts every value in `value` to a GEOS geometry, going through [`geo::Polygon`] (itself
+/// built straight off this array's [`CoordBuffer`] and `geom_offsets`/`ring_offsets` via
+/// [`PolygonArray::value_as_geo`]) rather than a WKB round-trip.
+#[cfg(feature = "geos")]
+impl TryFrom<&PolygonArray> for Vec<geos::Geometry> {
+    type Error = geos::Error;
+
+    fn try_from(value: &PolygonArray) -> Result<Self, Self::Error> {
+        value
+            .iter_geo_values()
+            .map(|geom| (&geom).try_into())
+            .collect()
     }
 }
 
@@ -321,34 +493,55 @@ impl TryFrom<ListArray<i64>> for PolygonArray {
         let validity = value.validity();
 
         let inner_dyn_array = value.values();
-        let inner_array = inner_dyn_array
-            .as_any()
-            .downcast_ref::<ListArray<i64>>()
-            .unwrap();
+        let inner_array_i64;
+        let inner_array = if let Some(arr) = inner_dyn_array.as_any().downcast_ref::<ListArray<i64>>() {
+            arr
+        } else {
+            let inner_array_i32 = inner_dyn_array
+                .as_any()
+                .downcast_ref::<ListArray<i32>>()
+                .unwrap();
+            inner_array_i64 = crate::offset::widen_list_array(inner_array_i32);
+            &inner_array_i64
+        };
 
         let ring_offsets = inner_array.offsets();
         let coords_dyn_array = inner_array.values();
-        let coords_array = coords_dyn_array
-            .as_any()
-            .downcast_ref::<StructArray>()
-            .unwrap();
-
-        let x_array_values = coords_array.values()[0]
-            .as_any()
-            .downcast_ref::<PrimitiveArray<f64>>()
-            .unwrap();
-        let y_array_values = coords_array.values()[1]
-            .as_any()
-            .downcast_ref::<PrimitiveArray<f64>>()
-            .unwrap();
-
-        Ok(Self::new(
-            x_array_values.values().clone(),
-            y_array_values.values().clone(),
+
+        let coords = if let Some(coords_array) =
+            coords_dyn_array.as_any().downcast_ref::<StructArray>()
+        {
+            let x_array_values = coords_array.values()[0]
+                .as_any()
+                .downcast_ref::<PrimitiveArray<f64>>()
+                .unwrap();
+            let y_array_values = coords_array.values()[1]
+                .as_any()
+                .downcast_ref::<PrimitiveArray<f64>>()
+                .unwrap();
+            CoordBuffer::Separated(
+                x_array_values.values().clone(),
+                y_array_values.values().clone(),
+            )
+        } else {
+            let coords_array = coords_dyn_array
+                .as_any()
+                .downcast_ref::<FixedSizeListArray>()
+                .unwrap();
+            let values = coords_array
+                .values()
+                .as_any()
+                .downcast_ref::<PrimitiveArray<f64>>()
+                .unwrap();
+            CoordBuffer::Interleaved(values.values().clone())
+        };
+
+        Self::try_new_from_coords(
+            coords,
             geom_offsets.clone(),
             ring_offsets.clone(),
             validity.cloned(),
-        ))
+        )
     }
 }
 
@@ -356,8 +549,15 @@ impl TryFrom<Box<dyn Array>> for PolygonArray {
     type Error = GeoArrowError;
 
     fn try_from(value: Box<dyn Array>) -> Result<Self, Self::Error> {
-        let arr = value.as_any().downcast_ref::<ListArray<i64>>().unwrap();
-        arr.clone().try_into()
+        // Accept either `LargeList` (i64 offsets, our own native width) or `List` (i32 offsets,
+        // e.g. from a producer that didn't opt into large offsets) by widening the latter up
+        // front; everything past this point only ever deals with `ListArray<i64>`.
+        if let Some(arr) = value.as_any().downcast_ref::<ListArray<i64>>() {
+            arr.clone().try_into()
+        } else {
+            let arr = value.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+            crate::offset::widen_list_array(arr).try_into()
+        }
     }
 }
 
@@ -413,16 +613,114 @@ impl From<Vec<Polygon>> for PolygonArray {
     }
 }
 
+impl From<PolygonArray> for crate::WKBArray {
+    fn from(value: PolygonArray) -> Self {
+        let geoms: Vec<Option<geo::Geometry>> = (0..value.len())
+            .map(|i| value.get_as_geo(i).map(geo::Geometry::Polygon))
+            .collect();
+        geoms.into()
+    }
+}
+
 /// Polygon and MultiLineString have the same layout, so enable conversions between the two to
-/// change the semantic type
+/// change the semantic type. Whichever [`CoordBuffer`] layout `value` was built with is preserved.
 impl From<PolygonArray> for MultiLineStringArray {
     fn from(value: PolygonArray) -> Self {
         Self::new(
-            value.x,
-            value.y,
+            value.coords,
             value.geom_offsets,
             value.ring_offsets,
             value.validity,
         )
     }
 }
+
+impl GeozeroGeometry for PolygonArray {
+    fn process_geom<P: GeomProcessor>(&self, processor: &mut P) -> geozero::error::Result<()>
+    where
+        Self: Sized,
+    {
+        let num_geometries = self.len();
+        processor.geometrycollection_begin(num_geometries, 0)?;
+
+        for geom_idx in 0..num_geometries {
+            let (start_ring_idx, end_ring_idx) = self.geom_offsets.start_end(geom_idx);
+
+            processor.polygon_begin(true, end_ring_idx - start_ring_idx, geom_idx)?;
+
+            for ring_idx in start_ring_idx..end_ring_idx {
+                let (start_coord_idx, end_coord_idx) = self.ring_offsets.start_end(ring_idx);
+
+                processor.linestring_begin(
+                    false,
+                    end_coord_idx - start_coord_idx,
+                    ring_idx - start_ring_idx,
+                )?;
+
+                for coord_idx in start_coord_idx..end_coord_idx {
+                    let (x, y) = self.coords.value(coord_idx);
+                    processor.xy(x, y, coord_idx - start_coord_idx)?;
+                }
+
+                processor.linestring_end(false, ring_idx - start_ring_idx)?;
+            }
+
+            processor.polygon_end(true, geom_idx)?;
+        }
+
+        processor.geometrycollection_end(num_geometries - 1)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use geo::polygon;
+    use geozero::ToWkt;
+
+    fn p0() -> Polygon {
+        polygon![
+            (x: 0., y: 0.),
+            (x: 4., y: 0.),
+            (x: 4., y: 4.),
+            (x: 0., y: 4.),
+            (x: 0., y: 0.),
+        ]
+    }
+
+    #[test]
+    fn geozero_process_geom() -> geozero::error::Result<()> {
+        let arr: PolygonArray = vec![p0()].into();
+        let wkt = arr.to_wkt()?;
+        let expected = "GEOMETRYCOLLECTION(POLYGON((0 0,4 0,4 4,0 4,0 0)))";
+        assert_eq!(wkt, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn bounds_and_total_bounds() {
+        let p1 = polygon![
+            (x: 10., y: 10.),
+            (x: 14., y: 10.),
+            (x: 14., y: 14.),
+            (x: 10., y: 14.),
+            (x: 10., y: 10.),
+        ];
+        let arr: PolygonArray = vec![p0(), p1].into();
+
+        let bounds = arr.bounds();
+        assert_eq!(
+            bounds.value_as_geo(0),
+            geo::Rect::new(geo::coord! { x: 0., y: 0. }, geo::coord! { x: 4., y: 4. })
+        );
+        assert_eq!(
+            bounds.value_as_geo(1),
+            geo::Rect::new(geo::coord! { x: 10., y: 10. }, geo::coord! { x: 14., y: 14. })
+        );
+
+        let total = arr.total_bounds();
+        assert_eq!(total.min(), geo::coord! { x: 0., y: 0. });
+        assert_eq!(total.max(), geo::coord! { x: 14., y: 14. });
+    }
+}
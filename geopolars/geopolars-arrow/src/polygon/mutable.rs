@@ -1,27 +1,46 @@
 use super::array::check;
-use arrow2::array::ListArray;
+use arrow2::array::{Array, FixedSizeListArray, ListArray, PrimitiveArray};
 use arrow2::bitmap::{Bitmap, MutableBitmap};
+use arrow2::datatypes::{DataType, Field};
 use arrow2::offset::{Offsets, OffsetsBuffer};
+use arrow2::types::Index;
 use geo::Polygon;
+use geozero::{CoordDimensions, GeomProcessor, GeozeroGeometry};
 
+use crate::coord::{CoordType, MutableCoordBuffer};
 use crate::error::GeoArrowError;
 use crate::multilinestring::MutableMultiLineStringArray;
 use crate::PolygonArray;
 
 pub type MutablePolygonParts = (
-    Vec<f64>,
-    Vec<f64>,
+    MutableCoordBuffer,
+    Option<Vec<f64>>,
     Offsets<i64>,
     Offsets<i64>,
     Option<MutableBitmap>,
 );
 
+/// Which coordinate dimensions a [`MutablePolygonArray`] carries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Dimension {
+    /// `x`/`y` only. This is the layout every `MutablePolygonArray` had before [`Dimension`]
+    /// existed.
+    #[default]
+    XY,
+    /// `x`/`y`/`z`, e.g. elevation carried through a WKB Z/EWKB Z input.
+    XYZ,
+}
+
 /// The Arrow equivalent to `Vec<Option<Polygon>>`.
-/// Converting a [`MutablePolygonArray`] into a [`PolygonArray`] is `O(1)`.
+/// Converting a [`MutablePolygonArray`] into a [`PolygonArray`], or building [`Self::into_arrow`]
+/// directly, is `O(1)` regardless of [`CoordType`].
 #[derive(Debug, Clone)]
 pub struct MutablePolygonArray {
-    x: Vec<f64>,
-    y: Vec<f64>,
+    coords: MutableCoordBuffer,
+
+    /// An optional `z` buffer, one value per coordinate in [`Self::coords`]. Guarded by
+    /// [`Self::dimension`].
+    z: Option<Vec<f64>>,
 
     /// Offsets into the ring array where each geometry starts
     geom_offsets: Offsets<i64>,
@@ -34,27 +53,31 @@ pub struct MutablePolygonArray {
 }
 
 impl MutablePolygonArray {
-    /// Creates a new empty [`MutableLineStringArray`].
+    /// Creates a new empty [`MutablePolygonArray`] storing coordinates as
+    /// [`CoordType::Separated`].
     pub fn new() -> Self {
-        Self::with_capacities(0, 0, 0)
+        Self::with_capacities(CoordType::Separated, 0, 0, 0)
     }
 
-    /// Creates a new [`MutableLineStringArray`] with a capacity.
+    /// Creates a new [`MutablePolygonArray`] with a capacity, storing coordinates in the
+    /// given [`CoordType`] layout.
     pub fn with_capacities(
+        coord_type: CoordType,
         coord_capacity: usize,
         geom_capacity: usize,
         ring_capacity: usize,
     ) -> Self {
         Self {
-            x: Vec::with_capacity(coord_capacity),
-            y: Vec::with_capacity(coord_capacity),
+            coords: MutableCoordBuffer::with_capacity(coord_type, coord_capacity),
+            z: None,
             geom_offsets: Offsets::<i64>::with_capacity(geom_capacity),
             ring_offsets: Offsets::<i64>::with_capacity(ring_capacity),
             validity: None,
         }
     }
 
-    /// The canonical method to create a [`MutableLineStringArray`] out of its internal components.
+    /// The canonical method to create a [`MutablePolygonArray`] out of its internal
+    /// components, storing `x`/`y` as [`CoordType::Separated`].
     /// # Implementation
     /// This function is `O(1)`.
     ///
@@ -68,30 +91,139 @@ impl MutablePolygonArray {
         ring_offsets: Offsets<i64>,
         validity: Option<MutableBitmap>,
     ) -> Result<Self, GeoArrowError> {
-        check(&x, &y, validity.as_ref().map(|x| x.len()))?;
-        Ok(Self {
+        Self::try_new_with_coord_type(
             x,
             y,
             geom_offsets,
             ring_offsets,
             validity,
+            CoordType::Separated,
+        )
+    }
+
+    /// Like [`Self::try_new`], but storing `x`/`y` in the given [`CoordType`] layout.
+    pub fn try_new_with_coord_type(
+        x: Vec<f64>,
+        y: Vec<f64>,
+        geom_offsets: Offsets<i64>,
+        ring_offsets: Offsets<i64>,
+        validity: Option<MutableBitmap>,
+        coord_type: CoordType,
+    ) -> Result<Self, GeoArrowError> {
+        check(
+            &x,
+            &y,
+            validity.as_ref().map(|x| x.len()),
+            &geom_offsets.clone().into(),
+            &ring_offsets.clone().into(),
+        )?;
+        let coords = match coord_type {
+            CoordType::Separated => MutableCoordBuffer::Separated(x, y),
+            CoordType::Interleaved => {
+                let mut xy = Vec::with_capacity(x.len() * 2);
+                for (x, y) in x.into_iter().zip(y) {
+                    xy.push(x);
+                    xy.push(y);
+                }
+                MutableCoordBuffer::Interleaved(xy)
+            }
+        };
+        Ok(Self {
+            coords,
+            z: None,
+            geom_offsets,
+            ring_offsets,
+            validity,
         })
     }
 
+    /// Create a new [`MutablePolygonArray`] from a [`MutableCoordBuffer`] in either the separated
+    /// or interleaved layout, with an optional `z` buffer.
+    /// # Implementation
+    /// This function is `O(1)`.
+    ///
+    /// # Errors
+    /// This function errors iff:
+    /// * The validity is not `None` and its length is different from `values`'s length
+    pub fn try_new_from_coords(
+        coords: MutableCoordBuffer,
+        z: Option<Vec<f64>>,
+        geom_offsets: Offsets<i64>,
+        ring_offsets: Offsets<i64>,
+        validity: Option<MutableBitmap>,
+    ) -> Result<Self, GeoArrowError> {
+        Ok(Self {
+            coords,
+            z,
+            geom_offsets,
+            ring_offsets,
+            validity,
+        })
+    }
+
+    /// The optional `z` buffer, one value per coordinate. Values on null slots are undetermined
+    /// (they can be anything).
+    #[inline]
+    pub fn z(&self) -> Option<&[f64]> {
+        self.z.as_deref()
+    }
+
+    /// Which coordinate dimensions this array carries.
+    #[inline]
+    pub fn dimension(&self) -> Dimension {
+        if self.z.is_some() {
+            Dimension::XYZ
+        } else {
+            Dimension::XY
+        }
+    }
+
     /// Extract the low-level APIs from the [`MutableLineStringArray`].
     pub fn into_inner(self) -> MutablePolygonParts {
         (
-            self.x,
-            self.y,
+            self.coords,
+            self.z,
             self.geom_offsets,
             self.ring_offsets,
             self.validity,
         )
     }
 
+    /// Converts this builder directly into Arrow's [`ListArray`] representation.
+    ///
+    /// Unlike going through [`PolygonArray`] (which has no `z` support, so a 3D array loses its
+    /// `z` buffer), this builds the coordinate child straight from this array's
+    /// [`CoordType`], so interleaved GeoArrow data round-trips without a re-striping copy. When
+    /// this array is 3D, the ring coordinate child is a `FixedSizeList<f64>[3]` regardless of
+    /// [`CoordType`], since a separated `z` buffer has no natural place in the `x`/`y` struct
+    /// layout.
     pub fn into_arrow(self) -> ListArray<i64> {
-        let polygon_array: PolygonArray = self.into();
-        polygon_array.into_arrow()
+        use arrow2::datatypes::{DataType, Field};
+
+        let validity: Option<Bitmap> = self.validity.and_then(|x| {
+            let bitmap: Bitmap = x.into();
+            (bitmap.unset_bits() > 0).then_some(bitmap)
+        });
+
+        let ring_offsets: OffsetsBuffer<i64> = self.ring_offsets.into();
+        let coord_array = coords_into_arrow(self.coords, self.z);
+        let coord_data_type = coord_array.data_type().clone();
+
+        let ring_data_type = DataType::LargeList(Box::new(Field::new(
+            "vertices",
+            coord_data_type,
+            false,
+        )));
+        let rings_array = ListArray::<i64>::new(ring_data_type.clone(), ring_offsets, coord_array, None);
+
+        let geom_offsets: OffsetsBuffer<i64> = self.geom_offsets.into();
+        let geom_data_type = DataType::LargeList(Box::new(Field::new(
+            "rings",
+            ring_data_type,
+            true,
+        )));
+
+        ListArray::new(geom_data_type, geom_offsets, rings_array.boxed(), validity)
     }
 }
 
@@ -101,6 +233,38 @@ impl Default for MutablePolygonArray {
     }
 }
 
+/// Builds the ring coordinate child array for [`MutablePolygonArray::into_arrow`], folding an
+/// optional `z` buffer into a `FixedSizeList<f64>[3]` on top of whichever [`CoordType`] layout
+/// `coords` was built with. Without `z` this is exactly [`MutableCoordBuffer::into_arrow`].
+fn coords_into_arrow(coords: MutableCoordBuffer, z: Option<Vec<f64>>) -> Box<dyn Array> {
+    let Some(z) = z else {
+        return coords.into_arrow();
+    };
+
+    let (x, y) = coords.into_separated();
+    let mut xyz = Vec::with_capacity(x.len() * 3);
+    for ((x, y), z) in x.into_iter().zip(y).zip(z) {
+        xyz.push(x);
+        xyz.push(y);
+        xyz.push(z);
+    }
+
+    let values_field = Field::new("xyz", DataType::Float64, false);
+    let values = PrimitiveArray::new(DataType::Float64, xyz.into(), None).boxed();
+
+    FixedSizeListArray::new(
+        DataType::FixedSizeList(Box::new(values_field), 3),
+        values,
+        None,
+    )
+    .boxed()
+}
+
+/// This preserves whichever [`CoordType`] `other` was built with, so converting a
+/// [`MutablePolygonArray`] built as [`CoordType::Interleaved`] into a [`PolygonArray`] is `O(1)`,
+/// with no re-striping copy. [`PolygonArray`] has no `z` support yet, so a 3D
+/// [`MutablePolygonArray`] drops its `z` buffer on this conversion; go through
+/// [`MutablePolygonArray::into_arrow`] instead to keep it.
 impl From<MutablePolygonArray> for PolygonArray {
     fn from(other: MutablePolygonArray) -> Self {
         let validity = other.validity.and_then(|x| {
@@ -115,73 +279,101 @@ impl From<MutablePolygonArray> for PolygonArray {
         let geom_offsets: OffsetsBuffer<i64> = other.geom_offsets.into();
         let ring_offsets: OffsetsBuffer<i64> = other.ring_offsets.into();
 
-        Self::new(
-            other.x.into(),
-            other.y.into(),
-            geom_offsets,
-            ring_offsets,
-            validity,
-        )
+        Self::try_new_from_coords(other.coords.into(), geom_offsets, ring_offsets, validity)
+            .unwrap()
     }
 }
 
-impl From<Vec<Polygon>> for MutablePolygonArray {
-    fn from(geoms: Vec<Polygon>) -> Self {
-        use geo::coords_iter::CoordsIter;
+/// Builds a [`MutablePolygonArray`] from owned geometries, storing coordinates in the given
+/// [`CoordType`] layout.
+pub(crate) fn polygon_from_geo_vec_with_coord_type(
+    geoms: Vec<Polygon>,
+    coord_type: CoordType,
+) -> MutablePolygonArray {
+    use geo::coords_iter::CoordsIter;
 
-        // Offset into ring indexes for each geometry
-        let mut geom_offsets = Offsets::<i64>::with_capacity(geoms.len());
+    // Offset into ring indexes for each geometry
+    let mut geom_offsets = Offsets::<i64>::with_capacity(geoms.len());
 
-        // Offset into coordinates for each ring
-        // This capacity will only be enough in the case where each geometry has only a single ring
-        let mut ring_offsets = Offsets::<i64>::with_capacity(geoms.len());
+    // Offset into coordinates for each ring
+    // This capacity will only be enough in the case where each geometry has only a single ring
+    let mut ring_offsets = Offsets::<i64>::with_capacity(geoms.len());
 
-        // Current offset into ring array
-        let mut current_geom_offset = 0;
+    // Current offset into ring array
+    let mut current_geom_offset = 0;
 
-        // Current offset into coord array
-        let mut current_ring_offset = 0;
+    // Current offset into coord array
+    let mut current_ring_offset = 0;
 
-        for geom in &geoms {
-            // Total number of rings in this polygon
-            current_geom_offset += geom.interiors().len() + 1;
-            geom_offsets.try_push_usize(current_geom_offset).unwrap();
+    for geom in &geoms {
+        // Total number of rings in this polygon
+        current_geom_offset += geom.interiors().len() + 1;
+        geom_offsets.try_push_usize(current_geom_offset).unwrap();
+
+        // Number of coords for each ring
+        current_ring_offset += geom.exterior().coords_count();
+        ring_offsets.try_push_usize(current_ring_offset).unwrap();
 
-            // Number of coords for each ring
-            current_ring_offset += geom.exterior().coords_count();
+        for int_ring in geom.interiors() {
+            current_ring_offset += int_ring.coords_count();
             ring_offsets.try_push_usize(current_ring_offset).unwrap();
+        }
+    }
 
-            for int_ring in geom.interiors() {
-                current_ring_offset += int_ring.coords_count();
-                ring_offsets.try_push_usize(current_ring_offset).unwrap();
+    let mut coords = MutableCoordBuffer::with_capacity(coord_type, current_ring_offset);
+
+    for geom in geoms {
+        let ext_ring = geom.exterior();
+        for coord in ext_ring.coords_iter() {
+            coords.push_xy(coord.x, coord.y);
+        }
+
+        for int_ring in geom.interiors() {
+            for coord in int_ring.coords_iter() {
+                coords.push_xy(coord.x, coord.y);
             }
         }
+    }
 
-        let mut x_arr = Vec::<f64>::with_capacity(current_ring_offset);
-        let mut y_arr = Vec::<f64>::with_capacity(current_ring_offset);
+    MutablePolygonArray {
+        coords,
+        z: None,
+        geom_offsets,
+        ring_offsets,
+        validity: None,
+    }
+}
 
-        for geom in geoms {
-            let ext_ring = geom.exterior();
-            for coord in ext_ring.coords_iter() {
-                x_arr.push(coord.x);
-                y_arr.push(coord.y);
-            }
+impl From<Vec<Polygon>> for MutablePolygonArray {
+    fn from(geoms: Vec<Polygon>) -> Self {
+        polygon_from_geo_vec_with_coord_type(geoms, CoordType::Separated)
+    }
+}
 
-            for int_ring in geom.interiors() {
-                for coord in int_ring.coords_iter() {
-                    x_arr.push(coord.x);
-                    y_arr.push(coord.y);
+/// Ingests a batch of GEOS result geometries (e.g. from a buffer or boolean overlay operation)
+/// back into a [`MutablePolygonArray`] by converting each one to [`geo::Polygon`] and pushing its
+/// coordinates/offsets through the same path as [`From<Vec<Polygon>>`].
+#[cfg(feature = "geos")]
+impl TryFrom<Vec<geos::Geometry>> for MutablePolygonArray {
+    type Error = GeoArrowError;
+
+    fn try_from(value: Vec<geos::Geometry>) -> Result<Self, Self::Error> {
+        let geoms = value
+            .iter()
+            .map(|geom| {
+                let geo_geom: geo::Geometry = geom
+                    .try_into()
+                    .map_err(|err: geos::Error| GeoArrowError::External(anyhow::Error::from(err)))?;
+                match geo_geom {
+                    geo::Geometry::Polygon(polygon) => Ok(polygon),
+                    _ => Err(GeoArrowError::General(
+                        "Expected a Polygon geometry from GEOS".to_string(),
+                    )),
                 }
-            }
-        }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
 
-        MutablePolygonArray {
-            x: x_arr,
-            y: y_arr,
-            geom_offsets,
-            ring_offsets,
-            validity: None,
-        }
+        Ok(geoms.into())
     }
 }
 
@@ -226,27 +418,25 @@ impl From<Vec<Option<Polygon>>> for MutablePolygonArray {
             }
         }
 
-        let mut x_arr = Vec::<f64>::with_capacity(current_ring_offset);
-        let mut y_arr = Vec::<f64>::with_capacity(current_ring_offset);
+        let mut coords =
+            MutableCoordBuffer::with_capacity(CoordType::Separated, current_ring_offset);
 
         for geom in geoms.into_iter().flatten() {
             let ext_ring = geom.exterior();
             for coord in ext_ring.coords_iter() {
-                x_arr.push(coord.x);
-                y_arr.push(coord.y);
+                coords.push_xy(coord.x, coord.y);
             }
 
             for int_ring in geom.interiors() {
                 for coord in int_ring.coords_iter() {
-                    x_arr.push(coord.x);
-                    y_arr.push(coord.y);
+                    coords.push_xy(coord.x, coord.y);
                 }
             }
         }
 
         MutablePolygonArray {
-            x: x_arr,
-            y: y_arr,
+            coords,
+            z: None,
             geom_offsets,
             ring_offsets,
             validity: Some(validity),
@@ -255,12 +445,13 @@ impl From<Vec<Option<Polygon>>> for MutablePolygonArray {
 }
 
 /// Polygon and MultiLineString have the same layout, so enable conversions between the two to
-/// change the semantic type
+/// change the semantic type. Whichever [`CoordType`] `value` was built with is preserved, and the
+/// `z` buffer (if any) carries over unchanged.
 impl From<MutablePolygonArray> for MutableMultiLineStringArray {
     fn from(value: MutablePolygonArray) -> Self {
-        Self::try_new(
-            value.x,
-            value.y,
+        Self::try_new_from_coords(
+            value.coords,
+            value.z,
             value.geom_offsets,
             value.ring_offsets,
             value.validity,
@@ -268,3 +459,286 @@ impl From<MutablePolygonArray> for MutableMultiLineStringArray {
         .unwrap()
     }
 }
+
+/// Convert to GeoArrow PolygonArray
+pub trait ToGeoArrowPolygon {
+    /// Convert to GeoArrow PolygonArray
+    fn to_geoarrow(&self) -> geozero::error::Result<PolygonArray>;
+
+    /// Convert to a GeoArrow MutablePolygonArray
+    fn to_mutable_geoarrow(&self) -> geozero::error::Result<MutablePolygonArray>;
+}
+
+impl<T: GeozeroGeometry> ToGeoArrowPolygon for T {
+    fn to_geoarrow(&self) -> geozero::error::Result<PolygonArray> {
+        Ok(self.to_mutable_geoarrow()?.into())
+    }
+
+    fn to_mutable_geoarrow(&self) -> geozero::error::Result<MutablePolygonArray> {
+        let mut mutable_polygon_array = MutablePolygonArray::new();
+        self.process_geom(&mut mutable_polygon_array)?;
+        Ok(mutable_polygon_array)
+    }
+}
+
+#[allow(unused_variables)]
+impl GeomProcessor for MutablePolygonArray {
+    /// Requests 3D coordinates from the source, so a WKB Z/EWKB Z input reaches [`Self::xyz`]
+    /// instead of being silently flattened to 2D through [`Self::xy`].
+    fn dimensions(&self) -> CoordDimensions {
+        CoordDimensions::xyz()
+    }
+
+    fn xy(&mut self, x: f64, y: f64, _idx: usize) -> geozero::error::Result<()> {
+        if self.z.is_some() {
+            return Err(geozero::error::GeozeroError::Geometry(
+                "cannot mix 2D and 3D coordinates in the same array".to_string(),
+            ));
+        }
+        self.coords.push_xy(x, y);
+        Ok(())
+    }
+
+    fn xyz(&mut self, x: f64, y: f64, z: f64, _idx: usize) -> geozero::error::Result<()> {
+        if self.z.is_none() {
+            if !self.coords.is_empty() {
+                return Err(geozero::error::GeozeroError::Geometry(
+                    "cannot mix 2D and 3D coordinates in the same array".to_string(),
+                ));
+            }
+            self.z = Some(Vec::new());
+        }
+        self.coords.push_xy(x, y);
+        self.z.as_mut().unwrap().push(z);
+        Ok(())
+    }
+
+    fn linestring_begin(
+        &mut self,
+        tagged: bool,
+        size: usize,
+        idx: usize,
+    ) -> geozero::error::Result<()> {
+        Ok(())
+    }
+
+    fn linestring_end(&mut self, tagged: bool, idx: usize) -> geozero::error::Result<()> {
+        let total_length = self.coords.len();
+        let offset = self.ring_offsets.last().to_usize();
+        let length = total_length
+            .checked_sub(offset)
+            .ok_or(GeoArrowError::Overflow)
+            .map_err(|err| geozero::error::GeozeroError::Geometry(err.to_string()))?;
+
+        self.ring_offsets.try_push_usize(length).unwrap();
+        Ok(())
+    }
+
+    fn polygon_begin(&mut self, tagged: bool, size: usize, idx: usize) -> geozero::error::Result<()> {
+        Ok(())
+    }
+
+    fn polygon_end(&mut self, tagged: bool, idx: usize) -> geozero::error::Result<()> {
+        let num_rings = self.ring_offsets.len_proxy() - self.geom_offsets.last().to_usize();
+        self.geom_offsets.try_push_usize(num_rings).unwrap();
+        if let Some(validity) = &mut self.validity {
+            validity.push(true);
+        }
+        Ok(())
+    }
+
+    // Override all other trait _begin methods
+    fn circularstring_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        Err(geozero::error::GeozeroError::Geometry(
+            "Only polygon geometries allowed".to_string(),
+        ))
+    }
+
+    fn compoundcurve_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        Err(geozero::error::GeozeroError::Geometry(
+            "Only polygon geometries allowed".to_string(),
+        ))
+    }
+
+    fn tin_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        Err(geozero::error::GeozeroError::Geometry(
+            "Only polygon geometries allowed".to_string(),
+        ))
+    }
+
+    fn triangle_begin(
+        &mut self,
+        tagged: bool,
+        size: usize,
+        idx: usize,
+    ) -> geozero::error::Result<()> {
+        Err(geozero::error::GeozeroError::Geometry(
+            "Only polygon geometries allowed".to_string(),
+        ))
+    }
+
+    fn multicurve_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        Err(geozero::error::GeozeroError::Geometry(
+            "Only polygon geometries allowed".to_string(),
+        ))
+    }
+
+    fn multipoint_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        Err(geozero::error::GeozeroError::Geometry(
+            "Only polygon geometries allowed".to_string(),
+        ))
+    }
+
+    fn curvepolygon_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        Err(geozero::error::GeozeroError::Geometry(
+            "Only polygon geometries allowed".to_string(),
+        ))
+    }
+
+    fn multipolygon_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        Err(geozero::error::GeozeroError::Geometry(
+            "Only polygon geometries allowed".to_string(),
+        ))
+    }
+
+    fn multisurface_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        Err(geozero::error::GeozeroError::Geometry(
+            "Only polygon geometries allowed".to_string(),
+        ))
+    }
+
+    fn multilinestring_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        Err(geozero::error::GeozeroError::Geometry(
+            "Only polygon geometries allowed".to_string(),
+        ))
+    }
+
+    fn polyhedralsurface_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        Err(geozero::error::GeozeroError::Geometry(
+            "Only polygon geometries allowed".to_string(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ToGeoArrowPolygon;
+    use crate::GeometryArrayTrait;
+    use geo::{polygon, Geometry, GeometryCollection, MultiPoint, Point, Polygon};
+
+    fn p0() -> Polygon {
+        polygon![
+            (x: 0., y: 0.),
+            (x: 4., y: 0.),
+            (x: 4., y: 4.),
+            (x: 0., y: 4.),
+            (x: 0., y: 0.),
+        ]
+    }
+
+    fn p1() -> Polygon {
+        polygon![
+            (x: 10., y: 10.),
+            (x: 14., y: 10.),
+            (x: 14., y: 14.),
+            (x: 10., y: 14.),
+            (x: 10., y: 10.),
+        ]
+    }
+
+    #[test]
+    fn from_geozero() {
+        let geo = Geometry::GeometryCollection(GeometryCollection(vec![
+            Geometry::Polygon(p0()),
+            Geometry::Polygon(p1()),
+        ]));
+        let polygon_array = geo.to_geoarrow().unwrap();
+        assert_eq!(polygon_array.value_as_geo(0), p0());
+        assert_eq!(polygon_array.value_as_geo(1), p1());
+    }
+
+    #[test]
+    fn from_geozero_error_multiple_geom_types() {
+        let geo = Geometry::GeometryCollection(GeometryCollection(vec![
+            Geometry::Polygon(p0()),
+            Geometry::MultiPoint(MultiPoint(vec![Point::new(0., 0.)])),
+        ]));
+        let err = geo.to_geoarrow().unwrap_err();
+        assert!(matches!(err, geozero::error::GeozeroError::Geometry(..)));
+    }
+
+    #[test]
+    fn interleaved_coord_type_round_trips_through_arrow() {
+        use super::polygon_from_geo_vec_with_coord_type;
+        use crate::coord::CoordType;
+        use arrow2::array::{Array, FixedSizeListArray, ListArray};
+
+        let arr = polygon_from_geo_vec_with_coord_type(vec![p0(), p1()], CoordType::Interleaved);
+        let arrow_arr = arr.into_arrow();
+
+        // The rings' coordinate child should be a FixedSizeList(2), not a StructArray, since
+        // the builder was constructed with CoordType::Interleaved.
+        let rings_arr = arrow_arr
+            .values()
+            .as_any()
+            .downcast_ref::<ListArray<i64>>()
+            .unwrap();
+        let coords_arr = rings_arr
+            .values()
+            .as_any()
+            .downcast_ref::<FixedSizeListArray>()
+            .unwrap();
+        assert_eq!(coords_arr.size(), 2);
+    }
+
+    #[test]
+    fn z_buffer_round_trips_as_fixed_size_list_3() {
+        use super::{Dimension, MutablePolygonArray};
+        use crate::coord::MutableCoordBuffer;
+        use arrow2::array::{Array, FixedSizeListArray, ListArray};
+        use arrow2::offset::Offsets;
+
+        let coords = MutableCoordBuffer::Separated(vec![0., 4., 4., 0.], vec![0., 0., 4., 4.]);
+        let mut geom_offsets = Offsets::<i64>::with_capacity(1);
+        geom_offsets.try_push_usize(1).unwrap();
+        let mut ring_offsets = Offsets::<i64>::with_capacity(1);
+        ring_offsets.try_push_usize(4).unwrap();
+
+        let arr = MutablePolygonArray::try_new_from_coords(
+            coords,
+            Some(vec![1., 2., 3., 4.]),
+            geom_offsets,
+            ring_offsets,
+            None,
+        )
+        .unwrap();
+        assert_eq!(arr.dimension(), Dimension::XYZ);
+        assert_eq!(arr.z(), Some([1., 2., 3., 4.].as_slice()));
+
+        let arrow_arr = arr.into_arrow();
+        let rings_arr = arrow_arr
+            .values()
+            .as_any()
+            .downcast_ref::<ListArray<i64>>()
+            .unwrap();
+        let coords_arr = rings_arr
+            .values()
+            .as_any()
+            .downcast_ref::<FixedSizeListArray>()
+            .unwrap();
+        assert_eq!(coords_arr.size(), 3);
+    }
+
+    #[test]
+    fn from_geozero_error_mixed_dimensions() {
+        use geozero::GeomProcessor;
+        use super::MutablePolygonArray;
+
+        let mut arr = MutablePolygonArray::new();
+        arr.polygon_begin(false, 1, 0).unwrap();
+        arr.linestring_begin(false, 2, 0).unwrap();
+        arr.xyz(0., 0., 1., 0).unwrap();
+        let err = arr.xy(1., 1., 1).unwrap_err();
+        assert!(matches!(err, geozero::error::GeozeroError::Geometry(..)));
+    }
+}
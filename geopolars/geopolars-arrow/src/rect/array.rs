@@ -0,0 +1,290 @@
+use crate::enum_::GeometryType;
+use crate::error::GeoArrowError;
+use crate::trait_::GeometryArray;
+use arrow2::array::{Array, FixedSizeListArray, PrimitiveArray};
+use arrow2::bitmap::utils::{BitmapIter, ZipValidity};
+use arrow2::bitmap::Bitmap;
+use arrow2::buffer::Buffer;
+use arrow2::datatypes::{DataType, Field};
+
+/// A [`GeometryArray`] semantically equivalent to `Vec<Option<geo::Rect>>`, backed by a single
+/// `FixedSizeList<f64>[4]` Arrow buffer laid out as `[minx, miny, maxx, maxy]` per element.
+///
+/// Unlike [`PointArray`](crate::PointArray), this array is usually built in one shot from an
+/// already-computed `Vec<geo::Rect>` (e.g. out of an `envelope()` kernel or [`GeometryArrayTrait::bounds`](crate::GeometryArrayTrait::bounds))
+/// rather than incrementally; [`MutableRectArray`](crate::MutableRectArray) is available for the
+/// latter case.
+#[derive(Debug, Clone)]
+pub struct RectArray {
+    values: Buffer<f64>,
+    validity: Option<Bitmap>,
+}
+
+pub(super) fn check(values: &[f64], validity_len: Option<usize>) -> Result<(), GeoArrowError> {
+    if values.len() % 4 != 0 {
+        return Err(GeoArrowError::General(
+            "values length must be a multiple of 4".to_string(),
+        ));
+    }
+
+    if validity_len.map_or(false, |len| len != values.len() / 4) {
+        return Err(GeoArrowError::General(
+            "validity mask length must match the number of rects".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+impl RectArray {
+    /// Create a new RectArray from parts
+    /// # Implementation
+    /// This function is `O(1)`.
+    pub fn new(values: Buffer<f64>, validity: Option<Bitmap>) -> Self {
+        check(&values, validity.as_ref().map(|v| v.len())).unwrap();
+        Self { values, validity }
+    }
+
+    /// Create a new RectArray from parts
+    /// # Implementation
+    /// This function is `O(1)`.
+    pub fn try_new(values: Buffer<f64>, validity: Option<Bitmap>) -> Result<Self, GeoArrowError> {
+        check(&values, validity.as_ref().map(|v| v.len()))?;
+        Ok(Self { values, validity })
+    }
+
+    /// Returns the number of geometries in this array
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.values.len() / 4
+    }
+
+    /// Returns true if the array is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The flat `[minx, miny, maxx, maxy, ...]` values [`Buffer`].
+    /// Values on null slots are undetermined (they can be anything).
+    #[inline]
+    pub fn values(&self) -> &Buffer<f64> {
+        &self.values
+    }
+
+    /// Returns the optional validity.
+    #[inline]
+    pub fn validity(&self) -> Option<&Bitmap> {
+        self.validity.as_ref()
+    }
+
+    /// Returns a clone of this array sliced by an offset and length.
+    /// # Implementation
+    /// This operation is `O(1)` as it amounts to increase two ref counts.
+    /// # Panic
+    /// This function panics iff `offset + length > self.len()`.
+    #[inline]
+    #[must_use]
+    pub fn slice(&self, offset: usize, length: usize) -> Self {
+        assert!(
+            offset + length <= self.len(),
+            "offset + length may not exceed length of array"
+        );
+        unsafe { self.slice_unchecked(offset, length) }
+    }
+
+    /// Returns a clone of this array sliced by an offset and length.
+    /// # Implementation
+    /// This operation is `O(1)` as it amounts to increase two ref counts.
+    /// # Safety
+    /// The caller must ensure that `offset + length <= self.len()`.
+    #[inline]
+    #[must_use]
+    pub unsafe fn slice_unchecked(&self, offset: usize, length: usize) -> Self {
+        let validity = self
+            .validity
+            .clone()
+            .map(|bitmap| bitmap.slice_unchecked(offset, length))
+            .and_then(|bitmap| (bitmap.unset_bits() > 0).then_some(bitmap));
+        Self {
+            values: self.values.clone().slice_unchecked(offset * 4, length * 4),
+            validity,
+        }
+    }
+}
+
+// Implement geometry accessors
+impl RectArray {
+    pub fn value(&self, i: usize) -> crate::Rect {
+        crate::Rect {
+            values: &self.values,
+            geom_index: i,
+        }
+    }
+
+    pub fn get(&self, i: usize) -> Option<crate::Rect> {
+        if self.is_null(i) {
+            return None;
+        }
+
+        Some(self.value(i))
+    }
+
+    /// Returns the value at slot `i` as a geo object.
+    pub fn value_as_geo(&self, i: usize) -> geo::Rect {
+        self.value(i).into()
+    }
+
+    /// Gets the value at slot `i` as a geo object, additionally checking the validity bitmap
+    pub fn get_as_geo(&self, i: usize) -> Option<geo::Rect> {
+        if self.is_null(i) {
+            return None;
+        }
+
+        Some(self.value_as_geo(i))
+    }
+
+    /// Iterator over geo Rect objects, not looking at validity
+    pub fn iter_geo_values(&self) -> impl Iterator<Item = geo::Rect> + '_ {
+        (0..self.len()).map(|i| self.value_as_geo(i))
+    }
+
+    /// Iterator over geo Rect objects, taking into account validity
+    pub fn iter_geo(
+        &self,
+    ) -> ZipValidity<geo::Rect, impl Iterator<Item = geo::Rect> + '_, BitmapIter> {
+        ZipValidity::new_with_validity(self.iter_geo_values(), self.validity())
+    }
+
+    /// Returns whether slot `i` is null.
+    #[inline]
+    fn is_null(&self, i: usize) -> bool {
+        self.validity
+            .as_ref()
+            .map(|x| !x.get_bit(i))
+            .unwrap_or(false)
+    }
+
+    pub fn into_arrow(self) -> FixedSizeListArray {
+        let values_field = Field::new("rect", DataType::Float64, false);
+        let values = PrimitiveArray::new(DataType::Float64, self.values, None).boxed();
+
+        FixedSizeListArray::new(
+            DataType::FixedSizeList(Box::new(values_field), 4),
+            values,
+            self.validity,
+        )
+    }
+}
+
+impl TryFrom<FixedSizeListArray> for RectArray {
+    type Error = GeoArrowError;
+
+    fn try_from(value: FixedSizeListArray) -> Result<Self, Self::Error> {
+        if value.size() != 4 {
+            return Err(GeoArrowError::General(
+                "Expected a FixedSizeListArray of width 4.".to_string(),
+            ));
+        }
+
+        let values = value
+            .values()
+            .as_any()
+            .downcast_ref::<PrimitiveArray<f64>>()
+            .unwrap();
+
+        Self::try_new(values.values().clone(), value.validity().cloned())
+    }
+}
+
+impl TryFrom<Box<dyn Array>> for RectArray {
+    type Error = GeoArrowError;
+
+    fn try_from(value: Box<dyn Array>) -> Result<Self, Self::Error> {
+        let arr = value
+            .as_any()
+            .downcast_ref::<FixedSizeListArray>()
+            .unwrap();
+        arr.clone().try_into()
+    }
+}
+
+impl GeometryArray for RectArray {
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn geometry_type(&self) -> GeometryType {
+        GeometryType::Rect
+    }
+
+    fn validity(&self) -> Option<&Bitmap> {
+        self.validity()
+    }
+
+    fn slice(&self, offset: usize, length: usize) -> Box<dyn GeometryArray> {
+        Box::new(self.slice(offset, length))
+    }
+
+    unsafe fn slice_unchecked(&self, offset: usize, length: usize) -> Box<dyn GeometryArray> {
+        Box::new(self.slice_unchecked(offset, length))
+    }
+
+    fn to_boxed(&self) -> Box<dyn GeometryArray> {
+        Box::new(self.clone())
+    }
+}
+
+impl From<Vec<geo::Rect>> for RectArray {
+    fn from(other: Vec<geo::Rect>) -> Self {
+        let mut values = Vec::with_capacity(other.len() * 4);
+        for rect in &other {
+            values.push(rect.min().x);
+            values.push(rect.min().y);
+            values.push(rect.max().x);
+            values.push(rect.max().y);
+        }
+        Self::new(values.into(), None)
+    }
+}
+
+impl From<Vec<Option<geo::Rect>>> for RectArray {
+    fn from(other: Vec<Option<geo::Rect>>) -> Self {
+        let mut values = Vec::with_capacity(other.len() * 4);
+        let mut validity = arrow2::bitmap::MutableBitmap::with_capacity(other.len());
+
+        for rect in &other {
+            match rect {
+                Some(rect) => {
+                    values.push(rect.min().x);
+                    values.push(rect.min().y);
+                    values.push(rect.max().x);
+                    values.push(rect.max().y);
+                    validity.push(true);
+                }
+                None => {
+                    values.push(0.0);
+                    values.push(0.0);
+                    values.push(0.0);
+                    values.push(0.0);
+                    validity.push(false);
+                }
+            }
+        }
+
+        let validity: Bitmap = validity.into();
+        let validity = (validity.unset_bits() > 0).then_some(validity);
+
+        Self::new(values.into(), validity)
+    }
+}
@@ -0,0 +1,9 @@
+//! Helpers for using Rect (axis-aligned bounding box) GeoArrow data
+
+pub use array::RectArray;
+pub use mutable::MutableRectArray;
+pub use scalar::{Rect, RectTrait};
+
+mod array;
+mod mutable;
+mod scalar;
@@ -0,0 +1,104 @@
+use arrow2::bitmap::MutableBitmap;
+
+use super::array::RectArray;
+
+/// The mutable (builder) counterpart to [`RectArray`]; appends bounding boxes one at a time into
+/// a flat `[minx, miny, maxx, maxy, ...]` buffer.
+#[derive(Debug, Clone)]
+pub struct MutableRectArray {
+    values: Vec<f64>,
+    validity: Option<MutableBitmap>,
+}
+
+impl MutableRectArray {
+    /// Creates a new empty [`MutableRectArray`].
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Creates a new [`MutableRectArray`] with space for `capacity` rects.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            values: Vec::with_capacity(capacity * 4),
+            validity: None,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.values.len() / 4
+    }
+
+    /// Adds a new bounding box to the array, given as `[minx, miny, maxx, maxy]`.
+    pub fn push_rect(&mut self, value: Option<[f64; 4]>) {
+        match value {
+            Some(value) => {
+                self.values.extend_from_slice(&value);
+                if let Some(validity) = &mut self.validity {
+                    validity.push(true);
+                }
+            }
+            None => {
+                self.values.extend_from_slice(&[0.0; 4]);
+                match &mut self.validity {
+                    Some(validity) => validity.push(false),
+                    None => self.init_validity(),
+                }
+            }
+        }
+    }
+
+    fn init_validity(&mut self) {
+        let mut validity = MutableBitmap::with_capacity(self.len());
+        validity.extend_constant(self.len(), true);
+        validity.set(self.len() - 1, false);
+        self.validity = Some(validity)
+    }
+
+    /// Converts this builder into an (immutable) [`RectArray`].
+    /// # Implementation
+    /// This is `O(1)`.
+    pub fn into_arrow(self) -> RectArray {
+        self.into()
+    }
+}
+
+impl Default for MutableRectArray {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<MutableRectArray> for RectArray {
+    fn from(other: MutableRectArray) -> Self {
+        RectArray::new(other.values.into(), other.validity.map(|v| v.into()))
+    }
+}
+
+impl From<Vec<Option<[f64; 4]>>> for MutableRectArray {
+    fn from(other: Vec<Option<[f64; 4]>>) -> Self {
+        let mut arr = Self::with_capacity(other.len());
+        for value in other {
+            arr.push_rect(value);
+        }
+        arr
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MutableRectArray;
+
+    #[test]
+    fn push_rect_and_push_null_round_trip() {
+        let mut mutable = MutableRectArray::new();
+        mutable.push_rect(Some([0., 0., 1., 1.]));
+        mutable.push_rect(None);
+        mutable.push_rect(Some([2., 2., 3., 3.]));
+
+        let array = mutable.into_arrow();
+        assert_eq!(array.len(), 3);
+        assert!(array.get(0).is_some());
+        assert!(array.get(1).is_none());
+        assert!(array.get(2).is_some());
+    }
+}
@@ -0,0 +1,93 @@
+use arrow2::buffer::Buffer;
+use geo::Coord;
+use rstar::{RTreeObject, AABB};
+
+/// An arrow equivalent of a `geo::Rect`, read out of a flat `[minx, miny, maxx, maxy, ...]` buffer.
+#[derive(Debug, Clone)]
+pub struct Rect<'a> {
+    pub values: &'a Buffer<f64>,
+    pub geom_index: usize,
+}
+
+impl Rect<'_> {
+    #[inline]
+    fn offset(&self) -> usize {
+        self.geom_index * 4
+    }
+
+    pub fn minx(&self) -> f64 {
+        self.values[self.offset()]
+    }
+
+    pub fn miny(&self) -> f64 {
+        self.values[self.offset() + 1]
+    }
+
+    pub fn maxx(&self) -> f64 {
+        self.values[self.offset() + 2]
+    }
+
+    pub fn maxy(&self) -> f64 {
+        self.values[self.offset() + 3]
+    }
+}
+
+impl From<Rect<'_>> for geo::Rect {
+    fn from(value: Rect<'_>) -> Self {
+        (&value).into()
+    }
+}
+
+impl From<&Rect<'_>> for geo::Rect {
+    fn from(value: &Rect<'_>) -> Self {
+        geo::Rect::new(
+            Coord {
+                x: value.minx(),
+                y: value.miny(),
+            },
+            Coord {
+                x: value.maxx(),
+                y: value.maxy(),
+            },
+        )
+    }
+}
+
+impl RTreeObject for Rect<'_> {
+    type Envelope = AABB<[f64; 2]>;
+
+    fn envelope(&self) -> Self::Envelope {
+        AABB::from_corners([self.minx(), self.miny()], [self.maxx(), self.maxy()])
+    }
+}
+
+/// A bounding box with `[min, max]` corner accessors, implemented by both this crate's
+/// arrow-native [`Rect`] and `geo::Rect`, so callers can write bbox-only code generically over
+/// either representation.
+pub trait RectTrait {
+    /// The `[x, y]` of this box's lower-left (minimum) corner.
+    fn lower(&self) -> [f64; 2];
+
+    /// The `[x, y]` of this box's upper-right (maximum) corner.
+    fn upper(&self) -> [f64; 2];
+}
+
+impl RectTrait for Rect<'_> {
+    fn lower(&self) -> [f64; 2] {
+        [self.minx(), self.miny()]
+    }
+
+    fn upper(&self) -> [f64; 2] {
+        [self.maxx(), self.maxy()]
+    }
+}
+
+impl RectTrait for geo::Rect {
+    fn lower(&self) -> [f64; 2] {
+        [self.min().x, self.min().y]
+    }
+
+    fn upper(&self) -> [f64; 2] {
+        [self.max().x, self.max().y]
+    }
+}
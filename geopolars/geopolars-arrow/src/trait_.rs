@@ -1,9 +1,14 @@
+use arrow2::bitmap::utils::{BitmapIter, ZipValidity};
 use arrow2::bitmap::{Bitmap, MutableBitmap};
-use rstar::{RTree, RTreeObject};
+use arrow2::trusted_len::TrustedLen;
+use rstar::{RTree, RTreeObject, AABB};
 use std::any::Any;
 
+use crate::index::PackedHilbertRTree;
+use crate::rect::RectArray;
+
 pub trait GeometryArrayTrait<'a> {
-    type Scalar: RTreeObject;
+    type Scalar: RTreeObject<Envelope = AABB<[f64; 2]>>;
     type ScalarGeo: From<Self::Scalar>;
     type ArrowArray;
 
@@ -35,6 +40,75 @@ pub trait GeometryArrayTrait<'a> {
     /// Build a spatial index containing this array's geometries
     fn rstar_tree(&'a self) -> RTree<Self::Scalar>;
 
+    /// Computes the envelope of every geometry in this array, returning a [`RectArray`] of the
+    /// same length.
+    ///
+    /// Each envelope is derived from `Self::Scalar`'s `RTreeObject` impl, which reads directly out
+    /// of the array's coordinate buffers, so this never materializes an owned `geo` geometry.
+    /// Values at null slots are undetermined (they can be anything).
+    fn bounds(&'a self) -> RectArray {
+        let mut values = Vec::with_capacity(self.len() * 4);
+        for i in 0..self.len() {
+            let envelope = self.value(i).envelope();
+            let lower = envelope.lower();
+            let upper = envelope.upper();
+            values.push(lower[0]);
+            values.push(lower[1]);
+            values.push(upper[0]);
+            values.push(upper[1]);
+        }
+        RectArray::new(values.into(), self.validity().cloned())
+    }
+
+    /// Reduces [`Self::bounds`] down to the single box covering every non-null geometry in this
+    /// array, analogous to Shapely/GeoPandas' `total_bounds`.
+    ///
+    /// # Panics
+    /// Panics if the array has no non-null geometries, since there is then no box to return.
+    fn total_bounds(&'a self) -> geo::Rect {
+        let bounds = self.bounds();
+        let mut rects = bounds.iter_geo().flatten();
+        let first = rects
+            .next()
+            .expect("total_bounds of an array with no non-null geometries");
+        rects.fold(first, |acc, rect| {
+            geo::Rect::new(
+                geo::coord! {
+                    x: acc.min().x.min(rect.min().x),
+                    y: acc.min().y.min(rect.min().y),
+                },
+                geo::coord! {
+                    x: acc.max().x.max(rect.max().x),
+                    y: acc.max().y.max(rect.max().y),
+                },
+            )
+        })
+    }
+
+    /// Returns an iterator of `Option<Self::Scalar>`, zipping [`Self::value`] up with the
+    /// validity bitmap.
+    ///
+    /// This is the same `ZipValidity::new_with_validity(values_iter, self.validity())` pattern
+    /// every concrete array (`PointArray`, `WKBArray`, `PolygonArray`, ...) already hand-rolls as
+    /// its own inherent `iter()`; having it here once means scalar-producing algorithms (`x`,
+    /// `y`, `area`, `length`, ...) can consume a single nulls-aware iterator instead of
+    /// re-threading validity themselves.
+    fn iter(&'a self) -> ZipValidity<Self::Scalar, GeometryArrayValuesIter<'a, Self>, BitmapIter<'a>>
+    where
+        Self: Sized,
+    {
+        ZipValidity::new_with_validity(GeometryArrayValuesIter::new(self), self.validity())
+    }
+
+    /// Builds a packed Hilbert R-tree over this array's bounding boxes (see [`Self::bounds`]).
+    ///
+    /// Unlike [`Self::rstar_tree`], this index supports only bbox-range queries (no
+    /// nearest-neighbor or exact-geometry predicates), in exchange for an allocation-light, flat
+    /// layout built in one pass. Useful for `sjoin`-style spatial joins over a whole array.
+    fn hilbert_rtree(&'a self) -> PackedHilbertRTree {
+        PackedHilbertRTree::new(&self.bounds())
+    }
+
     /// The length of the [`GeometryArray`]. Every array has a length corresponding to the number of
     /// elements (slots).
     fn len(&self) -> usize;
@@ -157,3 +231,47 @@ pub trait MutableGeometryArray: std::fmt::Debug + Send + Sync {
     // /// Shrink the array to fit its length.
     // fn shrink_to_fit(&mut self);
 }
+
+/// The values iterator backing [`GeometryArrayTrait::iter`], generic over any implementor.
+///
+/// Mirrors the per-type `*ArrayValuesIter` structs (e.g. `PointArrayValuesIter`) that each
+/// concrete array still keeps as its own inherent `values_iter()`, but works for any `A:
+/// GeometryArrayTrait` via `A::value`.
+#[derive(Clone, Debug)]
+pub struct GeometryArrayValuesIter<'a, A: GeometryArrayTrait<'a>> {
+    array: &'a A,
+    index: usize,
+    end: usize,
+}
+
+impl<'a, A: GeometryArrayTrait<'a>> GeometryArrayValuesIter<'a, A> {
+    #[inline]
+    pub fn new(array: &'a A) -> Self {
+        Self {
+            array,
+            index: 0,
+            end: array.len(),
+        }
+    }
+}
+
+impl<'a, A: GeometryArrayTrait<'a>> Iterator for GeometryArrayValuesIter<'a, A> {
+    type Item = A::Scalar;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index == self.end {
+            return None;
+        }
+        let old = self.index;
+        self.index += 1;
+        Some(self.array.value(old))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.end - self.index, Some(self.end - self.index))
+    }
+}
+
+unsafe impl<'a, A: GeometryArrayTrait<'a>> TrustedLen for GeometryArrayValuesIter<'a, A> {}
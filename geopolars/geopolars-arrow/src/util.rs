@@ -1,7 +1,14 @@
 use crate::GeometryArray;
-use arrow2::array::{Array, BinaryArray, ListArray, StructArray};
+use arrow2::array::{Array, BinaryArray, FixedSizeListArray, ListArray, StructArray, UnionArray};
 use arrow2::datatypes::DataType;
 
+/// True for a coordinate child's data type: either the `x`/`y` [`StructArray`] every builder in
+/// this crate used before [`crate::coord::CoordBuffer`] existed, or a `FixedSizeList<2, f64>`
+/// from a producer that ships interleaved coordinates.
+fn is_coord_data_type(dt: &DataType) -> bool {
+    matches!(dt, DataType::Struct(_) | DataType::FixedSizeList(_, 2))
+}
+
 pub fn array_to_geometry_array(arr: &dyn Array, is_multi: bool) -> GeometryArray {
     match arr.data_type() {
         DataType::LargeBinary => {
@@ -12,8 +19,12 @@ pub fn array_to_geometry_array(arr: &dyn Array, is_multi: bool) -> GeometryArray
             let lit_arr = arr.as_any().downcast_ref::<StructArray>().unwrap();
             GeometryArray::Point(lit_arr.clone().try_into().unwrap())
         }
+        DataType::FixedSizeList(_, 2) => {
+            let lit_arr = arr.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            GeometryArray::Point(lit_arr.clone().try_into().unwrap())
+        }
         DataType::List(dt) | DataType::LargeList(dt) => match dt.data_type() {
-            DataType::Struct(_) => {
+            dt if is_coord_data_type(dt) => {
                 let lit_arr = arr.as_any().downcast_ref::<ListArray<i64>>().unwrap();
 
                 if is_multi {
@@ -23,7 +34,7 @@ pub fn array_to_geometry_array(arr: &dyn Array, is_multi: bool) -> GeometryArray
                 }
             }
             DataType::List(dt2) | DataType::LargeList(dt2) => match dt2.data_type() {
-                DataType::Struct(_) => {
+                dt2 if is_coord_data_type(dt2) => {
                     let lit_arr = arr.as_any().downcast_ref::<ListArray<i64>>().unwrap();
                     if is_multi {
                         GeometryArray::MultiLineString(lit_arr.clone().try_into().unwrap())
@@ -39,6 +50,10 @@ pub fn array_to_geometry_array(arr: &dyn Array, is_multi: bool) -> GeometryArray
             },
             _ => panic!("Unexpected inner list type: {:?}", dt),
         },
+        DataType::Union(..) => {
+            let lit_arr = arr.as_any().downcast_ref::<UnionArray>().unwrap();
+            GeometryArray::Mixed(lit_arr.clone().try_into().unwrap())
+        }
         dt => panic!("Unexpected geoarrow type: {:?}", dt),
     }
 }
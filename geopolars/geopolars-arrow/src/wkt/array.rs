@@ -0,0 +1,125 @@
+use crate::error::GeoArrowError;
+use crate::{GeometryArrayTrait, MutableWKTArray, WKT};
+use arrow2::array::{Array, Utf8Array};
+use arrow2::bitmap::utils::{BitmapIter, ZipValidity};
+use arrow2::bitmap::Bitmap;
+use rstar::RTree;
+
+/// A [`GeometryArray`] semantically equivalent to `Vec<Option<Geometry>>` using Arrow's
+/// in-memory representation, backed by a [`Utf8Array`] of WKT strings.
+#[derive(Debug, Clone)]
+pub struct WKTArray(Utf8Array<i64>);
+
+// Implement geometry accessors
+impl WKTArray {
+    /// Create a new WKTArray from a Utf8Array
+    pub fn new(arr: Utf8Array<i64>) -> Self {
+        Self(arr)
+    }
+
+    /// Returns true if the array is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn with_validity(&self, validity: Option<Bitmap>) -> Self {
+        WKTArray::new(self.0.clone().with_validity(validity))
+    }
+}
+
+impl<'a> GeometryArrayTrait<'a> for WKTArray {
+    type Scalar = WKT<'a>;
+    type ScalarGeo = geo::Geometry;
+    type ArrowArray = Utf8Array<i64>;
+
+    fn value(&'a self, i: usize) -> Self::Scalar {
+        crate::WKT {
+            arr: &self.0,
+            geom_index: i,
+        }
+    }
+
+    fn into_arrow(self) -> Utf8Array<i64> {
+        self.0
+    }
+
+    /// Build a spatial index containing this array's geometries
+    fn rstar_tree(&'a self) -> RTree<Self::Scalar> {
+        let mut tree = RTree::new();
+        self.iter().flatten().for_each(|geom| tree.insert(geom));
+        tree
+    }
+
+    /// Returns the number of geometries in this array
+    #[inline]
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns the optional validity.
+    fn validity(&self) -> Option<&Bitmap> {
+        self.0.validity()
+    }
+
+    /// Returns a clone of this [`WKTArray`] sliced by an offset and length.
+    /// # Implementation
+    /// This operation is `O(1)` as it amounts to increase two ref counts.
+    /// # Panic
+    /// This function panics iff `offset + length > self.len()`.
+    #[inline]
+    #[must_use]
+    fn slice(&self, offset: usize, length: usize) -> Self {
+        WKTArray(self.0.slice(offset, length))
+    }
+
+    /// Returns a clone of this [`WKTArray`] sliced by an offset and length.
+    /// # Implementation
+    /// This operation is `O(1)` as it amounts to increase two ref counts.
+    /// # Safety
+    /// The caller must ensure that `offset + length <= self.len()`.
+    #[inline]
+    #[must_use]
+    unsafe fn slice_unchecked(&self, offset: usize, length: usize) -> Self {
+        WKTArray(self.0.slice_unchecked(offset, length))
+    }
+
+    fn to_boxed(&self) -> Box<Self> {
+        Box::new(self.clone())
+    }
+}
+
+impl WKTArray {
+    /// Iterator over geo Geometry objects, not looking at validity
+    pub fn iter_geo_values(&self) -> impl Iterator<Item = geo::Geometry> + '_ {
+        (0..self.len()).map(|i| self.value_as_geo(i))
+    }
+
+    /// Iterator over geo Geometry objects, taking into account validity
+    pub fn iter_geo(
+        &self,
+    ) -> ZipValidity<geo::Geometry, impl Iterator<Item = geo::Geometry> + '_, BitmapIter> {
+        ZipValidity::new_with_validity(self.iter_geo_values(), self.validity())
+    }
+}
+
+impl From<Utf8Array<i64>> for WKTArray {
+    fn from(other: Utf8Array<i64>) -> Self {
+        Self(other)
+    }
+}
+
+impl TryFrom<Box<dyn Array>> for WKTArray {
+    type Error = GeoArrowError;
+
+    fn try_from(value: Box<dyn Array>) -> Result<Self, Self::Error> {
+        let arr = value.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+        Ok(arr.clone().into())
+    }
+}
+
+impl From<Vec<Option<geo::Geometry>>> for WKTArray {
+    fn from(other: Vec<Option<geo::Geometry>>) -> Self {
+        let mut_arr: MutableWKTArray = other.into();
+        mut_arr.into()
+    }
+}
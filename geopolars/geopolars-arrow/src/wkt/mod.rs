@@ -0,0 +1,9 @@
+//! Helpers for using WKT-encoding GeoArrow data
+
+pub use array::WKTArray;
+pub use mutable::MutableWKTArray;
+pub use scalar::WKT;
+
+mod array;
+mod mutable;
+mod scalar;
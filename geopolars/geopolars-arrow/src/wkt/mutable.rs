@@ -0,0 +1,82 @@
+use arrow2::array::{MutableArray, MutableUtf8Array};
+use arrow2::bitmap::MutableBitmap;
+use geo::Geometry;
+use wkt::ToWkt;
+
+use crate::enum_::GeometryType;
+use crate::trait_::MutableGeometryArray;
+
+use super::array::WKTArray;
+
+/// The Arrow equivalent to `Vec<Option<Geometry>>`.
+/// Converting a [`MutableWKTArray`] into a [`WKTArray`] is `O(1)`.
+#[derive(Debug, Clone)]
+pub struct MutableWKTArray(MutableUtf8Array<i64>);
+
+impl Default for MutableWKTArray {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MutableWKTArray {
+    /// Creates a new empty [`MutableWKTArray`].
+    /// # Implementation
+    /// This allocates a [`Vec`] of one element
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Initializes a new [`MutableWKTArray`] with a pre-allocated capacity of slots.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacities(capacity, 0)
+    }
+
+    /// Initializes a new [`MutableWKTArray`] with a pre-allocated capacity of slots and values.
+    /// # Implementation
+    /// This does not allocate the validity.
+    pub fn with_capacities(capacity: usize, values: usize) -> Self {
+        Self(MutableUtf8Array::<i64>::with_capacities(capacity, values))
+    }
+}
+
+impl MutableGeometryArray for MutableWKTArray {
+    fn geometry_type(&self) -> GeometryType {
+        GeometryType::WKT
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn validity(&self) -> Option<&MutableBitmap> {
+        self.0.validity()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl From<Vec<Option<Geometry>>> for MutableWKTArray {
+    fn from(other: Vec<Option<Geometry>>) -> Self {
+        let mut wkt_array = MutableUtf8Array::<i64>::with_capacity(other.len());
+
+        for geom in other {
+            let wkt = geom.map(|g| g.to_wkt().to_string());
+            wkt_array.push(wkt);
+        }
+
+        Self(wkt_array)
+    }
+}
+
+impl From<MutableWKTArray> for WKTArray {
+    fn from(other: MutableWKTArray) -> Self {
+        Self::new(other.0.into())
+    }
+}
@@ -0,0 +1,36 @@
+use arrow2::array::Utf8Array;
+use geo::BoundingRect;
+use rstar::{RTreeObject, AABB};
+use wkt::TryFromWkt;
+
+/// An arrow equivalent of a WKT-encoded geometry
+#[derive(Debug, Clone)]
+pub struct WKT<'a> {
+    pub arr: &'a Utf8Array<i64>,
+    pub geom_index: usize,
+}
+
+impl From<WKT<'_>> for geo::Geometry {
+    fn from(value: WKT<'_>) -> Self {
+        (&value).into()
+    }
+}
+
+impl From<&WKT<'_>> for geo::Geometry {
+    fn from(value: &WKT<'_>) -> Self {
+        let s = value.arr.value(value.geom_index);
+        geo::Geometry::try_from_wkt_str(s).expect("Unable to parse WKT")
+    }
+}
+
+impl RTreeObject for WKT<'_> {
+    type Envelope = AABB<[f64; 2]>;
+
+    fn envelope(&self) -> Self::Envelope {
+        let geom: geo::Geometry = self.into();
+        let rect = geom.bounding_rect().unwrap();
+        let lower: [f64; 2] = rect.min().into();
+        let upper: [f64; 2] = rect.max().into();
+        AABB::from_corners(lower, upper)
+    }
+}
@@ -1,7 +1,13 @@
 use crate::error::Result;
 #[cfg(feature = "proj")]
 use crate::ops::proj::ProjOptions;
+pub use crate::ops::distance::DistanceMethod;
+#[cfg(feature = "geos")]
+pub use crate::ops::geos_buffer::{BufferParams, CapStyle, JoinStyle};
+pub use crate::ops::sjoin::SpatialPredicate;
 use geo::algorithm::affine_ops::AffineTransform;
+#[cfg(feature = "geos")]
+use geo::Geometry;
 use polars::prelude::Series;
 use std::convert::Into;
 
@@ -11,10 +17,24 @@ pub trait GeoSeries {
     /// Apply an affine transform to the geoseries and return a geoseries of the tranformed geometries;
     fn affine_transform(&self, matrix: impl Into<AffineTransform<f64>>) -> Result<Series>;
 
+    /// Applies `f` to every `(x, y)` coordinate of every geometry in this GeoSeries, returning a
+    /// GeoSeries of the transformed geometries.
+    ///
+    /// Every row is decoded into a `geo` geometry, mapped, and re-encoded as WKB; the native
+    /// `x`/`y` coordinate buffers of a typed GeoArrow array are not mapped in place.
+    fn map_coords(&self, f: impl Fn(f64, f64) -> (f64, f64) + Copy) -> Result<Series>;
+
     /// Returns a Series containing the area of each geometry in the GeoSeries expressed in the
     /// units of the CRS.
     fn area(&self) -> Result<Series>;
 
+    /// Returns a Series containing the geodesic area (in square meters) of each geometry in the
+    /// GeoSeries, computed via the Chamberlain–Duquette method.
+    ///
+    /// Unlike [`GeoSeries::area`], which is planar and only meaningful in a projected CRS, this
+    /// is correct for geometries given as WGS84 longitude/latitude coordinates.
+    fn area_geodesic(&self) -> Result<Series>;
+
     /// Returns a GeoSeries of points representing the centroid of each geometry.
     ///
     /// Note that centroid does not have to be on or within original geometry.
@@ -25,6 +45,29 @@ pub trait GeoSeries {
     /// The convex hull of a geometry is the smallest convex Polygon containing all the points in each geometry
     fn convex_hull(&self) -> Result<Series>;
 
+    /// Returns a single-geometry GeoSeries containing the convex hull enclosing every
+    /// coordinate across every geometry in the series, including bare points.
+    ///
+    /// Unlike [`GeoSeries::convex_hull`], which computes one hull per row, this collapses the
+    /// whole series into the one hull that encloses it, computed directly via Andrew's
+    /// monotone chain algorithm rather than geo's per-row implementation.
+    fn unary_union_convex_hull(&self) -> Result<Series>;
+
+    /// Returns a tighter-fitting, non-convex hull for each geometry, flattening a row's own
+    /// coordinates (e.g. a `MultiPolygon`'s parts) into one point set before hulling.
+    ///
+    /// Starts from the convex hull and repeatedly "digs in" along each edge towards the nearest
+    /// not-yet-included point, as long as doing so wouldn't self-intersect and the edge is loose
+    /// enough per `edge_length / distance_to_point > concavity`. Larger `concavity` values stay
+    /// closer to the convex hull; smaller values dig in more aggressively.
+    fn concave_hull(&self, concavity: f64) -> Result<Series>;
+
+    /// Returns a polygon GeoSeries with, for each row, the geometry in this GeoSeries offset
+    /// outward by `distance` (a negative distance shrinks the geometry instead), shaped by
+    /// `params`. Backed by GEOS.
+    #[cfg(feature = "geos")]
+    fn buffer(&self, distance: f64, params: BufferParams) -> Result<Series>;
+
     /// Returns a GeoSeries of geometries representing the envelope of each geometry.
     ///
     /// The envelope of a geometry is the bounding rectangle. That is, the point or smallest
@@ -55,7 +98,7 @@ pub trait GeoSeries {
     /// Not valid for Point or MultiPoint geometries. For Polygon it's the
     /// length of the exterior ring of the exterior ring of the Polygon and for MultiPolygon
     /// it returns the
-    fn geodesic_length(&self, method: Placeholder) -> Result<Series>;
+    fn geodesic_length(&self, method: DistanceMethod) -> Result<Series>;
 
     /// Returns the type ids of each geometry
     /// This mimics the pygeos implementation
@@ -115,6 +158,22 @@ pub trait GeoSeries {
     /// <https://docs.rs/geo/latest/geo/algorithm/simplify/trait.Simplify.html> for details
     fn simplify(&self, tolerance: f64) -> Result<Series>;
 
+    /// Returns a GeoSeries with each (possibly multi-part, possibly holed) polygon decomposed
+    /// into a `MultiPolygon` of triangles, via ear-clipping with hole bridging. Suitable for
+    /// rendering, area-weighted sampling, or mesh generation. Non-polygonal rows come back as an
+    /// empty `MultiPolygon`.
+    fn triangulate(&self) -> Result<Series>;
+
+    /// Like [`GeoSeries::triangulate`], but returns the triangle mesh directly as a
+    /// `(vertices, indices)` pair of per-row list columns instead of a reconstructed
+    /// `MultiPolygon`, so a GPU/mesh-rendering caller can index into the vertex buffer rather
+    /// than re-deriving triangle adjacency from polygon rings.
+    ///
+    /// `vertices` holds one list of `{x, y}` structs per input row; `indices` holds one list of
+    /// `i64`s per row, grouped in threes, each triple indexing into that row's own `vertices`
+    /// list. Non-polygonal rows come back with empty lists.
+    fn triangulate_indices(&self) -> Result<(Series, Series)>;
+
     /// Returns a GeoSeries with each of the geometries skewed by a fixed x and y amount around a
     /// given origin
     ///
@@ -138,12 +197,103 @@ pub trait GeoSeries {
     /// ```
     fn skew(&self, xs: f64, ys: f64, origin: Placeholder) -> Result<Series>;
 
-    /// Returns a Series containing the distance to aligned other. Distance is cartesian distance in 2D space, and the units of the output are in terms of the CRS of the two input series. The operation works on a 1-to-1 row-wise manner.
+    /// Returns a Series containing the distance to aligned other, under the given metric space.
+    /// The operation works on a 1-to-1 row-wise manner. `Euclidean` distance is valid for any
+    /// pair of geometries; `Haversine` and `Geodesic` are only defined between two `Point`s and
+    /// emit null for any other pairing.
     ///
     /// # Arguments
     ///
     /// * `other` - The Geoseries (elementwise) to find the distance to.
-    fn distance(&self, other: &Series) -> Result<Series>;
+    /// * `method` - The metric space to compute distance in.
+    fn distance(&self, other: &Series, method: DistanceMethod) -> Result<Series>;
+
+    /// Returns a Series containing the row-wise discrete Fréchet distance between aligned
+    /// geometries, a curve-similarity measure suited to comparing trajectories: it accounts for
+    /// the order in which vertices are traversed, unlike [`GeoSeries::hausdorff_distance`].
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The Geoseries (elementwise) to compare against.
+    fn frechet_distance(&self, other: &Series) -> Result<Series>;
+
+    /// Returns a Series containing the row-wise Hausdorff distance between aligned geometries:
+    /// the greatest of all the distances from a vertex in one geometry to its closest vertex in
+    /// the other, in either direction. Useful for shape matching where vertex order doesn't
+    /// matter.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The Geoseries (elementwise) to compare against.
+    fn hausdorff_distance(&self, other: &Series) -> Result<Series>;
+
+    /// Returns a boolean Series with value True for each row where the geometry in this
+    /// GeoSeries intersects the aligned geometry in `other` (they share at least one point).
+    fn intersects(&self, other: &Series) -> Result<Series>;
+
+    /// Returns a boolean Series with value True for each row where the geometry in this
+    /// GeoSeries contains the aligned geometry in `other`.
+    fn contains(&self, other: &Series) -> Result<Series>;
+
+    /// Returns a boolean Series with value True for each row where the geometry in this
+    /// GeoSeries is within the aligned geometry in `other`.
+    fn within(&self, other: &Series) -> Result<Series>;
+
+    /// Returns a boolean Series with value True for each row where the geometry in this
+    /// GeoSeries is disjoint from (shares no points with) the aligned geometry in `other`.
+    fn disjoint(&self, other: &Series) -> Result<Series>;
+
+    /// Returns a boolean Series with value True for each row where `query` intersects the
+    /// geometry in this GeoSeries.
+    ///
+    /// Unlike [`Self::intersects`], `query` is the same scalar geometry for every row: it is
+    /// prepared once via `geos::Geometry::to_prepared_geom`, which builds an internal spatial
+    /// index over it, so testing it against a large GeoSeries is far cheaper than one unprepared
+    /// pairwise call per row. Backed by GEOS.
+    #[cfg(feature = "geos")]
+    fn intersects_scalar(&self, query: &Geometry) -> Result<Series>;
+
+    /// Returns a boolean Series with value True for each row where the geometry in this
+    /// GeoSeries touches the aligned geometry in `other` (they share a boundary point but no
+    /// interior points). Backed by GEOS; `geo` has no native equivalent.
+    #[cfg(feature = "geos")]
+    fn touches(&self, other: &Series) -> Result<Series>;
+
+    /// Returns a boolean Series with value True for each row where the geometry in this
+    /// GeoSeries covers the aligned geometry in `other` (like [`Self::contains`], but also true
+    /// when `other` lies entirely on this geometry's boundary). Backed by GEOS.
+    #[cfg(feature = "geos")]
+    fn covers(&self, other: &Series) -> Result<Series>;
+
+    /// Returns a boolean Series with value True for each row where the geometry in this
+    /// GeoSeries crosses the aligned geometry in `other`. Backed by GEOS.
+    #[cfg(feature = "geos")]
+    fn crosses(&self, other: &Series) -> Result<Series>;
+
+    /// Returns a boolean Series with value True for each row where the geometry in this
+    /// GeoSeries overlaps the aligned geometry in `other`. Backed by GEOS.
+    #[cfg(feature = "geos")]
+    fn overlaps(&self, other: &Series) -> Result<Series>;
+
+    /// Returns a geometry Series with, for each row, the intersection of this GeoSeries'
+    /// geometry and the aligned geometry in `other`. Backed by GEOS.
+    #[cfg(feature = "geos")]
+    fn intersection(&self, other: &Series) -> Result<Series>;
+
+    /// Returns a geometry Series with, for each row, the union of this GeoSeries' geometry and
+    /// the aligned geometry in `other`. Backed by GEOS.
+    #[cfg(feature = "geos")]
+    fn union(&self, other: &Series) -> Result<Series>;
+
+    /// Returns a geometry Series with, for each row, the part of this GeoSeries' geometry that
+    /// does not intersect the aligned geometry in `other`. Backed by GEOS.
+    #[cfg(feature = "geos")]
+    fn difference(&self, other: &Series) -> Result<Series>;
+
+    /// Returns a geometry Series with, for each row, the parts of this GeoSeries' geometry and
+    /// the aligned geometry in `other` that do not intersect each other. Backed by GEOS.
+    #[cfg(feature = "geos")]
+    fn symmetric_difference(&self, other: &Series) -> Result<Series>;
 
     // Note: Ideally we wouldn't have both `from` and `to` here, where the series would include the
     // current CRS, but that would require polars to support extension types.
@@ -178,6 +328,71 @@ pub trait GeoSeries {
 
     /// Return the y location of point geometries in a GeoSeries
     fn y(&self) -> Result<Series>;
+
+    /// Parse a Series of WKT strings into a GeoSeries.
+    ///
+    /// Each value is parsed with the `wkt` crate into a `geo::Geometry` and stored using the
+    /// same internal representation as every other GeoSeries.
+    fn from_wkt(series: &Series) -> Result<Series>;
+
+    /// Returns a Series of WKT strings, one for each geometry in the GeoSeries.
+    fn to_wkt(&self) -> Result<Series>;
+
+    /// Parse a Series of Extended WKB (the PostGIS variant of WKB with an embedded SRID) binary
+    /// values into a GeoSeries.
+    ///
+    /// Each geometry is re-encoded using the same internal (plain WKB) representation as every
+    /// other GeoSeries; the SRID isn't attached to the returned Series, since a WKB-backed Series
+    /// has no slot to carry it.
+    fn from_ewkb(series: &Series) -> Result<Series>;
+
+    /// Returns a Series of Extended WKB (the PostGIS variant of WKB with an embedded SRID)
+    /// binary values, one for each geometry in the GeoSeries, stamped with `srid`.
+    ///
+    /// The counterpart to [`GeoSeries::from_ewkb`].
+    fn to_ewkb(&self, srid: i32) -> Result<Series>;
+
+    /// Ingests any geozero-readable geometry source (GeoJSON, WKB/EWKB, FlatGeobuf, ...) into a
+    /// GeoSeries, without requiring the caller to pre-sort by geometry type or materialize a
+    /// `Vec<geo::Geometry>` themselves first.
+    fn from_geozero<T: geozero::GeozeroGeometry>(source: &T) -> Result<Series>;
+
+    /// Returns a GeoSeries of points, one per polygon, giving the pole of inaccessibility (the
+    /// point farthest from any edge) computed via the polylabel algorithm.
+    ///
+    /// Unlike [`GeoSeries::centroid`], the returned point is always inside its polygon, which
+    /// matters for label placement on concave polygons. For a `MultiPolygon`, each part is run
+    /// independently and the point farthest from its own part's boundary wins. Other geometry
+    /// types are null.
+    ///
+    /// Equivalent to [`GeoSeries::representative_point_with_tolerance`] with a tolerance of
+    /// `1.0`.
+    fn representative_point(&self) -> Result<Series>;
+
+    /// Like [`GeoSeries::representative_point`], but lets the caller trade accuracy for speed.
+    ///
+    /// `tolerance` bounds how far the returned point may be from the true pole of
+    /// inaccessibility; smaller values subdivide the search grid further and cost more to
+    /// compute.
+    fn representative_point_with_tolerance(&self, tolerance: f64) -> Result<Series>;
+
+    /// Spatially joins this GeoSeries against `other`, returning the `(self_index, other_index)`
+    /// pairs for which `predicate` holds.
+    ///
+    /// Builds an R-tree over `other` and queries it with each of this series's geometries, so
+    /// the cost is roughly linear in `self.len()` rather than `self.len() * other.len()`.
+    fn sjoin(&self, other: &Series, predicate: SpatialPredicate) -> Result<Vec<(usize, usize)>>;
+
+    /// For each geometry in this GeoSeries, returns the index and distance of the closest
+    /// geometry in `other`, or `None` for a row if `other` is empty.
+    fn nearest(&self, other: &Series) -> Result<Vec<Option<(usize, f64)>>>;
+
+    /// Like [`GeoSeries::representative_point`], but computed directly off the native
+    /// GeoArrow coordinate buffers (via [`geopolars_arrow::algorithm::label_point`]) instead of
+    /// decoding each row's WKB, for GeoSeries already backed by a typed polygon array.
+    ///
+    /// Non-polygon rows are null. Uses a search tolerance of `1.0`.
+    fn label_point(&self) -> Result<Series>;
 }
 
 impl GeoSeries for Series {
@@ -185,10 +400,18 @@ impl GeoSeries for Series {
         todo!()
     }
 
+    fn map_coords(&self, f: impl Fn(f64, f64) -> (f64, f64) + Copy) -> Result<Series> {
+        crate::ops::affine::map_coords_series(self, f)
+    }
+
     fn area(&self) -> Result<Series> {
         todo!()
     }
 
+    fn area_geodesic(&self) -> Result<Series> {
+        crate::ops::area::area_geodesic(self)
+    }
+
     fn centroid(&self) -> Result<Series> {
         todo!()
     }
@@ -197,6 +420,19 @@ impl GeoSeries for Series {
         todo!()
     }
 
+    fn unary_union_convex_hull(&self) -> Result<Series> {
+        crate::ops::convex_hull::unary_union_convex_hull(self)
+    }
+
+    fn concave_hull(&self, concavity: f64) -> Result<Series> {
+        crate::ops::convex_hull::concave_hull_series(self, concavity)
+    }
+
+    #[cfg(feature = "geos")]
+    fn buffer(&self, distance: f64, params: BufferParams) -> Result<Series> {
+        crate::ops::geos_buffer::buffer(self, distance, params)
+    }
+
     fn envelope(&self) -> Result<Series> {
         todo!()
     }
@@ -213,8 +449,8 @@ impl GeoSeries for Series {
         todo!()
     }
 
-    fn geodesic_length(&self, _method: Placeholder) -> Result<Series> {
-        todo!()
+    fn geodesic_length(&self, method: DistanceMethod) -> Result<Series> {
+        crate::ops::distance::geodesic_length(self, &method)
     }
 
     fn geom_type(&self) -> Result<Series> {
@@ -241,13 +477,89 @@ impl GeoSeries for Series {
         todo!()
     }
 
+    fn triangulate(&self) -> Result<Series> {
+        crate::ops::triangulate::triangulate(self)
+    }
+
+    fn triangulate_indices(&self) -> Result<(Series, Series)> {
+        crate::ops::triangulate::triangulate_indices(self)
+    }
+
     fn skew(&self, _xs: f64, _ys: f64, _origin: Placeholder) -> Result<Series> {
         todo!()
     }
 
-    fn distance(&self, _other: &Series) -> Result<Series> {
-        todo!()
-        // crate::ops::distance::euclidean_distance(self, other)
+    fn distance(&self, other: &Series, method: DistanceMethod) -> Result<Series> {
+        crate::ops::distance::distance(self, other, method)
+    }
+
+    fn frechet_distance(&self, other: &Series) -> Result<Series> {
+        crate::ops::similarity::frechet_distance(self, other)
+    }
+
+    fn hausdorff_distance(&self, other: &Series) -> Result<Series> {
+        crate::ops::similarity::hausdorff_distance(self, other)
+    }
+
+    fn intersects(&self, other: &Series) -> Result<Series> {
+        crate::ops::predicate::intersects_series(self, other)
+    }
+
+    fn contains(&self, other: &Series) -> Result<Series> {
+        crate::ops::predicate::contains_series(self, other)
+    }
+
+    fn within(&self, other: &Series) -> Result<Series> {
+        crate::ops::predicate::within_series(self, other)
+    }
+
+    fn disjoint(&self, other: &Series) -> Result<Series> {
+        crate::ops::predicate::disjoint_series(self, other)
+    }
+
+    #[cfg(feature = "geos")]
+    fn intersects_scalar(&self, query: &Geometry) -> Result<Series> {
+        crate::ops::geos_predicate::intersects_scalar(self, query)
+    }
+
+    #[cfg(feature = "geos")]
+    fn touches(&self, other: &Series) -> Result<Series> {
+        crate::ops::geos_predicate::touches(self, other)
+    }
+
+    #[cfg(feature = "geos")]
+    fn covers(&self, other: &Series) -> Result<Series> {
+        crate::ops::geos_predicate::covers(self, other)
+    }
+
+    #[cfg(feature = "geos")]
+    fn crosses(&self, other: &Series) -> Result<Series> {
+        crate::ops::geos_predicate::crosses(self, other)
+    }
+
+    #[cfg(feature = "geos")]
+    fn overlaps(&self, other: &Series) -> Result<Series> {
+        crate::ops::geos_predicate::overlaps(self, other)
+    }
+
+    #[cfg(feature = "geos")]
+    fn intersection(&self, other: &Series) -> Result<Series> {
+        crate::ops::geos_predicate::intersection(self, other)
+    }
+
+    #[cfg(feature = "geos")]
+    fn union(&self, other: &Series) -> Result<Series> {
+        crate::ops::geos_predicate::union(self, other)
+    }
+
+    #[cfg(feature = "geos")]
+    fn difference(&self, other: &Series) -> Result<Series> {
+        crate::ops::geos_predicate::difference(self, other)
+    }
+
+    #[cfg(feature = "geos")]
+    fn symmetric_difference(&self, other: &Series) -> Result<Series> {
+        crate::ops::geos_predicate::symmetric_difference(self, other)
     }
 
     #[cfg(feature = "proj")]
@@ -276,4 +588,44 @@ impl GeoSeries for Series {
     fn y(&self) -> Result<Series> {
         todo!()
     }
+
+    fn from_wkt(series: &Series) -> Result<Series> {
+        crate::ops::wkt::from_wkt(series)
+    }
+
+    fn to_wkt(&self) -> Result<Series> {
+        crate::ops::wkt::to_wkt(self)
+    }
+
+    fn from_ewkb(series: &Series) -> Result<Series> {
+        crate::ops::ewkb::from_ewkb(series)
+    }
+
+    fn to_ewkb(&self, srid: i32) -> Result<Series> {
+        crate::ops::ewkb::to_ewkb(self, srid)
+    }
+
+    fn from_geozero<T: geozero::GeozeroGeometry>(source: &T) -> Result<Series> {
+        crate::ops::geozero::from_geozero(source)
+    }
+
+    fn representative_point(&self) -> Result<Series> {
+        crate::ops::polylabel::representative_point(self)
+    }
+
+    fn representative_point_with_tolerance(&self, tolerance: f64) -> Result<Series> {
+        crate::ops::polylabel::representative_point_with_tolerance(self, tolerance)
+    }
+
+    fn sjoin(&self, other: &Series, predicate: SpatialPredicate) -> Result<Vec<(usize, usize)>> {
+        crate::ops::sjoin::sjoin(self, other, predicate)
+    }
+
+    fn nearest(&self, other: &Series) -> Result<Vec<Option<(usize, f64)>>> {
+        crate::ops::sjoin::nearest(self, other)
+    }
+
+    fn label_point(&self) -> Result<Series> {
+        crate::ops::label_point::label_point_series(self)
+    }
 }
@@ -0,0 +1,196 @@
+use crate::error::Result;
+use crate::util::{from_geom_vec, iter_geom};
+use geo::map_coords::MapCoords;
+use geo::{coord, Geometry};
+use geopolars_arrow::algorithm::affine::{
+    map_coords_linestring, map_coords_multilinestring, map_coords_multipoint,
+    map_coords_multipolygon, map_coords_point, map_coords_polygon,
+};
+use geopolars_arrow::GeometryArray;
+use polars::prelude::Series;
+
+/// Applies `f` to every coordinate in `array`, same signature as georust's own `map_coords`
+/// (`f` taken by value as `impl Fn + Copy` so it inlines).
+///
+/// Typed arrays (`Point`, `LineString`, `Polygon` and their `Multi*` counterparts) are mapped
+/// coordinate-by-coordinate directly off their native buffers via
+/// [`geopolars_arrow::algorithm::affine`], without decoding through WKB; `WKB` rows are decoded
+/// and mapped via `geo`'s own [`MapCoords`].
+pub(crate) fn map_coords(
+    array: GeometryArray,
+    f: impl Fn(f64, f64) -> (f64, f64) + Copy,
+) -> Result<GeometryArray> {
+    match array {
+        GeometryArray::WKB(arr) => {
+            let output_geoms: Vec<Option<Geometry>> = arr
+                .iter_geo()
+                .map(|maybe_g| maybe_g.map(|geom| transform_geometry(geom, f)))
+                .collect();
+
+            Ok(GeometryArray::WKB(output_geoms.into()))
+        }
+        GeometryArray::Point(arr) => {
+            let output_geoms: Vec<Option<geo::Point>> = arr
+                .iter()
+                .map(|maybe_g| maybe_g.map(|g| map_coords_point(&g, f)))
+                .collect();
+
+            Ok(GeometryArray::Point(output_geoms.into()))
+        }
+        GeometryArray::MultiPoint(arr) => {
+            let output_geoms: Vec<Option<geo::MultiPoint>> = arr
+                .iter()
+                .map(|maybe_g| maybe_g.map(|g| map_coords_multipoint(&g, f)))
+                .collect();
+
+            Ok(GeometryArray::MultiPoint(output_geoms.into()))
+        }
+        GeometryArray::LineString(arr) => {
+            let output_geoms: Vec<Option<geo::LineString>> = arr
+                .iter()
+                .map(|maybe_g| maybe_g.map(|g| map_coords_linestring(&g, f)))
+                .collect();
+
+            Ok(GeometryArray::LineString(output_geoms.into()))
+        }
+        GeometryArray::MultiLineString(arr) => {
+            let output_geoms: Vec<Option<geo::MultiLineString>> = arr
+                .iter()
+                .map(|maybe_g| maybe_g.map(|g| map_coords_multilinestring(&g, f)))
+                .collect();
+
+            Ok(GeometryArray::MultiLineString(output_geoms.into()))
+        }
+        GeometryArray::Polygon(arr) => {
+            let output_geoms: Vec<Option<geo::Polygon>> = arr
+                .iter()
+                .map(|maybe_g| maybe_g.map(|g| map_coords_polygon(&g, f)))
+                .collect();
+
+            Ok(GeometryArray::Polygon(output_geoms.into()))
+        }
+        GeometryArray::MultiPolygon(arr) => {
+            let output_geoms: Vec<Option<geo::MultiPolygon>> = arr
+                .iter()
+                .map(|maybe_g| maybe_g.map(|g| map_coords_multipolygon(&g, f)))
+                .collect();
+
+            Ok(GeometryArray::MultiPolygon(output_geoms.into()))
+        }
+        GeometryArray::GeometryCollection(arr) => {
+            let output_geoms: Vec<Option<Geometry>> = arr
+                .iter_geo()
+                .map(|maybe_g| maybe_g.map(|geom| transform_geometry(geom, f)))
+                .collect();
+
+            Ok(GeometryArray::GeometryCollection(output_geoms.into()))
+        }
+    }
+}
+
+fn transform_geometry(geom: Geometry, f: impl Fn(f64, f64) -> (f64, f64) + Copy) -> Geometry {
+    geom.map_coords(|c| {
+        let (x, y) = f(c.x, c.y);
+        coord! { x: x, y: y }
+    })
+}
+
+/// Series-level entry point for `GeoSeries::map_coords`: applies `f` to every coordinate of
+/// every geometry in the WKB column and re-encodes the result. `translate`/`scale`/`rotate`/
+/// `skew` below go through [`affine_transform`] and the array-level [`map_coords`] instead.
+pub(crate) fn map_coords_series(
+    series: &Series,
+    f: impl Fn(f64, f64) -> (f64, f64) + Copy,
+) -> Result<Series> {
+    let geoms: Vec<Geometry> = iter_geom(series)
+        .map(|geom| transform_geometry(geom, f))
+        .collect();
+
+    from_geom_vec(&geoms)
+}
+
+/// Applies `x' = coeffs[0] * x + coeffs[1] * y + coeffs[2]`, `y' = coeffs[3] * x + coeffs[4] * y
+/// + coeffs[5]` to every coordinate in `array`, in the same `[a, b, xoff, d, e, yoff]` order as
+/// `geo::AffineTransform`'s matrix. Just [`map_coords`] with that formula plugged in.
+pub(crate) fn affine_transform(array: GeometryArray, coeffs: [f64; 6]) -> Result<GeometryArray> {
+    map_coords(array, move |x, y| {
+        (
+            coeffs[0] * x + coeffs[1] * y + coeffs[2],
+            coeffs[3] * x + coeffs[4] * y + coeffs[5],
+        )
+    })
+}
+
+/// Translates every coordinate in `array` by `(xoff, yoff)`.
+pub(crate) fn translate(array: GeometryArray, xoff: f64, yoff: f64) -> Result<GeometryArray> {
+    affine_transform(array, [1.0, 0.0, xoff, 0.0, 1.0, yoff])
+}
+
+/// Scales every coordinate in `array` by `(xfact, yfact)` about the origin `(0, 0)`.
+///
+/// Unlike the top-level `geopolars` crate's WKB-based `scale`, which supports transforming about
+/// an arbitrary origin (a geometry's centroid, its bounding box center, or a fixed point), this
+/// native-buffer variant always scales about the origin; callers wanting to scale about a
+/// geometry's own centroid should translate to and from the origin themselves.
+pub(crate) fn scale(array: GeometryArray, xfact: f64, yfact: f64) -> Result<GeometryArray> {
+    affine_transform(array, [xfact, 0.0, 0.0, 0.0, yfact, 0.0])
+}
+
+/// Rotates every coordinate in `array` counterclockwise by `angle_degrees` about the origin
+/// `(0, 0)`.
+pub(crate) fn rotate(array: GeometryArray, angle_degrees: f64) -> Result<GeometryArray> {
+    let radians = angle_degrees.to_radians();
+    let (sin, cos) = radians.sin_cos();
+    affine_transform(array, [cos, -sin, 0.0, sin, cos, 0.0])
+}
+
+/// Skews every coordinate in `array` by `(xs_degrees, ys_degrees)` about the origin `(0, 0)`.
+pub(crate) fn skew(array: GeometryArray, xs_degrees: f64, ys_degrees: f64) -> Result<GeometryArray> {
+    let tan_x = xs_degrees.to_radians().tan();
+    let tan_y = ys_degrees.to_radians().tan();
+    affine_transform(array, [1.0, tan_x, 0.0, tan_y, 1.0, 0.0])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{rotate, scale, translate};
+    use geo::{point, Geometry};
+    use geopolars_arrow::{GeometryArray, GeometryArrayTrait, PointArray};
+
+    #[test]
+    fn translate_point() {
+        let input_array: PointArray = vec![point! { x: 1.0, y: 2.0 }].into();
+        let result_array = translate(GeometryArray::Point(input_array), 10.0, -5.0).unwrap();
+
+        assert_eq!(
+            Geometry::Point(point! { x: 11.0, y: -3.0 }),
+            result_array.get_as_geo(0).unwrap()
+        );
+    }
+
+    #[test]
+    fn scale_point_about_origin() {
+        let input_array: PointArray = vec![point! { x: 2.0, y: 3.0 }].into();
+        let result_array = scale(GeometryArray::Point(input_array), 2.0, 0.5).unwrap();
+
+        assert_eq!(
+            Geometry::Point(point! { x: 4.0, y: 1.5 }),
+            result_array.get_as_geo(0).unwrap()
+        );
+    }
+
+    #[test]
+    fn rotate_point_90_degrees() {
+        let input_array: PointArray = vec![point! { x: 1.0, y: 0.0 }].into();
+        let result_array = rotate(GeometryArray::Point(input_array), 90.0).unwrap();
+
+        let rotated = result_array.get_as_geo(0).unwrap();
+        match rotated {
+            Geometry::Point(p) => {
+                assert!(p.x().abs() < 1e-10);
+                assert!((p.y() - 1.0).abs() < 1e-10);
+            }
+            other => panic!("expected a point, got {other:?}"),
+        }
+    }
+}
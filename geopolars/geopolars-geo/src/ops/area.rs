@@ -1,7 +1,14 @@
 use crate::error::Result;
+use crate::util::iter_geom;
 use geo::prelude::Area;
+use geo::{Coord, Geometry, LineString};
 use geoarrow::{GeometryArray, GeometryArrayTrait};
-use polars::export::arrow::array::{MutablePrimitiveArray, PrimitiveArray};
+use polars::export::arrow::array::{Array, MutablePrimitiveArray, PrimitiveArray};
+use polars::prelude::Series;
+
+/// Mean Earth radius in meters, matching the value used elsewhere in the crate for Haversine
+/// distance (see [`crate::ops::distance::DistanceMethod::Haversine`]).
+const EARTH_RADIUS_M: f64 = 6_371_008.8;
 
 pub(crate) fn area(array: GeometryArray) -> Result<PrimitiveArray<f64>> {
     let mut output_array = MutablePrimitiveArray::<f64>::with_capacity(array.len());
@@ -35,7 +42,91 @@ pub(crate) fn area(array: GeometryArray) -> Result<PrimitiveArray<f64>> {
             arr.iter_geo()
                 .for_each(|maybe_g| output_array.push(maybe_g.map(|g| g.unsigned_area())));
         }
+        GeometryArray::GeometryCollection(arr) => {
+            arr.iter_geo().for_each(|maybe_g| {
+                output_array.push(maybe_g.map(|g| {
+                    g.0.into_iter()
+                        .map(|geom| geom.unsigned_area())
+                        .sum::<f64>()
+                }))
+            });
+        }
     }
 
     Ok(output_array.into())
 }
+
+/// Unsigned (absolute) spherical area of a single ring via the Chamberlain–Duquette method: sum
+/// `(lambda_{i+1} - lambda_i) * (2 + sin(phi_i) + sin(phi_{i+1}))` over consecutive vertex pairs
+/// (coordinates in radians, ring implicitly closed), scaled by `R^2 / 2`.
+fn ring_geodesic_area(ring: &LineString<f64>) -> f64 {
+    let coords: Vec<Coord<f64>> = ring
+        .0
+        .iter()
+        .map(|c| Coord {
+            x: c.x.to_radians(),
+            y: c.y.to_radians(),
+        })
+        .collect();
+
+    if coords.len() < 3 {
+        return 0.0;
+    }
+
+    let mut sum = 0.0;
+    for i in 0..coords.len() {
+        let next = (i + 1) % coords.len();
+        let (lambda_i, phi_i) = (coords[i].x, coords[i].y);
+        let (lambda_next, phi_next) = (coords[next].x, coords[next].y);
+        sum += (lambda_next - lambda_i) * (2.0 + phi_i.sin() + phi_next.sin());
+    }
+
+    (sum * EARTH_RADIUS_M * EARTH_RADIUS_M / 2.0).abs()
+}
+
+/// Unsigned spherical area of `geom` under the Chamberlain–Duquette method (see
+/// [`ring_geodesic_area`]), in square meters assuming WGS84 longitude/latitude coordinates.
+///
+/// Polygon area is the exterior ring's area minus its interior rings'; MultiPolygon area sums
+/// across parts. Points, lines and their multi-part variants have no area and are `0.0`;
+/// GeometryCollections aren't supported and are `None`.
+fn geometry_geodesic_area(geom: &Geometry<f64>) -> Option<f64> {
+    match geom {
+        Geometry::Point(_) | Geometry::MultiPoint(_) => Some(0.0),
+        Geometry::Line(_) | Geometry::LineString(_) | Geometry::MultiLineString(_) => Some(0.0),
+        Geometry::Polygon(poly) => {
+            let exterior = ring_geodesic_area(poly.exterior());
+            let interior: f64 = poly.interiors().iter().map(ring_geodesic_area).sum();
+            Some(exterior - interior)
+        }
+        Geometry::MultiPolygon(multi_poly) => Some(
+            multi_poly
+                .iter()
+                .map(|poly| {
+                    let exterior = ring_geodesic_area(poly.exterior());
+                    let interior: f64 = poly.interiors().iter().map(ring_geodesic_area).sum();
+                    exterior - interior
+                })
+                .sum(),
+        ),
+        Geometry::GeometryCollection(_) => None,
+        _ => None,
+    }
+}
+
+/// Compute the geodesic (Chamberlain–Duquette) area of every geometry in `series`, in square
+/// meters assuming WGS84 longitude/latitude coordinates.
+///
+/// Unlike [`crate::geoseries::GeoSeries::area`], which is planar and only meaningful in a
+/// projected CRS, this gives correct square-meter results directly for lon/lat data.
+pub(crate) fn area_geodesic(series: &Series) -> Result<Series> {
+    let mut output = MutablePrimitiveArray::<f64>::with_capacity(series.len());
+
+    for geom in iter_geom(series) {
+        output.push(geometry_geodesic_area(&geom));
+    }
+
+    let array: PrimitiveArray<f64> = output.into();
+    let series = Series::try_from(("area", Box::new(array) as Box<dyn Array>))?;
+    Ok(series)
+}
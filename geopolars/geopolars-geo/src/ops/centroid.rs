@@ -35,6 +35,10 @@ pub(crate) fn centroid(array: GeometryArray) -> Result<PointArray> {
             arr.iter_geo()
                 .for_each(|maybe_g| output_array.push_geo(maybe_g.and_then(|g| g.centroid())));
         }
+        GeometryArray::Mixed(arr) => {
+            arr.iter_geo()
+                .for_each(|maybe_g| output_array.push_geo(maybe_g.and_then(|g| g.centroid())));
+        }
     }
 
     Ok(output_array.into())
@@ -1,7 +1,312 @@
 use crate::error::Result;
+use crate::util::{from_geom_vec, from_geom_vec_opt, iter_geom};
 use geo::algorithm::convex_hull::ConvexHull;
-use geo::Polygon;
+use geo::{Coord, Geometry, LineString, Polygon};
+use geopolars_arrow::algorithm::convex_hull::{
+    convex_hull_linestring, convex_hull_multilinestring, convex_hull_multipoint,
+    convex_hull_multipolygon, convex_hull_point, convex_hull_polygon,
+};
 use geopolars_arrow::GeometryArray;
+use polars::prelude::Series;
+
+/// Returns the single convex hull enclosing every coordinate across every geometry in the
+/// series, accepting any geometry type (including bare points).
+///
+/// Implemented directly with Andrew's monotone chain algorithm rather than going through
+/// `geo`'s per-row `ConvexHull` impl, since that rejects `Point`/`GeometryCollection` and we'd
+/// otherwise pay the overhead of unioning every row's hull together.
+pub(crate) fn unary_union_convex_hull(series: &Series) -> Result<Series> {
+    let mut coords: Vec<Coord> = iter_geom(series)
+        .flat_map(|geom| geom_coords(&geom))
+        .collect();
+
+    let hull = monotone_chain_hull(&mut coords);
+    from_geom_vec(&[hull])
+}
+
+fn geom_coords(geom: &Geometry) -> Vec<Coord> {
+    use geo::CoordsIter;
+    geom.coords_iter().collect()
+}
+
+/// Andrew's monotone chain convex hull algorithm.
+///
+/// Sorts all points lexicographically by (x, y), then sweeps once left-to-right to build the
+/// lower hull and once right-to-left to build the upper hull. At each step, while the last two
+/// hull points plus the candidate make a non-counterclockwise turn (cross product
+/// `(p1-p0) x (p2-p0) <= 0`) the last hull point is popped; this also drops collinear points.
+/// The two chains are then concatenated (dropping the duplicated endpoints) into a closed,
+/// counterclockwise ring.
+fn monotone_chain_hull(coords: &mut [Coord]) -> Geometry {
+    coords.sort_by(|a, b| (a.x, a.y).partial_cmp(&(b.x, b.y)).unwrap());
+    coords.dedup_by(|a, b| (a.x, a.y) == (b.x, b.y));
+
+    if coords.len() < 3 {
+        return match coords.len() {
+            1 => Geometry::Point(coords[0].into()),
+            _ => Geometry::LineString(LineString::new(coords.to_vec())),
+        };
+    }
+
+    fn cross(o: Coord, a: Coord, b: Coord) -> f64 {
+        (a.x - o.x) * (b.y - o.y) - (a.y - o.y) * (b.x - o.x)
+    }
+
+    let mut lower: Vec<Coord> = Vec::with_capacity(coords.len());
+    for &p in coords.iter() {
+        while lower.len() >= 2
+            && cross(lower[lower.len() - 2], lower[lower.len() - 1], p) <= 0.0
+        {
+            lower.pop();
+        }
+        lower.push(p);
+    }
+
+    let mut upper: Vec<Coord> = Vec::with_capacity(coords.len());
+    for &p in coords.iter().rev() {
+        while upper.len() >= 2
+            && cross(upper[upper.len() - 2], upper[upper.len() - 1], p) <= 0.0
+        {
+            upper.pop();
+        }
+        upper.push(p);
+    }
+
+    lower.pop();
+    upper.pop();
+    lower.extend(upper);
+    lower.push(lower[0]);
+
+    Geometry::Polygon(Polygon::new(LineString::new(lower), vec![]))
+}
+
+/// Builds the convex hull of already sorted, deduplicated `coords` as a closed ring (first ==
+/// last coordinate).
+///
+/// Unlike [`monotone_chain_hull`], this never collapses a degenerate (fewer than 3 point) input
+/// down to a bare `Point`/`LineString`, since [`concave_hull_of_coords`] always needs a ring to
+/// dig into.
+fn convex_hull_ring(coords: &[Coord]) -> Vec<Coord> {
+    fn cross(o: Coord, a: Coord, b: Coord) -> f64 {
+        (a.x - o.x) * (b.y - o.y) - (a.y - o.y) * (b.x - o.x)
+    }
+
+    if coords.len() < 3 {
+        let mut ring = coords.to_vec();
+        if let Some(&first) = ring.first() {
+            ring.push(first);
+        }
+        return ring;
+    }
+
+    let mut lower: Vec<Coord> = Vec::with_capacity(coords.len());
+    for &p in coords {
+        while lower.len() >= 2 && cross(lower[lower.len() - 2], lower[lower.len() - 1], p) <= 0.0 {
+            lower.pop();
+        }
+        lower.push(p);
+    }
+
+    let mut upper: Vec<Coord> = Vec::with_capacity(coords.len());
+    for &p in coords.iter().rev() {
+        while upper.len() >= 2 && cross(upper[upper.len() - 2], upper[upper.len() - 1], p) <= 0.0 {
+            upper.pop();
+        }
+        upper.push(p);
+    }
+
+    lower.pop();
+    upper.pop();
+    lower.extend(upper);
+    lower.push(lower[0]);
+    lower
+}
+
+fn dist(a: Coord, b: Coord) -> f64 {
+    ((a.x - b.x).powi(2) + (a.y - b.y).powi(2)).sqrt()
+}
+
+/// Shortest distance from `p` to the segment `a`-`b`.
+fn point_segment_distance(p: Coord, a: Coord, b: Coord) -> f64 {
+    let (dx, dy) = (b.x - a.x, b.y - a.y);
+    let len_sq = dx * dx + dy * dy;
+    if len_sq == 0.0 {
+        return dist(p, a);
+    }
+
+    let t = (((p.x - a.x) * dx + (p.y - a.y) * dy) / len_sq).clamp(0.0, 1.0);
+    let proj = Coord {
+        x: a.x + t * dx,
+        y: a.y + t * dy,
+    };
+    dist(p, proj)
+}
+
+/// The closest of `points` to the segment `a`-`b`, as `(index into points, point, distance)`.
+fn nearest_to_segment(points: &[Coord], a: Coord, b: Coord) -> Option<(usize, Coord, f64)> {
+    points
+        .iter()
+        .enumerate()
+        .map(|(i, &p)| (i, p, point_segment_distance(p, a, b)))
+        .min_by(|(_, _, d1), (_, _, d2)| d1.partial_cmp(d2).unwrap())
+}
+
+fn segments_intersect(p1: Coord, p2: Coord, p3: Coord, p4: Coord) -> bool {
+    fn orientation(a: Coord, b: Coord, c: Coord) -> f64 {
+        (b.x - a.x) * (c.y - a.y) - (b.y - a.y) * (c.x - a.x)
+    }
+    fn on_segment(a: Coord, b: Coord, c: Coord) -> bool {
+        c.x >= a.x.min(b.x) && c.x <= a.x.max(b.x) && c.y >= a.y.min(b.y) && c.y <= a.y.max(b.y)
+    }
+
+    let d1 = orientation(p3, p4, p1);
+    let d2 = orientation(p3, p4, p2);
+    let d3 = orientation(p1, p2, p3);
+    let d4 = orientation(p1, p2, p4);
+
+    if ((d1 > 0.0 && d2 < 0.0) || (d1 < 0.0 && d2 > 0.0))
+        && ((d3 > 0.0 && d4 < 0.0) || (d3 < 0.0 && d4 > 0.0))
+    {
+        return true;
+    }
+
+    (d1 == 0.0 && on_segment(p3, p4, p1))
+        || (d2 == 0.0 && on_segment(p3, p4, p2))
+        || (d3 == 0.0 && on_segment(p1, p2, p3))
+        || (d4 == 0.0 && on_segment(p1, p2, p4))
+}
+
+/// Would replacing the edge at `hull[edge_idx]..hull[edge_idx + 1]` with a detour through
+/// `point` cross any of the hull's other edges?
+///
+/// Edges adjacent to the one being split are skipped, since they legitimately share an
+/// endpoint with the new detour rather than crossing it.
+fn would_self_intersect(hull: &[Coord], edge_idx: usize, point: Coord) -> bool {
+    let n_edges = hull.len() - 1;
+    let a = hull[edge_idx];
+    let b = hull[edge_idx + 1];
+    let prev_edge = (edge_idx + n_edges - 1) % n_edges;
+    let next_edge = (edge_idx + 1) % n_edges;
+
+    (0..n_edges)
+        .filter(|&i| i != edge_idx && i != prev_edge && i != next_edge)
+        .any(|i| {
+            let c = hull[i];
+            let d = hull[i + 1];
+            segments_intersect(a, point, c, d) || segments_intersect(point, b, c, d)
+        })
+}
+
+/// Digs candidate points from `interior` into `hull` edge by edge until no edge's
+/// `edge_length / distance_to_nearest_point` ratio still exceeds `concavity`.
+fn dig_in(hull: &mut Vec<Coord>, interior: &mut Vec<Coord>, concavity: f64) {
+    let mut edge_idx = 0;
+    while edge_idx + 1 < hull.len() {
+        let a = hull[edge_idx];
+        let b = hull[edge_idx + 1];
+        let edge_length = dist(a, b);
+
+        let candidate = nearest_to_segment(interior, a, b)
+            .filter(|&(_, _, distance)| distance > 0.0 && edge_length / distance > concavity)
+            .filter(|&(_, point, _)| !would_self_intersect(hull, edge_idx, point));
+
+        match candidate {
+            Some((idx, point, _)) => {
+                hull.insert(edge_idx + 1, point);
+                interior.remove(idx);
+            }
+            None => edge_idx += 1,
+        }
+    }
+}
+
+fn concave_hull_of_coords(coords: &mut [Coord], concavity: f64) -> Polygon {
+    coords.sort_by(|a, b| (a.x, a.y).partial_cmp(&(b.x, b.y)).unwrap());
+
+    let mut hull = convex_hull_ring(coords);
+    if hull.len() < 4 {
+        return Polygon::new(LineString::new(hull), vec![]);
+    }
+
+    let on_hull: std::collections::HashSet<(u64, u64)> =
+        hull.iter().map(|c| (c.x.to_bits(), c.y.to_bits())).collect();
+    let mut interior: Vec<Coord> = coords
+        .iter()
+        .copied()
+        .filter(|c| !on_hull.contains(&(c.x.to_bits(), c.y.to_bits())))
+        .collect();
+
+    dig_in(&mut hull, &mut interior, concavity);
+
+    Polygon::new(LineString::new(hull), vec![])
+}
+
+fn concave_hull_geoms<T: geo::CoordsIter>(
+    iter: impl Iterator<Item = Option<T>>,
+    concavity: f64,
+) -> Vec<Option<Polygon>> {
+    iter.map(|maybe_g| {
+        maybe_g.map(|geom| {
+            let mut coords: Vec<Coord> = geom.coords_iter().collect();
+            concave_hull_of_coords(&mut coords, concavity)
+        })
+    })
+    .collect()
+}
+
+/// Returns a tighter-fitting, non-convex hull for every geometry in `series`, flattening each
+/// row's own coordinates (e.g. a MultiPoint/MultiPolygon's points) before hulling.
+///
+/// Starts from the convex hull and repeatedly "digs in": for each hull edge, the nearest
+/// not-yet-included point is pulled onto the boundary (replacing the edge with two edges routed
+/// through it) as long as doing so wouldn't self-intersect and the edge is "loose" enough,
+/// i.e. `edge_length / distance_to_point > concavity`. Larger `concavity` values are more
+/// conservative, yielding a hull closer to the convex one; smaller values dig in more
+/// aggressively.
+/// Series-level entry point for `GeoSeries::concave_hull`: hulls each row's own coordinates
+/// directly via [`concave_hull_of_coords`] instead of going through the `GeometryArray` dispatch
+/// below, since there's no generic `Series` <-> `GeometryArray` conversion in this crate yet -
+/// every other Series-only operation in this module (e.g. [`unary_union_convex_hull`]) takes the
+/// same direct `iter_geom`/`from_geom_vec_opt` route.
+pub(crate) fn concave_hull_series(series: &Series, concavity: f64) -> Result<Series> {
+    let hulls: Vec<Option<Geometry>> = iter_geom(series)
+        .map(|geom| {
+            let mut coords: Vec<Coord> = geo::CoordsIter::coords_iter(&geom).collect();
+            Some(Geometry::Polygon(concave_hull_of_coords(&mut coords, concavity)))
+        })
+        .collect();
+
+    from_geom_vec_opt(&hulls)
+}
+
+pub(crate) fn concave_hull(array: GeometryArray, concavity: f64) -> Result<GeometryArray> {
+    match array {
+        GeometryArray::WKB(arr) => {
+            Ok(GeometryArray::Polygon(concave_hull_geoms(arr.iter_geo(), concavity).into()))
+        }
+        GeometryArray::Point(arr) => {
+            Ok(GeometryArray::Polygon(concave_hull_geoms(arr.iter_geo(), concavity).into()))
+        }
+        GeometryArray::MultiPoint(arr) => {
+            Ok(GeometryArray::Polygon(concave_hull_geoms(arr.iter_geo(), concavity).into()))
+        }
+        GeometryArray::LineString(arr) => {
+            Ok(GeometryArray::Polygon(concave_hull_geoms(arr.iter_geo(), concavity).into()))
+        }
+        GeometryArray::MultiLineString(arr) => {
+            Ok(GeometryArray::Polygon(concave_hull_geoms(arr.iter_geo(), concavity).into()))
+        }
+        GeometryArray::Polygon(arr) => {
+            Ok(GeometryArray::Polygon(concave_hull_geoms(arr.iter_geo(), concavity).into()))
+        }
+        GeometryArray::MultiPolygon(arr) => {
+            Ok(GeometryArray::Polygon(concave_hull_geoms(arr.iter_geo(), concavity).into()))
+        }
+        GeometryArray::GeometryCollection(arr) => {
+            Ok(GeometryArray::Polygon(concave_hull_geoms(arr.iter_geo(), concavity).into()))
+        }
+    }
+}
 
 pub(crate) fn convex_hull(array: GeometryArray) -> Result<GeometryArray> {
     match array {
@@ -15,8 +320,8 @@ pub(crate) fn convex_hull(array: GeometryArray) -> Result<GeometryArray> {
         }
         GeometryArray::Point(arr) => {
             let output_geoms: Vec<Option<Polygon>> = arr
-                .iter_geo()
-                .map(|maybe_g| maybe_g.map(|geom| geom.convex_hull()))
+                .iter()
+                .map(|maybe_g| maybe_g.map(|g| convex_hull_point(&g)))
                 .collect();
 
             Ok(GeometryArray::Polygon(output_geoms.into()))
@@ -24,40 +329,61 @@ pub(crate) fn convex_hull(array: GeometryArray) -> Result<GeometryArray> {
 
         GeometryArray::MultiPoint(arr) => {
             let output_geoms: Vec<Option<Polygon>> = arr
-                .iter_geo()
-                .map(|maybe_g| maybe_g.map(|geom| geom.convex_hull()))
+                .iter()
+                .map(|maybe_g| maybe_g.map(|g| convex_hull_multipoint(&g)))
                 .collect();
 
             Ok(GeometryArray::Polygon(output_geoms.into()))
         }
         GeometryArray::LineString(arr) => {
             let output_geoms: Vec<Option<Polygon>> = arr
-                .iter_geo()
-                .map(|maybe_g| maybe_g.map(|geom| geom.convex_hull()))
+                .iter()
+                .map(|maybe_g| maybe_g.map(|g| convex_hull_linestring(&g)))
                 .collect();
 
             Ok(GeometryArray::Polygon(output_geoms.into()))
         }
         GeometryArray::MultiLineString(arr) => {
             let output_geoms: Vec<Option<Polygon>> = arr
-                .iter_geo()
-                .map(|maybe_g| maybe_g.map(|geom| geom.convex_hull()))
+                .iter()
+                .map(|maybe_g| maybe_g.map(|g| convex_hull_multilinestring(&g)))
                 .collect();
 
             Ok(GeometryArray::Polygon(output_geoms.into()))
         }
         GeometryArray::Polygon(arr) => {
             let output_geoms: Vec<Option<Polygon>> = arr
-                .iter_geo()
-                .map(|maybe_g| maybe_g.map(|geom| geom.convex_hull()))
+                .iter()
+                .map(|maybe_g| maybe_g.map(|g| convex_hull_polygon(&g)))
                 .collect();
 
             Ok(GeometryArray::Polygon(output_geoms.into()))
         }
         GeometryArray::MultiPolygon(arr) => {
+            let output_geoms: Vec<Option<Polygon>> = arr
+                .iter()
+                .map(|maybe_g| maybe_g.map(|g| convex_hull_multipolygon(&g)))
+                .collect();
+
+            Ok(GeometryArray::Polygon(output_geoms.into()))
+        }
+        // `geo`'s `ConvexHull` impl doesn't cover `GeometryCollection`, so its hull is built
+        // directly from the flattened coordinates of every child geometry, same as
+        // `unary_union_convex_hull`.
+        GeometryArray::GeometryCollection(arr) => {
+            use geo::CoordsIter;
+
             let output_geoms: Vec<Option<Polygon>> = arr
                 .iter_geo()
-                .map(|maybe_g| maybe_g.map(|geom| geom.convex_hull()))
+                .map(|maybe_g| {
+                    maybe_g.map(|geom| {
+                        let mut coords: Vec<Coord> = geom.coords_iter().collect();
+                        coords.sort_by(|a, b| (a.x, a.y).partial_cmp(&(b.x, b.y)).unwrap());
+                        coords.dedup_by(|a, b| (a.x, a.y) == (b.x, b.y));
+                        let ring = convex_hull_ring(&coords);
+                        Polygon::new(LineString::new(ring), vec![])
+                    })
+                })
                 .collect();
 
             Ok(GeometryArray::Polygon(output_geoms.into()))
@@ -67,8 +393,8 @@ pub(crate) fn convex_hull(array: GeometryArray) -> Result<GeometryArray> {
 
 #[cfg(test)]
 mod tests {
-    use super::convex_hull;
-    use geo::{line_string, polygon, Geometry, MultiPoint, Point};
+    use super::{concave_hull, convex_hull};
+    use geo::{line_string, polygon, CoordsIter, Geometry, MultiPoint, Point};
     use geopolars_arrow::{GeometryArray, GeometryArrayTrait, LineStringArray, MultiPointArray};
 
     #[test]
@@ -133,4 +459,56 @@ mod tests {
             result_array.get_as_geo(0).unwrap()
         );
     }
+
+    #[test]
+    fn concave_hull_digs_in_towards_a_close_point() {
+        // A square with one point tucked just inside an edge: with a tight concavity threshold
+        // the hull should dig in to trace through it instead of cutting straight across.
+        let input_geom: MultiPoint = vec![
+            Point::new(0.0, 0.0),
+            Point::new(10.0, 0.0),
+            Point::new(10.0, 10.0),
+            Point::new(0.0, 10.0),
+            Point::new(5.0, 1.0),
+        ]
+        .into();
+        let input_array: MultiPointArray = vec![input_geom].into();
+        let result_array =
+            concave_hull(GeometryArray::MultiPoint(input_array), 3.0).unwrap();
+
+        let hull = match result_array.get_as_geo(0).unwrap() {
+            Geometry::Polygon(polygon) => polygon,
+            other => panic!("expected a polygon, got {other:?}"),
+        };
+
+        assert_eq!(hull.exterior().coords_count(), 6);
+        assert!(hull
+            .exterior()
+            .coords_iter()
+            .any(|c| (c.x - 5.0).abs() < f64::EPSILON && (c.y - 1.0).abs() < f64::EPSILON));
+    }
+
+    #[test]
+    fn concave_hull_with_loose_concavity_matches_convex_hull() {
+        // A high enough concavity threshold means no edge ever qualifies for digging in, so the
+        // result should be exactly the convex hull.
+        let input_geom: MultiPoint = vec![
+            Point::new(0.0, 0.0),
+            Point::new(10.0, 0.0),
+            Point::new(10.0, 10.0),
+            Point::new(0.0, 10.0),
+            Point::new(5.0, 1.0),
+        ]
+        .into();
+        let input_array: MultiPointArray = vec![input_geom].into();
+        let result_array =
+            concave_hull(GeometryArray::MultiPoint(input_array), 1000.0).unwrap();
+
+        let hull = match result_array.get_as_geo(0).unwrap() {
+            Geometry::Polygon(polygon) => polygon,
+            other => panic!("expected a polygon, got {other:?}"),
+        };
+
+        assert_eq!(hull.exterior().coords_count(), 5);
+    }
 }
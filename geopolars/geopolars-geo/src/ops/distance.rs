@@ -0,0 +1,118 @@
+use crate::error::Result;
+use crate::util::iter_geom;
+use geo::algorithm::euclidean_distance::EuclideanDistance;
+use geo::algorithm::euclidean_length::EuclideanLength;
+use geo::algorithm::haversine_distance::HaversineDistance;
+use geo::algorithm::haversine_length::HaversineLength;
+use geo::algorithm::vincenty_distance::VincentyDistance;
+use geo::algorithm::vincenty_length::VincentyLength;
+use geo::Geometry;
+use polars::export::arrow::array::{Array, MutablePrimitiveArray, PrimitiveArray};
+use polars::prelude::Series;
+
+/// The metric space used by [`crate::geoseries::GeoSeries::distance`] and
+/// [`crate::geoseries::GeoSeries::geodesic_length`].
+///
+/// Mirrors `geo`'s split between planar and ellipsoidal/great-circle distance algorithms.
+pub enum DistanceMethod {
+    /// Planar Cartesian distance, valid for any pair of geometries in a projected CRS.
+    Euclidean,
+    /// Great-circle distance between two points, assuming a spherical earth of radius
+    /// 6,371,008.8 m.
+    Haversine,
+    /// Geodesic distance between two points on the WGS84 ellipsoid, via Vincenty's inverse
+    /// formula.
+    Geodesic,
+}
+
+/// Compute the row-wise distance between `left` and `right` under `method`.
+///
+/// `Haversine` and `Geodesic` are only defined between two `Point`s; any other geometry pairing
+/// under those methods (or a Vincenty computation that fails to converge) is emitted as null
+/// rather than erroring the whole series.
+pub(crate) fn distance(left: &Series, right: &Series, method: DistanceMethod) -> Result<Series> {
+    let mut output = MutablePrimitiveArray::<f64>::with_capacity(left.len());
+
+    for (left_geom, right_geom) in iter_geom(left).zip(iter_geom(right)) {
+        output.push(pair_distance(&left_geom, &right_geom, &method));
+    }
+
+    let array: PrimitiveArray<f64> = output.into();
+    let series = Series::try_from(("distance", Box::new(array) as Box<dyn Array>))?;
+    Ok(series)
+}
+
+fn pair_distance(left: &Geometry, right: &Geometry, method: &DistanceMethod) -> Option<f64> {
+    match method {
+        DistanceMethod::Euclidean => Some(left.euclidean_distance(right)),
+        DistanceMethod::Haversine => match (left, right) {
+            (Geometry::Point(a), Geometry::Point(b)) => Some(a.haversine_distance(b)),
+            _ => None,
+        },
+        DistanceMethod::Geodesic => match (left, right) {
+            (Geometry::Point(a), Geometry::Point(b)) => a.vincenty_distance(b).ok(),
+            _ => None,
+        },
+    }
+}
+
+/// Compute the length of every geometry in `series` under `method`.
+///
+/// `Point`s and `MultiPoint`s have no length and are emitted as `0.0`; `GeometryCollection`s are
+/// not supported under any method and are emitted as null.
+pub(crate) fn geodesic_length(series: &Series, method: &DistanceMethod) -> Result<Series> {
+    let mut output = MutablePrimitiveArray::<f64>::with_capacity(series.len());
+
+    for geom in iter_geom(series) {
+        output.push(geometry_length(&geom, method));
+    }
+
+    let array: PrimitiveArray<f64> = output.into();
+    let series = Series::try_from(("length", Box::new(array) as Box<dyn Array>))?;
+    Ok(series)
+}
+
+fn geometry_length(geom: &Geometry, method: &DistanceMethod) -> Option<f64> {
+    match method {
+        DistanceMethod::Euclidean => match geom {
+            Geometry::Point(_) | Geometry::MultiPoint(_) => Some(0.0),
+            Geometry::Line(g) => Some(g.euclidean_length()),
+            Geometry::LineString(g) => Some(g.euclidean_length()),
+            Geometry::Polygon(g) => Some(g.exterior().euclidean_length()),
+            Geometry::MultiLineString(g) => Some(g.euclidean_length()),
+            Geometry::MultiPolygon(g) => Some(
+                g.iter()
+                    .map(|poly| poly.exterior().euclidean_length())
+                    .sum(),
+            ),
+            _ => None,
+        },
+        DistanceMethod::Haversine => match geom {
+            Geometry::Point(_) | Geometry::MultiPoint(_) => Some(0.0),
+            Geometry::Line(g) => Some(g.haversine_length()),
+            Geometry::LineString(g) => Some(g.haversine_length()),
+            Geometry::Polygon(g) => Some(g.exterior().haversine_length()),
+            Geometry::MultiLineString(g) => Some(g.haversine_length()),
+            Geometry::MultiPolygon(g) => Some(
+                g.iter()
+                    .map(|poly| poly.exterior().haversine_length())
+                    .sum(),
+            ),
+            _ => None,
+        },
+        DistanceMethod::Geodesic => match geom {
+            Geometry::Point(_) | Geometry::MultiPoint(_) => Some(0.0),
+            Geometry::Line(g) => g.vincenty_length().ok(),
+            Geometry::LineString(g) => g.vincenty_length().ok(),
+            Geometry::Polygon(g) => g.exterior().vincenty_length().ok(),
+            Geometry::MultiLineString(g) => g.vincenty_length().ok(),
+            Geometry::MultiPolygon(g) => g
+                .iter()
+                .map(|poly| poly.exterior().vincenty_length())
+                .collect::<std::result::Result<Vec<_>, _>>()
+                .ok()
+                .map(|lengths| lengths.into_iter().sum()),
+            _ => None,
+        },
+    }
+}
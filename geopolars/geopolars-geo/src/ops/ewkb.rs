@@ -0,0 +1,49 @@
+use crate::error::{GeopolarsError, Result};
+use crate::util::iter_geom;
+use geopolars_arrow::{GeometryArrayTrait, MutableWKBArray, WKBArray};
+use polars::export::arrow::array::{Array, BinaryArray, MutableBinaryArray};
+use polars::prelude::Series;
+
+/// Parse a Series of Extended WKB (the PostGIS variant of WKB with an embedded SRID) binary
+/// values into the crate's canonical (WKB-backed) geometry Series.
+///
+/// The SRID isn't preserved on the returned Series (a WKB-backed Series has nowhere to carry
+/// one). The intermediate [`MutableWKBArray`] builder records the last non-null SRID seen as
+/// [`MutableWKBArray::crs`], and that builder is dropped once converted.
+pub(crate) fn from_ewkb(series: &Series) -> Result<Series> {
+    let chunks = series.binary()?;
+
+    let bufs: Vec<Option<Vec<u8>>> = chunks
+        .into_iter()
+        .map(|row| row.map(|value| value.to_vec()))
+        .collect();
+
+    let array = MutableWKBArray::from_ewkb(&bufs)
+        .map_err(|err| GeopolarsError::EwkbParseError(format!("{err:?}")))?;
+    let array: WKBArray = array.into();
+
+    let series = Series::try_from(("geometry", array.into_arrow().boxed()))?;
+    Ok(series)
+}
+
+/// Encode every geometry in a geometry Series as Extended WKB (the PostGIS variant of WKB),
+/// stamping `srid` into each row's header.
+///
+/// The counterpart to [`from_ewkb`]: whatever CRS identity was dropped on the way into the
+/// crate's (plain WKB) canonical representation is re-attached on the way out.
+pub(crate) fn to_ewkb(series: &Series, srid: i32) -> Result<Series> {
+    use geozero::{CoordDimensions, ToWkb};
+
+    let mut result = MutableBinaryArray::<i64>::with_capacity(series.len());
+
+    for geom in iter_geom(series) {
+        let ewkb = geom
+            .to_ewkb(CoordDimensions::xy(), Some(srid))
+            .map_err(|err| GeopolarsError::EwkbParseError(format!("{err:?}")))?;
+        result.push(Some(ewkb));
+    }
+
+    let result: BinaryArray<i64> = result.into();
+    let series = Series::try_from(("geometry", Box::new(result) as Box<dyn Array>))?;
+    Ok(series)
+}
@@ -1,105 +1,317 @@
 use crate::error::Result;
 use geo::Geometry;
-use geoarrow::{GeometryArray, WKBArray};
+use geoarrow::coord::MutableCoordBuffer;
+use geoarrow::{
+    GeometryArray, GeometryArrayTrait, LineStringArray, MultiLineStringArray, MultiPointArray,
+    MultiPolygonArray, PointArray, PolygonArray, WKBArray,
+};
+use polars::export::arrow::offset::OffsetsBuffer;
 
-pub(crate) fn explode(array: GeometryArray) -> Result<GeometryArray> {
+/// Explodes multi-part geometries into one row per single-part geometry.
+///
+/// `GeometryCollection`s (including ones nested inside other collections) are descended into
+/// recursively, and any `Multi*` member found along the way is itself exploded into its single
+/// parts, mirroring GeoPandas' `explode`. This recursion applies to WKB input; typed arrays
+/// other than the point, line string and polygon families (and their `Multi*` forms) are not
+/// handled.
+///
+/// When `index_parts` is `true`, the second element of the returned tuple holds the originating
+/// row index for every exploded part (same length and order as the returned array); it is
+/// `None` when `index_parts` is `false`, matching GeoPandas' `index_parts` flag.
+pub(crate) fn explode(
+    array: GeometryArray,
+    index_parts: bool,
+) -> Result<(GeometryArray, Option<Vec<usize>>)> {
     match array {
-        GeometryArray::WKB(arr) => Ok(GeometryArray::WKB(explode_wkb(arr)?)),
-        GeometryArray::Point(arr) => Ok(GeometryArray::Point(arr)),
-        GeometryArray::LineString(arr) => Ok(GeometryArray::LineString(arr)),
-        GeometryArray::Polygon(arr) => Ok(GeometryArray::Polygon(arr)),
+        GeometryArray::WKB(arr) => {
+            let (exploded, indices) = explode_wkb(arr, index_parts)?;
+            Ok((GeometryArray::WKB(exploded), indices))
+        }
+        GeometryArray::Point(arr) => {
+            let indices = trivial_indices(arr.len(), index_parts);
+            Ok((GeometryArray::Point(arr), indices))
+        }
+        GeometryArray::LineString(arr) => {
+            let indices = trivial_indices(arr.len(), index_parts);
+            Ok((GeometryArray::LineString(arr), indices))
+        }
+        GeometryArray::Polygon(arr) => {
+            let indices = trivial_indices(arr.len(), index_parts);
+            Ok((GeometryArray::Polygon(arr), indices))
+        }
+        GeometryArray::MultiPoint(arr) => {
+            let (exploded, indices) = explode_multi_point(arr, index_parts);
+            Ok((GeometryArray::Point(exploded), indices))
+        }
+        GeometryArray::MultiLineString(arr) => {
+            let (exploded, indices) = explode_multi_linestring(arr, index_parts);
+            Ok((GeometryArray::LineString(exploded), indices))
+        }
+        GeometryArray::MultiPolygon(arr) => {
+            let (exploded, indices) = explode_multi_polygon(arr, index_parts);
+            Ok((GeometryArray::Polygon(exploded), indices))
+        }
         _ => todo!(),
     }
 }
 
-fn explode_wkb(array: WKBArray) -> Result<WKBArray> {
-    let mut exploded_vector = Vec::new();
+/// For an already single-part array, each row is its own (and only) part.
+fn trivial_indices(len: usize, index_parts: bool) -> Option<Vec<usize>> {
+    index_parts.then(|| (0..len).collect())
+}
 
-    for geometry in array.iter_geo().flatten() {
-        match geometry {
-            Geometry::Point(geometry) => {
-                let point = Geometry::Point(geometry);
-                exploded_vector.push(Some(point))
-            }
-            Geometry::MultiPoint(geometry) => {
-                for geom in geometry.into_iter() {
-                    let point = Geometry::Point(geom);
-                    exploded_vector.push(Some(point))
-                }
-            }
-            Geometry::Line(geometry) => {
-                let line = Geometry::Line(geometry);
-                exploded_vector.push(Some(line))
-            }
-            Geometry::LineString(geometry) => {
-                let line_string = Geometry::LineString(geometry);
-                exploded_vector.push(Some(line_string))
+/// Explodes a `MultiPointArray` into a flat `PointArray`, one row per member point, by walking
+/// `geom_offsets` directly instead of decoding through WKB. Null parent rows contribute no rows.
+fn explode_multi_point(array: MultiPointArray, index_parts: bool) -> (PointArray, Option<Vec<usize>>) {
+    let mut coords = MutableCoordBuffer::with_capacity(array.coords().coord_type(), array.coords().len());
+    let mut row_indices = Vec::new();
+
+    for row_index in 0..array.len() {
+        if array.is_null(row_index) {
+            continue;
+        }
+
+        let (start, end) = array.geom_offsets().start_end(row_index);
+        for coord_idx in start..end {
+            let (x, y) = array.coords().value(coord_idx);
+            coords.push_xy(x, y);
+            row_indices.push(row_index);
+        }
+    }
+
+    let point_array = PointArray::try_new_from_coords(coords.into(), None, None).unwrap();
+    (point_array, index_parts.then_some(row_indices))
+}
+
+/// Explodes a `MultiLineStringArray` into a flat `LineStringArray`, one row per member
+/// `LineString`, by walking `geom_offsets`/`ring_offsets` directly instead of decoding through
+/// WKB. Null parent rows contribute no rows.
+fn explode_multi_linestring(
+    array: MultiLineStringArray,
+    index_parts: bool,
+) -> (LineStringArray, Option<Vec<usize>>) {
+    let mut coords = MutableCoordBuffer::with_capacity(array.coords().coord_type(), array.coords().len());
+    let mut geom_offsets = vec![0i64];
+    let mut row_indices = Vec::new();
+
+    for row_index in 0..array.len() {
+        if array.is_null(row_index) {
+            continue;
+        }
+
+        let (start_ring, end_ring) = array.geom_offsets().start_end(row_index);
+        for ring_idx in start_ring..end_ring {
+            let (start_coord, end_coord) = array.ring_offsets().start_end(ring_idx);
+            for coord_idx in start_coord..end_coord {
+                let (x, y) = array.coords().value(coord_idx);
+                coords.push_xy(x, y);
             }
-            Geometry::MultiLineString(geometry) => {
-                for geom in geometry.into_iter() {
-                    let line_string = Geometry::LineString(geom);
-                    exploded_vector.push(Some(line_string))
+            geom_offsets.push(coords.len() as i64);
+            row_indices.push(row_index);
+        }
+    }
+
+    let line_string_array = LineStringArray::try_new_from_coords(
+        coords.into(),
+        OffsetsBuffer::try_from(geom_offsets).unwrap(),
+        None,
+    )
+    .unwrap();
+    (line_string_array, index_parts.then_some(row_indices))
+}
+
+/// Explodes a `MultiPolygonArray` into a flat `PolygonArray`, one row per member `Polygon`, by
+/// walking `geom_offsets`/`polygon_offsets`/`ring_offsets` directly instead of decoding through
+/// WKB. Null parent rows contribute no rows.
+fn explode_multi_polygon(
+    array: MultiPolygonArray,
+    index_parts: bool,
+) -> (PolygonArray, Option<Vec<usize>>) {
+    let mut coords = MutableCoordBuffer::with_capacity(array.coords().coord_type(), array.coords().len());
+    let mut geom_offsets = vec![0i64];
+    let mut ring_offsets = vec![0i64];
+    let mut row_indices = Vec::new();
+
+    for row_index in 0..array.len() {
+        let is_null = array
+            .validity()
+            .map(|v| !v.get_bit(row_index))
+            .unwrap_or(false);
+        if is_null {
+            continue;
+        }
+
+        let (start_polygon, end_polygon) = array.geom_offsets().start_end(row_index);
+        for polygon_idx in start_polygon..end_polygon {
+            let (start_ring, end_ring) = array.polygon_offsets().start_end(polygon_idx);
+            for ring_idx in start_ring..end_ring {
+                let (start_coord, end_coord) = array.ring_offsets().start_end(ring_idx);
+                for coord_idx in start_coord..end_coord {
+                    let (x, y) = array.coords().value(coord_idx);
+                    coords.push_xy(x, y);
                 }
+                ring_offsets.push(coords.len() as i64);
             }
-            Geometry::Polygon(geometry) => {
-                let polygon = Geometry::Polygon(geometry);
-                exploded_vector.push(Some(polygon))
+            geom_offsets.push(ring_offsets.len() as i64 - 1);
+            row_indices.push(row_index);
+        }
+    }
+
+    let polygon_array = PolygonArray::try_new_from_coords(
+        coords.into(),
+        OffsetsBuffer::try_from(geom_offsets).unwrap(),
+        OffsetsBuffer::try_from(ring_offsets).unwrap(),
+        None,
+    )
+    .unwrap();
+    (polygon_array, index_parts.then_some(row_indices))
+}
+
+fn explode_wkb(array: WKBArray, index_parts: bool) -> Result<(WKBArray, Option<Vec<usize>>)> {
+    let mut exploded_vector = Vec::new();
+    let mut row_indices = Vec::new();
+
+    for (row_index, geometry) in array.iter_geo().enumerate() {
+        if let Some(geometry) = geometry {
+            flatten(geometry, row_index, &mut exploded_vector, &mut row_indices);
+        }
+    }
+
+    let indices = index_parts.then_some(row_indices);
+    Ok((exploded_vector.into(), indices))
+}
+
+/// Pushes `geometry`'s single-part leaves onto `out` (recursively descending into any
+/// `GeometryCollection`), recording `row_index` in `indices` once per leaf so the two vectors
+/// stay aligned.
+fn flatten(geometry: Geometry, row_index: usize, out: &mut Vec<Option<Geometry>>, indices: &mut Vec<usize>) {
+    match geometry {
+        Geometry::Point(geometry) => out.push(Some(Geometry::Point(geometry))),
+        Geometry::MultiPoint(geometry) => {
+            for geom in geometry.into_iter() {
+                out.push(Some(Geometry::Point(geom)));
+                indices.push(row_index);
             }
-            Geometry::MultiPolygon(geometry) => {
-                for geom in geometry.into_iter() {
-                    let polygon = Geometry::Polygon(geom);
-                    exploded_vector.push(Some(polygon))
-                }
+            return;
+        }
+        Geometry::Line(geometry) => out.push(Some(Geometry::Line(geometry))),
+        Geometry::LineString(geometry) => out.push(Some(Geometry::LineString(geometry))),
+        Geometry::MultiLineString(geometry) => {
+            for geom in geometry.into_iter() {
+                out.push(Some(Geometry::LineString(geom)));
+                indices.push(row_index);
             }
-            Geometry::Rect(geometry) => {
-                let rectangle = Geometry::Rect(geometry);
-                exploded_vector.push(Some(rectangle))
+            return;
+        }
+        Geometry::Polygon(geometry) => out.push(Some(Geometry::Polygon(geometry))),
+        Geometry::MultiPolygon(geometry) => {
+            for geom in geometry.into_iter() {
+                out.push(Some(Geometry::Polygon(geom)));
+                indices.push(row_index);
             }
-            Geometry::Triangle(geometry) => {
-                let triangle = Geometry::Triangle(geometry);
-                exploded_vector.push(Some(triangle))
+            return;
+        }
+        Geometry::Rect(geometry) => out.push(Some(Geometry::Rect(geometry))),
+        Geometry::Triangle(geometry) => out.push(Some(Geometry::Triangle(geometry))),
+        Geometry::GeometryCollection(geometry) => {
+            for geom in geometry.into_iter() {
+                flatten(geom, row_index, out, indices);
             }
-            _ => unimplemented!(),
-        };
-    }
+            return;
+        }
+    };
 
-    Ok(exploded_vector.into())
+    indices.push(row_index);
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::geoseries::GeoSeries;
-    use crate::util::from_geom_vec;
-    use geo::{Geometry, MultiPoint, Point};
+    use super::explode;
+    use geo::{
+        Geometry, GeometryCollection, LineString, MultiPoint, MultiPolygon, Point, Polygon,
+    };
+    use geoarrow::{GeometryArray, GeometryArrayTrait, WKBArray};
+
+    fn wkb_array(geoms: Vec<Geometry>) -> WKBArray {
+        geoms.into_iter().map(Some).collect::<Vec<_>>().into()
+    }
+
+    #[test]
+    fn explode_flattens_a_multi_point() {
+        let point_0 = Point::new(0., 0.);
+        let point_1 = Point::new(1., 1.);
+
+        let input = wkb_array(vec![Geometry::MultiPoint(MultiPoint::new(vec![
+            point_0, point_1,
+        ]))]);
+
+        let (result, indices) = explode(GeometryArray::WKB(input), true).unwrap();
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(result.get_as_geo(0).unwrap(), Geometry::Point(point_0));
+        assert_eq!(result.get_as_geo(1).unwrap(), Geometry::Point(point_1));
+        assert_eq!(indices, Some(vec![0, 0]));
+    }
 
     #[test]
-    fn explode() {
+    fn explode_recurses_into_a_collection_of_mixed_multi_geometries_in_traversal_order() {
+        // A GeometryCollection holding a MultiPoint, a bare Polygon, and a nested collection
+        // holding a MultiPolygon: every Multi* member should be exploded to its singles, and the
+        // nested collection's member should surface too, all in the order encountered.
         let point_0 = Point::new(0., 0.);
         let point_1 = Point::new(1., 1.);
-        let point_2 = Point::new(2., 2.);
-        let point_3 = Point::new(3., 3.);
-        let point_4 = Point::new(4., 4.);
+        let polygon_a: Polygon = Polygon::new(
+            LineString::from(vec![(0., 0.), (1., 0.), (1., 1.), (0., 0.)]),
+            vec![],
+        );
+        let polygon_b: Polygon = Polygon::new(
+            LineString::from(vec![(2., 2.), (3., 2.), (3., 3.), (2., 2.)]),
+            vec![],
+        );
+        let polygon_c: Polygon = Polygon::new(
+            LineString::from(vec![(4., 4.), (5., 4.), (5., 5.), (4., 4.)]),
+            vec![],
+        );
+
+        let nested = GeometryCollection(vec![Geometry::MultiPolygon(MultiPolygon::new(vec![
+            polygon_c.clone(),
+        ]))]);
 
-        let expected_series = from_geom_vec(&[
+        let collection = GeometryCollection(vec![
+            Geometry::MultiPoint(MultiPoint::new(vec![point_0, point_1])),
+            Geometry::Polygon(polygon_a.clone()),
+            Geometry::GeometryCollection(nested),
+        ]);
+
+        let input = wkb_array(vec![
+            Geometry::GeometryCollection(collection),
+            Geometry::Polygon(polygon_b.clone()),
+        ]);
+
+        let (result, indices) = explode(GeometryArray::WKB(input), true).unwrap();
+
+        let expected = vec![
             Geometry::Point(point_0),
             Geometry::Point(point_1),
-            Geometry::Point(point_2),
-            Geometry::Point(point_3),
-            Geometry::Point(point_4),
-        ])
-        .unwrap();
+            Geometry::Polygon(polygon_a),
+            Geometry::Polygon(polygon_c),
+            Geometry::Polygon(polygon_b),
+        ];
 
-        let multipoint_0 = MultiPoint::new(vec![point_0, point_1]);
-        let multipoint_1 = MultiPoint::new(vec![point_2, point_3, point_4]);
-
-        let input_series = from_geom_vec(&[
-            Geometry::MultiPoint(multipoint_0),
-            Geometry::MultiPoint(multipoint_1),
-        ])
-        .unwrap();
+        assert_eq!(result.len(), expected.len());
+        for (i, expected_geom) in expected.into_iter().enumerate() {
+            assert_eq!(result.get_as_geo(i).unwrap(), expected_geom);
+        }
+        assert_eq!(indices, Some(vec![0, 0, 0, 0, 1]));
+    }
 
-        let output_series = GeoSeries::explode(&input_series).unwrap();
+    #[test]
+    fn explode_without_index_parts_returns_none() {
+        let input = wkb_array(vec![Geometry::MultiPoint(MultiPoint::new(vec![
+            Point::new(0., 0.),
+        ]))]);
 
-        assert_eq!(output_series, expected_series);
+        let (_, indices) = explode(GeometryArray::WKB(input), false).unwrap();
+        assert_eq!(indices, None);
     }
 }
@@ -0,0 +1,104 @@
+use crate::error::{GeopolarsError, Result};
+use crate::util::{from_geom_vec_opt, iter_geom};
+use geo::Geometry;
+use geos::{BufferParams as GeosBufferParams, CapStyle as GeosCapStyle, JoinStyle as GeosJoinStyle};
+use polars::prelude::Series;
+
+/// End-cap style for [`buffer`], mirroring `geos::CapStyle`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CapStyle {
+    Round,
+    Flat,
+    Square,
+}
+
+impl From<CapStyle> for GeosCapStyle {
+    fn from(style: CapStyle) -> Self {
+        match style {
+            CapStyle::Round => GeosCapStyle::Round,
+            CapStyle::Flat => GeosCapStyle::Flat,
+            CapStyle::Square => GeosCapStyle::Square,
+        }
+    }
+}
+
+/// Join style for [`buffer`], mirroring `geos::JoinStyle`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JoinStyle {
+    Round,
+    Mitre,
+    Bevel,
+}
+
+impl From<JoinStyle> for GeosJoinStyle {
+    fn from(style: JoinStyle) -> Self {
+        match style {
+            JoinStyle::Round => GeosJoinStyle::Round,
+            JoinStyle::Mitre => GeosJoinStyle::Mitre,
+            JoinStyle::Bevel => GeosJoinStyle::Bevel,
+        }
+    }
+}
+
+/// Options controlling the shape of [`buffer`]'s output, mirroring GEOS's own `BufferParams`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BufferParams {
+    /// Number of line segments used to approximate a quarter circle (only relevant for round
+    /// end caps/joins).
+    pub quadrant_segments: i32,
+    pub end_cap_style: CapStyle,
+    pub join_style: JoinStyle,
+    /// Limits how far a mitred join may extend past the buffer distance; only used when
+    /// `join_style` is [`JoinStyle::Mitre`].
+    pub mitre_limit: f64,
+    /// If true, only one side of a line is buffered (the left side for a positive distance).
+    /// Has no effect on (multi)polygon input.
+    pub single_sided: bool,
+}
+
+impl Default for BufferParams {
+    fn default() -> Self {
+        Self {
+            quadrant_segments: 8,
+            end_cap_style: CapStyle::Round,
+            join_style: JoinStyle::Round,
+            mitre_limit: 5.0,
+            single_sided: false,
+        }
+    }
+}
+
+impl BufferParams {
+    fn to_geos(self) -> Result<GeosBufferParams> {
+        GeosBufferParams::builder()
+            .end_cap_style(self.end_cap_style.into())
+            .join_style(self.join_style.into())
+            .mitre_limit(self.mitre_limit)
+            .quadrant_segments(self.quadrant_segments)
+            .single_sided(self.single_sided)
+            .build()
+            .map_err(|err| GeopolarsError::GeosError(format!("{err:?}")))
+    }
+}
+
+/// Returns a Series with, for each row, the polygon obtained by offsetting the geometry in
+/// `series` by `distance` (negative distances shrink the geometry), shaped by `params`.
+pub(crate) fn buffer(series: &Series, distance: f64, params: BufferParams) -> Result<Series> {
+    let geos_params = params.to_geos()?;
+    let mut output = Vec::with_capacity(series.len());
+
+    for geom in iter_geom(series) {
+        let geos_geom: geos::Geometry = (&geom)
+            .try_into()
+            .map_err(|err| GeopolarsError::GeosError(format!("{err:?}")))?;
+        let buffered = geos_geom
+            .buffer_with_params(&geos_params, distance)
+            .map_err(|err| GeopolarsError::GeosError(format!("GEOS buffer failed: {err:?}")))?;
+        let geo_geom: Geometry = buffered
+            .try_into()
+            .map_err(|err| GeopolarsError::GeosError(format!("{err:?}")))?;
+        output.push(Some(geo_geom));
+    }
+
+    from_geom_vec_opt(&output)
+}
@@ -0,0 +1,143 @@
+use crate::error::{GeopolarsError, Result};
+use crate::util::{from_geom_vec_opt, iter_geom};
+use geo::Geometry;
+use geos::{Geom, PreparedGeom};
+use polars::export::arrow::array::{Array, BooleanArray, MutableBooleanArray};
+use polars::prelude::Series;
+
+/// Converts a decoded `geo::Geometry` to its GEOS equivalent.
+///
+/// `geo`'s own algorithms already cover [`crate::ops::predicate`]'s `intersects`/`contains`/
+/// `within`/`disjoint`; this module exists for the DE-9IM predicates and set operations GEOS
+/// implements that `geo` doesn't (yet) have a pure-Rust equivalent for.
+fn to_geos(geom: &Geometry) -> Result<geos::Geometry> {
+    geom.try_into()
+        .map_err(|err| GeopolarsError::GeosError(format!("{err:?}")))
+}
+
+fn from_geos(geom: geos::Geometry) -> Result<Geometry> {
+    geom.try_into()
+        .map_err(|err| GeopolarsError::GeosError(format!("{err:?}")))
+}
+
+/// Row-wise GEOS predicate between `left` and `right`, panicking on a null row the same way
+/// [`iter_geom`] does elsewhere in this crate.
+fn geos_predicate_series(
+    left: &Series,
+    right: &Series,
+    predicate: impl Fn(&geos::Geometry, &geos::Geometry) -> bool,
+) -> Result<Series> {
+    let mut output = MutableBooleanArray::with_capacity(left.len());
+    for (left_geom, right_geom) in iter_geom(left).zip(iter_geom(right)) {
+        let left_geos = to_geos(&left_geom)?;
+        let right_geos = to_geos(&right_geom)?;
+        output.push(Some(predicate(&left_geos, &right_geos)));
+    }
+
+    let array: BooleanArray = output.into();
+    let series = Series::try_from(("predicate", Box::new(array) as Box<dyn Array>))?;
+    Ok(series)
+}
+
+/// Returns, for each row, whether `query` intersects the geometry in `series`.
+///
+/// `query` is prepared once via [`geos::Geometry::to_prepared_geom`] before the column is
+/// scanned - GEOS builds an internal STR-tree index over `query` on the prepared side, so
+/// testing it against every row is far cheaper than `series.len()` unprepared pairwise calls.
+/// This is the standalone counterpart to [`super::predicate::geos::prepared_predicate`] array
+/// helper: this one decodes a WKB `Series` instead of a typed `geoarrow::GeometryArray`.
+pub(crate) fn intersects_scalar(series: &Series, query: &Geometry) -> Result<Series> {
+    let query_geos = to_geos(query)?;
+    let mut output = MutableBooleanArray::with_capacity(series.len());
+
+    match query_geos.to_prepared_geom() {
+        Ok(prepared) => {
+            for geom in iter_geom(series) {
+                let geos_geom = to_geos(&geom)?;
+                output.push(Some(prepared.intersects(&geos_geom).unwrap()));
+            }
+        }
+        Err(_) => {
+            for geom in iter_geom(series) {
+                let geos_geom = to_geos(&geom)?;
+                output.push(Some(query_geos.intersects(&geos_geom).unwrap()));
+            }
+        }
+    }
+
+    let array: BooleanArray = output.into();
+    let series = Series::try_from(("predicate", Box::new(array) as Box<dyn Array>))?;
+    Ok(series)
+}
+
+/// Returns, for each row, whether the geometry in `left` touches the geometry in `right` (they
+/// share a boundary point but no interior points).
+pub(crate) fn touches(left: &Series, right: &Series) -> Result<Series> {
+    geos_predicate_series(left, right, |l, r| l.touches(r).unwrap())
+}
+
+/// Returns, for each row, whether the geometry in `left` covers the geometry in `right` (like
+/// `contains`, but also true when `right` lies entirely on `left`'s boundary).
+pub(crate) fn covers(left: &Series, right: &Series) -> Result<Series> {
+    geos_predicate_series(left, right, |l, r| l.covers(r).unwrap())
+}
+
+/// Returns, for each row, whether the geometry in `left` crosses the geometry in `right` (their
+/// interiors intersect, but neither contains the other, and the intersection is lower-dimensional
+/// than the larger of the two operands).
+pub(crate) fn crosses(left: &Series, right: &Series) -> Result<Series> {
+    geos_predicate_series(left, right, |l, r| l.crosses(r).unwrap())
+}
+
+/// Returns, for each row, whether the geometry in `left` overlaps the geometry in `right` (they
+/// intersect in a region of the same dimension as both operands, but neither contains the other).
+pub(crate) fn overlaps(left: &Series, right: &Series) -> Result<Series> {
+    geos_predicate_series(left, right, |l, r| l.overlaps(r).unwrap())
+}
+
+/// Row-wise GEOS set operation between `left` and `right`, re-encoded back to a WKB geometry
+/// Series. Null on either side propagates to a null output row.
+fn geos_set_op_series(
+    left: &Series,
+    right: &Series,
+    op: impl Fn(&geos::Geometry, &geos::Geometry) -> geos::GResult<geos::Geometry>,
+) -> Result<Series> {
+    let left_len = left.len();
+    assert_eq!(
+        left_len,
+        right.len(),
+        "left and right geometry series must have the same length"
+    );
+
+    let mut output = Vec::with_capacity(left_len);
+    for (left_geom, right_geom) in iter_geom(left).zip(iter_geom(right)) {
+        let left_geos = to_geos(&left_geom)?;
+        let right_geos = to_geos(&right_geom)?;
+        let result = op(&left_geos, &right_geos).map_err(|err| {
+            GeopolarsError::GeosError(format!("GEOS operation failed: {err:?}"))
+        })?;
+        output.push(Some(from_geos(result)?));
+    }
+
+    from_geom_vec_opt(&output)
+}
+
+/// Returns, for each row, the geometric intersection of `left` and `right`.
+pub(crate) fn intersection(left: &Series, right: &Series) -> Result<Series> {
+    geos_set_op_series(left, right, |l, r| l.intersection(r))
+}
+
+/// Returns, for each row, the geometric union of `left` and `right`.
+pub(crate) fn union(left: &Series, right: &Series) -> Result<Series> {
+    geos_set_op_series(left, right, |l, r| l.union(r))
+}
+
+/// Returns, for each row, the part of `left` that does not intersect `right`.
+pub(crate) fn difference(left: &Series, right: &Series) -> Result<Series> {
+    geos_set_op_series(left, right, |l, r| l.difference(r))
+}
+
+/// Returns, for each row, the parts of `left` and `right` that do not intersect each other.
+pub(crate) fn symmetric_difference(left: &Series, right: &Series) -> Result<Series> {
+    geos_set_op_series(left, right, |l, r| l.sym_difference(r))
+}
@@ -0,0 +1,25 @@
+use crate::error::{GeopolarsError, Result};
+use crate::util::from_geom_vec;
+use geo::Geometry;
+use geozero::{GeozeroGeometry, ToGeo};
+use polars::prelude::Series;
+
+/// Ingests any geozero-readable geometry source (GeoJSON, WKB/EWKB, FlatGeobuf, ...) into the
+/// crate's canonical (WKB-backed) geometry Series, without requiring callers to pre-sort by
+/// geometry type or materialize a `Vec<geo::Geometry>` themselves first.
+///
+/// A top-level `GeometryCollection` in `source` is flattened into one row per member rather than
+/// kept as a single collection row, matching how a multi-feature dataset (e.g. a GeoJSON
+/// `FeatureCollection`) is expected to map onto Series rows.
+pub(crate) fn from_geozero<T: GeozeroGeometry>(source: &T) -> Result<Series> {
+    let geom = source
+        .to_geo()
+        .map_err(|err| GeopolarsError::GeozeroError(format!("{err:?}")))?;
+
+    let geoms: Vec<Geometry> = match geom {
+        Geometry::GeometryCollection(collection) => collection.into_iter().collect(),
+        other => vec![other],
+    };
+
+    from_geom_vec(&geoms)
+}
@@ -0,0 +1,202 @@
+use crate::ops::predicate::geoms;
+use crate::ops::sjoin::{predicate_holds, SpatialPredicate};
+use crate::util::iter_geom;
+use geo::{BoundingRect, Geometry};
+use geopolars_arrow::GeometryArray;
+use polars::prelude::Series;
+use rstar::{RTree, RTreeObject, AABB};
+
+/// A geometry paired with its row index, so an [`RTree`] built from one series can be queried
+/// and still report which row each hit came from.
+pub(crate) struct IndexedGeometry {
+    pub(crate) index: usize,
+    pub(crate) geom: Geometry<f64>,
+}
+
+impl RTreeObject for IndexedGeometry {
+    type Envelope = AABB<[f64; 2]>;
+
+    fn envelope(&self) -> Self::Envelope {
+        let rect = self.geom.bounding_rect().unwrap();
+        let lower: [f64; 2] = rect.min().into();
+        let upper: [f64; 2] = rect.max().into();
+        AABB::from_corners(lower, upper)
+    }
+}
+
+/// Grows `envelope` by `distance` on every side, so an envelope-intersection query against the
+/// expanded box can't miss a candidate that's within `distance` but doesn't overlap the original
+/// envelope.
+fn expand_envelope(envelope: &AABB<[f64; 2]>, distance: f64) -> AABB<[f64; 2]> {
+    let lower = envelope.lower();
+    let upper = envelope.upper();
+    AABB::from_corners(
+        [lower[0] - distance, lower[1] - distance],
+        [upper[0] + distance, upper[1] + distance],
+    )
+}
+
+/// An R*-tree over the envelopes of every geometry in a series, used to prune candidates for
+/// [`crate::ops::sjoin::sjoin`] and [`crate::ops::sjoin::nearest`] before falling back to exact
+/// `geo` predicates on the decoded geometries.
+pub(crate) struct SpatialIndex {
+    pub(crate) tree: RTree<IndexedGeometry>,
+}
+
+impl SpatialIndex {
+    /// Bulk-loads every geometry in `series` into an R*-tree, keyed by row index.
+    ///
+    /// Bulk-loading (rstar's STR implementation) produces a better-balanced tree than inserting
+    /// one row at a time, which matters here since the tree is rebuilt per call.
+    pub(crate) fn build(series: &Series) -> Self {
+        let nodes = iter_geom(series)
+            .enumerate()
+            .map(|(index, geom)| IndexedGeometry { index, geom })
+            .collect();
+
+        SpatialIndex {
+            tree: RTree::bulk_load(nodes),
+        }
+    }
+
+    /// Returns every indexed geometry whose envelope could satisfy `predicate` against `other`'s
+    /// envelope.
+    ///
+    /// For every predicate but [`SpatialPredicate::DWithin`] this is a plain envelope
+    /// intersection test. `DWithin(dist)` first grows `other`'s envelope by `dist` on every side
+    /// (mirroring `expand_envelope` in `geopolars::spatial_index` and the
+    /// `locate_within_distance` query [`geopolars_arrow::WKBArray::sjoin`] runs), so two
+    /// geometries that are near but not overlapping - e.g. two points 5 units apart with
+    /// zero-size envelopes - still surface as candidates for `predicate_holds` to confirm
+    /// exactly.
+    pub(crate) fn candidates(
+        &self,
+        other: &Geometry<f64>,
+        predicate: SpatialPredicate,
+    ) -> impl Iterator<Item = &IndexedGeometry> {
+        let rect = other.bounding_rect().unwrap();
+        let lower: [f64; 2] = rect.min().into();
+        let upper: [f64; 2] = rect.max().into();
+        let aabb = AABB::from_corners(lower, upper);
+        let aabb = match predicate {
+            SpatialPredicate::DWithin(dist) => expand_envelope(&aabb, dist),
+            _ => aabb,
+        };
+        self.tree.locate_in_envelope_intersecting(&aabb)
+    }
+
+    /// Returns the indexed geometry whose envelope is closest to `other`, or `None` if the index
+    /// is empty.
+    pub(crate) fn nearest(&self, other: &Geometry<f64>) -> Option<&IndexedGeometry> {
+        let rect = other.bounding_rect().unwrap();
+        let center: [f64; 2] = rect.center().into();
+        self.tree.nearest_neighbor(&center)
+    }
+
+    /// Returns up to `k` indexed geometries closest to `other`, nearest first.
+    pub(crate) fn k_nearest(
+        &self,
+        other: &Geometry<f64>,
+        k: usize,
+    ) -> impl Iterator<Item = &IndexedGeometry> {
+        let rect = other.bounding_rect().unwrap();
+        let center: [f64; 2] = rect.center().into();
+        self.tree.nearest_neighbor_iter(&center).take(k)
+    }
+
+    /// Bulk-loads every geometry in a `GeometryArray`, keyed by row index. Null rows are skipped,
+    /// since they have no envelope to index.
+    pub(crate) fn build_from_array(array: GeometryArray) -> Self {
+        let nodes = geoms(array)
+            .into_iter()
+            .enumerate()
+            .filter_map(|(index, geom)| geom.map(|geom| IndexedGeometry { index, geom }))
+            .collect();
+
+        SpatialIndex {
+            tree: RTree::bulk_load(nodes),
+        }
+    }
+}
+
+/// Joins two `GeometryArray`s on a spatial predicate, returning the `(left_index, right_index)`
+/// pairs for which `predicate` holds between `left[left_index]` and `right[right_index]`.
+///
+/// Builds a [`SpatialIndex`] over `right` and prunes candidates by envelope intersection before
+/// confirming each pair with the exact `geo` predicate, avoiding the full `O(n·m)` comparison.
+pub(crate) fn spatial_join(
+    left: GeometryArray,
+    right: GeometryArray,
+    predicate: SpatialPredicate,
+) -> Vec<(usize, usize)> {
+    let index = SpatialIndex::build_from_array(right);
+
+    let mut pairs = Vec::new();
+    for (left_index, left_geom) in geoms(left).into_iter().enumerate() {
+        let Some(left_geom) = left_geom else {
+            continue;
+        };
+        for candidate in index.candidates(&left_geom, predicate) {
+            if predicate_holds(predicate, &left_geom, &candidate.geom) {
+                pairs.push((left_index, candidate.index));
+            }
+        }
+    }
+
+    pairs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{spatial_join, SpatialIndex};
+    use crate::ops::sjoin::SpatialPredicate;
+    use geo::{polygon, Point};
+    use geopolars_arrow::polygon::MutablePolygonArray;
+    use geopolars_arrow::{GeometryArray, GeometryArrayTrait, PointArray, PolygonArray};
+
+    fn square() -> geo::Polygon {
+        polygon![
+            (x: 0., y: 0.),
+            (x: 4., y: 0.),
+            (x: 4., y: 4.),
+            (x: 0., y: 4.),
+            (x: 0., y: 0.),
+        ]
+    }
+
+    fn polygon_array(polygons: Vec<geo::Polygon>) -> PolygonArray {
+        let mut_arr: MutablePolygonArray = polygons.into();
+        let arr = mut_arr.into_arrow();
+        arr.try_into().unwrap()
+    }
+
+    fn point_array(points: Vec<geo::Point>) -> PointArray {
+        points.into()
+    }
+
+    #[test]
+    fn spatial_join_prunes_with_envelope_then_confirms_with_predicate() {
+        let left = polygon_array(vec![square()]);
+        let right = point_array(vec![
+            Point::new(2., 2.),
+            Point::new(20., 20.),
+            Point::new(1., 1.),
+        ]);
+
+        let mut pairs = spatial_join(
+            GeometryArray::Polygon(left),
+            GeometryArray::Point(right),
+            SpatialPredicate::Contains,
+        );
+        pairs.sort();
+
+        assert_eq!(pairs, vec![(0, 0), (0, 2)]);
+    }
+
+    #[test]
+    fn build_from_array_skips_null_rows() {
+        let points = point_array(vec![Point::new(0., 0.), Point::new(5., 5.)]);
+        let index = SpatialIndex::build_from_array(GeometryArray::Point(points));
+        assert_eq!(index.tree.size(), 2);
+    }
+}
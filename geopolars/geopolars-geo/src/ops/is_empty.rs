@@ -1,8 +1,14 @@
 use crate::error::Result;
 use geo::dimensions::HasDimensions;
+use geoarrow::algorithm::dimensions::{
+    is_empty_linestring, is_empty_multilinestring, is_empty_multipoint, is_empty_multipolygon,
+    is_empty_point, is_empty_polygon,
+};
 use geoarrow::{GeometryArray, GeometryArrayTrait};
 use polars::export::arrow::array::{BooleanArray, MutableBooleanArray};
 
+/// Unlike the typed arms below, WKB rows have no offset structure to read emptiness off of, so
+/// this still has to decode each blob into a `geo::Geometry` via [`HasDimensions`].
 pub(crate) fn is_empty(array: GeometryArray) -> Result<BooleanArray> {
     let mut output_array = MutableBooleanArray::with_capacity(array.len());
 
@@ -12,28 +18,30 @@ pub(crate) fn is_empty(array: GeometryArray) -> Result<BooleanArray> {
                 .for_each(|maybe_g| output_array.push(maybe_g.map(|g| g.is_empty())));
         }
         GeometryArray::Point(arr) => {
-            arr.iter_geo()
-                .for_each(|maybe_g| output_array.push(maybe_g.map(|g| g.is_empty())));
+            arr.iter()
+                .for_each(|maybe_g| output_array.push(maybe_g.map(|g| is_empty_point(&g))));
         }
         GeometryArray::LineString(arr) => {
-            arr.iter_geo()
-                .for_each(|maybe_g| output_array.push(maybe_g.map(|g| g.is_empty())));
+            arr.iter()
+                .for_each(|maybe_g| output_array.push(maybe_g.map(|g| is_empty_linestring(&g))));
         }
         GeometryArray::Polygon(arr) => {
-            arr.iter_geo()
-                .for_each(|maybe_g| output_array.push(maybe_g.map(|g| g.is_empty())));
+            arr.iter()
+                .for_each(|maybe_g| output_array.push(maybe_g.map(|g| is_empty_polygon(&g))));
         }
         GeometryArray::MultiPoint(arr) => {
-            arr.iter_geo()
-                .for_each(|maybe_g| output_array.push(maybe_g.map(|g| g.is_empty())));
+            arr.iter()
+                .for_each(|maybe_g| output_array.push(maybe_g.map(|g| is_empty_multipoint(&g))));
         }
         GeometryArray::MultiLineString(arr) => {
-            arr.iter_geo()
-                .for_each(|maybe_g| output_array.push(maybe_g.map(|g| g.is_empty())));
+            arr.iter().for_each(|maybe_g| {
+                output_array.push(maybe_g.map(|g| is_empty_multilinestring(&g)))
+            });
         }
         GeometryArray::MultiPolygon(arr) => {
-            arr.iter_geo()
-                .for_each(|maybe_g| output_array.push(maybe_g.map(|g| g.is_empty())));
+            arr.iter().for_each(|maybe_g| {
+                output_array.push(maybe_g.map(|g| is_empty_multipolygon(&g)))
+            });
         }
     }
 
@@ -0,0 +1,122 @@
+use crate::error::Result;
+use crate::util::{from_geom_vec_opt, iter_geom_opt};
+use geo::Geometry;
+use geopolars_arrow::algorithm::label_point::label_point as label_point_polygon;
+use geopolars_arrow::{GeometryArray, GeometryArrayTrait, MutablePointArray, PointArray};
+use polars::prelude::Series;
+
+/// Returns a point per polygon giving the pole of inaccessibility, computed directly off the
+/// native coordinate buffers via [`label_point_polygon`] rather than decoding WKB.
+///
+/// Non-polygon rows (including `WKB` rows, which would need decoding to tell whether they hold
+/// a polygon at all) are emitted as null, matching how [`crate::ops::is_empty::is_empty`]
+/// leaves types it doesn't support as null.
+pub(crate) fn label_point(array: GeometryArray, precision: f64) -> Result<PointArray> {
+    let mut output_array = MutablePointArray::with_capacity(array.len());
+
+    match array {
+        GeometryArray::Polygon(arr) => {
+            arr.iter().for_each(|maybe_g| {
+                output_array.push_geo(maybe_g.map(|g| label_point_polygon(&g, precision)))
+            });
+        }
+        other => (0..other.len()).for_each(|_| output_array.push_geo(None)),
+    }
+
+    Ok(output_array.into())
+}
+
+/// [`label_point`] for a plain (WKB-backed) geometry `Series`, matching the
+/// [`crate::geoseries::GeoSeries::label_point`] trait method.
+///
+/// Decodes each row through [`iter_geom_opt`] directly instead of going through the
+/// `GeometryArray` dispatch above - there's no generic `Series` <-> `GeometryArray` conversion in
+/// this crate yet, so routing a WKB-backed `Series` through [`label_point`]'s `GeometryArray::WKB`
+/// arm would always come back null. Non-polygon rows are still emitted as null, matching
+/// [`label_point`] itself.
+pub(crate) fn label_point_series(series: &Series) -> Result<Series> {
+    let points: Vec<Option<Geometry<f64>>> = iter_geom_opt(series)
+        .map(|maybe_g| match maybe_g {
+            Some(Geometry::Polygon(polygon)) => Some(label_point_polygon(&polygon, 1.0).into()),
+            _ => None,
+        })
+        .collect();
+
+    from_geom_vec_opt(&points)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{label_point, label_point_series};
+    use crate::util::{from_geom_vec, iter_geom_opt};
+    use geo::{polygon, Geometry};
+    use geopolars_arrow::{GeometryArray, GeometryArrayTrait, PolygonArray};
+
+    #[test]
+    fn label_point_is_inside_a_concave_polygon() {
+        // A "C" shape whose centroid falls outside it, but whose pole of inaccessibility must
+        // still land inside one of the two arms.
+        let input_geom = polygon![
+            (x: 0.0, y: 0.0),
+            (x: 10.0, y: 0.0),
+            (x: 10.0, y: 2.0),
+            (x: 2.0, y: 2.0),
+            (x: 2.0, y: 8.0),
+            (x: 10.0, y: 8.0),
+            (x: 10.0, y: 10.0),
+            (x: 0.0, y: 10.0),
+        ];
+
+        let input_array: PolygonArray = vec![input_geom.clone()].into();
+        let result_array = label_point(GeometryArray::Polygon(input_array), 0.1).unwrap();
+
+        let point = result_array.get_as_geo(0).unwrap();
+        assert!(
+            geo::algorithm::contains::Contains::contains(&input_geom, &point),
+            "the pole of inaccessibility should lie inside the polygon"
+        );
+    }
+
+    #[test]
+    fn label_point_is_null_for_non_polygon_rows() {
+        use geo::Point;
+        use geopolars_arrow::MultiPointArray;
+
+        let input_array: MultiPointArray = vec![geo::MultiPoint(vec![Point::new(0.0, 0.0)])].into();
+        let result_array = label_point(GeometryArray::MultiPoint(input_array), 0.1).unwrap();
+
+        assert!(result_array.get_as_geo(0).is_none());
+    }
+
+    #[test]
+    fn label_point_series_decodes_wkb_polygons() {
+        let input_geom = polygon![
+            (x: 0.0, y: 0.0),
+            (x: 10.0, y: 0.0),
+            (x: 10.0, y: 10.0),
+            (x: 0.0, y: 10.0),
+        ];
+
+        let series = from_geom_vec(&[Geometry::Polygon(input_geom.clone())]).unwrap();
+        let result = label_point_series(&series).unwrap();
+
+        let point = match iter_geom_opt(&result).next().unwrap() {
+            Some(Geometry::Point(point)) => point,
+            other => panic!("expected a labelled point, got {other:?}"),
+        };
+        assert!(
+            geo::algorithm::contains::Contains::contains(&input_geom, &point),
+            "the pole of inaccessibility should lie inside the polygon"
+        );
+    }
+
+    #[test]
+    fn label_point_series_is_null_for_non_polygon_rows() {
+        use geo::Point;
+
+        let series = from_geom_vec(&[Geometry::Point(Point::new(0.0, 0.0))]).unwrap();
+        let result = label_point_series(&series).unwrap();
+
+        assert!(iter_geom_opt(&result).next().unwrap().is_none());
+    }
+}
@@ -5,11 +5,9 @@ use geo::algorithm::haversine_length::HaversineLength;
 use geo::algorithm::vincenty_length::VincentyLength;
 use geo::Geometry;
 use geoarrow::{GeometryArray, GeometryArrayTrait};
-use polars::error::ErrString;
 use polars::export::arrow::array::{MutablePrimitiveArray, PrimitiveArray};
 use polars::export::arrow::bitmap::Bitmap;
 use polars::export::arrow::datatypes::DataType as ArrowDataType;
-use polars::prelude::PolarsError;
 
 pub enum GeodesicLengthMethod {
     Haversine,
@@ -17,45 +15,63 @@ pub enum GeodesicLengthMethod {
     Vincenty,
 }
 
-pub(crate) fn euclidean_length(array: GeometryArray) -> Result<PrimitiveArray<f64>> {
-    let mut output_array = MutablePrimitiveArray::<f64>::with_capacity(array.len());
-
-    match array {
-        GeometryArray::WKB(arr) => {
-            arr.iter_geo()
-                .for_each(|maybe_g| output_array.push(maybe_g.map(geometry_euclidean_length)));
-        }
-        GeometryArray::Point(arr) => {
-            return Ok(zero_arr(arr.len(), arr.validity()));
-        }
-        GeometryArray::LineString(arr) => {
-            arr.iter_geo()
-                .for_each(|maybe_g| output_array.push(maybe_g.map(|g| g.euclidean_length())));
-        }
-        GeometryArray::Polygon(arr) => {
-            arr.iter_geo().for_each(|maybe_g| {
-                output_array.push(maybe_g.map(|g| g.exterior().euclidean_length()))
-            });
-        }
-        GeometryArray::MultiPoint(arr) => {
-            return Ok(zero_arr(arr.len(), arr.validity()));
-        }
-        GeometryArray::MultiLineString(arr) => {
-            arr.iter_geo()
-                .for_each(|maybe_g| output_array.push(maybe_g.map(|g| g.euclidean_length())));
-        }
-        GeometryArray::MultiPolygon(arr) => {
-            arr.iter_geo().for_each(|maybe_g| {
-                output_array.push(maybe_g.map(|g| {
-                    g.iter()
-                        .map(|poly| poly.exterior().euclidean_length())
-                        .sum()
-                }))
-            });
-        }
-    }
+/// Expands to the seven-arm `GeometryArray` variant dispatch shared by every per-row length
+/// kernel below: the `WKB` arm decodes each row through `$wkb_fn`, `Point`/`MultiPoint` rows are
+/// zero-length without ever inspecting their coordinates (true of every length kernel in this
+/// file), and every other variant applies its matching closure to each row via `iter_geo()`.
+/// Every closure returns `Option<f64>`, so a fallible algorithm (Vincenty) and infallible ones
+/// (Euclidean, Haversine, geodesic) share the same `push` path instead of diverging per kernel.
+macro_rules! geometry_length_dispatch {
+    (
+        $array:expr,
+        wkb: $wkb_fn:expr,
+        line_string: $line_string_fn:expr,
+        polygon: $polygon_fn:expr,
+        multi_line_string: $multi_line_string_fn:expr,
+        multi_polygon: $multi_polygon_fn:expr $(,)?
+    ) => {{
+        let array = $array;
+        let mut output_array = MutablePrimitiveArray::<f64>::with_capacity(array.len());
+
+        match array {
+            GeometryArray::WKB(arr) => {
+                arr.iter_geo()
+                    .for_each(|maybe_g| output_array.push(maybe_g.and_then($wkb_fn)));
+            }
+            GeometryArray::Point(arr) => return Ok(zero_arr(arr.len(), arr.validity())),
+            GeometryArray::LineString(arr) => {
+                arr.iter_geo()
+                    .for_each(|maybe_g| output_array.push(maybe_g.and_then($line_string_fn)));
+            }
+            GeometryArray::Polygon(arr) => {
+                arr.iter_geo()
+                    .for_each(|maybe_g| output_array.push(maybe_g.and_then($polygon_fn)));
+            }
+            GeometryArray::MultiPoint(arr) => return Ok(zero_arr(arr.len(), arr.validity())),
+            GeometryArray::MultiLineString(arr) => {
+                arr.iter_geo().for_each(|maybe_g| {
+                    output_array.push(maybe_g.and_then($multi_line_string_fn))
+                });
+            }
+            GeometryArray::MultiPolygon(arr) => {
+                arr.iter_geo()
+                    .for_each(|maybe_g| output_array.push(maybe_g.and_then($multi_polygon_fn)));
+            }
+        }
+
+        Ok(output_array.into())
+    }};
+}
 
-    Ok(output_array.into())
+pub(crate) fn euclidean_length(array: GeometryArray) -> Result<PrimitiveArray<f64>> {
+    geometry_length_dispatch!(
+        array,
+        wkb: |g| Some(geometry_euclidean_length(g)),
+        line_string: |g| Some(g.euclidean_length()),
+        polygon: |g| Some(g.exterior().euclidean_length()),
+        multi_line_string: |g| Some(g.euclidean_length()),
+        multi_polygon: |g| Some(g.iter().map(|poly| poly.exterior().euclidean_length()).sum()),
+    )
 }
 
 pub(crate) fn geodesic_length(
@@ -70,140 +86,41 @@ pub(crate) fn geodesic_length(
 }
 
 fn _geodesic_length(array: GeometryArray) -> Result<PrimitiveArray<f64>> {
-    let mut output_array = MutablePrimitiveArray::<f64>::with_capacity(array.len());
-
-    match array {
-        GeometryArray::WKB(arr) => {
-            arr.iter_geo()
-                .for_each(|maybe_g| output_array.push(maybe_g.map(geometry_geodesic_length)));
-        }
-        GeometryArray::Point(arr) => {
-            return Ok(zero_arr(arr.len(), arr.validity()));
-        }
-        GeometryArray::LineString(arr) => {
-            arr.iter_geo()
-                .for_each(|maybe_g| output_array.push(maybe_g.map(|g| g.geodesic_length())));
-        }
-        GeometryArray::Polygon(arr) => {
-            arr.iter_geo().for_each(|maybe_g| {
-                output_array.push(maybe_g.map(|g| g.exterior().geodesic_length()))
-            });
-        }
-        GeometryArray::MultiPoint(arr) => {
-            return Ok(zero_arr(arr.len(), arr.validity()));
-        }
-        GeometryArray::MultiLineString(arr) => {
-            arr.iter_geo()
-                .for_each(|maybe_g| output_array.push(maybe_g.map(|g| g.geodesic_length())));
-        }
-        GeometryArray::MultiPolygon(arr) => {
-            arr.iter_geo().for_each(|maybe_g| {
-                output_array.push(
-                    maybe_g.map(|g| g.iter().map(|poly| poly.exterior().geodesic_length()).sum()),
-                )
-            });
-        }
-    }
-
-    Ok(output_array.into())
+    geometry_length_dispatch!(
+        array,
+        wkb: |g| Some(geometry_geodesic_length(g)),
+        line_string: |g| Some(g.geodesic_length()),
+        polygon: |g| Some(g.exterior().geodesic_length()),
+        multi_line_string: |g| Some(g.geodesic_length()),
+        multi_polygon: |g| Some(g.iter().map(|poly| poly.exterior().geodesic_length()).sum()),
+    )
 }
 
 fn haversine_length(array: GeometryArray) -> Result<PrimitiveArray<f64>> {
-    let mut output_array = MutablePrimitiveArray::<f64>::with_capacity(array.len());
-
-    match array {
-        GeometryArray::WKB(arr) => {
-            arr.iter_geo()
-                .for_each(|maybe_g| output_array.push(maybe_g.map(geometry_haversine_length)));
-        }
-        GeometryArray::Point(arr) => {
-            return Ok(zero_arr(arr.len(), arr.validity()));
-        }
-        GeometryArray::LineString(arr) => {
-            arr.iter_geo()
-                .for_each(|maybe_g| output_array.push(maybe_g.map(|g| g.haversine_length())));
-        }
-        GeometryArray::Polygon(arr) => {
-            arr.iter_geo().for_each(|maybe_g| {
-                output_array.push(maybe_g.map(|g| g.exterior().haversine_length()))
-            });
-        }
-        GeometryArray::MultiPoint(arr) => {
-            return Ok(zero_arr(arr.len(), arr.validity()));
-        }
-        GeometryArray::MultiLineString(arr) => {
-            arr.iter_geo()
-                .for_each(|maybe_g| output_array.push(maybe_g.map(|g| g.haversine_length())));
-        }
-        GeometryArray::MultiPolygon(arr) => {
-            arr.iter_geo().for_each(|maybe_g| {
-                output_array.push(maybe_g.map(|g| {
-                    g.iter()
-                        .map(|poly| poly.exterior().haversine_length())
-                        .sum()
-                }))
-            });
-        }
-    }
-
-    Ok(output_array.into())
+    geometry_length_dispatch!(
+        array,
+        wkb: |g| Some(geometry_haversine_length(g)),
+        line_string: |g| Some(g.haversine_length()),
+        polygon: |g| Some(g.exterior().haversine_length()),
+        multi_line_string: |g| Some(g.haversine_length()),
+        multi_polygon: |g| Some(g.iter().map(|poly| poly.exterior().haversine_length()).sum()),
+    )
 }
 
+/// Computes Vincenty length per row, pushing a null instead of panicking when a row's
+/// computation fails to converge (e.g. a nearly-antipodal segment pair).
 fn vincenty_length(array: GeometryArray) -> Result<PrimitiveArray<f64>> {
-    let mut output_array = MutablePrimitiveArray::<f64>::with_capacity(array.len());
-    let map_vincenty_error =
-        |_| PolarsError::ComputeError(ErrString::from("Failed to calculate vincenty length"));
-
-    match array {
-        GeometryArray::WKB(arr) => {
-            arr.iter_geo()
-                .for_each(|maybe_g| output_array.push(maybe_g.map(geometry_vincenty_length)));
-        }
-        GeometryArray::Point(arr) => {
-            return Ok(zero_arr(arr.len(), arr.validity()));
-        }
-        GeometryArray::LineString(arr) => {
-            arr.iter_geo().for_each(|maybe_g| {
-                output_array
-                    .push(maybe_g.map(|g| g.vincenty_length().map_err(map_vincenty_error).unwrap()))
-            });
-        }
-        GeometryArray::Polygon(arr) => {
-            arr.iter_geo().for_each(|maybe_g| {
-                output_array.push(maybe_g.map(|g| {
-                    g.exterior()
-                        .vincenty_length()
-                        .map_err(map_vincenty_error)
-                        .unwrap()
-                }))
-            });
-        }
-        GeometryArray::MultiPoint(arr) => {
-            return Ok(zero_arr(arr.len(), arr.validity()));
-        }
-        GeometryArray::MultiLineString(arr) => {
-            arr.iter_geo().for_each(|maybe_g| {
-                output_array
-                    .push(maybe_g.map(|g| g.vincenty_length().map_err(map_vincenty_error).unwrap()))
-            });
-        }
-        GeometryArray::MultiPolygon(arr) => {
-            arr.iter_geo().for_each(|maybe_g| {
-                output_array.push(maybe_g.map(|g| {
-                    g.iter()
-                        .map(|poly| {
-                            poly.exterior()
-                                .vincenty_length()
-                                .map_err(map_vincenty_error)
-                                .unwrap()
-                        })
-                        .sum()
-                }))
-            });
-        }
-    }
-
-    Ok(output_array.into())
+    geometry_length_dispatch!(
+        array,
+        wkb: geometry_vincenty_length,
+        line_string: |g| g.vincenty_length().ok(),
+        polygon: |g| g.exterior().vincenty_length().ok(),
+        multi_line_string: |g| g.vincenty_length().ok(),
+        multi_polygon: |g| g
+            .iter()
+            .map(|poly| poly.exterior().vincenty_length().ok())
+            .sum::<Option<f64>>(),
+    )
 }
 
 /// Create a Float64Array with given length and validity
@@ -227,9 +144,10 @@ fn geometry_euclidean_length(geom: Geometry) -> f64 {
             .iter()
             .map(|poly| poly.exterior().euclidean_length())
             .sum(),
-        Geometry::GeometryCollection(_) => {
-            panic!("Length methods are not implemented for geometry collection")
-        }
+        Geometry::GeometryCollection(collection) => collection
+            .into_iter()
+            .map(geometry_euclidean_length)
+            .sum(),
         Geometry::Rect(rec) => rec.to_polygon().exterior().euclidean_length(),
         Geometry::Triangle(triangle) => triangle.to_polygon().exterior().euclidean_length(),
     }
@@ -247,8 +165,8 @@ fn geometry_geodesic_length(geom: Geometry) -> f64 {
             .iter()
             .map(|poly| poly.exterior().geodesic_length())
             .sum(),
-        Geometry::GeometryCollection(_) => {
-            panic!("Length methods are not implemented for geometry collection")
+        Geometry::GeometryCollection(collection) => {
+            collection.into_iter().map(geometry_geodesic_length).sum()
         }
         Geometry::Rect(rec) => rec.to_polygon().exterior().geodesic_length(),
         Geometry::Triangle(triangle) => triangle.to_polygon().exterior().geodesic_length(),
@@ -267,59 +185,35 @@ fn geometry_haversine_length(geom: Geometry) -> f64 {
             .iter()
             .map(|poly| poly.exterior().haversine_length())
             .sum(),
-        Geometry::GeometryCollection(_) => {
-            panic!("Length methods are not implemented for geometry collection")
+        Geometry::GeometryCollection(collection) => {
+            collection.into_iter().map(geometry_haversine_length).sum()
         }
         Geometry::Rect(rec) => rec.to_polygon().exterior().haversine_length(),
         Geometry::Triangle(triangle) => triangle.to_polygon().exterior().haversine_length(),
     }
 }
 
-fn geometry_vincenty_length(geom: Geometry) -> f64 {
-    let map_vincenty_error =
-        |_| PolarsError::ComputeError(ErrString::from("Failed to calculate vincenty length"));
-
+/// Computes a single geometry's Vincenty length, returning `None` instead of panicking when
+/// the computation fails to converge (e.g. a nearly-antipodal segment pair). For a
+/// `GeometryCollection`, any member that fails to converge makes the whole collection's length
+/// `None` rather than silently dropping that member's contribution.
+fn geometry_vincenty_length(geom: Geometry) -> Option<f64> {
     match geom {
-        Geometry::Point(_) => 0.0,
-        Geometry::Line(line) => line.vincenty_length().map_err(map_vincenty_error).unwrap(),
-        Geometry::LineString(line_string) => line_string
-            .vincenty_length()
-            .map_err(map_vincenty_error)
-            .unwrap(),
-        Geometry::Polygon(polygon) => polygon
-            .exterior()
-            .vincenty_length()
-            .map_err(map_vincenty_error)
-            .unwrap(),
-        Geometry::MultiPoint(_) => 0.0,
-        Geometry::MultiLineString(multi_line_string) => multi_line_string
-            .vincenty_length()
-            .map_err(map_vincenty_error)
-            .unwrap(),
+        Geometry::Point(_) => Some(0.0),
+        Geometry::Line(line) => line.vincenty_length().ok(),
+        Geometry::LineString(line_string) => line_string.vincenty_length().ok(),
+        Geometry::Polygon(polygon) => polygon.exterior().vincenty_length().ok(),
+        Geometry::MultiPoint(_) => Some(0.0),
+        Geometry::MultiLineString(multi_line_string) => multi_line_string.vincenty_length().ok(),
         Geometry::MultiPolygon(mutli_polygon) => mutli_polygon
             .iter()
-            .map(|poly| {
-                poly.exterior()
-                    .vincenty_length()
-                    .map_err(map_vincenty_error)
-                    .unwrap()
-            })
+            .map(|poly| poly.exterior().vincenty_length().ok())
             .sum(),
-        Geometry::GeometryCollection(_) => {
-            panic!("Length methods are not implemented for geometry collection")
+        Geometry::GeometryCollection(collection) => {
+            collection.into_iter().map(geometry_vincenty_length).sum()
         }
-        Geometry::Rect(rec) => rec
-            .to_polygon()
-            .exterior()
-            .vincenty_length()
-            .map_err(map_vincenty_error)
-            .unwrap(),
-        Geometry::Triangle(triangle) => triangle
-            .to_polygon()
-            .exterior()
-            .vincenty_length()
-            .map_err(map_vincenty_error)
-            .unwrap(),
+        Geometry::Rect(rec) => rec.to_polygon().exterior().vincenty_length().ok(),
+        Geometry::Triangle(triangle) => triangle.to_polygon().exterior().vincenty_length().ok(),
     }
 }
 
@@ -453,6 +347,41 @@ mod tests {
         assert!(result_array.is_valid(0));
     }
 
+    #[test]
+    fn vincenty_length_wkb_is_null_when_non_convergent() {
+        // Nearly-antipodal points: Vincenty's inverse formula is known not to converge for pairs
+        // this close to exactly antipodal, so this must come back null rather than panicking.
+        let input_geom: Geometry = line_string![
+            (x: 0.0, y: 0.0),
+            (x: 179.5, y: 0.5),
+        ]
+        .into();
+        let input_array: WKBArray = vec![Some(input_geom)].into();
+        let result_array = geodesic_length(
+            GeometryArray::WKB(input_array),
+            &GeodesicLengthMethod::Vincenty,
+        )
+        .unwrap();
+
+        assert!(!result_array.is_valid(0));
+    }
+
+    #[test]
+    fn vincenty_length_geoarrow_is_null_when_non_convergent() {
+        let input_geom = line_string![
+            (x: 0.0, y: 0.0),
+            (x: 179.5, y: 0.5),
+        ];
+        let input_array: LineStringArray = vec![input_geom].into();
+        let result_array = geodesic_length(
+            GeometryArray::LineString(input_array),
+            &GeodesicLengthMethod::Vincenty,
+        )
+        .unwrap();
+
+        assert!(!result_array.is_valid(0));
+    }
+
     #[test]
     fn geodesic_length_wkb() {
         let input_geom: Geometry = line_string![
@@ -4,6 +4,16 @@ use geopolars_arrow::{GeometryArray, GeometryArrayTrait};
 use polars::export::arrow::array::{MutablePrimitiveArray, PrimitiveArray};
 use polars::export::arrow::datatypes::DataType;
 
+/// Picks out a point's coordinate from a decoded WKB geometry, or `None` for any other geometry
+/// type, so a mixed-geometry `WKB` column degrades to nulls rather than panicking the way a
+/// column of `Point`-typed geoarrow geometries structurally cannot.
+fn wkb_coord(geom: Geometry, coord: impl Fn(geo::Point) -> f64) -> Option<f64> {
+    match geom {
+        Geometry::Point(pt) => Some(coord(pt)),
+        _ => None,
+    }
+}
+
 pub(crate) fn x(array: GeometryArray) -> Result<PrimitiveArray<f64>> {
     match array {
         GeometryArray::Point(arr) => Ok(PrimitiveArray::<f64>::new(
@@ -14,11 +24,7 @@ pub(crate) fn x(array: GeometryArray) -> Result<PrimitiveArray<f64>> {
         GeometryArray::WKB(arr) => {
             let mut output_arr = MutablePrimitiveArray::<f64>::with_capacity(arr.len());
             arr.iter_geo().for_each(|maybe_geom| {
-                let maybe_point = maybe_geom.map(|geom| match geom {
-                    Geometry::Point(pt) => pt,
-                    _ => panic!("x only implemented for points"),
-                });
-                output_arr.push(maybe_point.map(|pt| pt.x()))
+                output_arr.push(maybe_geom.and_then(|geom| wkb_coord(geom, |pt| pt.x())))
             });
             Ok(output_arr.into())
         }
@@ -36,11 +42,7 @@ pub(crate) fn y(array: GeometryArray) -> Result<PrimitiveArray<f64>> {
         GeometryArray::WKB(arr) => {
             let mut output_arr = MutablePrimitiveArray::<f64>::with_capacity(arr.len());
             arr.iter_geo().for_each(|maybe_geom| {
-                let maybe_point = maybe_geom.map(|geom| match geom {
-                    Geometry::Point(pt) => pt,
-                    _ => panic!("x only implemented for points"),
-                });
-                output_arr.push(maybe_point.map(|pt| pt.y()))
+                output_arr.push(maybe_geom.and_then(|geom| wkb_coord(geom, |pt| pt.y())))
             });
             Ok(output_arr.into())
         }
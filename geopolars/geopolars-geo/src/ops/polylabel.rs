@@ -0,0 +1,239 @@
+use crate::error::Result;
+use crate::util::{from_geom_vec_opt, iter_geom};
+use geo::{BoundingRect, Coord, Geometry, LineString, Point, Polygon};
+use polars::prelude::Series;
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+/// Returns a GeoSeries of points representing the pole of inaccessibility (visual center) of
+/// each polygon, via the polylabel algorithm. Non-polygon rows are emitted as null.
+pub(crate) fn representative_point(series: &Series) -> Result<Series> {
+    representative_point_with_tolerance(series, 1.0)
+}
+
+/// Like [`representative_point`], but lets the caller choose the polylabel search tolerance.
+pub(crate) fn representative_point_with_tolerance(
+    series: &Series,
+    tolerance: f64,
+) -> Result<Series> {
+    let points: Vec<Option<Geometry<f64>>> = iter_geom(series)
+        .map(|geom| match geom {
+            Geometry::Polygon(polygon) => Some(Point::from(polylabel(&polygon, tolerance)).into()),
+            Geometry::MultiPolygon(multi_polygon) => {
+                multi_polygon_polylabel(&multi_polygon, tolerance).map(|coord| Point::from(coord).into())
+            }
+            _ => None,
+        })
+        .collect();
+
+    from_geom_vec_opt(&points)
+}
+
+/// Runs [`polylabel`] on each polygon of `multi_polygon` and keeps the result farthest from its
+/// own polygon's boundary, matching GeoPandas' handling of `representative_point`/`polylabel` on
+/// multi-part geometries.
+fn multi_polygon_polylabel(
+    multi_polygon: &geo::MultiPolygon<f64>,
+    tolerance: f64,
+) -> Option<Coord<f64>> {
+    multi_polygon
+        .0
+        .iter()
+        .map(|polygon| {
+            let coord = polylabel(polygon, tolerance);
+            let dist = signed_distance(polygon, coord.x, coord.y);
+            (coord, dist)
+        })
+        .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap())
+        .map(|(coord, _)| coord)
+}
+
+/// A candidate square cell, covering `[x - h, x + h] x [y - h, y + h]`.
+struct Cell {
+    x: f64,
+    y: f64,
+    h: f64,
+    /// Signed distance from the cell's center to the polygon boundary (positive inside).
+    dist: f64,
+    /// An upper bound on the distance any point within this cell could have to the boundary.
+    max_dist: f64,
+}
+
+impl Cell {
+    fn new(x: f64, y: f64, h: f64, polygon: &Polygon<f64>) -> Self {
+        let dist = signed_distance(polygon, x, y);
+        Cell {
+            x,
+            y,
+            h,
+            dist,
+            max_dist: dist + h * std::f64::consts::SQRT_2,
+        }
+    }
+}
+
+impl PartialEq for Cell {
+    fn eq(&self, other: &Self) -> bool {
+        self.max_dist == other.max_dist
+    }
+}
+impl Eq for Cell {}
+impl PartialOrd for Cell {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for Cell {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.max_dist.partial_cmp(&other.max_dist).unwrap()
+    }
+}
+
+/// The polylabel algorithm: find the point inside `polygon` that is farthest from any edge,
+/// to within `precision`.
+///
+/// Covers the polygon's bounding box with square cells, and repeatedly subdivides the most
+/// promising cell (per its `max_dist` upper bound) until no remaining cell could possibly beat
+/// the best point found so far by more than `precision`.
+fn polylabel(polygon: &Polygon<f64>, precision: f64) -> Coord<f64> {
+    let bbox = polygon
+        .bounding_rect()
+        .expect("a polygon must have a non-empty bounding box");
+    let width = bbox.width();
+    let height = bbox.height();
+    let cell_size = width.min(height);
+
+    if cell_size == 0.0 {
+        return bbox.min();
+    }
+
+    let mut h = cell_size / 2.0;
+    let mut heap = BinaryHeap::new();
+
+    let mut x = bbox.min().x;
+    while x < bbox.max().x {
+        let mut y = bbox.min().y;
+        while y < bbox.max().y {
+            heap.push(Cell::new(x + h, y + h, h, polygon));
+            y += cell_size;
+        }
+        x += cell_size;
+    }
+
+    let centroid = polygon.exterior().0.iter().fold(Coord { x: 0.0, y: 0.0 }, |acc, c| {
+        Coord {
+            x: acc.x + c.x,
+            y: acc.y + c.y,
+        }
+    });
+    let n = polygon.exterior().0.len().max(1) as f64;
+    let centroid_cell = Cell::new(centroid.x / n, centroid.y / n, 0.0, polygon);
+    let bbox_center_cell = Cell::new(
+        bbox.min().x + width / 2.0,
+        bbox.min().y + height / 2.0,
+        0.0,
+        polygon,
+    );
+
+    let mut best = if centroid_cell.dist > bbox_center_cell.dist {
+        centroid_cell
+    } else {
+        bbox_center_cell
+    };
+
+    while let Some(cell) = heap.pop() {
+        if cell.dist > best.dist {
+            best = Cell::new(cell.x, cell.y, cell.h, polygon);
+        }
+
+        if cell.max_dist - best.dist <= precision {
+            continue;
+        }
+
+        h = cell.h / 2.0;
+        heap.push(Cell::new(cell.x - h, cell.y - h, h, polygon));
+        heap.push(Cell::new(cell.x + h, cell.y - h, h, polygon));
+        heap.push(Cell::new(cell.x - h, cell.y + h, h, polygon));
+        heap.push(Cell::new(cell.x + h, cell.y + h, h, polygon));
+    }
+
+    Coord {
+        x: best.x,
+        y: best.y,
+    }
+}
+
+/// The distance from `(x, y)` to the polygon's boundary, positive when `(x, y)` is inside the
+/// polygon (accounting for holes) and negative otherwise.
+fn signed_distance(polygon: &Polygon<f64>, x: f64, y: f64) -> f64 {
+    let point = Coord { x, y };
+
+    let mut inside = false;
+    let mut min_dist = f64::INFINITY;
+
+    let mut visit_ring = |ring: &LineString<f64>| {
+        if ring_crossing(ring, point) {
+            inside = !inside;
+        }
+        min_dist = min_dist.min(distance_to_ring(ring, point));
+    };
+
+    visit_ring(polygon.exterior());
+    for interior in polygon.interiors() {
+        visit_ring(interior);
+    }
+
+    if inside {
+        min_dist
+    } else {
+        -min_dist
+    }
+}
+
+/// An even-odd ray-casting test: does a ray cast from `point` to `+x infinity` cross `ring` an
+/// odd number of times?
+fn ring_crossing(ring: &LineString<f64>, point: Coord<f64>) -> bool {
+    let coords = &ring.0;
+    let mut crossing = false;
+
+    let mut j = coords.len() - 1;
+    for i in 0..coords.len() {
+        let a = coords[i];
+        let b = coords[j];
+
+        if (a.y > point.y) != (b.y > point.y)
+            && point.x < (b.x - a.x) * (point.y - a.y) / (b.y - a.y) + a.x
+        {
+            crossing = !crossing;
+        }
+
+        j = i;
+    }
+
+    crossing
+}
+
+fn distance_to_ring(ring: &LineString<f64>, point: Coord<f64>) -> f64 {
+    let coords = &ring.0;
+    let mut min_dist = f64::INFINITY;
+
+    let mut j = coords.len() - 1;
+    for i in 0..coords.len() {
+        min_dist = min_dist.min(point_to_segment_distance(point, coords[j], coords[i]));
+        j = i;
+    }
+
+    min_dist
+}
+
+fn point_to_segment_distance(p: Coord<f64>, a: Coord<f64>, b: Coord<f64>) -> f64 {
+    let (dx, dy) = (b.x - a.x, b.y - a.y);
+
+    if dx == 0.0 && dy == 0.0 {
+        return ((p.x - a.x).powi(2) + (p.y - a.y).powi(2)).sqrt();
+    }
+
+    let t = (((p.x - a.x) * dx + (p.y - a.y) * dy) / (dx * dx + dy * dy)).clamp(0.0, 1.0);
+    let (cx, cy) = (a.x + t * dx, a.y + t * dy);
+    ((p.x - cx).powi(2) + (p.y - cy).powi(2)).sqrt()
+}
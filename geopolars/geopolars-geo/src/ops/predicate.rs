@@ -0,0 +1,279 @@
+use crate::error::Result;
+use crate::util::iter_geom;
+use geo::{Contains, Geometry, Intersects, Within};
+use geopolars_arrow::GeometryArray;
+use polars::export::arrow::array::{Array, BooleanArray, MutableBooleanArray};
+use polars::prelude::Series;
+
+/// Decodes every element of `array` to an owned `geo::Geometry`, preserving nulls.
+pub(crate) fn geoms(array: GeometryArray) -> Vec<Option<Geometry>> {
+    match array {
+        GeometryArray::WKB(arr) => arr.iter_geo().collect(),
+        GeometryArray::Point(arr) => arr.iter_geo().map(|g| g.map(Geometry::Point)).collect(),
+        GeometryArray::LineString(arr) => {
+            arr.iter_geo().map(|g| g.map(Geometry::LineString)).collect()
+        }
+        GeometryArray::Polygon(arr) => arr.iter_geo().map(|g| g.map(Geometry::Polygon)).collect(),
+        GeometryArray::MultiPoint(arr) => {
+            arr.iter_geo().map(|g| g.map(Geometry::MultiPoint)).collect()
+        }
+        GeometryArray::MultiLineString(arr) => arr
+            .iter_geo()
+            .map(|g| g.map(Geometry::MultiLineString))
+            .collect(),
+        GeometryArray::MultiPolygon(arr) => arr
+            .iter_geo()
+            .map(|g| g.map(Geometry::MultiPolygon))
+            .collect(),
+        GeometryArray::GeometryCollection(arr) => arr
+            .iter_geo()
+            .map(|g| g.map(Geometry::GeometryCollection))
+            .collect(),
+    }
+}
+
+/// Zips `left` and `right` element-wise, applying `predicate` to each pair and propagating
+/// nullness: the output is null wherever either side is null.
+///
+/// # Panics
+/// Panics if `left` and `right` don't have the same length.
+fn try_binary_boolean(
+    left: GeometryArray,
+    right: GeometryArray,
+    predicate: impl Fn(&Geometry, &Geometry) -> bool,
+) -> Result<BooleanArray> {
+    let left_geoms = geoms(left);
+    let right_geoms = geoms(right);
+    assert_eq!(
+        left_geoms.len(),
+        right_geoms.len(),
+        "left and right geometry arrays must have the same length"
+    );
+
+    let mut output = MutableBooleanArray::with_capacity(left_geoms.len());
+    for (l, r) in left_geoms.into_iter().zip(right_geoms) {
+        output.push(match (l, r) {
+            (Some(l), Some(r)) => Some(predicate(&l, &r)),
+            _ => None,
+        });
+    }
+
+    Ok(output.into())
+}
+
+/// Applies `predicate` between every geometry in `array` and the single scalar `geom`,
+/// broadcasting it across every row and propagating `array`'s nulls.
+fn try_binary_boolean_scalar(
+    array: GeometryArray,
+    geom: &Geometry,
+    predicate: impl Fn(&Geometry, &Geometry) -> bool,
+) -> Result<BooleanArray> {
+    let array_geoms = geoms(array);
+
+    let mut output = MutableBooleanArray::with_capacity(array_geoms.len());
+    for maybe_geom in array_geoms {
+        output.push(maybe_geom.map(|g| predicate(&g, geom)));
+    }
+
+    Ok(output.into())
+}
+
+/// Returns, for each row, whether the geometry in `left` intersects the geometry in `right`.
+pub(crate) fn intersects(left: GeometryArray, right: GeometryArray) -> Result<BooleanArray> {
+    try_binary_boolean(left, right, |l, r| l.intersects(r))
+}
+
+/// Returns, for each row in `array`, whether the geometry intersects the scalar `geom`.
+pub(crate) fn intersects_scalar(array: GeometryArray, geom: &Geometry) -> Result<BooleanArray> {
+    try_binary_boolean_scalar(array, geom, |l, r| l.intersects(r))
+}
+
+/// Returns, for each row, whether the geometry in `left` contains the geometry in `right`.
+pub(crate) fn contains(left: GeometryArray, right: GeometryArray) -> Result<BooleanArray> {
+    try_binary_boolean(left, right, |l, r| l.contains(r))
+}
+
+/// Returns, for each row in `array`, whether the geometry contains the scalar `geom`.
+pub(crate) fn contains_scalar(array: GeometryArray, geom: &Geometry) -> Result<BooleanArray> {
+    try_binary_boolean_scalar(array, geom, |l, r| l.contains(r))
+}
+
+/// Returns, for each row, whether the geometry in `left` is within the geometry in `right`.
+pub(crate) fn is_within(left: GeometryArray, right: GeometryArray) -> Result<BooleanArray> {
+    try_binary_boolean(left, right, |l, r| l.is_within(r))
+}
+
+/// Returns, for each row in `array`, whether the geometry is within the scalar `geom`.
+pub(crate) fn is_within_scalar(array: GeometryArray, geom: &Geometry) -> Result<BooleanArray> {
+    try_binary_boolean_scalar(array, geom, |l, r| l.is_within(r))
+}
+
+/// Returns, for each row, whether the geometry in `left` is disjoint from the geometry in
+/// `right` (they share no points).
+pub(crate) fn disjoint(left: GeometryArray, right: GeometryArray) -> Result<BooleanArray> {
+    try_binary_boolean(left, right, |l, r| !l.intersects(r))
+}
+
+/// Returns, for each row in `array`, whether the geometry is disjoint from the scalar `geom`.
+pub(crate) fn disjoint_scalar(array: GeometryArray, geom: &Geometry) -> Result<BooleanArray> {
+    try_binary_boolean_scalar(array, geom, |l, r| !l.intersects(r))
+}
+
+/// Row-wise wrapper around a [`GeometryArray`]-level predicate, for the
+/// [`GeoSeries`](crate::geoseries::GeoSeries) methods that take the aligned `other` Series
+/// directly rather than a [`GeometryArray`].
+///
+/// Unlike [`try_binary_boolean`], this decodes each side through [`iter_geom`], matching how
+/// [`crate::ops::distance::distance`] and its siblings read their Series arguments; a null row
+/// on either side panics the same way [`iter_geom`] does elsewhere in this crate.
+fn series_predicate(
+    left: &Series,
+    right: &Series,
+    predicate: impl Fn(&Geometry, &Geometry) -> bool,
+) -> Result<Series> {
+    let mut output = MutableBooleanArray::with_capacity(left.len());
+    for (left_geom, right_geom) in iter_geom(left).zip(iter_geom(right)) {
+        output.push(Some(predicate(&left_geom, &right_geom)));
+    }
+
+    let array: BooleanArray = output.into();
+    let series = Series::try_from(("predicate", Box::new(array) as Box<dyn Array>))?;
+    Ok(series)
+}
+
+/// Returns, for each row, whether the geometry in `left` intersects the geometry in `right`.
+pub(crate) fn intersects_series(left: &Series, right: &Series) -> Result<Series> {
+    series_predicate(left, right, |l, r| l.intersects(r))
+}
+
+/// Returns, for each row, whether the geometry in `left` contains the geometry in `right`.
+pub(crate) fn contains_series(left: &Series, right: &Series) -> Result<Series> {
+    series_predicate(left, right, |l, r| l.contains(r))
+}
+
+/// Returns, for each row, whether the geometry in `left` is within the geometry in `right`.
+pub(crate) fn within_series(left: &Series, right: &Series) -> Result<Series> {
+    series_predicate(left, right, |l, r| l.is_within(r))
+}
+
+/// Returns, for each row, whether the geometry in `left` is disjoint from the geometry in
+/// `right`.
+pub(crate) fn disjoint_series(left: &Series, right: &Series) -> Result<Series> {
+    series_predicate(left, right, |l, r| !l.intersects(r))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{contains, disjoint, intersects, intersects_scalar, is_within};
+    use geo::{polygon, Geometry, Point};
+    use geopolars_arrow::polygon::MutablePolygonArray;
+    use geopolars_arrow::{GeometryArray, GeometryArrayTrait, PointArray, PolygonArray};
+    use polars::export::arrow::array::Array;
+
+    fn square() -> geo::Polygon {
+        polygon![
+            (x: 0., y: 0.),
+            (x: 4., y: 0.),
+            (x: 4., y: 4.),
+            (x: 0., y: 4.),
+            (x: 0., y: 0.),
+        ]
+    }
+
+    fn polygon_array(polygons: Vec<geo::Polygon>) -> PolygonArray {
+        let mut_arr: MutablePolygonArray = polygons.into();
+        let arr = mut_arr.into_arrow();
+        arr.try_into().unwrap()
+    }
+
+    fn point_array(points: Vec<geo::Point>) -> PointArray {
+        points.into()
+    }
+
+    #[test]
+    fn intersects_for_overlapping_and_disjoint_polygons() {
+        let left = polygon_array(vec![square(), square()]);
+        let right = polygon_array(vec![
+            square(),
+            polygon![
+                (x: 10., y: 10.),
+                (x: 14., y: 10.),
+                (x: 14., y: 14.),
+                (x: 10., y: 14.),
+                (x: 10., y: 10.),
+            ],
+        ]);
+
+        let result =
+            intersects(GeometryArray::Polygon(left), GeometryArray::Polygon(right)).unwrap();
+        assert!(result.value(0));
+        assert!(!result.value(1));
+    }
+
+    #[test]
+    fn contains_point_in_polygon() {
+        let left = polygon_array(vec![square()]);
+        let right = point_array(vec![Point::new(2., 2.)]);
+
+        let result = contains(GeometryArray::Polygon(left), GeometryArray::Point(right)).unwrap();
+        assert!(result.value(0));
+    }
+
+    #[test]
+    fn is_within_point_in_polygon() {
+        let left = point_array(vec![Point::new(2., 2.)]);
+        let right = polygon_array(vec![square()]);
+
+        let result =
+            is_within(GeometryArray::Point(left), GeometryArray::Polygon(right)).unwrap();
+        assert!(result.value(0));
+    }
+
+    #[test]
+    fn intersects_scalar_broadcasts_across_every_row() {
+        let left = polygon_array(vec![
+            square(),
+            polygon![
+                (x: 10., y: 10.),
+                (x: 14., y: 10.),
+                (x: 14., y: 14.),
+                (x: 10., y: 14.),
+                (x: 10., y: 10.),
+            ],
+        ]);
+        let scalar = Geometry::Point(Point::new(2., 2.));
+
+        let result = intersects_scalar(GeometryArray::Polygon(left), &scalar).unwrap();
+        assert!(result.value(0));
+        assert!(!result.value(1));
+    }
+
+    #[test]
+    fn disjoint_for_overlapping_and_disjoint_polygons() {
+        let left = polygon_array(vec![square(), square()]);
+        let right = polygon_array(vec![
+            square(),
+            polygon![
+                (x: 10., y: 10.),
+                (x: 14., y: 10.),
+                (x: 14., y: 14.),
+                (x: 10., y: 14.),
+                (x: 10., y: 10.),
+            ],
+        ]);
+
+        let result =
+            disjoint(GeometryArray::Polygon(left), GeometryArray::Polygon(right)).unwrap();
+        assert!(!result.value(0));
+        assert!(result.value(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "same length")]
+    fn mismatched_lengths_panics() {
+        let left = polygon_array(vec![square()]);
+        let right = polygon_array(vec![square(), square()]);
+
+        intersects(GeometryArray::Polygon(left), GeometryArray::Polygon(right)).unwrap();
+    }
+}
@@ -0,0 +1,98 @@
+use crate::error::Result;
+use crate::ops::affine::map_coords;
+use crate::util::{from_geom_vec, iter_geom};
+use geo::Geometry;
+use geopolars_arrow::GeometryArray;
+use polars::prelude::Series;
+use proj::{ProjBuilder, Transform};
+use std::cell::RefCell;
+use std::path::PathBuf;
+
+/// Options to be passed to ProjBuilder
+/// We use a custom ProjOptions struct instead of accepting ProjBuilder so that we can more easily
+/// use multithreading in the future.
+#[derive(Default, Clone)]
+pub struct ProjOptions {
+    /// Search paths to set through PROJ
+    pub search_paths: Option<Vec<PathBuf>>,
+}
+
+impl ProjOptions {
+    pub fn to_proj_builder(&self) -> Result<ProjBuilder> {
+        let mut builder = ProjBuilder::new();
+
+        if let Some(search_paths) = &self.search_paths {
+            for search_path in search_paths {
+                builder.set_search_paths(search_path)?;
+            }
+        }
+        Ok(builder)
+    }
+}
+
+pub(crate) fn to_crs(series: &Series, from: &str, to: &str) -> Result<Series> {
+    to_crs_with_options(series, from, to, ProjOptions::default())
+}
+
+pub(crate) fn to_crs_with_options(
+    series: &Series,
+    from: &str,
+    to: &str,
+    proj_options: ProjOptions,
+) -> Result<Series> {
+    to_crs_with_options_wkb(series, from, to, proj_options)
+}
+
+fn to_crs_with_options_wkb(
+    series: &Series,
+    from: &str,
+    to: &str,
+    proj_options: ProjOptions,
+) -> Result<Series> {
+    let proj = proj_options
+        .to_proj_builder()?
+        .proj_known_crs(from, to, None)?;
+
+    let output_vec: Result<Vec<Geometry>> = iter_geom(series)
+        .map(|mut geom| {
+            geom.transform(&proj)?;
+            Ok(geom)
+        })
+        .collect();
+
+    from_geom_vec(&output_vec?)
+}
+
+/// Reprojects a native `GeometryArray` in bulk without ever materializing an intermediate
+/// `geo::Geometry`: the coordinate-buffer-backed variants (`Point`/`LineString`/`Polygon` and
+/// their `Multi*` counterparts) feed their flat x/y buffers through the `proj` transform directly
+/// via [`map_coords`], reusing the existing `geom_offsets`/`ring_offsets` untouched; only the
+/// `WKB` variant still decodes/re-encodes each row, since it has no typed coordinate buffer to
+/// transform in place.
+pub(crate) fn to_crs_array(
+    array: GeometryArray,
+    from: &str,
+    to: &str,
+    proj_options: ProjOptions,
+) -> Result<GeometryArray> {
+    let proj = proj_options
+        .to_proj_builder()?
+        .proj_known_crs(from, to, None)?;
+
+    // `map_coords`'s closure can't return a `Result`, so a failed conversion is stashed here and
+    // surfaced once the traversal finishes instead of being silently dropped.
+    let error: RefCell<Option<proj::ProjError>> = RefCell::new(None);
+
+    let result = map_coords(array, |x, y| match proj.convert((x, y)) {
+        Ok(point) => point,
+        Err(err) => {
+            *error.borrow_mut() = Some(err);
+            (x, y)
+        }
+    })?;
+
+    match error.into_inner() {
+        Some(err) => Err(err.into()),
+        None => Ok(result),
+    }
+}
@@ -0,0 +1,91 @@
+use crate::error::Result;
+use crate::util::iter_geom;
+use geo::{Coord, CoordsIter, EuclideanDistance, Geometry, Point};
+use polars::export::arrow::array::{Array, MutablePrimitiveArray, PrimitiveArray};
+use polars::prelude::Series;
+
+fn geom_coords(geom: &Geometry<f64>) -> Vec<Coord<f64>> {
+    geom.coords_iter().collect()
+}
+
+/// Discrete Fréchet distance between the vertex sequences of `p` and `q`.
+///
+/// Fills the `m x n` dynamic-programming table `ca[i][j] = max(min(ca[i-1][j], ca[i-1][j-1],
+/// ca[i][j-1]), dist(p[i], q[j]))`, iteratively rather than via the textbook recursive
+/// definition, so long polylines don't blow the call stack. `None` if either input has no
+/// vertices.
+fn frechet(p: &[Coord<f64>], q: &[Coord<f64>]) -> Option<f64> {
+    let (m, n) = (p.len(), q.len());
+    if m == 0 || n == 0 {
+        return None;
+    }
+
+    let dist = |i: usize, j: usize| Point(p[i]).euclidean_distance(&Point(q[j]));
+
+    let mut ca = vec![vec![0.0_f64; n]; m];
+    ca[0][0] = dist(0, 0);
+    for j in 1..n {
+        ca[0][j] = ca[0][j - 1].max(dist(0, j));
+    }
+    for i in 1..m {
+        ca[i][0] = ca[i - 1][0].max(dist(i, 0));
+    }
+    for i in 1..m {
+        for j in 1..n {
+            let prev_min = ca[i - 1][j].min(ca[i - 1][j - 1]).min(ca[i][j - 1]);
+            ca[i][j] = prev_min.max(dist(i, j));
+        }
+    }
+
+    Some(ca[m - 1][n - 1])
+}
+
+/// Hausdorff distance between the vertex sets of `p` and `q`: the larger of the two directed
+/// distances `sup_{a in p} inf_{b in q} dist(a, b)` and its mirror. `None` if either input has
+/// no vertices.
+fn hausdorff(p: &[Coord<f64>], q: &[Coord<f64>]) -> Option<f64> {
+    if p.is_empty() || q.is_empty() {
+        return None;
+    }
+
+    let directed = |from: &[Coord<f64>], to: &[Coord<f64>]| -> f64 {
+        from.iter()
+            .map(|&a| {
+                to.iter()
+                    .map(|&b| Point(a).euclidean_distance(&Point(b)))
+                    .fold(f64::INFINITY, f64::min)
+            })
+            .fold(0.0_f64, f64::max)
+    };
+
+    Some(directed(p, q).max(directed(q, p)))
+}
+
+/// Compute the row-wise discrete Fréchet distance between `left` and `right`.
+pub(crate) fn frechet_distance(left: &Series, right: &Series) -> Result<Series> {
+    let mut output = MutablePrimitiveArray::<f64>::with_capacity(left.len());
+
+    for (left_geom, right_geom) in iter_geom(left).zip(iter_geom(right)) {
+        output.push(frechet(&geom_coords(&left_geom), &geom_coords(&right_geom)));
+    }
+
+    let array: PrimitiveArray<f64> = output.into();
+    let series = Series::try_from(("frechet_distance", Box::new(array) as Box<dyn Array>))?;
+    Ok(series)
+}
+
+/// Compute the row-wise Hausdorff distance between `left` and `right`.
+pub(crate) fn hausdorff_distance(left: &Series, right: &Series) -> Result<Series> {
+    let mut output = MutablePrimitiveArray::<f64>::with_capacity(left.len());
+
+    for (left_geom, right_geom) in iter_geom(left).zip(iter_geom(right)) {
+        output.push(hausdorff(
+            &geom_coords(&left_geom),
+            &geom_coords(&right_geom),
+        ));
+    }
+
+    let array: PrimitiveArray<f64> = output.into();
+    let series = Series::try_from(("hausdorff_distance", Box::new(array) as Box<dyn Array>))?;
+    Ok(series)
+}
@@ -1,14 +1,42 @@
 use crate::error::Result;
 use geo::algorithm::simplify::Simplify;
-use geo::{Geometry, LineString, MultiLineString, MultiPolygon, Polygon};
+use geo::algorithm::simplifyvw::{SimplifyVW, SimplifyVWPreserve};
+use geo::{Geometry, GeometryCollection, LineString, MultiLineString, MultiPolygon, Polygon};
 use geoarrow::GeometryArray;
 
-pub(crate) fn simplify(array: GeometryArray, tolerance: &f64) -> Result<GeometryArray> {
+/// The algorithm used by [`simplify`] to reduce the number of points in a geometry.
+///
+/// `DouglasPeucker`'s `tolerance` is a distance (how far a point may stray from the simplified
+/// line); the two Visvalingam-Whyatt variants below instead treat `tolerance` as an area threshold
+/// (the effective area a point's triangle must fall under to be removed), so the same numeric
+/// value means something different depending on the chosen method.
+pub enum SimplifyMethod {
+    /// Douglas-Peucker: recursively splits the line into smaller parts and connects each part's
+    /// endpoints by a straight line, removing points whose distance to that line is smaller
+    /// than the tolerance. Never moves a point closer to the line than it would otherwise be.
+    DouglasPeucker,
+    /// Visvalingam-Whyatt: repeatedly removes the point whose triangle (formed with its two
+    /// neighbors) has the smallest effective area, until no further point's area is below the
+    /// tolerance. Tends to produce smoother results than Douglas-Peucker at the same tolerance,
+    /// but does not guarantee that every remaining point is within `tolerance` of the original
+    /// line, and can produce self-intersecting output for polygon data.
+    VisvalingamWhyatt,
+    /// Visvalingam-Whyatt, but a point is only removed when collapsing its triangle would not
+    /// make the ring/line intersect itself. Slower than [`SimplifyMethod::VisvalingamWhyatt`],
+    /// but safe to use on polygon data that must remain valid.
+    VisvalingamWhyattPreserve,
+}
+
+pub(crate) fn simplify(
+    array: GeometryArray,
+    tolerance: &f64,
+    method: &SimplifyMethod,
+) -> Result<GeometryArray> {
     match array {
         GeometryArray::WKB(arr) => {
             let output_geoms: Vec<Option<Geometry>> = arr
                 .iter_geo()
-                .map(|maybe_g| maybe_g.map(|geom| simplify_geometry(geom, tolerance)))
+                .map(|maybe_g| maybe_g.map(|geom| simplify_geometry(geom, tolerance, method)))
                 .collect();
 
             Ok(GeometryArray::WKB(output_geoms.into()))
@@ -18,7 +46,7 @@ pub(crate) fn simplify(array: GeometryArray, tolerance: &f64) -> Result<Geometry
         GeometryArray::LineString(arr) => {
             let output_geoms: Vec<Option<LineString>> = arr
                 .iter_geo()
-                .map(|maybe_g| maybe_g.map(|geom| geom.simplify(tolerance)))
+                .map(|maybe_g| maybe_g.map(|geom| simplify_line_string(geom, tolerance, method)))
                 .collect();
 
             Ok(GeometryArray::LineString(output_geoms.into()))
@@ -26,7 +54,15 @@ pub(crate) fn simplify(array: GeometryArray, tolerance: &f64) -> Result<Geometry
         GeometryArray::MultiLineString(arr) => {
             let output_geoms: Vec<Option<MultiLineString>> = arr
                 .iter_geo()
-                .map(|maybe_g| maybe_g.map(|geom| geom.simplify(tolerance)))
+                .map(|maybe_g| {
+                    maybe_g.map(|geom| match method {
+                        SimplifyMethod::DouglasPeucker => geom.simplify(tolerance),
+                        SimplifyMethod::VisvalingamWhyatt => geom.simplifyvw(tolerance),
+                        SimplifyMethod::VisvalingamWhyattPreserve => {
+                            geom.simplifyvw_preserve(tolerance)
+                        }
+                    })
+                })
                 .collect();
 
             Ok(GeometryArray::MultiLineString(output_geoms.into()))
@@ -34,7 +70,15 @@ pub(crate) fn simplify(array: GeometryArray, tolerance: &f64) -> Result<Geometry
         GeometryArray::Polygon(arr) => {
             let output_geoms: Vec<Option<Polygon>> = arr
                 .iter_geo()
-                .map(|maybe_g| maybe_g.map(|geom| geom.simplify(tolerance)))
+                .map(|maybe_g| {
+                    maybe_g.map(|geom| match method {
+                        SimplifyMethod::DouglasPeucker => geom.simplify(tolerance),
+                        SimplifyMethod::VisvalingamWhyatt => geom.simplifyvw(tolerance),
+                        SimplifyMethod::VisvalingamWhyattPreserve => {
+                            geom.simplifyvw_preserve(tolerance)
+                        }
+                    })
+                })
                 .collect();
 
             Ok(GeometryArray::Polygon(output_geoms.into()))
@@ -42,7 +86,15 @@ pub(crate) fn simplify(array: GeometryArray, tolerance: &f64) -> Result<Geometry
         GeometryArray::MultiPolygon(arr) => {
             let output_geoms: Vec<Option<MultiPolygon>> = arr
                 .iter_geo()
-                .map(|maybe_g| maybe_g.map(|geom| geom.simplify(tolerance)))
+                .map(|maybe_g| {
+                    maybe_g.map(|geom| match method {
+                        SimplifyMethod::DouglasPeucker => geom.simplify(tolerance),
+                        SimplifyMethod::VisvalingamWhyatt => geom.simplifyvw(tolerance),
+                        SimplifyMethod::VisvalingamWhyattPreserve => {
+                            geom.simplifyvw_preserve(tolerance)
+                        }
+                    })
+                })
                 .collect();
 
             Ok(GeometryArray::MultiPolygon(output_geoms.into()))
@@ -50,23 +102,113 @@ pub(crate) fn simplify(array: GeometryArray, tolerance: &f64) -> Result<Geometry
     }
 }
 
-fn simplify_geometry(geom: Geometry, tolerance: &f64) -> Geometry {
+fn simplify_line_string(geom: LineString, tolerance: &f64, method: &SimplifyMethod) -> LineString {
+    match method {
+        SimplifyMethod::DouglasPeucker => geom.simplify(tolerance),
+        SimplifyMethod::VisvalingamWhyatt => geom.simplifyvw(tolerance),
+        SimplifyMethod::VisvalingamWhyattPreserve => geom.simplifyvw_preserve(tolerance),
+    }
+}
+
+fn simplify_geometry(geom: Geometry, tolerance: &f64, method: &SimplifyMethod) -> Geometry {
     match geom {
         Geometry::Point(g) => Geometry::Point(g),
         Geometry::MultiPoint(g) => Geometry::MultiPoint(g),
-        Geometry::LineString(g) => Geometry::LineString(g.simplify(tolerance)),
-        Geometry::MultiLineString(g) => Geometry::MultiLineString(g.simplify(tolerance)),
-        Geometry::Polygon(g) => Geometry::Polygon(g.simplify(tolerance)),
-        Geometry::MultiPolygon(g) => Geometry::MultiPolygon(g.simplify(tolerance)),
+        Geometry::LineString(g) => Geometry::LineString(simplify_line_string(g, tolerance, method)),
+        Geometry::MultiLineString(g) => Geometry::MultiLineString(match method {
+            SimplifyMethod::DouglasPeucker => g.simplify(tolerance),
+            SimplifyMethod::VisvalingamWhyatt => g.simplifyvw(tolerance),
+            SimplifyMethod::VisvalingamWhyattPreserve => g.simplifyvw_preserve(tolerance),
+        }),
+        Geometry::Polygon(g) => Geometry::Polygon(match method {
+            SimplifyMethod::DouglasPeucker => g.simplify(tolerance),
+            SimplifyMethod::VisvalingamWhyatt => g.simplifyvw(tolerance),
+            SimplifyMethod::VisvalingamWhyattPreserve => g.simplifyvw_preserve(tolerance),
+        }),
+        Geometry::MultiPolygon(g) => Geometry::MultiPolygon(match method {
+            SimplifyMethod::DouglasPeucker => g.simplify(tolerance),
+            SimplifyMethod::VisvalingamWhyatt => g.simplifyvw(tolerance),
+            SimplifyMethod::VisvalingamWhyattPreserve => g.simplifyvw_preserve(tolerance),
+        }),
+        Geometry::GeometryCollection(g) => simplify_collection(g, tolerance, method),
         _ => unimplemented!(),
     }
 }
 
+/// Simplifies every member of a `GeometryCollection`, rebuilding a collection with the same
+/// shape. Nested collections are walked with an explicit stack of in-progress frames rather than
+/// by calling back into `simplify_geometry`, so a collection nested arbitrarily deep can't
+/// overflow the call stack.
+fn simplify_collection(
+    collection: GeometryCollection,
+    tolerance: &f64,
+    method: &SimplifyMethod,
+) -> Geometry {
+    enum Frame {
+        Collection {
+            remaining: std::vec::IntoIter<Geometry>,
+            done: Vec<Geometry>,
+        },
+    }
+
+    let mut stack: Vec<Frame> = Vec::new();
+    let mut current = Geometry::GeometryCollection(collection);
+
+    'descend: loop {
+        // Walk down through nested collections, pushing a frame for each one so its siblings
+        // can be picked up again once the one we're about to descend into is fully simplified.
+        while let Geometry::GeometryCollection(collection) = current {
+            let mut remaining = collection.into_iter();
+            match remaining.next() {
+                Some(first) => {
+                    stack.push(Frame::Collection {
+                        remaining,
+                        done: Vec::new(),
+                    });
+                    current = first;
+                }
+                None => {
+                    current = Geometry::GeometryCollection(GeometryCollection(Vec::new()));
+                    break;
+                }
+            }
+        }
+
+        if !matches!(current, Geometry::GeometryCollection(_)) {
+            current = simplify_geometry(current, tolerance, method);
+        }
+
+        // Fold the simplified geometry into its parent frame, moving on to the next sibling if
+        // one remains, or finishing that frame's collection and bubbling it up otherwise.
+        loop {
+            match stack.pop() {
+                None => return current,
+                Some(Frame::Collection {
+                    mut remaining,
+                    mut done,
+                }) => {
+                    done.push(current);
+                    match remaining.next() {
+                        Some(next) => {
+                            stack.push(Frame::Collection { remaining, done });
+                            current = next;
+                            continue 'descend;
+                        }
+                        None => {
+                            current = Geometry::GeometryCollection(GeometryCollection(done));
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::simplify;
-    use geo::{line_string, polygon, Geometry};
-    use geoarrow::{GeometryArray, GeometryArrayTrait, LineStringArray, PolygonArray};
+    use super::{simplify, SimplifyMethod};
+    use geo::{line_string, polygon, Geometry, GeometryCollection};
+    use geoarrow::{GeometryArray, GeometryArrayTrait, LineStringArray, PolygonArray, WKBArray};
 
     #[test]
     fn rdp_test() {
@@ -78,7 +220,12 @@ mod tests {
             (x: 27.8, y: 0.1 ),
         ];
         let input_array: LineStringArray = vec![input_geom].into();
-        let result_array = simplify(GeometryArray::LineString(input_array), &1.0).unwrap();
+        let result_array = simplify(
+            GeometryArray::LineString(input_array),
+            &1.0,
+            &SimplifyMethod::DouglasPeucker,
+        )
+        .unwrap();
 
         let expected = line_string![
             ( x: 0.0, y: 0.0 ),
@@ -104,7 +251,12 @@ mod tests {
             (x: 0., y: 0.),
         ];
         let input_array: PolygonArray = vec![input_geom].into();
-        let result_array = simplify(GeometryArray::Polygon(input_array), &2.0).unwrap();
+        let result_array = simplify(
+            GeometryArray::Polygon(input_array),
+            &2.0,
+            &SimplifyMethod::DouglasPeucker,
+        )
+        .unwrap();
 
         let expected = polygon![
             (x: 0., y: 0.),
@@ -119,4 +271,81 @@ mod tests {
             result_array.get_as_geo(0).unwrap()
         );
     }
+
+    #[test]
+    fn visvalingam_whyatt_test() {
+        let input_geom = line_string![
+            (x: 0.0, y: 0.0 ),
+            (x: 5.0, y: 4.0 ),
+            (x: 11.0, y: 5.5 ),
+            (x: 17.3, y: 3.2 ),
+            (x: 27.8, y: 0.1 ),
+        ];
+        let input_array: LineStringArray = vec![input_geom.clone()].into();
+        let result_array = simplify(
+            GeometryArray::LineString(input_array),
+            &30.0,
+            &SimplifyMethod::VisvalingamWhyatt,
+        )
+        .unwrap();
+
+        let simplified = result_array.get_as_geo(0).unwrap();
+        assert_ne!(simplified, Geometry::LineString(input_geom));
+    }
+
+    #[test]
+    fn visvalingam_whyatt_preserve_test() {
+        let input_geom = line_string![
+            (x: 0.0, y: 0.0 ),
+            (x: 5.0, y: 4.0 ),
+            (x: 11.0, y: 5.5 ),
+            (x: 17.3, y: 3.2 ),
+            (x: 27.8, y: 0.1 ),
+        ];
+        let input_array: LineStringArray = vec![input_geom.clone()].into();
+        let result_array = simplify(
+            GeometryArray::LineString(input_array),
+            &30.0,
+            &SimplifyMethod::VisvalingamWhyattPreserve,
+        )
+        .unwrap();
+
+        let simplified = result_array.get_as_geo(0).unwrap();
+        assert_ne!(simplified, Geometry::LineString(input_geom));
+    }
+
+    #[test]
+    fn geometry_collection_test() {
+        let input_geom = line_string![
+            (x: 0.0, y: 0.0 ),
+            (x: 5.0, y: 4.0 ),
+            (x: 11.0, y: 5.5 ),
+            (x: 17.3, y: 3.2 ),
+            (x: 27.8, y: 0.1 ),
+        ];
+        let nested = Geometry::GeometryCollection(GeometryCollection(vec![Geometry::LineString(
+            input_geom.clone(),
+        )]));
+        let collection = Geometry::GeometryCollection(GeometryCollection(vec![nested]));
+
+        let input_array: WKBArray = vec![Some(collection)].into();
+        let result_array = simplify(
+            GeometryArray::WKB(input_array),
+            &1.0,
+            &SimplifyMethod::DouglasPeucker,
+        )
+        .unwrap();
+
+        let simplified = result_array.get_as_geo(0).unwrap();
+        let expected = line_string![
+            ( x: 0.0, y: 0.0 ),
+            ( x: 5.0, y: 4.0 ),
+            ( x: 11.0, y: 5.5 ),
+            ( x: 27.8, y: 0.1 ),
+        ];
+        let expected = Geometry::GeometryCollection(GeometryCollection(vec![
+            Geometry::GeometryCollection(GeometryCollection(vec![Geometry::LineString(expected)])),
+        ]));
+        assert_eq!(simplified, expected);
+    }
 }
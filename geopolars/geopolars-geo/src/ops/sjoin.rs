@@ -0,0 +1,78 @@
+//! `Series`-level spatial join/nearest, built on the same [`SpatialPredicate`]/[`predicate_holds`]
+//! [`WKBArray::sjoin`](geopolars_arrow::WKBArray::sjoin) uses, so the two layers can't disagree on
+//! what a predicate means even though this one prunes candidates with a [`SpatialIndex`] over
+//! already-decoded `geo::Geometry` rather than a `WKBArray`'s raw bytes.
+
+use crate::error::Result;
+use crate::ops::index::SpatialIndex;
+use crate::util::iter_geom;
+use geo::EuclideanDistance;
+pub use geopolars_arrow::{predicate_holds, SpatialPredicate};
+use polars::prelude::Series;
+
+/// Join two geometry series on a spatial predicate, returning the `(left_index, right_index)`
+/// pairs for which `predicate` holds between `left[left_index]` and `right[right_index]`.
+///
+/// Builds a [`SpatialIndex`] over `right` and queries it with each `left` geometry's envelope;
+/// the candidates surfaced by that AABB index are then refined with an exact `geo` predicate
+/// check, since the index alone can only rule out pairs, not confirm them.
+pub(crate) fn sjoin(
+    left: &Series,
+    right: &Series,
+    predicate: SpatialPredicate,
+) -> Result<Vec<(usize, usize)>> {
+    let index = SpatialIndex::build(right);
+
+    let mut pairs = Vec::new();
+    for (left_index, left_geom) in iter_geom(left).enumerate() {
+        for candidate in index.candidates(&left_geom, predicate) {
+            if predicate_holds(predicate, &left_geom, &candidate.geom) {
+                pairs.push((left_index, candidate.index));
+            }
+        }
+    }
+
+    Ok(pairs)
+}
+
+/// For each geometry in `left`, find the index and distance of the closest geometry in `right`.
+///
+/// Builds a [`SpatialIndex`] over `right` and uses rstar's nearest-neighbor traversal to narrow
+/// down to a candidate envelope in roughly logarithmic time, then computes the exact Euclidean
+/// distance between the two decoded geometries. Returns `None` for a row when `right` is empty.
+pub(crate) fn nearest(left: &Series, right: &Series) -> Result<Vec<Option<(usize, f64)>>> {
+    let index = SpatialIndex::build(right);
+
+    let nearest_indices = iter_geom(left)
+        .map(|left_geom| {
+            index.nearest(&left_geom).map(|candidate| {
+                let distance = left_geom.euclidean_distance(&candidate.geom);
+                (candidate.index, distance)
+            })
+        })
+        .collect();
+
+    Ok(nearest_indices)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{sjoin, SpatialPredicate};
+    use crate::util::from_geom_vec;
+    use geo::{Geometry, Point};
+
+    #[test]
+    fn sjoin_dwithin_matches_nearby_non_overlapping_points() {
+        // Two points 5 units apart: their (zero-size) envelopes don't overlap, so a plain
+        // envelope-intersection candidate prune would wrongly drop this pair before
+        // `predicate_holds` ever gets a chance to confirm it.
+        let left = from_geom_vec(&[Geometry::Point(Point::new(0.0, 0.0))]).unwrap();
+        let right = from_geom_vec(&[Geometry::Point(Point::new(5.0, 0.0))]).unwrap();
+
+        let pairs = sjoin(&left, &right, SpatialPredicate::DWithin(10.0)).unwrap();
+        assert_eq!(pairs, vec![(0, 0)]);
+
+        let pairs = sjoin(&left, &right, SpatialPredicate::DWithin(1.0)).unwrap();
+        assert!(pairs.is_empty());
+    }
+}
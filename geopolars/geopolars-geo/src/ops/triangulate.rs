@@ -0,0 +1,166 @@
+use crate::error::Result;
+use crate::util::{from_geom_vec_opt, iter_geom};
+use geo::{Coord, Geometry, LineString, MultiPolygon, Polygon};
+use geoarrow::algorithm::triangulate::tessellate;
+use geoarrow::coord::CoordBuffer;
+use polars::export::arrow::array::{Array, ListArray, PrimitiveArray, StructArray};
+use polars::export::arrow::datatypes::DataType as ArrowDataType;
+use polars::export::arrow::offset::OffsetsBuffer;
+use polars::prelude::{ArrowField, Series};
+
+/// Decomposes every (possibly multi-part, possibly holed) polygon in `series` into a
+/// `MultiPolygon` of triangles, via [`geoarrow::algorithm::triangulate::tessellate`]'s
+/// ear-clipping-with-hole-bridging engine. Non-polygonal rows come back empty.
+pub(crate) fn triangulate(series: &Series) -> Result<Series> {
+    let triangulated: Vec<Option<Geometry<f64>>> = iter_geom(series)
+        .map(|geom| match geom {
+            Geometry::Polygon(polygon) => Some(Geometry::MultiPolygon(
+                triangulate_polygons(std::iter::once(&polygon)),
+            )),
+            Geometry::MultiPolygon(multi_polygon) => Some(Geometry::MultiPolygon(
+                triangulate_polygons(multi_polygon.iter()),
+            )),
+            _ => Some(Geometry::MultiPolygon(MultiPolygon(Vec::new()))),
+        })
+        .collect();
+
+    from_geom_vec_opt(&triangulated)
+}
+
+/// Triangulates every polygon yielded by `polygons`, returning every resulting triangle as one
+/// flat `MultiPolygon`.
+fn triangulate_polygons<'a>(polygons: impl Iterator<Item = &'a Polygon<f64>>) -> MultiPolygon<f64> {
+    let (vertices, indices) = feature_mesh(polygons);
+
+    let mut triangles = Vec::with_capacity(indices.len() / 3);
+    for triangle in indices.chunks_exact(3) {
+        let vertex = |i: i64| {
+            let [x, y] = vertices[i as usize];
+            Coord { x, y }
+        };
+        triangles.push(Polygon::new(
+            LineString(vec![
+                vertex(triangle[0]),
+                vertex(triangle[1]),
+                vertex(triangle[2]),
+                vertex(triangle[0]),
+            ]),
+            Vec::new(),
+        ));
+    }
+
+    MultiPolygon(triangles)
+}
+
+/// Tessellates every polygon yielded by `polygons` into one shared mesh (as [`feature_mesh`]'s
+/// inputs all describe a single input feature), returning flat `[x, y]` vertices alongside
+/// triangle vertex indices into that same vertex list.
+fn feature_mesh<'a>(polygons: impl Iterator<Item = &'a Polygon<f64>>) -> (Vec<[f64; 2]>, Vec<i64>) {
+    let mut vertices = Vec::new();
+    let mut indices = Vec::new();
+
+    for polygon in polygons {
+        let rings: Vec<&LineString<f64>> = std::iter::once(polygon.exterior())
+            .chain(polygon.interiors())
+            .collect();
+
+        let mut xs = Vec::new();
+        let mut ys = Vec::new();
+        let mut ring_offsets = vec![0i64];
+        for ring in &rings {
+            for coord in &ring.0 {
+                xs.push(coord.x);
+                ys.push(coord.y);
+            }
+            ring_offsets.push(xs.len() as i64);
+        }
+
+        let coords = CoordBuffer::Separated(xs.into(), ys.into());
+        let geom_offsets = OffsetsBuffer::try_from(vec![0i64, rings.len() as i64]).unwrap();
+        let ring_offsets = OffsetsBuffer::try_from(ring_offsets).unwrap();
+
+        let mesh = tessellate(&coords, &geom_offsets, &ring_offsets);
+
+        let vertex_base = vertices.len() as i64;
+        for vertex in mesh.vertices.chunks_exact(2) {
+            vertices.push([vertex[0], vertex[1]]);
+        }
+        indices.extend(mesh.indices.iter().map(|&i| vertex_base + i));
+    }
+
+    (vertices, indices)
+}
+
+/// Like [`triangulate`], but returns the mesh as a `(vertices, indices)` pair of per-row list
+/// columns instead of reconstructed `Polygon` geometries: `vertices` is a list of `{x, y}`
+/// structs per row, `indices` is a list of `i64`s per row (grouped in threes) indexing into that
+/// row's own `vertices` list. Non-polygonal rows come back with empty lists.
+pub(crate) fn triangulate_indices(series: &Series) -> Result<(Series, Series)> {
+    let mut x_coords = Vec::<f64>::new();
+    let mut y_coords = Vec::<f64>::new();
+    let mut vertex_offsets = vec![0i64];
+
+    let mut indices = Vec::<i64>::new();
+    let mut index_offsets = vec![0i64];
+
+    for geom in iter_geom(series) {
+        let mesh = match geom {
+            Geometry::Polygon(polygon) => Some(feature_mesh(std::iter::once(&polygon))),
+            Geometry::MultiPolygon(multi_polygon) => Some(feature_mesh(multi_polygon.iter())),
+            _ => None,
+        };
+
+        if let Some((vertices, triangle_indices)) = mesh {
+            let vertex_base = x_coords.len() as i64;
+            for [x, y] in vertices {
+                x_coords.push(x);
+                y_coords.push(y);
+            }
+            indices.extend(triangle_indices.into_iter().map(|i| vertex_base + i));
+        }
+
+        vertex_offsets.push(x_coords.len() as i64);
+        index_offsets.push(indices.len() as i64);
+    }
+
+    let coord_field_x = ArrowField::new("x", ArrowDataType::Float64, false);
+    let coord_field_y = ArrowField::new("y", ArrowDataType::Float64, false);
+    let struct_data_type = ArrowDataType::Struct(vec![coord_field_x, coord_field_y]);
+    let vertices_list_type = ArrowDataType::LargeList(Box::new(ArrowField::new(
+        "vertices",
+        struct_data_type.clone(),
+        false,
+    )));
+
+    let coord_array = StructArray::new(
+        struct_data_type,
+        vec![
+            Box::new(PrimitiveArray::<f64>::from_vec(x_coords)) as Box<dyn Array>,
+            Box::new(PrimitiveArray::<f64>::from_vec(y_coords)) as Box<dyn Array>,
+        ],
+        None,
+    );
+    let vertices_array = ListArray::<i64>::new(
+        vertices_list_type,
+        unsafe { OffsetsBuffer::new_unchecked(vertex_offsets.into()) },
+        Box::new(coord_array),
+        None,
+    );
+
+    let indices_list_type = ArrowDataType::LargeList(Box::new(ArrowField::new(
+        "item",
+        ArrowDataType::Int64,
+        false,
+    )));
+    let indices_array = ListArray::<i64>::new(
+        indices_list_type,
+        unsafe { OffsetsBuffer::new_unchecked(index_offsets.into()) },
+        Box::new(PrimitiveArray::<i64>::from_vec(indices)),
+        None,
+    );
+
+    Ok((
+        Series::try_from(("vertices", Box::new(vertices_array) as Box<dyn Array>))?,
+        Series::try_from(("indices", Box::new(indices_array) as Box<dyn Array>))?,
+    ))
+}
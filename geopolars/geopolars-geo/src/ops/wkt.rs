@@ -0,0 +1,40 @@
+use crate::error::{GeopolarsError, Result};
+use crate::util::{from_geom_vec_opt, iter_geom_opt};
+use geo::Geometry;
+use polars::export::arrow::array::{Array, MutableUtf8Array};
+use polars::prelude::Series;
+use wkt::{ToWkt, TryFromWkt};
+
+/// Parse a Series of WKT strings into the crate's canonical (WKB-backed) geometry Series.
+///
+/// A null row comes back as a null row; a non-null row that fails to parse as WKT surfaces as a
+/// [`GeopolarsError::WktParseError`] rather than panicking.
+pub(crate) fn from_wkt(series: &Series) -> Result<Series> {
+    let chunks = series.utf8()?;
+
+    let geoms: Vec<Option<Geometry>> = chunks
+        .into_iter()
+        .map(|row| {
+            row.map(|value| {
+                Geometry::try_from_wkt_str(value)
+                    .map_err(|err| GeopolarsError::WktParseError(format!("{err:?}")))
+            })
+            .transpose()
+        })
+        .collect::<Result<_>>()?;
+
+    from_geom_vec_opt(&geoms)
+}
+
+/// Format each geometry in a geometry Series as a WKT string, leaving null rows null.
+pub(crate) fn to_wkt(series: &Series) -> Result<Series> {
+    let mut result = MutableUtf8Array::<i64>::with_capacity(series.len());
+
+    for geom in iter_geom_opt(series) {
+        result.push(geom.map(|geom| geom.to_wkt().to_string()));
+    }
+
+    let result: polars::export::arrow::array::Utf8Array<i64> = result.into();
+    let series = Series::try_from(("geometry", Box::new(result) as Box<dyn Array>))?;
+    Ok(series)
+}
@@ -23,6 +23,30 @@ pub fn from_geom_vec(geoms: &[Geometry<f64>]) -> Result<Series> {
     Ok(series)
 }
 
+/// Like [`from_geom_vec`], but allows null rows for operations that can't produce a geometry
+/// for every input (e.g. a representative point of a non-polygonal row).
+pub fn from_geom_vec_opt(geoms: &[Option<Geometry<f64>>]) -> Result<Series> {
+    let mut wkb_array = MutableBinaryArray::<i32>::with_capacity(geoms.len());
+
+    for geom in geoms {
+        let wkb = geom
+            .as_ref()
+            .map(|g| {
+                g.to_wkb(CoordDimensions::xy()).map_err(|_| {
+                    PolarsError::ComputeError(ErrString::from(
+                        "Failed to convert geom vec to GeoSeries",
+                    ))
+                })
+            })
+            .transpose()?;
+        wkb_array.push(wkb);
+    }
+    let array: BinaryArray<i32> = wkb_array.into();
+
+    let series = Series::try_from(("geometry", Box::new(array) as Box<dyn Array>))?;
+    Ok(series)
+}
+
 /// Helper function to iterate over geometries from polars Series
 pub(crate) fn iter_geom(series: &Series) -> impl Iterator<Item = Geometry<f64>> + '_ {
     let chunks = series.binary().expect("series was not a list type");
@@ -36,6 +60,20 @@ pub(crate) fn iter_geom(series: &Series) -> impl Iterator<Item = Geometry<f64>>
     })
 }
 
+/// Like [`iter_geom`], but a null row comes back as `None` instead of panicking.
+pub(crate) fn iter_geom_opt(series: &Series) -> impl Iterator<Item = Option<Geometry<f64>>> + '_ {
+    let chunks = series.binary().expect("series was not a list type");
+
+    let iter = chunks.into_iter();
+    iter.map(|row| {
+        row.map(|value| {
+            Wkb(value.to_vec())
+                .to_geo()
+                .expect("unable to convert to geo")
+        })
+    })
+}
+
 // This is a workaround hack because StructChunked::from_chunks doesn't exist
 pub fn struct_series_from_chunks(chunks: Vec<Box<dyn Array>>) -> Result<Series> {
     let refs: Vec<&dyn Array> = chunks.iter().map(|chunk| chunk.as_ref()).collect();
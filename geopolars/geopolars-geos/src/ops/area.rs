@@ -1,7 +1,95 @@
+use geo::prelude::Area;
 use geoarrow::{GeometryArray, GeometryArrayTrait};
 use geos::Geom;
 use polars::export::arrow::array::{MutablePrimitiveArray, PrimitiveArray};
 
+/// Area of every geometry in `array`, computed natively via `geo`'s [`Area`] trait rather than a
+/// GEOS round-trip, so every variant (including the Multi* ones [`area`] can't yet handle) is
+/// supported.
+///
+/// The sign of the result encodes ring winding order: counter-clockwise rings contribute a
+/// positive area, clockwise rings a negative one, so a polygon's exterior and interiors can
+/// partially cancel. See [`unsigned_area`] for the magnitude alone.
+pub fn signed_area(array: GeometryArray) -> PrimitiveArray<f64> {
+    let mut output_array = MutablePrimitiveArray::<f64>::with_capacity(array.len());
+
+    match array {
+        GeometryArray::WKB(arr) => {
+            arr.iter_geo()
+                .for_each(|maybe_g| output_array.push(maybe_g.map(|g| g.signed_area())));
+        }
+        GeometryArray::Point(arr) => {
+            arr.iter_geo()
+                .for_each(|maybe_g| output_array.push(maybe_g.map(|g| g.signed_area())));
+        }
+        GeometryArray::LineString(arr) => {
+            arr.iter_geo()
+                .for_each(|maybe_g| output_array.push(maybe_g.map(|g| g.signed_area())));
+        }
+        GeometryArray::Polygon(arr) => {
+            arr.iter_geo()
+                .for_each(|maybe_g| output_array.push(maybe_g.map(|g| g.signed_area())));
+        }
+        GeometryArray::MultiPoint(arr) => {
+            arr.iter_geo()
+                .for_each(|maybe_g| output_array.push(maybe_g.map(|g| g.signed_area())));
+        }
+        GeometryArray::MultiLineString(arr) => {
+            arr.iter_geo()
+                .for_each(|maybe_g| output_array.push(maybe_g.map(|g| g.signed_area())));
+        }
+        GeometryArray::MultiPolygon(arr) => {
+            arr.iter_geo()
+                .for_each(|maybe_g| output_array.push(maybe_g.map(|g| g.signed_area())));
+        }
+    }
+
+    output_array.into()
+}
+
+/// Unsigned (magnitude-only) area of every geometry in `array`, computed natively via `geo`'s
+/// [`Area`] trait. See [`signed_area`] for a variant whose sign encodes ring winding order.
+pub fn unsigned_area(array: GeometryArray) -> PrimitiveArray<f64> {
+    let mut output_array = MutablePrimitiveArray::<f64>::with_capacity(array.len());
+
+    match array {
+        GeometryArray::WKB(arr) => {
+            arr.iter_geo()
+                .for_each(|maybe_g| output_array.push(maybe_g.map(|g| g.unsigned_area())));
+        }
+        GeometryArray::Point(arr) => {
+            arr.iter_geo()
+                .for_each(|maybe_g| output_array.push(maybe_g.map(|g| g.unsigned_area())));
+        }
+        GeometryArray::LineString(arr) => {
+            arr.iter_geo()
+                .for_each(|maybe_g| output_array.push(maybe_g.map(|g| g.unsigned_area())));
+        }
+        GeometryArray::Polygon(arr) => {
+            arr.iter_geo()
+                .for_each(|maybe_g| output_array.push(maybe_g.map(|g| g.unsigned_area())));
+        }
+        GeometryArray::MultiPoint(arr) => {
+            arr.iter_geo()
+                .for_each(|maybe_g| output_array.push(maybe_g.map(|g| g.unsigned_area())));
+        }
+        GeometryArray::MultiLineString(arr) => {
+            arr.iter_geo()
+                .for_each(|maybe_g| output_array.push(maybe_g.map(|g| g.unsigned_area())));
+        }
+        GeometryArray::MultiPolygon(arr) => {
+            arr.iter_geo()
+                .for_each(|maybe_g| output_array.push(maybe_g.map(|g| g.unsigned_area())));
+        }
+    }
+
+    output_array.into()
+}
+
+/// Area of every geometry in `array`, computed via a GEOS round-trip.
+///
+/// Only Point/LineString/Polygon/WKB are supported; see [`signed_area`]/[`unsigned_area`] for a
+/// native implementation that covers every variant.
 pub fn area(array: GeometryArray) -> PrimitiveArray<f64> {
     let mut output_array = MutablePrimitiveArray::<f64>::with_capacity(array.len());
 
@@ -42,7 +130,7 @@ pub fn area(array: GeometryArray) -> PrimitiveArray<f64> {
 
 #[cfg(test)]
 mod tests {
-    use super::area;
+    use super::{area, signed_area, unsigned_area};
     use approx::assert_relative_eq;
     use geo::{polygon, Polygon};
     use geoarrow::polygon::MutablePolygonArray;
@@ -63,6 +151,34 @@ mod tests {
         result_arr.clone()
     }
 
+    fn call_signed_area(input: Vec<Polygon>) -> PrimitiveArray<f64> {
+        let mut_polygon_arr: MutablePolygonArray = input.into();
+        let polygon_arr = mut_polygon_arr.into_arrow();
+
+        let polygon_arr2: PolygonArray = polygon_arr.try_into().unwrap();
+
+        let result = signed_area(GeometryArray::Polygon(polygon_arr2));
+        let result_arr = result
+            .as_any()
+            .downcast_ref::<PrimitiveArray<f64>>()
+            .unwrap();
+        result_arr.clone()
+    }
+
+    fn call_unsigned_area(input: Vec<Polygon>) -> PrimitiveArray<f64> {
+        let mut_polygon_arr: MutablePolygonArray = input.into();
+        let polygon_arr = mut_polygon_arr.into_arrow();
+
+        let polygon_arr2: PolygonArray = polygon_arr.try_into().unwrap();
+
+        let result = unsigned_area(GeometryArray::Polygon(polygon_arr2));
+        let result_arr = result
+            .as_any()
+            .downcast_ref::<PrimitiveArray<f64>>()
+            .unwrap();
+        result_arr.clone()
+    }
+
     #[test]
     fn area_empty_polygon_test() {
         let polygons = vec![polygon![]];
@@ -82,4 +198,30 @@ mod tests {
         let result = call_area(polygons);
         assert_relative_eq!(result.value(0), 30.);
     }
+
+    #[test]
+    fn signed_area_clockwise_polygon_test() {
+        let polygons = vec![polygon![
+            (x: 0., y: 0.),
+            (x: 0., y: 6.),
+            (x: 5., y: 6.),
+            (x: 5., y: 0.),
+            (x: 0., y: 0.)
+        ]];
+        let result = call_signed_area(polygons);
+        assert_relative_eq!(result.value(0), -30.);
+    }
+
+    #[test]
+    fn unsigned_area_polygon_test() {
+        let polygons = vec![polygon![
+            (x: 0., y: 0.),
+            (x: 0., y: 6.),
+            (x: 5., y: 6.),
+            (x: 5., y: 0.),
+            (x: 0., y: 0.)
+        ]];
+        let result = call_unsigned_area(polygons);
+        assert_relative_eq!(result.value(0), 30.);
+    }
 }
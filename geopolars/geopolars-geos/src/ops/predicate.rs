@@ -0,0 +1,215 @@
+use geoarrow::{GeometryArray, GeometryArrayTrait};
+use geos::{Geom, PreparedGeom};
+use polars::export::arrow::array::{BooleanArray, MutableBooleanArray};
+
+/// Validity of every geometry in `array`, computed via a GEOS round-trip.
+///
+/// `geo` has no general-purpose OGC validity check, so this is the one thing in this module
+/// that has no native-`geo` counterpart to fall back to. Only Point/LineString/Polygon/WKB are
+/// supported, mirroring [`super::area::area`]; a null row stays null.
+pub fn is_valid(array: GeometryArray) -> BooleanArray {
+    let mut output_array = MutableBooleanArray::with_capacity(array.len());
+
+    match array {
+        GeometryArray::WKB(arr) => {
+            arr.iter_geos()
+                .for_each(|maybe_g| output_array.push(maybe_g.map(|g| g.is_valid())));
+        }
+        GeometryArray::Point(arr) => {
+            arr.iter_geos()
+                .for_each(|maybe_g| output_array.push(maybe_g.map(|g| g.is_valid())));
+        }
+        GeometryArray::LineString(arr) => {
+            arr.iter_geos()
+                .for_each(|maybe_g| output_array.push(maybe_g.map(|g| g.is_valid())));
+        }
+        GeometryArray::Polygon(arr) => {
+            arr.iter_geos()
+                .for_each(|maybe_g| output_array.push(maybe_g.map(|g| g.is_valid())));
+        }
+        _ => unimplemented!(),
+    }
+
+    output_array.into()
+}
+
+/// The DE-9IM intersection matrix between `left[i]` and `right[i]`, as GEOS's 9-character
+/// matrix string (e.g. `"212101212"`), for every row. Null if either side is null.
+///
+/// Only Point/LineString/Polygon/WKB are supported, mirroring [`super::area::area`].
+///
+/// # Panics
+/// Panics if `left` and `right` don't have the same length.
+pub fn relate(left: GeometryArray, right: GeometryArray) -> Vec<Option<String>> {
+    assert_eq!(
+        left.len(),
+        right.len(),
+        "left and right geometry arrays must have the same length"
+    );
+
+    let left_geoms: Vec<_> = geos_values(left);
+    let right_geoms: Vec<_> = geos_values(right);
+
+    left_geoms
+        .into_iter()
+        .zip(right_geoms)
+        .map(|(l, r)| match (l, r) {
+            (Some(l), Some(r)) => Some(l.relate(&r).unwrap().to_string()),
+            _ => None,
+        })
+        .collect()
+}
+
+fn geos_values(array: GeometryArray) -> Vec<Option<geos::Geometry>> {
+    match array {
+        GeometryArray::WKB(arr) => arr.iter_geos().collect(),
+        GeometryArray::Point(arr) => arr.iter_geos().collect(),
+        GeometryArray::LineString(arr) => arr.iter_geos().collect(),
+        GeometryArray::Polygon(arr) => arr.iter_geos().collect(),
+        GeometryArray::MultiPoint(arr) => arr.iter_geos().collect(),
+        GeometryArray::MultiLineString(arr) => arr.iter_geos().collect(),
+        GeometryArray::MultiPolygon(arr) => arr.iter_geos().collect(),
+        _ => unimplemented!(),
+    }
+}
+
+/// A binary spatial predicate GEOS can evaluate either pairwise or against a prepared geometry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Predicate {
+    Intersects,
+    Contains,
+    Within,
+    Touches,
+    Covers,
+    Crosses,
+    Overlaps,
+}
+
+impl Predicate {
+    fn eval(&self, a: &geos::Geometry, b: &geos::Geometry) -> bool {
+        match self {
+            Self::Intersects => a.intersects(b).unwrap(),
+            Self::Contains => a.contains(b).unwrap(),
+            Self::Within => a.within(b).unwrap(),
+            Self::Touches => a.touches(b).unwrap(),
+            Self::Covers => a.covers(b).unwrap(),
+            Self::Crosses => a.crosses(b).unwrap(),
+            Self::Overlaps => a.overlaps(b).unwrap(),
+        }
+    }
+
+    fn eval_prepared(&self, prepared: &geos::PreparedGeometry, b: &geos::Geometry) -> bool {
+        match self {
+            Self::Intersects => prepared.intersects(b).unwrap(),
+            Self::Contains => prepared.contains(b).unwrap(),
+            Self::Within => prepared.within(b).unwrap(),
+            Self::Touches => prepared.touches(b).unwrap(),
+            Self::Covers => prepared.covers(b).unwrap(),
+            Self::Crosses => prepared.crosses(b).unwrap(),
+            Self::Overlaps => prepared.overlaps(b).unwrap(),
+        }
+    }
+}
+
+/// Evaluates `predicate` between `query` and every element of `array`, as a boolean mask.
+///
+/// `query` is prepared once via [`geos::Geometry::to_prepared_geom`] — GEOS builds an internal
+/// spatial index over `query`'s geometry on the prepared side, so evaluating it against every
+/// row of `array` is far cheaper than `array.len()` unprepared pairwise calls. `query` is kept
+/// alive for the whole call so its `GEOSContextHandle` (borrowed by the prepared geometry) stays
+/// valid for as long as `prepared` is in scope. If `query` can't be prepared (some geometry
+/// types, e.g. empty ones, aren't supported by GEOS's prepared-geometry machinery), this falls
+/// back to the unprepared pairwise evaluation instead of failing outright.
+///
+/// Respects the validity bitmap: a null row in `array` produces a null (not `false`) in the
+/// output mask.
+pub fn prepared_predicate(
+    query: &geos::Geometry,
+    array: GeometryArray,
+    predicate: Predicate,
+) -> BooleanArray {
+    let mut output_array = MutableBooleanArray::with_capacity(array.len());
+    let values = geos_values(array);
+
+    match query.to_prepared_geom() {
+        Ok(prepared) => values.into_iter().for_each(|maybe_g| {
+            output_array.push(maybe_g.map(|g| predicate.eval_prepared(&prepared, &g)))
+        }),
+        Err(_) => values
+            .into_iter()
+            .for_each(|maybe_g| output_array.push(maybe_g.map(|g| predicate.eval(query, &g)))),
+    }
+
+    output_array.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_valid, prepared_predicate, relate, Predicate};
+    use geo::polygon;
+    use geoarrow::polygon::MutablePolygonArray;
+    use geoarrow::{GeometryArray, PolygonArray};
+    use geos::Geom;
+    use polars::export::arrow::array::Array;
+
+    fn polygon_array(polygons: Vec<geo::Polygon>) -> PolygonArray {
+        let mut_arr: MutablePolygonArray = polygons.into();
+        let arr = mut_arr.into_arrow();
+        arr.try_into().unwrap()
+    }
+
+    #[test]
+    fn is_valid_for_a_simple_polygon() {
+        let polygons = vec![polygon![
+            (x: 0., y: 0.),
+            (x: 5., y: 0.),
+            (x: 5., y: 6.),
+            (x: 0., y: 6.),
+            (x: 0., y: 0.)
+        ]];
+        let result = is_valid(GeometryArray::Polygon(polygon_array(polygons)));
+        assert!(result.value(0));
+    }
+
+    #[test]
+    fn relate_for_identical_polygons() {
+        let square = || {
+            polygon![
+                (x: 0., y: 0.),
+                (x: 4., y: 0.),
+                (x: 4., y: 4.),
+                (x: 0., y: 4.),
+                (x: 0., y: 0.),
+            ]
+        };
+        let left = GeometryArray::Polygon(polygon_array(vec![square()]));
+        let right = GeometryArray::Polygon(polygon_array(vec![square()]));
+
+        let result = relate(left, right);
+        assert_eq!(result, vec![Some("2FFF1FFF2".to_string())]);
+    }
+
+    #[test]
+    fn prepared_predicate_intersects_against_many_rows() {
+        let square = |x0: f64, y0: f64, x1: f64, y1: f64| {
+            polygon![
+                (x: x0, y: y0),
+                (x: x1, y: y0),
+                (x: x1, y: y1),
+                (x: x0, y: y1),
+                (x: x0, y: y0),
+            ]
+        };
+
+        // One polygon overlapping the query, one disjoint from it.
+        let array = GeometryArray::Polygon(polygon_array(vec![
+            square(0., 0., 2., 2.),
+            square(10., 10., 12., 12.),
+        ]));
+        let query: geos::Geometry = (&square(1., 1., 3., 3.)).try_into().unwrap();
+
+        let result = prepared_predicate(&query, array, Predicate::Intersects);
+        assert!(result.value(0));
+        assert!(!result.value(1));
+    }
+}
@@ -25,6 +25,15 @@ pub enum GeopolarsError {
 
     #[error(transparent)]
     PolarsError(Box<PolarsError>),
+
+    #[error("Failed to parse WKT: {0}")]
+    WktParseError(String),
+
+    #[error("Failed to parse EWKB: {0}")]
+    EwkbParseError(String),
+
+    #[error("Failed to reproject with geodesy: {0}")]
+    GeodesyError(String),
 }
 
 pub type Result<T> = std::result::Result<T, GeopolarsError>;
@@ -0,0 +1,91 @@
+use polars::export::arrow::array::{FixedSizeListArray, PrimitiveArray};
+
+/// The physical layout used to store a geometry's coordinates.
+///
+/// GeoArrow producers disagree on this: some ship a single interleaved `FixedSizeList<f64>[2]`
+/// buffer (`[x0, y0, x1, y1, ...]`), others ship separated `x`/`y` `PrimitiveArray<f64>` buffers.
+/// [`CoordBuffer`] lets the geometry array types read through either one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoordType {
+    /// A single buffer of interleaved `x`/`y` pairs.
+    Interleaved,
+    /// Two separate `x` and `y` buffers.
+    Separated,
+}
+
+/// A coordinate buffer storing either interleaved or separated `x`/`y` values.
+///
+/// This is read through by [`super::point::array::PointArray`],
+/// [`super::linestring::array::LineStringArray`], and
+/// [`super::polygon::array::PolygonArray`] so the same geometry logic works regardless of which
+/// physical layout the underlying Arrow array uses.
+#[derive(Debug, Clone)]
+pub enum CoordBuffer {
+    /// `[x0, y0, x1, y1, ...]` packed into a `FixedSizeList<f64>[2]`.
+    Interleaved(FixedSizeListArray),
+    /// Separate `x` and `y` buffers.
+    Separated(PrimitiveArray<f64>, PrimitiveArray<f64>),
+}
+
+impl CoordBuffer {
+    /// Which physical layout this buffer uses.
+    pub fn coord_type(&self) -> CoordType {
+        match self {
+            CoordBuffer::Interleaved(_) => CoordType::Interleaved,
+            CoordBuffer::Separated(_, _) => CoordType::Separated,
+        }
+    }
+
+    /// The number of coordinates in the buffer.
+    pub fn len(&self) -> usize {
+        match self {
+            CoordBuffer::Interleaved(arr) => arr.len(),
+            CoordBuffer::Separated(x, _) => x.len(),
+        }
+    }
+
+    /// Returns true if the buffer is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the `(x, y)` pair at slot `i`, regardless of the underlying layout.
+    pub fn value(&self, i: usize) -> (f64, f64) {
+        match self {
+            CoordBuffer::Interleaved(arr) => {
+                let values = arr
+                    .values()
+                    .as_any()
+                    .downcast_ref::<PrimitiveArray<f64>>()
+                    .unwrap();
+                (values.value(i * 2), values.value(i * 2 + 1))
+            }
+            CoordBuffer::Separated(x, y) => (x.value(i), y.value(i)),
+        }
+    }
+
+    /// Converts this buffer to the separated `(x, y)` layout, materializing new buffers if it
+    /// was interleaved.
+    pub fn into_separated(self) -> (PrimitiveArray<f64>, PrimitiveArray<f64>) {
+        match self {
+            CoordBuffer::Separated(x, y) => (x, y),
+            CoordBuffer::Interleaved(arr) => {
+                let values = arr
+                    .values()
+                    .as_any()
+                    .downcast_ref::<PrimitiveArray<f64>>()
+                    .unwrap();
+                let mut x = Vec::with_capacity(arr.len());
+                let mut y = Vec::with_capacity(arr.len());
+                for i in 0..arr.len() {
+                    x.push(values.value(i * 2));
+                    y.push(values.value(i * 2 + 1));
+                }
+                (
+                    PrimitiveArray::<f64>::from_vec(x),
+                    PrimitiveArray::<f64>::from_vec(y),
+                )
+            }
+        }
+    }
+}
@@ -1,8 +1,10 @@
 // use crate::traits::line_string::LineStringTrait;
 use geo::{Coord, LineString};
 use polars::export::arrow::array::{Array, ListArray, PrimitiveArray, StructArray};
+use polars::export::arrow::bitmap::utils::{BitmapIter, ZipValidity};
 use polars::prelude::Series;
 
+use crate::geoarrow::coord::CoordBuffer;
 use crate::util::index_to_chunked_index;
 
 /// A struct representing a non-null single LineString geometry
@@ -39,6 +41,97 @@ impl LineStringScalar {
 #[derive(Debug, Clone)]
 pub struct LineStringArray(ListArray<i64>);
 
+/// The decomposed parts of a [`LineStringArray`]: each geometry's coordinates read through a
+/// per-row [`CoordBuffer`] so it doesn't matter whether a given row's coordinates are stored
+/// interleaved or separated.
+pub struct LineStringArrayParts(ListArray<i64>);
+
+impl LineStringArrayParts {
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn get_as_geo(&self, i: usize) -> Option<LineString> {
+        if self.0.is_null(i) {
+            return None;
+        }
+
+        let struct_array = self.0.value(i);
+        let struct_array = struct_array.as_any().downcast_ref::<StructArray>().unwrap();
+        let struct_array_values = struct_array.values();
+
+        let x = struct_array_values[0]
+            .as_any()
+            .downcast_ref::<PrimitiveArray<f64>>()
+            .unwrap()
+            .clone();
+        let y = struct_array_values[1]
+            .as_any()
+            .downcast_ref::<PrimitiveArray<f64>>()
+            .unwrap()
+            .clone();
+
+        let coords = CoordBuffer::Separated(x, y);
+        let coords: Vec<Coord> = (0..coords.len())
+            .map(|j| {
+                let (x, y) = coords.value(j);
+                Coord { x, y }
+            })
+            .collect();
+
+        Some(LineString::new(coords))
+    }
+
+    /// Iterator over every linestring in the array, ignoring validity.
+    pub fn iter_geo_values(&self) -> impl Iterator<Item = LineString> + '_ {
+        (0..self.len()).map(|i| {
+            let struct_array = self.0.value(i);
+            let struct_array = struct_array.as_any().downcast_ref::<StructArray>().unwrap();
+            let struct_array_values = struct_array.values();
+
+            let x = struct_array_values[0]
+                .as_any()
+                .downcast_ref::<PrimitiveArray<f64>>()
+                .unwrap()
+                .clone();
+            let y = struct_array_values[1]
+                .as_any()
+                .downcast_ref::<PrimitiveArray<f64>>()
+                .unwrap()
+                .clone();
+
+            let coords = CoordBuffer::Separated(x, y);
+            let coords: Vec<Coord> = (0..coords.len())
+                .map(|j| {
+                    let (x, y) = coords.value(j);
+                    Coord { x, y }
+                })
+                .collect();
+
+            LineString::new(coords)
+        })
+    }
+
+    /// A null-aware iterator that walks the list array once while consuming its validity
+    /// [`Bitmap`] in lockstep, rather than re-checking validity on every `get_as_geo` call.
+    pub fn iter_geo(
+        &self,
+    ) -> ZipValidity<LineString, impl Iterator<Item = LineString> + '_, BitmapIter> {
+        ZipValidity::new_with_validity(self.iter_geo_values(), self.0.validity())
+    }
+}
+
+impl LineStringArray {
+    /// Decompose this array into its [`LineStringArrayParts`].
+    pub fn parts(&self) -> LineStringArrayParts {
+        LineStringArrayParts(self.0.clone())
+    }
+}
+
 impl LineStringArray {
     pub fn get(&self, i: usize) -> Option<LineStringScalar> {
         if self.0.is_null(i) {
@@ -66,7 +159,7 @@ impl LineStringArray {
 }
 
 #[derive(Debug, Clone)]
-pub struct LineStringSeries(Series);
+pub struct LineStringSeries(pub Series);
 
 impl LineStringSeries {
     pub fn get(&self, i: usize) -> Option<LineStringScalar> {
@@ -93,3 +186,23 @@ impl LineStringSeries {
         }
     }
 }
+
+impl LineStringSeries {
+    pub fn chunks(&self) -> Vec<LineStringArray> {
+        self.0
+            .chunks()
+            .iter()
+            .map(|chunk| {
+                LineStringArray(chunk.as_any().downcast_ref::<ListArray<i64>>().unwrap().clone())
+            })
+            .collect()
+    }
+
+    /// A null-aware iterator over every linestring in the series, walking each chunk's list
+    /// array once instead of re-resolving the chunk on every index.
+    pub fn iter_geo(&self) -> impl Iterator<Item = Option<LineString>> + '_ {
+        self.chunks()
+            .into_iter()
+            .flat_map(|chunk| chunk.iter_geo().collect::<Vec<_>>())
+    }
+}
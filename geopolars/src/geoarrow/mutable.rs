@@ -0,0 +1,20 @@
+/// A minimal interface for this module's mutable geometry array builders, so code that pushes
+/// rows onto a builder without knowing its concrete geometry type (e.g. a future type-generic
+/// `from_geom_vec`) can go through one trait instead of matching on geometry type first.
+///
+/// Only [`super::point::mutable::MutablePointArray`] implements this today; `MutableLineStringArray`
+/// and `MutablePolygonArray` only support building from a whole `Vec<Option<_>>` at once and don't
+/// yet have the incremental `push`/`push_null` this trait needs.
+pub trait MutableGeometryArray {
+    /// The number of geometries pushed onto this builder so far.
+    fn len(&self) -> usize;
+
+    /// Returns true if no geometries have been pushed onto this builder yet.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Pushes a null row, lazily initializing the validity bitmap on the first null the same way
+    /// every concrete builder's own `push`/`push_opt` does.
+    fn push_null(&mut self);
+}
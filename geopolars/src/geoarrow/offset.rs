@@ -0,0 +1,58 @@
+//! Helpers for converting between 32-bit and 64-bit Arrow list offsets.
+//!
+//! Every list-backed geometry type in this module is hardcoded to `i64` offsets, but many
+//! Arrow producers emit 32-bit offsets, and forcing a copy into `i64` blocks zero-copy interop.
+//! These helpers convert between the two so callers can accept either.
+
+use polars::export::arrow::array::ListArray;
+use polars::export::arrow::datatypes::DataType;
+use polars::export::arrow::offset::OffsetsBuffer;
+
+/// Widen a buffer of 32-bit offsets into 64-bit offsets.
+pub fn offsets_buffer_i32_to_i64(offsets: &OffsetsBuffer<i32>) -> OffsetsBuffer<i64> {
+    let widened: Vec<i64> = offsets.as_slice().iter().map(|&o| o as i64).collect();
+    OffsetsBuffer::try_from(widened).unwrap()
+}
+
+/// Narrow a buffer of 64-bit offsets into 32-bit offsets.
+///
+/// # Panics
+///
+/// Panics if any offset overflows `i32`.
+pub fn offsets_buffer_i64_to_i32(offsets: &OffsetsBuffer<i64>) -> OffsetsBuffer<i32> {
+    let narrowed: Vec<i32> = offsets
+        .as_slice()
+        .iter()
+        .map(|&o| i32::try_from(o).expect("offset overflows i32"))
+        .collect();
+    OffsetsBuffer::try_from(narrowed).unwrap()
+}
+
+/// Widen a `ListArray<i32>` into a `ListArray<i64>`, sharing the same child values and
+/// validity and only materializing a new offsets buffer.
+pub fn list_array_i32_to_i64(arr: &ListArray<i32>) -> ListArray<i64> {
+    let field = match arr.data_type() {
+        DataType::List(field) => field.clone(),
+        other => panic!("expected DataType::List, got {:?}", other),
+    };
+
+    ListArray::<i64>::new(
+        DataType::LargeList(field),
+        offsets_buffer_i32_to_i64(arr.offsets()),
+        arr.values().clone(),
+        arr.validity().cloned(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrips_through_i32_and_i64() {
+        let offsets = OffsetsBuffer::try_from(vec![0i64, 2, 5, 9]).unwrap();
+        let narrowed = offsets_buffer_i64_to_i32(&offsets);
+        let widened = offsets_buffer_i32_to_i64(&narrowed);
+        assert_eq!(offsets.as_slice(), widened.as_slice());
+    }
+}
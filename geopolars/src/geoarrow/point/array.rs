@@ -1,37 +1,96 @@
 use geo::Point;
 use polars::export::arrow::array::{Array, PrimitiveArray, StructArray};
+use polars::export::arrow::bitmap::utils::{BitmapIter, ZipValidity};
+use polars::export::arrow::bitmap::Bitmap;
 use polars::prelude::Series;
 
+use crate::geoarrow::coord::CoordBuffer;
 use crate::util::index_to_chunked_index;
 
 #[derive(Debug, Clone)]
 pub struct PointArray(StructArray);
 
+/// The decomposed parts of a [`PointArray`]: a single coordinate buffer (read through
+/// [`CoordBuffer`] so it doesn't matter whether the backing Arrow data is interleaved or
+/// separated) plus the array's validity.
+pub struct PointArrayParts {
+    pub coords: CoordBuffer,
+    pub validity: Option<Bitmap>,
+}
+
+impl PointArrayParts {
+    pub fn len(&self) -> usize {
+        self.coords.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn get_as_geo(&self, i: usize) -> Option<Point> {
+        if self
+            .validity
+            .as_ref()
+            .map(|v| !v.get_bit(i))
+            .unwrap_or(false)
+        {
+            return None;
+        }
+
+        let (x, y) = self.coords.value(i);
+        Some(Point::new(x, y))
+    }
+
+    /// Iterator over every point in the array, ignoring validity.
+    pub fn iter_geo_values(&self) -> impl Iterator<Item = Point> + '_ {
+        (0..self.len()).map(|i| {
+            let (x, y) = self.coords.value(i);
+            Point::new(x, y)
+        })
+    }
+
+    /// A null-aware iterator that walks the coordinate buffer once while consuming the
+    /// validity [`Bitmap`] in lockstep, instead of re-checking validity on every
+    /// `get_as_geo` call.
+    pub fn iter_geo(&self) -> ZipValidity<Point, impl Iterator<Item = Point> + '_, BitmapIter> {
+        ZipValidity::new_with_validity(self.iter_geo_values(), self.validity.as_ref())
+    }
+}
+
 impl PointArray {
     pub fn get_as_geo(&self, i: usize) -> Option<Point> {
         if self.0.is_null(i) {
             return None;
         }
 
-        let struct_array_values = self.0.values();
-        let x_arrow_array = &struct_array_values[0];
-        let y_arrow_array = &struct_array_values[1];
+        let parts = self.parts();
+        parts.get_as_geo(i)
+    }
 
-        let x_array_values = x_arrow_array
+    /// Decompose this array into its [`PointArrayParts`], reading through the separated `x`/`y`
+    /// buffers this array is backed by.
+    pub fn parts(&self) -> PointArrayParts {
+        let struct_array_values = self.0.values();
+        let x_array_values = struct_array_values[0]
             .as_any()
             .downcast_ref::<PrimitiveArray<f64>>()
-            .unwrap();
-        let y_array_values = y_arrow_array
+            .unwrap()
+            .clone();
+        let y_array_values = struct_array_values[1]
             .as_any()
             .downcast_ref::<PrimitiveArray<f64>>()
-            .unwrap();
+            .unwrap()
+            .clone();
 
-        Some(Point::new(x_array_values.value(i), y_array_values.value(i)))
+        PointArrayParts {
+            coords: CoordBuffer::Separated(x_array_values, y_array_values),
+            validity: self.0.validity().cloned(),
+        }
     }
 }
 
 #[derive(Debug, Clone)]
-pub struct PointSeries(Series);
+pub struct PointSeries(pub Series);
 
 impl PointSeries {
     pub fn get_as_geo(&self, i: usize) -> Option<Point> {
@@ -47,4 +106,22 @@ impl PointSeries {
         );
         pa.get_as_geo(local_idx)
     }
+
+    pub fn chunks(&self) -> Vec<PointArray> {
+        self.0
+            .chunks()
+            .iter()
+            .map(|chunk| {
+                PointArray(chunk.as_any().downcast_ref::<StructArray>().unwrap().clone())
+            })
+            .collect()
+    }
+
+    /// A null-aware iterator over every point in the series, walking each chunk's coordinate
+    /// buffer once instead of re-resolving the chunk on every index.
+    pub fn iter_geo(&self) -> impl Iterator<Item = Option<Point>> + '_ {
+        self.chunks()
+            .into_iter()
+            .flat_map(|chunk| chunk.parts().iter_geo().collect::<Vec<_>>())
+    }
 }
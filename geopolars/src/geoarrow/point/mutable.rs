@@ -4,6 +4,9 @@ use polars::export::arrow::bitmap::{Bitmap, MutableBitmap};
 use polars::export::arrow::datatypes::DataType;
 use polars::prelude::ArrowField;
 
+use crate::geoarrow::mutable::MutableGeometryArray;
+
+/// The Arrow equivalent to `Vec<Option<Point>>`, modeled on arrow2's `MutableStructArray`.
 #[derive(Debug, Clone)]
 pub struct MutablePointArray {
     x: Vec<f64>,
@@ -12,6 +15,29 @@ pub struct MutablePointArray {
 }
 
 impl MutablePointArray {
+    /// Creates a new empty [`MutablePointArray`].
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Creates a new [`MutablePointArray`] with a capacity.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            x: Vec::with_capacity(capacity),
+            y: Vec::with_capacity(capacity),
+            validity: None,
+        }
+    }
+
+    /// Reserves capacity for at least `additional` more points.
+    pub fn reserve(&mut self, additional: usize) {
+        self.x.reserve(additional);
+        self.y.reserve(additional);
+        if let Some(validity) = self.validity.as_mut() {
+            validity.reserve(additional)
+        }
+    }
+
     pub fn into_arrow(self) -> StructArray {
         let field_x = ArrowField::new("x", DataType::Float64, false);
         let field_y = ArrowField::new("y", DataType::Float64, false);
@@ -32,54 +58,113 @@ impl MutablePointArray {
     }
 
     pub fn push(&mut self, p: Point) {
-        self.x.push(p.x());
-        self.y.push(p.y());
+        self.push_opt(Some(p))
+    }
+
+    /// Adds a new, possibly-null, value to the array.
+    pub fn push_opt(&mut self, value: Option<Point>) {
+        match value {
+            Some(value) => {
+                self.x.push(value.x());
+                self.y.push(value.y());
+                if let Some(validity) = &mut self.validity {
+                    validity.push(true)
+                }
+            }
+            None => self.push_null(),
+        }
+    }
+}
+
+impl Default for MutablePointArray {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
-// /// Setters
-// impl MutablePointArray {
-//     /// Sets position `index` to `value`.
-//     /// Note that if it is the first time a null appears in this array,
-//     /// this initializes the validity bitmap (`O(N)`).
-//     /// # Panic
-//     /// Panics iff index is larger than `self.len()`.
-//     pub fn set(&mut self, index: usize, value: Option<(f64, f64)>) {
-//         self.x.set(index, value.u);
-//         self.y.set(index, value);
-//         assert!(index < self.len());
-//         // Safety:
-//         // we just checked bounds
-//         unsafe { self.set_unchecked(index, value) }
-//     }
-
-//     /// Sets position `index` to `value`.
-//     /// Note that if it is the first time a null appears in this array,
-//     /// this initializes the validity bitmap (`O(N)`).
-//     /// # Safety
-//     /// Caller must ensure `index < self.len()`
-//     pub unsafe fn set_unchecked(&mut self, index: usize, value: Option<(f64, f64)>) {
-//         *self.values.get_unchecked_mut(index) = value.unwrap_or_default();
-
-//         if value.is_none() && self.validity.is_none() {
-//             // When the validity is None, all elements so far are valid. When one of the elements is set fo null,
-//             // the validity must be initialized.
-//             let mut validity = MutableBitmap::new();
-//             validity.extend_constant(self.len(), true);
-//             self.validity = Some(validity);
-//         }
-//         if let Some(x) = self.validity.as_mut() {
-//             x.set_unchecked(index, value.is_some())
-//         }
-//     }
-
-//     /// Sets the validity.
-//     /// # Panic
-//     /// Panics iff the validity's len is not equal to the existing values' length.
-//     pub fn set_validity(&mut self, validity: Option<MutableBitmap>) {
-//         if let Some(validity) = &validity {
-//             assert_eq!(self.values.len(), validity.len())
-//         }
-//         self.validity = validity;
-//     }
-// }
+impl From<Vec<Point>> for MutablePointArray {
+    fn from(geoms: Vec<Point>) -> Self {
+        let mut arr = MutablePointArray::with_capacity(geoms.len());
+        for geom in geoms {
+            arr.push(geom);
+        }
+        arr
+    }
+}
+
+impl From<Vec<Option<Point>>> for MutablePointArray {
+    fn from(geoms: Vec<Option<Point>>) -> Self {
+        let mut arr = MutablePointArray::with_capacity(geoms.len());
+        for geom in geoms {
+            arr.push_opt(geom);
+        }
+        arr
+    }
+}
+
+/// Setters
+impl MutablePointArray {
+    /// Sets position `index` to `value`.
+    /// Note that if it is the first time a null appears in this array,
+    /// this initializes the validity bitmap (`O(N)`).
+    /// # Panic
+    /// Panics iff index is larger than `self.len()`.
+    pub fn set(&mut self, index: usize, value: Option<(f64, f64)>) {
+        assert!(index < self.len());
+        // Safety:
+        // we just checked bounds
+        unsafe { self.set_unchecked(index, value) }
+    }
+
+    /// Sets position `index` to `value`.
+    /// Note that if it is the first time a null appears in this array,
+    /// this initializes the validity bitmap (`O(N)`).
+    /// # Safety
+    /// Caller must ensure `index < self.len()`
+    pub unsafe fn set_unchecked(&mut self, index: usize, value: Option<(f64, f64)>) {
+        let (x, y) = value.unwrap_or_default();
+        *self.x.get_unchecked_mut(index) = x;
+        *self.y.get_unchecked_mut(index) = y;
+
+        if value.is_none() && self.validity.is_none() {
+            // When the validity is None, all elements so far are valid. When one of the elements is set to null,
+            // the validity must be initialized.
+            let mut validity = MutableBitmap::new();
+            validity.extend_constant(self.len(), true);
+            self.validity = Some(validity);
+        }
+        if let Some(x) = self.validity.as_mut() {
+            x.set_unchecked(index, value.is_some())
+        }
+    }
+
+    /// Sets the validity.
+    /// # Panic
+    /// Panics iff the validity's len is not equal to the existing values' length.
+    pub fn set_validity(&mut self, validity: Option<MutableBitmap>) {
+        if let Some(validity) = &validity {
+            assert_eq!(self.len(), validity.len())
+        }
+        self.validity = validity;
+    }
+}
+
+impl MutableGeometryArray for MutablePointArray {
+    fn len(&self) -> usize {
+        self.x.len()
+    }
+
+    fn push_null(&mut self) {
+        self.x.push(f64::default());
+        self.y.push(f64::default());
+        match &mut self.validity {
+            Some(validity) => validity.push(false),
+            None => {
+                let mut validity = MutableBitmap::with_capacity(self.x.capacity());
+                validity.extend_constant(self.len() - 1, true);
+                validity.push(false);
+                self.validity = Some(validity)
+            }
+        }
+    }
+}
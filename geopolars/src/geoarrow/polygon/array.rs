@@ -1,12 +1,29 @@
-use geo::{LineString, Polygon};
+use std::borrow::Cow;
+
+use geo::{Coord, LineString, Polygon};
 use polars::export::arrow::array::{Array, ListArray, PrimitiveArray, StructArray};
+use polars::export::arrow::bitmap::utils::{BitmapIter, ZipValidity};
 use polars::export::arrow::bitmap::Bitmap;
 use polars::export::arrow::offset::OffsetsBuffer;
 use polars::prelude::Series;
 
+use crate::geoarrow::coord::CoordBuffer;
 use crate::geoarrow::linestring::array::LineStringScalar;
+use crate::geoarrow::offset::list_array_i32_to_i64;
 use crate::util::index_to_chunked_index;
 
+/// Downcast an Arrow array to a `ListArray<i64>`, widening it first if it was actually backed
+/// by 32-bit offsets, instead of panicking on the unconditional `i64` downcast.
+fn downcast_list_array(arr: &dyn Array) -> Cow<ListArray<i64>> {
+    if let Some(arr) = arr.as_any().downcast_ref::<ListArray<i64>>() {
+        Cow::Borrowed(arr)
+    } else if let Some(arr) = arr.as_any().downcast_ref::<ListArray<i32>>() {
+        Cow::Owned(list_array_i32_to_i64(arr))
+    } else {
+        panic!("expected a ListArray<i32> or ListArray<i64>")
+    }
+}
+
 /// A struct representing a non-null single LineString geometry
 #[derive(Debug, Clone)]
 pub struct PolygonScalar(ListArray<i64>);
@@ -35,16 +52,89 @@ impl PolygonScalar {
     }
 }
 
-pub struct PolygonArrayParts<'a> {
-    pub x: &'a PrimitiveArray<f64>,
-    pub y: &'a PrimitiveArray<f64>,
-    pub ring_offsets: &'a OffsetsBuffer<i64>,
-    pub geom_offsets: &'a OffsetsBuffer<i64>,
-    pub validity: Option<&'a Bitmap>,
+/// The decomposed parts of a [`PolygonArray`]: a single coordinate buffer shared by every ring
+/// (read through [`CoordBuffer`] so it doesn't matter whether the array was built from an
+/// interleaved or separated physical layout), plus the offsets needed to find each polygon's
+/// rings within it.
+pub struct PolygonArrayParts {
+    pub coords: CoordBuffer,
+    pub ring_offsets: OffsetsBuffer<i64>,
+    pub geom_offsets: OffsetsBuffer<i64>,
+    pub validity: Option<Bitmap>,
+}
+
+impl PolygonArrayParts {
+    pub fn len(&self) -> usize {
+        self.geom_offsets.len_proxy()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn get_as_geo(&self, i: usize) -> Option<Polygon> {
+        if self
+            .validity
+            .as_ref()
+            .map(|v| !v.get_bit(i))
+            .unwrap_or(false)
+        {
+            return None;
+        }
+
+        let (ring_start, ring_end) = self.geom_offsets.start_end(i);
+
+        let ring_as_linestring = |ring_idx: usize| -> LineString {
+            let (coord_start, coord_end) = self.ring_offsets.start_end(ring_idx);
+            let coords: Vec<Coord> = (coord_start..coord_end)
+                .map(|j| {
+                    let (x, y) = self.coords.value(j);
+                    Coord { x, y }
+                })
+                .collect();
+            LineString::new(coords)
+        };
+
+        let exterior = ring_as_linestring(ring_start);
+        let interiors = (ring_start + 1..ring_end).map(ring_as_linestring).collect();
+
+        Some(Polygon::new(exterior, interiors))
+    }
+
+    /// Iterator over every polygon in the array, ignoring validity.
+    pub fn iter_geo_values(&self) -> impl Iterator<Item = Polygon> + '_ {
+        // `get_as_geo` only returns `None` because of validity, which we're deliberately
+        // ignoring here, so every row in range is guaranteed to produce a `Some`.
+        (0..self.len()).map(|i| {
+            let (ring_start, ring_end) = self.geom_offsets.start_end(i);
+
+            let ring_as_linestring = |ring_idx: usize| -> LineString {
+                let (coord_start, coord_end) = self.ring_offsets.start_end(ring_idx);
+                let coords: Vec<Coord> = (coord_start..coord_end)
+                    .map(|j| {
+                        let (x, y) = self.coords.value(j);
+                        Coord { x, y }
+                    })
+                    .collect();
+                LineString::new(coords)
+            };
+
+            let exterior = ring_as_linestring(ring_start);
+            let interiors = (ring_start + 1..ring_end).map(ring_as_linestring).collect();
+            Polygon::new(exterior, interiors)
+        })
+    }
+
+    /// A null-aware iterator that walks the coordinate buffers once while consuming the
+    /// validity [`Bitmap`] in lockstep, rather than re-checking validity on every `get_as_geo`
+    /// call. Null slots yield `None` without attempting to decode a (meaningless) geometry.
+    pub fn iter_geo(&self) -> ZipValidity<Polygon, impl Iterator<Item = Polygon> + '_, BitmapIter> {
+        ZipValidity::new_with_validity(self.iter_geo_values(), self.validity.as_ref())
+    }
 }
 
 #[derive(Debug, Clone)]
-pub struct PolygonArray<'a>(&'a ListArray<i64>);
+pub struct PolygonArray<'a>(Cow<'a, ListArray<i64>>);
 
 impl<'a> PolygonArray<'a> {
     pub fn get(&self, i: usize) -> Option<PolygonScalar> {
@@ -65,14 +155,42 @@ impl<'a> PolygonArray<'a> {
         polygon_item.map(|p| p.into_geo())
     }
 
-    // pub fn parts(&self) -> PolygonArrayParts<'a> {
-    //     let geom_offsets = self.0.offsets();
+    pub fn parts(&self) -> PolygonArrayParts {
+        let geom_offsets = self.0.offsets().clone();
+        let validity = self.0.validity().cloned();
 
-    //     let inner_values = self.0.values();
+        let rings = self
+            .0
+            .values()
+            .as_any()
+            .downcast_ref::<ListArray<i64>>()
+            .unwrap();
+        let ring_offsets = rings.offsets().clone();
 
-    //     // PolygonArrayParts { x: (), y: (), ring_offsets: (), geom_offsets: (), validity: () }
-    //     todo!()
-    // }
+        let coord_struct = rings
+            .values()
+            .as_any()
+            .downcast_ref::<StructArray>()
+            .unwrap();
+        let coord_values = coord_struct.values();
+        let x = coord_values[0]
+            .as_any()
+            .downcast_ref::<PrimitiveArray<f64>>()
+            .unwrap()
+            .clone();
+        let y = coord_values[1]
+            .as_any()
+            .downcast_ref::<PrimitiveArray<f64>>()
+            .unwrap()
+            .clone();
+
+        PolygonArrayParts {
+            coords: CoordBuffer::Separated(x, y),
+            ring_offsets,
+            geom_offsets,
+            validity,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -83,7 +201,7 @@ impl PolygonSeries<'_> {
         let (chunk_idx, local_idx) = index_to_chunked_index(self.0, i);
         let chunk = &self.0.chunks()[chunk_idx];
 
-        let polygon_array = PolygonArray(chunk.as_any().downcast_ref::<ListArray<i64>>().unwrap());
+        let polygon_array = PolygonArray(downcast_list_array(chunk.as_ref()));
         polygon_array.get(local_idx)
     }
 
@@ -96,7 +214,16 @@ impl PolygonSeries<'_> {
         self.0
             .chunks()
             .iter()
-            .map(|chunk| PolygonArray(chunk.as_any().downcast_ref::<ListArray<i64>>().unwrap()))
+            .map(|chunk| PolygonArray(downcast_list_array(chunk.as_ref())))
             .collect()
     }
+
+    /// A null-aware iterator over every polygon in the series, walking each chunk's coordinate
+    /// buffers once instead of re-resolving the chunk and re-checking validity on every index
+    /// the way [`PolygonSeries::get_as_geo`] does.
+    pub fn iter_geo(&self) -> impl Iterator<Item = Option<Polygon>> + '_ {
+        self.chunks()
+            .into_iter()
+            .flat_map(|chunk| chunk.parts().iter_geo().collect::<Vec<_>>())
+    }
 }
@@ -15,12 +15,8 @@ where
 
     let series = PointSeries(series);
 
-    for point_array in series.chunks() {
-        let parts = point_array.parts();
-        for i in 0..parts.len() {
-            let point = parts.get_as_geo(i);
-            result.push(point.map(&op))
-        }
+    for point in series.iter_geo() {
+        result.push(point.map(&op))
     }
 
     let result: PrimitiveArray<f64> = result.into();
@@ -36,12 +32,8 @@ where
 
     let series = LineStringSeries(series);
 
-    for line_string_array in series.chunks() {
-        let parts = line_string_array.parts();
-        for i in 0..parts.len() {
-            let line_string = parts.get_as_geo(i);
-            result.push(line_string.map(&op))
-        }
+    for line_string in series.iter_geo() {
+        result.push(line_string.map(&op))
     }
 
     let result: PrimitiveArray<f64> = result.into();
@@ -57,12 +49,8 @@ where
 
     let series = PolygonSeries(series);
 
-    for polygon_array in series.chunks() {
-        let parts = polygon_array.parts();
-        for i in 0..parts.len() {
-            let polygon = parts.get_as_geo(i);
-            result.push(polygon.map(&op))
-        }
+    for polygon in series.iter_geo() {
+        result.push(polygon.map(&op))
     }
 
     let result: PrimitiveArray<f64> = result.into();
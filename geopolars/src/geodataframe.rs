@@ -1,10 +1,21 @@
 use crate::error::Result;
 use crate::geoseries::GeoSeries;
+use crate::spatial_index::{spatial_join, SpatialJoinArgs};
+use crate::util::Predicate;
 use polars::prelude::{DataFrame, Series};
 
 pub trait GeoDataFrame {
     fn centroid(&self) -> Result<Series>;
     fn convex_hull(&self) -> Result<Series>;
+
+    /// Spatially joins this dataframe's `geometry` column against `other`'s, returning an inner
+    /// join of the two dataframes (plus their original columns) for every row pair where
+    /// `predicate` holds.
+    ///
+    /// Builds an R-tree over each side's geometry column (via [`SpatialIndex`](crate::spatial_index::SpatialIndex))
+    /// and only falls back to the exact `geo` predicate for the candidate pairs whose envelopes
+    /// overlap, same as [`spatial_join`].
+    fn sjoin(&self, other: &DataFrame, predicate: Predicate) -> Result<DataFrame>;
 }
 
 impl GeoDataFrame for DataFrame {
@@ -17,4 +28,12 @@ impl GeoDataFrame for DataFrame {
         let geom_column = self.column("geometry")?;
         geom_column.convex_hull()
     }
+
+    fn sjoin(&self, other: &DataFrame, predicate: Predicate) -> Result<DataFrame> {
+        let options = SpatialJoinArgs {
+            predicate,
+            ..Default::default()
+        };
+        Ok(spatial_join(self, other, options)?)
+    }
 }
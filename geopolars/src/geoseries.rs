@@ -5,6 +5,7 @@ use crate::ops::length::GeodesicLengthMethod;
 use crate::ops::proj::ProjOptions;
 use geo::algorithm::affine_ops::AffineTransform;
 use geo::Geometry;
+use geodesy::Direction;
 use geozero::{CoordDimensions, ToWkb};
 use polars::error::ErrString;
 use polars::export::arrow::array::{Array, BinaryArray, MutableBinaryArray};
@@ -24,6 +25,11 @@ pub trait GeoSeries {
     /// Note that centroid does not have to be on or within original geometry.
     fn centroid(&self) -> Result<Series>;
 
+    /// Returns a `Series` of each geometry's axis-aligned bounding box, laid out as the
+    /// `[minx, miny, maxx, maxy]` [`geopolars_arrow::RectArray`] does, computed by streaming the
+    /// GeoArrow coordinate buffer directly rather than decoding WKB.
+    fn bounds(&self) -> Result<Series>;
+
     /// Returns a GeoSeries of geometries representing the convex hull of each geometry.
     ///
     /// The convex hull of a geometry is the smallest convex Polygon containing all the points in each geometry
@@ -89,6 +95,22 @@ pub trait GeoSeries {
     /// implicitly closed by copying the first tuple to the last index.
     fn is_ring(&self) -> Result<Series>;
 
+    /// Returns a Series with the value of the euclidean perimeter of each geometry.
+    ///
+    /// Unlike [`GeoSeries::euclidean_length`], a `Polygon`/`MultiPolygon`'s interior rings
+    /// (holes) are included alongside the exterior ring, giving the full perimeter rather than
+    /// just the outer boundary. Degenerates to the same value as `euclidean_length` for Point,
+    /// MultiPoint, and linear geometries.
+    fn euclidean_perimeter(&self) -> Result<Series>;
+
+    /// Returns a Series with the value of the geodesic perimeter of each geometry.
+    ///
+    /// Unlike [`GeoSeries::geodesic_length`], a `Polygon`/`MultiPolygon`'s interior rings
+    /// (holes) are included alongside the exterior ring, giving the full perimeter rather than
+    /// just the outer boundary. Degenerates to the same value as `geodesic_length` for Point,
+    /// MultiPoint, and linear geometries.
+    fn geodesic_perimeter(&self, method: GeodesicLengthMethod) -> Result<Series>;
+
     /// Returns a GeoSeries with each of the geometries rotated by a fixed x and y ammount around
     /// some origin.
     ///
@@ -152,6 +174,44 @@ pub trait GeoSeries {
     /// * `other` - The Geoseries (elementwise) to find the distance to.
     fn distance(&self, other: &Series) -> Result<Series>;
 
+    /// Spatially joins `self` against `other`, returning the matched `(self_index, other_index)`
+    /// row pairs as two `u64` index Series suitable for feeding into a polars join.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The GeoSeries to join against.
+    ///
+    /// * `predicate` - One of `"intersects"`, `"contains"`, `"within"`, or `"dwithin(<distance>)"`
+    /// (e.g. `"dwithin(0.5)"`).
+    fn sjoin(&self, other: &Series, predicate: &str) -> Result<(Series, Series)>;
+
+    /// Reprojects every coordinate of a GeoSeries in a single bulk pass, using a
+    /// [geodesy](https://docs.rs/geodesy) pipeline/definition string rather than `PROJ`.
+    ///
+    /// Unlike [`GeoSeries::to_crs`], which transforms one geometry at a time through `PROJ`, this
+    /// walks each GeoArrow array's flat coordinate buffer directly and applies `definition` to all
+    /// of it at once, leaving `geom_offsets`/`ring_offsets`/validity untouched.
+    ///
+    /// # Arguments
+    ///
+    /// * `definition` - A geodesy pipeline/operation definition string.
+    ///
+    /// * `direction` - Whether to run `definition` forward or in reverse.
+    #[cfg(feature = "geodesy")]
+    fn reproject(&self, definition: &str, direction: Direction) -> Result<Series>;
+
+    /// Returns each geometry's length after reprojecting it from WGS84 into the UTM zone implied
+    /// by its centroid, together with the EPSG code of the zone chosen for that row.
+    ///
+    /// Euclidean length on raw lon/lat coordinates is meaningless (it's in degrees), and until now
+    /// [`GeoSeries::geodesic_length`] was the only correct option. This gives fast, accurate
+    /// planar lengths for local/regional data via a single UTM reprojection instead of per-segment
+    /// geodesic trigonometry. It isn't aware of geometries spanning more than one UTM zone -- the
+    /// returned zone `Series` lets a caller flag those rows (by comparing zones across a
+    /// geometry's own vertices) and fall back to `geodesic_length` for them.
+    #[cfg(feature = "geodesy")]
+    fn utm_length(&self) -> Result<(Series, Series)>;
+
     // Note: Ideally we wouldn't have both `from` and `to` here, where the series would include the
     // current CRS, but that would require polars to support extension types.
     #[cfg(feature = "proj")]
@@ -167,6 +227,21 @@ pub trait GeoSeries {
         proj_options: ProjOptions,
     ) -> Result<Series>;
 
+    /// Reprojects an EWKB-encoded `series` to `to`, reading each geometry's source CRS from its
+    /// own embedded SRID instead of requiring a `from` CRS string up front (unlike
+    /// [`GeoSeries::to_crs`]/[`GeoSeries::to_crs_with_options`]), and re-embeds the target SRID
+    /// into the output EWKB when `to` is itself an `"EPSG:<code>"` string, so chained calls stay
+    /// CRS-aware without the caller tracking a CRS per column themselves.
+    #[cfg(feature = "proj")]
+    fn to_crs_from_ewkb(&self, to: &str, proj_options: ProjOptions) -> Result<Series>;
+
+    /// Reduces [`GeoSeries::bounds`] down to the single box covering every non-null geometry in
+    /// the series.
+    ///
+    /// # Panics
+    /// Panics if the series has no non-null geometries, since there is then no box to return.
+    fn total_bounds(&self) -> Result<geo::Rect>;
+
     /// Returns a GeoSeries with each of the geometries translated by a fixed x and y amount
     ///
     /// # Arguments
@@ -185,6 +260,10 @@ pub trait GeoSeries {
 
     /// Return the y location of point geometries in a GeoSeries
     fn y(&self) -> Result<Series>;
+
+    /// Return the z location of point geometries in a GeoSeries, or null where a geometry has
+    /// no third ordinate (matching how GeoPandas yields `NaN` for 2D points)
+    fn z(&self) -> Result<Series>;
 }
 
 impl GeoSeries for Series {
@@ -196,6 +275,10 @@ impl GeoSeries for Series {
         crate::ops::area::area(self)
     }
 
+    fn bounds(&self) -> Result<Series> {
+        crate::ops::bounds::bounds(self)
+    }
+
     fn centroid(&self) -> Result<Series> {
         crate::ops::centroid::centroid(self)
     }
@@ -253,6 +336,14 @@ impl GeoSeries for Series {
         crate::ops::is_ring::is_ring(self)
     }
 
+    fn euclidean_perimeter(&self) -> Result<Series> {
+        crate::ops::length::euclidean_perimeter(self)
+    }
+
+    fn geodesic_perimeter(&self, method: GeodesicLengthMethod) -> Result<Series> {
+        crate::ops::length::geodesic_perimeter(self, method)
+    }
+
     fn rotate(&self, angle: f64, origin: TransformOrigin) -> Result<Series> {
         crate::ops::affine::rotate(self, angle, origin)
     }
@@ -273,6 +364,20 @@ impl GeoSeries for Series {
         crate::ops::distance::euclidean_distance(self, other)
     }
 
+    fn sjoin(&self, other: &Series, predicate: &str) -> Result<(Series, Series)> {
+        crate::ops::sjoin::sjoin(self, other, predicate)
+    }
+
+    #[cfg(feature = "geodesy")]
+    fn reproject(&self, definition: &str, direction: Direction) -> Result<Series> {
+        crate::ops::reproject::reproject(self, definition, direction)
+    }
+
+    #[cfg(feature = "geodesy")]
+    fn utm_length(&self) -> Result<(Series, Series)> {
+        crate::ops::utm_length::utm_length(self)
+    }
+
     #[cfg(feature = "proj")]
     fn to_crs(&self, from: &str, to: &str) -> Result<Series> {
         crate::ops::proj::to_crs(self, from, to)
@@ -288,6 +393,15 @@ impl GeoSeries for Series {
         crate::ops::proj::to_crs_with_options(self, from, to, proj_options)
     }
 
+    #[cfg(feature = "proj")]
+    fn to_crs_from_ewkb(&self, to: &str, proj_options: ProjOptions) -> Result<Series> {
+        crate::ops::proj::to_crs_from_ewkb(self, to, proj_options)
+    }
+
+    fn total_bounds(&self) -> Result<geo::Rect> {
+        crate::ops::bounds::total_bounds(self)
+    }
+
     fn translate(&self, x: f64, y: f64) -> Result<Series> {
         crate::ops::affine::translate(self, x, y)
     }
@@ -299,6 +413,10 @@ impl GeoSeries for Series {
     fn y(&self) -> Result<Series> {
         crate::ops::point::y(self)
     }
+
+    fn z(&self) -> Result<Series> {
+        crate::ops::point::z(self)
+    }
 }
 
 #[cfg(test)]
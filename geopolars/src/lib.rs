@@ -1,3 +1,11 @@
+//! # Python FFI
+//!
+//! This crate is Rust-only: the `rust_series_to_py_geoseries`/`py_series_to_rust_series`
+//! boundary lives in the sibling `py-geopolars` bindings crate. The Arrow C Stream
+//! (`ArrowArrayStream`) export/import this note used to claim was out of scope now lives there
+//! too, as `rust_series_to_py_stream`/`py_stream_to_rust_series` in `py-geopolars/src/ffi.rs`,
+//! alongside the existing single-chunk `_export_to_c`/`_import_from_c` helpers.
+
 pub mod ops;
 pub mod spatial_index;
 pub mod util;
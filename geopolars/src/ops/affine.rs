@@ -6,8 +6,8 @@ use geo::algorithm::affine_ops::AffineTransform;
 use geo::algorithm::bounding_rect::BoundingRect;
 use geo::algorithm::centroid::Centroid;
 use geo::Geometry;
-use geo::{map_coords::MapCoords, Point};
-use polars::export::arrow::array::Array;
+use geo::{map_coords::MapCoords, Coord, Point};
+use polars::export::arrow::array::{Array, ListArray, PrimitiveArray, StructArray};
 use polars::prelude::Series;
 
 use crate::util::iter_geom;
@@ -32,12 +32,24 @@ pub(crate) fn affine_transform(
     match get_geoarrow_type(series) {
         GeoArrowType::WKB => affine_transform_wkb(series, matrix),
         GeoArrowType::Point => affine_transform_geoarrow_point(series, matrix),
+        GeoArrowType::LineString => affine_transform_geoarrow_linestring(series, matrix),
+        GeoArrowType::Polygon => affine_transform_geoarrow_polygon(series, matrix),
         _ => todo!(),
     }
 }
 
 pub(crate) fn rotate(series: &Series, angle: f64, origin: TransformOrigin) -> Result<Series> {
-    rotate_wkb(series, angle, origin)
+    match get_geoarrow_type(series) {
+        GeoArrowType::LineString => {
+            rotate_scale_skew_geoarrow_linestring(series, &origin, move |o| {
+                AffineTransform::rotate(angle, o)
+            })
+        }
+        GeoArrowType::Polygon => rotate_scale_skew_geoarrow_polygon(series, &origin, move |o| {
+            AffineTransform::rotate(angle, o)
+        }),
+        _ => rotate_wkb(series, angle, origin),
+    }
 }
 
 pub(crate) fn scale(
@@ -46,11 +58,31 @@ pub(crate) fn scale(
     yfact: f64,
     origin: TransformOrigin,
 ) -> Result<Series> {
-    scale_wkb(series, xfact, yfact, origin)
+    match get_geoarrow_type(series) {
+        GeoArrowType::LineString => {
+            rotate_scale_skew_geoarrow_linestring(series, &origin, move |o| {
+                AffineTransform::scale(xfact, yfact, o)
+            })
+        }
+        GeoArrowType::Polygon => rotate_scale_skew_geoarrow_polygon(series, &origin, move |o| {
+            AffineTransform::scale(xfact, yfact, o)
+        }),
+        _ => scale_wkb(series, xfact, yfact, origin),
+    }
 }
 
 pub(crate) fn skew(series: &Series, xs: f64, ys: f64, origin: TransformOrigin) -> Result<Series> {
-    skew_wkb(series, xs, ys, origin)
+    match get_geoarrow_type(series) {
+        GeoArrowType::LineString => {
+            rotate_scale_skew_geoarrow_linestring(series, &origin, move |o| {
+                AffineTransform::skew(xs, ys, o)
+            })
+        }
+        GeoArrowType::Polygon => rotate_scale_skew_geoarrow_polygon(series, &origin, move |o| {
+            AffineTransform::skew(xs, ys, o)
+        }),
+        _ => skew_wkb(series, xs, ys, origin),
+    }
 }
 
 pub(crate) fn translate(series: &Series, x: f64, y: f64) -> Result<Series> {
@@ -88,42 +120,368 @@ fn affine_transform_geoarrow_point(
     Ok(series)
 }
 
-// fn affine_transform_geoarrow_linestring(
-//     series: &Series,
-//     matrix: impl Into<AffineTransform<f64>>,
-// ) -> Result<Series> {
-//     let transform: AffineTransform<f64> = matrix.into();
-
-//     // TODO: need to copy offsets from
-//     let mut result = MutableLineStringArray::with_capacity(series.len());
-//     for chunk in LineStringSeries(series).chunks() {
-//         let parts = chunk.parts();
-//         for coord in parts.iter_coords() {
-//             result.push(coord.map(|c| Point(transform.apply(c))));
-//         }
-//     }
-
-//     let series = Series::try_from(("geometry", Box::new(result.into_arrow()) as Box<dyn Array>))?;
-//     Ok(series)
-// }
-
-// fn affine_transform_geoarrow_polygon(
-//     series: &Series,
-//     matrix: impl Into<AffineTransform<f64>>,
-// ) -> Result<Series> {
-//     let transform: AffineTransform<f64> = matrix.into();
-
-//     let mut result = MutablePolygonArray::with_capacity(series.len());
-//     for chunk in PolygonSeries(series).chunks() {
-//         let parts = chunk.parts();
-//         for coord in parts.iter_coords() {
-//             result.push(coord.map(|c| Point(transform.apply(c))));
-//         }
-//     }
-
-//     let series = Series::try_from(("geometry", Box::new(result.into_arrow()) as Box<dyn Array>))?;
-//     Ok(series)
-// }
+/// Applies `matrix` directly to a GeoArrow LineString column's flat coordinate buffer, copying
+/// `geom_offsets` verbatim: an affine map only ever rewrites coordinate values, so the offsets
+/// that say which points belong to which `LineString` never change.
+fn affine_transform_geoarrow_linestring(
+    series: &Series,
+    matrix: impl Into<AffineTransform<f64>>,
+) -> Result<Series> {
+    let transform: AffineTransform<f64> = matrix.into();
+
+    let list_array = series.chunks()[0]
+        .as_any()
+        .downcast_ref::<ListArray<i64>>()
+        .unwrap();
+    let struct_array = list_array
+        .values()
+        .as_any()
+        .downcast_ref::<StructArray>()
+        .unwrap();
+
+    let transformed_struct = transform_struct_coords(struct_array, &transform);
+    let transformed_list = ListArray::<i64>::new(
+        list_array.data_type().clone(),
+        list_array.offsets().clone(),
+        transformed_struct.boxed(),
+        list_array.validity().cloned(),
+    );
+
+    let series = Series::try_from(("geometry", Box::new(transformed_list) as Box<dyn Array>))?;
+    Ok(series)
+}
+
+/// Applies `matrix` directly to a GeoArrow Polygon column's flat coordinate buffer, copying both
+/// the `geom_offsets` (polygon -> rings) and `ring_offsets` (ring -> points) verbatim, for the
+/// same reason [`affine_transform_geoarrow_linestring`] copies its `geom_offsets`.
+fn affine_transform_geoarrow_polygon(
+    series: &Series,
+    matrix: impl Into<AffineTransform<f64>>,
+) -> Result<Series> {
+    let transform: AffineTransform<f64> = matrix.into();
+
+    let polygon_list = series.chunks()[0]
+        .as_any()
+        .downcast_ref::<ListArray<i64>>()
+        .unwrap();
+    let ring_list = polygon_list
+        .values()
+        .as_any()
+        .downcast_ref::<ListArray<i64>>()
+        .unwrap();
+    let struct_array = ring_list
+        .values()
+        .as_any()
+        .downcast_ref::<StructArray>()
+        .unwrap();
+
+    let transformed_struct = transform_struct_coords(struct_array, &transform);
+    let transformed_ring_list = ListArray::<i64>::new(
+        ring_list.data_type().clone(),
+        ring_list.offsets().clone(),
+        transformed_struct.boxed(),
+        ring_list.validity().cloned(),
+    );
+    let transformed_polygon_list = ListArray::<i64>::new(
+        polygon_list.data_type().clone(),
+        polygon_list.offsets().clone(),
+        transformed_ring_list.boxed(),
+        polygon_list.validity().cloned(),
+    );
+
+    let series = Series::try_from((
+        "geometry",
+        Box::new(transformed_polygon_list) as Box<dyn Array>,
+    ))?;
+    Ok(series)
+}
+
+/// Applies `transform` to every `(x, y)` pair in `struct_array`'s `x`/`y` children, keeping its
+/// validity bitmap untouched.
+fn transform_struct_coords(struct_array: &StructArray, transform: &AffineTransform<f64>) -> StructArray {
+    let x_array = struct_array.values()[0]
+        .as_any()
+        .downcast_ref::<PrimitiveArray<f64>>()
+        .unwrap();
+    let y_array = struct_array.values()[1]
+        .as_any()
+        .downcast_ref::<PrimitiveArray<f64>>()
+        .unwrap();
+
+    let (new_x, new_y): (Vec<f64>, Vec<f64>) = x_array
+        .values_iter()
+        .zip(y_array.values_iter())
+        .map(|(&x, &y)| {
+            let c = transform.apply(Coord { x, y });
+            (c.x, c.y)
+        })
+        .unzip();
+
+    StructArray::new(
+        struct_array.data_type().clone(),
+        vec![
+            PrimitiveArray::new(x_array.data_type().clone(), new_x.into(), None).boxed(),
+            PrimitiveArray::new(y_array.data_type().clone(), new_y.into(), None).boxed(),
+        ],
+        struct_array.validity().cloned(),
+    )
+}
+
+/// Rotates/scales/skews a GeoArrow LineString column about a per-geometry origin: for each row,
+/// `origin` is resolved against that row's own coordinate slice (its centroid, its bounding-box
+/// center, or a fixed point) before `build`'s transform is applied to the same slice. Unlike
+/// [`affine_transform_geoarrow_linestring`], `geom_offsets` are untouched but the transform
+/// itself varies row to row, so this walks the coordinate buffer row-by-row rather than in one
+/// flat pass.
+fn rotate_scale_skew_geoarrow_linestring(
+    series: &Series,
+    origin: &TransformOrigin,
+    build: impl Fn(Coord<f64>) -> AffineTransform<f64>,
+) -> Result<Series> {
+    let list_array = series.chunks()[0]
+        .as_any()
+        .downcast_ref::<ListArray<i64>>()
+        .unwrap();
+    let struct_array = list_array
+        .values()
+        .as_any()
+        .downcast_ref::<StructArray>()
+        .unwrap();
+    let x_array = struct_array.values()[0]
+        .as_any()
+        .downcast_ref::<PrimitiveArray<f64>>()
+        .unwrap();
+    let y_array = struct_array.values()[1]
+        .as_any()
+        .downcast_ref::<PrimitiveArray<f64>>()
+        .unwrap();
+
+    let offsets = list_array.offsets();
+    let mut new_x = Vec::with_capacity(x_array.len());
+    let mut new_y = Vec::with_capacity(y_array.len());
+
+    for row in 0..list_array.len() {
+        let (start, end) = offsets.start_end(row);
+        let xs = &x_array.values()[start..end];
+        let ys = &y_array.values()[start..end];
+
+        let origin_point = match origin {
+            TransformOrigin::Centroid => {
+                let (x, y) = linestring_centroid(xs, ys);
+                Coord { x, y }
+            }
+            TransformOrigin::Center => {
+                let (x, y) = bbox_center(xs, ys);
+                Coord { x, y }
+            }
+            TransformOrigin::Point(point) => Coord {
+                x: point.x(),
+                y: point.y(),
+            },
+        };
+        let transform = build(origin_point);
+
+        for (&x, &y) in xs.iter().zip(ys.iter()) {
+            let c = transform.apply(Coord { x, y });
+            new_x.push(c.x);
+            new_y.push(c.y);
+        }
+    }
+
+    let transformed_struct = StructArray::new(
+        struct_array.data_type().clone(),
+        vec![
+            PrimitiveArray::new(x_array.data_type().clone(), new_x.into(), None).boxed(),
+            PrimitiveArray::new(y_array.data_type().clone(), new_y.into(), None).boxed(),
+        ],
+        struct_array.validity().cloned(),
+    );
+    let transformed_list = ListArray::<i64>::new(
+        list_array.data_type().clone(),
+        list_array.offsets().clone(),
+        transformed_struct.boxed(),
+        list_array.validity().cloned(),
+    );
+
+    let series = Series::try_from(("geometry", Box::new(transformed_list) as Box<dyn Array>))?;
+    Ok(series)
+}
+
+/// Rotates/scales/skews a GeoArrow Polygon column about a per-geometry origin, the Polygon
+/// counterpart of [`rotate_scale_skew_geoarrow_linestring`]. A polygon's own coordinate slice
+/// spans every ring it owns (exterior first, then holes), found by following its `geom_offsets`
+/// entry into `ring_offsets`.
+fn rotate_scale_skew_geoarrow_polygon(
+    series: &Series,
+    origin: &TransformOrigin,
+    build: impl Fn(Coord<f64>) -> AffineTransform<f64>,
+) -> Result<Series> {
+    let polygon_list = series.chunks()[0]
+        .as_any()
+        .downcast_ref::<ListArray<i64>>()
+        .unwrap();
+    let ring_list = polygon_list
+        .values()
+        .as_any()
+        .downcast_ref::<ListArray<i64>>()
+        .unwrap();
+    let struct_array = ring_list
+        .values()
+        .as_any()
+        .downcast_ref::<StructArray>()
+        .unwrap();
+    let x_array = struct_array.values()[0]
+        .as_any()
+        .downcast_ref::<PrimitiveArray<f64>>()
+        .unwrap();
+    let y_array = struct_array.values()[1]
+        .as_any()
+        .downcast_ref::<PrimitiveArray<f64>>()
+        .unwrap();
+
+    let polygon_offsets = polygon_list.offsets();
+    let ring_offsets = ring_list.offsets();
+    let mut new_x = Vec::with_capacity(x_array.len());
+    let mut new_y = Vec::with_capacity(y_array.len());
+
+    for row in 0..polygon_list.len() {
+        let (ring_start, ring_end) = polygon_offsets.start_end(row);
+        let coord_start = ring_offsets.start_end(ring_start).0;
+        let coord_end = if ring_end > ring_start {
+            ring_offsets.start_end(ring_end - 1).1
+        } else {
+            coord_start
+        };
+        let xs = &x_array.values()[coord_start..coord_end];
+        let ys = &y_array.values()[coord_start..coord_end];
+
+        let origin_point = match origin {
+            TransformOrigin::Centroid => {
+                let local_ring_offsets: Vec<i64> = ring_offsets.as_slice()
+                    [ring_start..=ring_end]
+                    .iter()
+                    .map(|&o| o - coord_start as i64)
+                    .collect();
+                let (x, y) = polygon_centroid(xs, ys, &local_ring_offsets);
+                Coord { x, y }
+            }
+            TransformOrigin::Center => {
+                let (x, y) = bbox_center(xs, ys);
+                Coord { x, y }
+            }
+            TransformOrigin::Point(point) => Coord {
+                x: point.x(),
+                y: point.y(),
+            },
+        };
+        let transform = build(origin_point);
+
+        for (&x, &y) in xs.iter().zip(ys.iter()) {
+            let c = transform.apply(Coord { x, y });
+            new_x.push(c.x);
+            new_y.push(c.y);
+        }
+    }
+
+    let transformed_struct = StructArray::new(
+        struct_array.data_type().clone(),
+        vec![
+            PrimitiveArray::new(x_array.data_type().clone(), new_x.into(), None).boxed(),
+            PrimitiveArray::new(y_array.data_type().clone(), new_y.into(), None).boxed(),
+        ],
+        struct_array.validity().cloned(),
+    );
+    let transformed_ring_list = ListArray::<i64>::new(
+        ring_list.data_type().clone(),
+        ring_list.offsets().clone(),
+        transformed_struct.boxed(),
+        ring_list.validity().cloned(),
+    );
+    let transformed_polygon_list = ListArray::<i64>::new(
+        polygon_list.data_type().clone(),
+        polygon_list.offsets().clone(),
+        transformed_ring_list.boxed(),
+        polygon_list.validity().cloned(),
+    );
+
+    let series = Series::try_from((
+        "geometry",
+        Box::new(transformed_polygon_list) as Box<dyn Array>,
+    ))?;
+    Ok(series)
+}
+
+/// The length-weighted midpoint along a coordinate slice, matching `geo::Centroid`'s definition
+/// for a `LineString`; falls back to the lone coordinate for a single-point slice, and to it
+/// again if every segment has zero length.
+fn linestring_centroid(xs: &[f64], ys: &[f64]) -> (f64, f64) {
+    if xs.len() < 2 {
+        return (xs[0], ys[0]);
+    }
+
+    let mut total_length = 0.0;
+    let mut x_sum = 0.0;
+    let mut y_sum = 0.0;
+    for i in 0..xs.len() - 1 {
+        let (x0, y0) = (xs[i], ys[i]);
+        let (x1, y1) = (xs[i + 1], ys[i + 1]);
+        let length = ((x1 - x0).powi(2) + (y1 - y0).powi(2)).sqrt();
+        x_sum += (x0 + x1) / 2.0 * length;
+        y_sum += (y0 + y1) / 2.0 * length;
+        total_length += length;
+    }
+
+    if total_length == 0.0 {
+        (xs[0], ys[0])
+    } else {
+        (x_sum / total_length, y_sum / total_length)
+    }
+}
+
+/// The center of a coordinate slice's axis-aligned bounding box.
+fn bbox_center(xs: &[f64], ys: &[f64]) -> (f64, f64) {
+    let (mut min_x, mut max_x) = (xs[0], xs[0]);
+    let (mut min_y, mut max_y) = (ys[0], ys[0]);
+    for (&x, &y) in xs.iter().zip(ys.iter()) {
+        min_x = min_x.min(x);
+        max_x = max_x.max(x);
+        min_y = min_y.min(y);
+        max_y = max_y.max(y);
+    }
+    ((min_x + max_x) / 2.0, (min_y + max_y) / 2.0)
+}
+
+/// The area-weighted centroid of a polygon, accumulated ring by ring via the shoelace formula
+/// (`ring_offsets` gives each ring's span within `xs`/`ys`, both local to this one polygon).
+/// Holes subtract from the total the same way they do in `geo::Centroid`'s own algorithm,
+/// provided rings follow the OGC winding convention (exterior counterclockwise, holes clockwise).
+/// Falls back to the bounding-box center for a degenerate (zero-area) polygon.
+fn polygon_centroid(xs: &[f64], ys: &[f64], ring_offsets: &[i64]) -> (f64, f64) {
+    let mut area_sum = 0.0;
+    let mut x_sum = 0.0;
+    let mut y_sum = 0.0;
+
+    for ring in ring_offsets.windows(2) {
+        let (start, end) = (ring[0] as usize, ring[1] as usize);
+        let ring_xs = &xs[start..end];
+        let ring_ys = &ys[start..end];
+
+        for i in 0..ring_xs.len().saturating_sub(1) {
+            let (x0, y0) = (ring_xs[i], ring_ys[i]);
+            let (x1, y1) = (ring_xs[i + 1], ring_ys[i + 1]);
+            let cross = x0 * y1 - x1 * y0;
+            area_sum += cross;
+            x_sum += (x0 + x1) * cross;
+            y_sum += (y0 + y1) * cross;
+        }
+    }
+
+    if area_sum == 0.0 {
+        bbox_center(xs, ys)
+    } else {
+        (x_sum / (3.0 * area_sum), y_sum / (3.0 * area_sum))
+    }
+}
 
 fn rotate_wkb(series: &Series, angle: f64, origin: TransformOrigin) -> Result<Series> {
     match origin {
@@ -1,10 +1,46 @@
 use crate::error::Result;
-use crate::util::iter_geom;
+use crate::geoarrow::polygon::array::PolygonSeries;
+use crate::util::{get_geoarrow_type, iter_geom, GeoArrowType};
 use geo::prelude::Area;
+use polars::export::arrow::array::{Array, MutablePrimitiveArray, PrimitiveArray};
 use polars::prelude::Series;
 
 pub(crate) fn area(series: &Series) -> Result<Series> {
-    area_wkb(series)
+    match get_geoarrow_type(series) {
+        GeoArrowType::Point => area_zero(series),
+        GeoArrowType::LineString => area_zero(series),
+        GeoArrowType::Polygon => area_geoarrow_polygon(series),
+        GeoArrowType::WKB => area_wkb(series),
+    }
+}
+
+/// Points and lines have no area, so this fills the output with zeros without touching the
+/// coordinate buffers at all.
+fn area_zero(series: &Series) -> Result<Series> {
+    let mut result = MutablePrimitiveArray::<f64>::with_capacity(series.len());
+    for _ in 0..series.len() {
+        result.push(Some(0.0));
+    }
+
+    let result: PrimitiveArray<f64> = result.into();
+    let series = Series::try_from(("result", Box::new(result) as Box<dyn Array>))?;
+    Ok(series)
+}
+
+/// Reads the native GeoArrow Polygon buffers directly, skipping the WKB parse.
+fn area_geoarrow_polygon(series: &Series) -> Result<Series> {
+    let mut result = MutablePrimitiveArray::<f64>::with_capacity(series.len());
+
+    for chunk in PolygonSeries(series).chunks() {
+        let parts = chunk.parts();
+        for i in 0..parts.len() {
+            result.push(parts.get_as_geo(i).map(|polygon| polygon.unsigned_area()));
+        }
+    }
+
+    let result: PrimitiveArray<f64> = result.into();
+    let series = Series::try_from(("result", Box::new(result) as Box<dyn Array>))?;
+    Ok(series)
 }
 
 fn area_wkb(series: &Series) -> Result<Series> {
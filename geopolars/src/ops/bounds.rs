@@ -0,0 +1,139 @@
+use crate::error::Result;
+use crate::util::{get_geoarrow_type, iter_linestrings, iter_polygons, GeoArrowType};
+use polars::export::arrow::array::{Array, FixedSizeListArray, PrimitiveArray};
+use polars::export::arrow::bitmap::Bitmap;
+use polars::export::arrow::datatypes::{DataType, Field};
+use polars::prelude::Series;
+
+pub(crate) fn bounds(series: &Series) -> Result<Series> {
+    match get_geoarrow_type(series) {
+        GeoArrowType::LineString => bounds_geoarrow_linestring(series),
+        GeoArrowType::Polygon => bounds_geoarrow_polygon(series),
+        _ => todo!(),
+    }
+}
+
+pub(crate) fn total_bounds(series: &Series) -> Result<geo::Rect> {
+    match get_geoarrow_type(series) {
+        GeoArrowType::LineString => total_bounds_geoarrow_linestring(series),
+        GeoArrowType::Polygon => total_bounds_geoarrow_polygon(series),
+        _ => todo!(),
+    }
+}
+
+/// The axis-aligned bounding box of a coordinate slice, as `(min_x, min_y, max_x, max_y)`.
+fn row_bounds(xs: &[f64], ys: &[f64]) -> (f64, f64, f64, f64) {
+    let (mut min_x, mut max_x) = (xs[0], xs[0]);
+    let (mut min_y, mut max_y) = (ys[0], ys[0]);
+    for (&x, &y) in xs.iter().zip(ys.iter()) {
+        min_x = min_x.min(x);
+        max_x = max_x.max(x);
+        min_y = min_y.min(y);
+        max_y = max_y.max(y);
+    }
+    (min_x, min_y, max_x, max_y)
+}
+
+/// Folds per-row `(min_x, min_y, max_x, max_y)` tuples of every non-null row into a single
+/// [`geo::Rect`] covering the whole column, matching
+/// [`geopolars_arrow::PolygonArray::total_bounds`].
+///
+/// # Panics
+/// Panics if `rows` is empty, since there is then no box to return.
+fn reduce_total_bounds(mut rows: impl Iterator<Item = (f64, f64, f64, f64)>) -> geo::Rect {
+    let first = rows
+        .next()
+        .expect("total_bounds of a column with no non-null geometries");
+    let (min_x, min_y, max_x, max_y) = rows.fold(first, |acc, row| {
+        (
+            acc.0.min(row.0),
+            acc.1.min(row.1),
+            acc.2.max(row.2),
+            acc.3.max(row.3),
+        )
+    });
+    geo::Rect::new(
+        geo::coord! { x: min_x, y: min_y },
+        geo::coord! { x: max_x, y: max_y },
+    )
+}
+
+/// Builds a [`RectArray`](geopolars_arrow::RectArray)-shaped `Series`: a `FixedSizeList<f64>[4]`
+/// laid out `[minx, miny, maxx, maxy]` per element.
+fn rect_series(values: Vec<f64>, validity: Option<Bitmap>) -> Result<Series> {
+    let values_field = Field::new("rect", DataType::Float64, false);
+    let values_array = PrimitiveArray::new(DataType::Float64, values.into(), None).boxed();
+
+    let rect_array = FixedSizeListArray::new(
+        DataType::FixedSizeList(Box::new(values_field), 4),
+        values_array,
+        validity,
+    );
+
+    let series = Series::try_from(("geometry", Box::new(rect_array) as Box<dyn Array>))?;
+    Ok(series)
+}
+
+/// Computes the envelope of every LineString in `series` by streaming its flat `x`/`y` coordinate
+/// buffer over `geom_offsets`, without decoding WKB. Null rows carry their null through to the
+/// output via the same validity bitmap.
+fn bounds_geoarrow_linestring(series: &Series) -> Result<Series> {
+    let mut values = Vec::with_capacity(series.len() * 4);
+    let mut is_valid = Vec::with_capacity(series.len());
+    for row in iter_linestrings(series)? {
+        is_valid.push(row.is_some());
+        let (min_x, min_y, max_x, max_y) = match row {
+            Some(row) => row_bounds(row.xs, row.ys),
+            None => (0.0, 0.0, 0.0, 0.0),
+        };
+        values.extend_from_slice(&[min_x, min_y, max_x, max_y]);
+    }
+
+    rect_series(values, validity_bitmap(is_valid))
+}
+
+fn total_bounds_geoarrow_linestring(series: &Series) -> Result<geo::Rect> {
+    let rows = iter_linestrings(series)?
+        .flatten()
+        .map(|row| row_bounds(row.xs, row.ys));
+
+    Ok(reduce_total_bounds(rows))
+}
+
+/// Computes the envelope of every Polygon in `series` by streaming its flat `x`/`y` coordinate
+/// buffer across every ring a polygon owns (exterior and holes alike — a hole never extends a
+/// polygon's bounding box beyond its exterior, so there's no need to treat rings separately here
+/// the way `geom_offsets`/`ring_offsets` are treated elsewhere), without decoding WKB. Null rows
+/// carry their null through to the output via the same validity bitmap.
+fn bounds_geoarrow_polygon(series: &Series) -> Result<Series> {
+    let mut values = Vec::with_capacity(series.len() * 4);
+    let mut is_valid = Vec::with_capacity(series.len());
+    for row in iter_polygons(series)? {
+        is_valid.push(row.is_some());
+        let (min_x, min_y, max_x, max_y) = match row {
+            Some(row) => row_bounds(row.xs, row.ys),
+            None => (0.0, 0.0, 0.0, 0.0),
+        };
+        values.extend_from_slice(&[min_x, min_y, max_x, max_y]);
+    }
+
+    rect_series(values, validity_bitmap(is_valid))
+}
+
+fn total_bounds_geoarrow_polygon(series: &Series) -> Result<geo::Rect> {
+    let rows = iter_polygons(series)?
+        .flatten()
+        .map(|row| row_bounds(row.xs, row.ys));
+
+    Ok(reduce_total_bounds(rows))
+}
+
+/// Collapses a per-row validity mask down to `None` when every row is valid, matching the shape
+/// `list_array.validity()` itself takes when a column has no nulls at all.
+fn validity_bitmap(is_valid: Vec<bool>) -> Option<Bitmap> {
+    if is_valid.iter().all(|&v| v) {
+        None
+    } else {
+        Some(Bitmap::from(is_valid))
+    }
+}
@@ -1,13 +1,59 @@
 use crate::error::Result;
-use crate::util::iter_geom;
+use crate::geoarrow::point::array::PointSeries;
+use crate::util::{get_geoarrow_type, iter_geom, iter_linestrings, iter_polygons, GeoArrowType};
 use geo::algorithm::centroid::Centroid;
 use geo::Geometry;
 use geozero::{CoordDimensions, ToWkb};
-use polars::export::arrow::array::{Array, BinaryArray, MutableBinaryArray};
+use polars::export::arrow::array::{
+    Array, BinaryArray, MutableBinaryArray, PrimitiveArray, StructArray,
+};
+use polars::export::arrow::bitmap::Bitmap;
+use polars::export::arrow::datatypes::{DataType, Field};
 use polars::prelude::Series;
 
 pub(crate) fn centroid(series: &Series) -> Result<Series> {
-    centroid_wkb(series)
+    match get_geoarrow_type(series) {
+        GeoArrowType::Point => centroid_of(
+            series.len(),
+            PointSeries(series).iter_geo().map(|g| g.map(Geometry::Point)),
+        ),
+        GeoArrowType::LineString => centroid_geoarrow_linestring(series),
+        GeoArrowType::Polygon => centroid_geoarrow_polygon(series),
+        GeoArrowType::WKB => centroid_wkb(series),
+        GeoArrowType::MultiPolygon => todo!(),
+    }
+}
+
+/// Encodes the centroid of every geometry yielded by `geoms` as a WKB Point, reading the native
+/// GeoArrow buffers through `geoms` instead of decoding each feature from WKB first.
+///
+/// The output is always encoded with [`CoordDimensions::xy`]: `geoms` yields `geo::Geometry<f64>`,
+/// which carries no Z coordinate in this crate, so there's no elevation to preserve through the
+/// centroid regardless of what the source GeoArrow buffers looked like.
+///
+/// This stays on `MutableBinaryArray<i32>` rather than `geoarrow::WKBArray` (whose `BinaryArray`
+/// uses `i64` offsets): every WKB column elsewhere in this crate, including [`iter_geom`] and
+/// [`crate::util::get_geoarrow_type`], is built on the narrower `Binary` (`i32`) Arrow type, and
+/// switching just this kernel to `i64` would make its output unrecognizable as WKB to the rest of
+/// the crate.
+fn centroid_of(len: usize, geoms: impl Iterator<Item = Option<Geometry<f64>>>) -> Result<Series> {
+    let mut output_array = MutableBinaryArray::<i32>::with_capacity(len);
+
+    for geom in geoms {
+        let wkb = geom.map(|geom| {
+            let value: Geometry<f64> = geom.centroid().expect("could not create centroid").into();
+            value
+                .to_wkb(CoordDimensions::xy())
+                .expect("Unable to create wkb")
+        });
+
+        output_array.push(wkb);
+    }
+
+    let result: BinaryArray<i32> = output_array.into();
+
+    let series = Series::try_from(("geometry", Box::new(result) as Box<dyn Array>))?;
+    Ok(series)
 }
 
 fn centroid_wkb(series: &Series) -> Result<Series> {
@@ -27,3 +73,149 @@ fn centroid_wkb(series: &Series) -> Result<Series> {
     let series = Series::try_from(("geometry", Box::new(result) as Box<dyn Array>))?;
     Ok(series)
 }
+
+/// Walks a GeoArrow LineString column's flat coordinate buffer directly, computing each row's
+/// centroid as the length-weighted average of its segment midpoints, without ever materializing a
+/// `geo::LineString` or round-tripping through WKB. Rows the source `validity` bitmap marks null
+/// carry that same null through to the output.
+fn centroid_geoarrow_linestring(series: &Series) -> Result<Series> {
+    let mut out_x = Vec::with_capacity(series.len());
+    let mut out_y = Vec::with_capacity(series.len());
+    let mut is_valid = Vec::with_capacity(series.len());
+    for row in iter_linestrings(series)? {
+        is_valid.push(row.is_some());
+        let (x, y) = match row {
+            Some(row) => linestring_centroid(row.xs, row.ys),
+            None => (0.0, 0.0),
+        };
+        out_x.push(x);
+        out_y.push(y);
+    }
+
+    point_struct_series(out_x, out_y, validity_bitmap(is_valid))
+}
+
+/// Walks a GeoArrow Polygon column's flat coordinate buffer directly, computing each row's
+/// centroid via the area-weighted shoelace formula over its rings, without ever materializing a
+/// `geo::Polygon` or round-tripping through WKB. Rows the source `validity` bitmap marks null
+/// carry that same null through to the output.
+fn centroid_geoarrow_polygon(series: &Series) -> Result<Series> {
+    let mut out_x = Vec::with_capacity(series.len());
+    let mut out_y = Vec::with_capacity(series.len());
+    let mut is_valid = Vec::with_capacity(series.len());
+    for row in iter_polygons(series)? {
+        is_valid.push(row.is_some());
+        let (x, y) = match row {
+            Some(row) => polygon_centroid(row.xs, row.ys, &row.ring_offsets),
+            None => (0.0, 0.0),
+        };
+        out_x.push(x);
+        out_y.push(y);
+    }
+
+    point_struct_series(out_x, out_y, validity_bitmap(is_valid))
+}
+
+/// Collapses a per-row validity mask down to `None` when every row is valid, matching the shape
+/// `list_array.validity()` itself takes when a column has no nulls at all.
+fn validity_bitmap(is_valid: Vec<bool>) -> Option<Bitmap> {
+    if is_valid.iter().all(|&v| v) {
+        None
+    } else {
+        Some(Bitmap::from(is_valid))
+    }
+}
+
+/// Builds a GeoArrow Point `Series` (an `x`/`y` [`StructArray`]) from per-row coordinates,
+/// matching the layout [`crate::util::geom_at_index`] reads a `Point` column as.
+fn point_struct_series(
+    x: Vec<f64>,
+    y: Vec<f64>,
+    validity: Option<Bitmap>,
+) -> Result<Series> {
+    let field_x = Field::new("x", DataType::Float64, false);
+    let field_y = Field::new("y", DataType::Float64, false);
+
+    let array_x = PrimitiveArray::new(DataType::Float64, x.into(), None).boxed();
+    let array_y = PrimitiveArray::new(DataType::Float64, y.into(), None).boxed();
+
+    let struct_array = StructArray::new(
+        DataType::Struct(vec![field_x, field_y]),
+        vec![array_x, array_y],
+        validity,
+    );
+
+    let series = Series::try_from(("geometry", Box::new(struct_array) as Box<dyn Array>))?;
+    Ok(series)
+}
+
+/// The length-weighted midpoint along a coordinate slice, matching `geo::Centroid`'s definition
+/// for a `LineString`; falls back to the arithmetic mean of the vertices if every segment has zero
+/// length (including the single-vertex case).
+fn linestring_centroid(xs: &[f64], ys: &[f64]) -> (f64, f64) {
+    let mut total_length = 0.0;
+    let mut x_sum = 0.0;
+    let mut y_sum = 0.0;
+    for i in 0..xs.len().saturating_sub(1) {
+        let (x0, y0) = (xs[i], ys[i]);
+        let (x1, y1) = (xs[i + 1], ys[i + 1]);
+        let length = (x1 - x0).hypot(y1 - y0);
+        x_sum += (x0 + x1) / 2.0 * length;
+        y_sum += (y0 + y1) / 2.0 * length;
+        total_length += length;
+    }
+
+    if total_length == 0.0 {
+        (
+            xs.iter().sum::<f64>() / xs.len() as f64,
+            ys.iter().sum::<f64>() / ys.len() as f64,
+        )
+    } else {
+        (x_sum / total_length, y_sum / total_length)
+    }
+}
+
+/// The center of a coordinate slice's axis-aligned bounding box.
+fn bbox_center(xs: &[f64], ys: &[f64]) -> (f64, f64) {
+    let (mut min_x, mut max_x) = (xs[0], xs[0]);
+    let (mut min_y, mut max_y) = (ys[0], ys[0]);
+    for (&x, &y) in xs.iter().zip(ys.iter()) {
+        min_x = min_x.min(x);
+        max_x = max_x.max(x);
+        min_y = min_y.min(y);
+        max_y = max_y.max(y);
+    }
+    ((min_x + max_x) / 2.0, (min_y + max_y) / 2.0)
+}
+
+/// The area-weighted centroid of a polygon, accumulated ring by ring via the shoelace formula
+/// (`ring_offsets` gives each ring's span within `xs`/`ys`, both local to this one polygon).
+/// Holes subtract from the total the same way they do in `geo::Centroid`'s own algorithm,
+/// provided rings follow the OGC winding convention (exterior counterclockwise, holes clockwise).
+/// Falls back to the bounding-box center for a degenerate (zero-area) polygon.
+fn polygon_centroid(xs: &[f64], ys: &[f64], ring_offsets: &[i64]) -> (f64, f64) {
+    let mut area_sum = 0.0;
+    let mut x_sum = 0.0;
+    let mut y_sum = 0.0;
+
+    for ring in ring_offsets.windows(2) {
+        let (start, end) = (ring[0] as usize, ring[1] as usize);
+        let ring_xs = &xs[start..end];
+        let ring_ys = &ys[start..end];
+
+        for i in 0..ring_xs.len().saturating_sub(1) {
+            let (x0, y0) = (ring_xs[i], ring_ys[i]);
+            let (x1, y1) = (ring_xs[i + 1], ring_ys[i + 1]);
+            let cross = x0 * y1 - x1 * y0;
+            area_sum += cross;
+            x_sum += (x0 + x1) * cross;
+            y_sum += (y0 + y1) * cross;
+        }
+    }
+
+    if area_sum == 0.0 {
+        bbox_center(xs, ys)
+    } else {
+        (x_sum / (3.0 * area_sum), y_sum / (3.0 * area_sum))
+    }
+}
@@ -4,20 +4,20 @@ use crate::util::{get_geoarrow_type, iter_geom, GeoArrowType};
 use geo::Geometry;
 use geozero::{CoordDimensions, ToWkb};
 use polars::export::arrow::array::{
-    Array, BinaryArray, MutableBinaryArray, MutablePrimitiveArray, PrimitiveArray,
+    Array, BinaryArray, ListArray, MutableBinaryArray, PrimitiveArray, StructArray,
 };
+use polars::export::arrow::bitmap::MutableBitmap;
+use polars::export::arrow::datatypes::DataType as ArrowDataType;
+use polars::export::arrow::offset::OffsetsBuffer;
+use polars::prelude::ArrowField;
 use polars::prelude::Series;
 
-// pub(crate) fn exterior(series: &Series) -> Result<Series> {
-//     match get_geoarrow_type(series) {
-//         GeoArrowType::WKB => exterior_wkb(series),
-//         GeoArrowType::Polygon => exterior_geoarrow_polygon(series),
-//         _ => panic!("Unexpected geometry type for operation exterior"),
-//     }
-// }
-
 pub(crate) fn exterior(series: &Series) -> Result<Series> {
-    exterior_wkb(series)
+    match get_geoarrow_type(series) {
+        GeoArrowType::WKB => exterior_wkb(series),
+        GeoArrowType::Polygon => exterior_geoarrow_polygon(series),
+        _ => panic!("Unexpected geometry type for operation exterior"),
+    }
 }
 
 fn exterior_wkb(series: &Series) -> Result<Series> {
@@ -42,35 +42,82 @@ fn exterior_wkb(series: &Series) -> Result<Series> {
     ))?)
 }
 
-// fn exterior_geoarrow_polygon(series: &Series) -> Result<Series> {
-//     let ps = PolygonSeries(series);
-//     let chunks: Vec<PolygonArrayParts> = ps.chunks().iter().map(|chunk| chunk.parts()).collect();
-
-//     let (coord_length, offsets_length) = get_polygon_output_lengths(chunks);
-
-//     let offsets_buffer = vec![0_i64; offsets_length];
-
-//     let x_coord_buffer = MutablePrimitiveArray::<f64>::with_capacity(coord_length);
-//     x_coord_buffer.s
-//     // let x_coord_buffer = Vec::<f64>::with_capacity(coord_length);
-//     let y_coord_buffer = Vec::<f64>::with_capacity(coord_length);
-
-//     for chunk in chunks {
-//         for geom_offset in chunk.geom_offsets.as_slice() {
-//             let (ext_ring_start, ext_ring_end) = chunk.ring_offsets.start_end(*geom_offset as usize);
-//             let x_ext = chunk.x.slice(ext_ring_start, ext_ring_end - ext_ring_start);
-//             let y_ext = chunk.y.slice(ext_ring_start, ext_ring_end - ext_ring_start);
+/// Builds a LineString-typed `Series` holding each input polygon's exterior ring, slicing the
+/// existing x/y coordinate buffers rather than decoding each polygon into a `geo::Polygon` and
+/// re-encoding it as WKB.
+fn exterior_geoarrow_polygon(series: &Series) -> Result<Series> {
+    let ps = PolygonSeries(series);
+    let chunks: Vec<PolygonArrayParts> = ps.chunks().iter().map(|chunk| chunk.parts()).collect();
+
+    let (coord_length, offsets_length) = get_polygon_output_lengths(&chunks);
+
+    let mut x_coords = Vec::<f64>::with_capacity(coord_length);
+    let mut y_coords = Vec::<f64>::with_capacity(coord_length);
+    let mut offsets = Vec::<i64>::with_capacity(offsets_length);
+    offsets.push(0);
+    let mut validity = MutableBitmap::with_capacity(offsets_length.saturating_sub(1));
+    let mut any_null = false;
+
+    let mut running_offset: i64 = 0;
+    for chunk in &chunks {
+        for i in 0..chunk.len() {
+            let is_valid = chunk
+                .validity
+                .as_ref()
+                .map(|bitmap| bitmap.get_bit(i))
+                .unwrap_or(true);
+            validity.push(is_valid);
+            any_null |= !is_valid;
+
+            let (ring_start, ring_end) = chunk.geom_offsets.start_end(i);
+            if ring_end > ring_start {
+                // Only the exterior ring (the first ring of the polygon) is copied: interior
+                // rings aren't part of a LineString's worth of output.
+                let (coord_start, coord_end) = chunk.ring_offsets.start_end(ring_start);
+                for j in coord_start..coord_end {
+                    let (x, y) = chunk.coords.value(j);
+                    x_coords.push(x);
+                    y_coords.push(y);
+                }
+                running_offset += (coord_end - coord_start) as i64;
+            }
+            offsets.push(running_offset);
+        }
+    }
 
-//             // TODO: copy these slices into the x_coord_buffer,
-//             // Update offsets buffer
-//         }
-//         chunk.x.slice(0, 6).set_values(values)
-//     }
+    let coord_field_x = ArrowField::new("x", ArrowDataType::Float64, false);
+    let coord_field_y = ArrowField::new("y", ArrowDataType::Float64, false);
+    let struct_data_type = ArrowDataType::Struct(vec![coord_field_x, coord_field_y]);
+    let list_data_type = ArrowDataType::LargeList(Box::new(ArrowField::new(
+        "vertices",
+        struct_data_type.clone(),
+        false,
+    )));
+
+    let coord_array = StructArray::new(
+        struct_data_type,
+        vec![
+            Box::new(PrimitiveArray::<f64>::from_vec(x_coords)) as Box<dyn Array>,
+            Box::new(PrimitiveArray::<f64>::from_vec(y_coords)) as Box<dyn Array>,
+        ],
+        None,
+    );
+
+    let offsets_buffer = unsafe { OffsetsBuffer::new_unchecked(offsets.into()) };
+    let result = ListArray::<i64>::new(
+        list_data_type,
+        offsets_buffer,
+        Box::new(coord_array),
+        any_null.then(|| validity.into()),
+    );
 
-//     todo!()
-// }
+    Ok(Series::try_from((
+        "geometry",
+        Box::new(result) as Box<dyn Array>,
+    ))?)
+}
 
-fn get_polygon_output_lengths(chunks: Vec<PolygonArrayParts>) -> (usize, usize) {
+fn get_polygon_output_lengths(chunks: &[PolygonArrayParts]) -> (usize, usize) {
     // The length of the coordinates buffer
     let mut coord_length: usize = 0;
 
@@ -82,11 +129,13 @@ fn get_polygon_output_lengths(chunks: Vec<PolygonArrayParts>) -> (usize, usize)
     for chunk in chunks {
         offsets_length += chunk.geom_offsets.len();
 
-        // Only care about the first geom_offset since we only care about the exterior ring
-        for geom_offset in chunk.geom_offsets.as_slice() {
-            let (ext_ring_start, ext_ring_end) =
-                chunk.ring_offsets.start_end(*geom_offset as usize);
-            coord_length += ext_ring_end - ext_ring_start;
+        // Only care about the first ring of each polygon since we only care about the exterior.
+        for i in 0..chunk.len() {
+            let (ring_start, ring_end) = chunk.geom_offsets.start_end(i);
+            if ring_end > ring_start {
+                let (coord_start, coord_end) = chunk.ring_offsets.start_end(ring_start);
+                coord_length += coord_end - coord_start;
+            }
         }
     }
 
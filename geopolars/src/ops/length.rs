@@ -23,22 +23,205 @@ pub(crate) fn geodesic_length(series: &Series, method: GeodesicLengthMethod) ->
     geodesic_length_wkb(series, method)
 }
 
+pub(crate) fn euclidean_perimeter(series: &Series) -> Result<Series> {
+    euclidean_perimeter_wkb(series)
+}
+
+pub(crate) fn geodesic_perimeter(series: &Series, method: GeodesicLengthMethod) -> Result<Series> {
+    geodesic_perimeter_wkb(series, method)
+}
+
+/// Computes a single geometry's euclidean length, descending into a `GeometryCollection`'s
+/// members (including nested collections) with an explicit stack instead of recursive calls, so
+/// a pathologically deep collection can't overflow the call stack.
+fn euclidean_length_geometry(geom: Geometry) -> f64 {
+    let mut stack = vec![geom];
+    let mut total = 0.0;
+
+    while let Some(geom) = stack.pop() {
+        total += match geom {
+            Geometry::Point(_) => 0.0,
+            Geometry::Line(line) => line.euclidean_length(),
+            Geometry::LineString(line_string) => line_string.euclidean_length(),
+            Geometry::Polygon(polygon) => polygon.exterior().euclidean_length(),
+            Geometry::MultiPoint(_) => 0.0,
+            Geometry::MultiLineString(multi_line_string) => multi_line_string.euclidean_length(),
+            Geometry::MultiPolygon(mutli_polygon) => mutli_polygon
+                .iter()
+                .map(|poly| poly.exterior().euclidean_length())
+                .sum(),
+            Geometry::GeometryCollection(collection) => {
+                stack.extend(collection);
+                0.0
+            }
+            Geometry::Rect(rec) => rec.to_polygon().exterior().euclidean_length(),
+            Geometry::Triangle(triangle) => triangle.to_polygon().exterior().euclidean_length(),
+        };
+    }
+
+    total
+}
+
 fn euclidean_length_wkb(series: &Series) -> Result<Series> {
     let mut result = MutablePrimitiveArray::<f64>::with_capacity(series.len());
 
+    for geom in iter_geom(series) {
+        result.push(Some(euclidean_length_geometry(geom)));
+    }
+
+    let result: PrimitiveArray<f64> = result.into();
+    let series = Series::try_from(("geometry", Box::new(result) as Box<dyn Array>))?;
+    Ok(series)
+}
+
+/// Computes a single geometry's geodesic length, descending into a `GeometryCollection`'s
+/// members (including nested collections) with an explicit stack instead of recursive calls, so
+/// a pathologically deep collection can't overflow the call stack.
+fn geodesic_length_geometry(geom: Geometry, method: &GeodesicLengthMethod) -> Result<f64> {
+    let map_vincenty_error =
+        |_| PolarsError::ComputeError(ErrString::from("Failed to calculate vincenty length"));
+
+    let mut stack = vec![geom];
+    let mut total = 0.0;
+
+    while let Some(geom) = stack.pop() {
+        let length: f64 = match (method, geom) {
+            (_, Geometry::Point(_)) => Ok(0.0),
+
+            (GeodesicLengthMethod::Haversine, Geometry::Line(line)) => Ok(line.haversine_length()),
+            (GeodesicLengthMethod::Geodesic, Geometry::Line(line)) => Ok(line.geodesic_length()),
+            (GeodesicLengthMethod::Vincenty, Geometry::Line(line)) => {
+                line.vincenty_length().map_err(map_vincenty_error)
+            }
+
+            (GeodesicLengthMethod::Haversine, Geometry::LineString(line_string)) => {
+                Ok(line_string.haversine_length())
+            }
+            (GeodesicLengthMethod::Geodesic, Geometry::LineString(line_string)) => {
+                Ok(line_string.geodesic_length())
+            }
+            (GeodesicLengthMethod::Vincenty, Geometry::LineString(line_string)) => {
+                line_string.vincenty_length().map_err(map_vincenty_error)
+            }
+
+            (GeodesicLengthMethod::Haversine, Geometry::Polygon(polygon)) => {
+                Ok(polygon.exterior().haversine_length())
+            }
+            (GeodesicLengthMethod::Geodesic, Geometry::Polygon(polygon)) => {
+                Ok(polygon.exterior().geodesic_length())
+            }
+            (GeodesicLengthMethod::Vincenty, Geometry::Polygon(polygon)) => polygon
+                .exterior()
+                .vincenty_length()
+                .map_err(map_vincenty_error),
+
+            (_, Geometry::MultiPoint(_)) => Ok(0.0),
+
+            (GeodesicLengthMethod::Haversine, Geometry::MultiLineString(multi_line_string)) => {
+                Ok(multi_line_string.haversine_length())
+            }
+
+            (GeodesicLengthMethod::Geodesic, Geometry::MultiLineString(multi_line_string)) => {
+                Ok(multi_line_string.geodesic_length())
+            }
+            (GeodesicLengthMethod::Vincenty, Geometry::MultiLineString(multi_line_string)) => {
+                multi_line_string
+                    .vincenty_length()
+                    .map_err(map_vincenty_error)
+            }
+            (GeodesicLengthMethod::Haversine, Geometry::MultiPolygon(mutli_polygon)) => {
+                Ok(mutli_polygon
+                    .iter()
+                    .map(|poly| poly.exterior().haversine_length())
+                    .sum())
+            }
+            (GeodesicLengthMethod::Geodesic, Geometry::MultiPolygon(mutli_polygon)) => {
+                Ok(mutli_polygon
+                    .iter()
+                    .map(|poly| poly.exterior().geodesic_length())
+                    .sum())
+            }
+
+            (GeodesicLengthMethod::Vincenty, Geometry::MultiPolygon(mutli_polygon)) => {
+                let result: std::result::Result<Vec<f64>, _> = mutli_polygon
+                    .iter()
+                    .map(|poly| poly.exterior().vincenty_length())
+                    .collect();
+                result.map(|v| v.iter().sum()).map_err(map_vincenty_error)
+            }
+            (_, Geometry::GeometryCollection(collection)) => {
+                stack.extend(collection);
+                Ok(0.0)
+            }
+            (GeodesicLengthMethod::Haversine, Geometry::Rect(rec)) => {
+                Ok(rec.to_polygon().exterior().haversine_length())
+            }
+            (GeodesicLengthMethod::Geodesic, Geometry::Rect(rec)) => {
+                Ok(rec.to_polygon().exterior().geodesic_length())
+            }
+            (GeodesicLengthMethod::Vincenty, Geometry::Rect(rec)) => rec
+                .to_polygon()
+                .exterior()
+                .vincenty_length()
+                .map_err(map_vincenty_error),
+            (GeodesicLengthMethod::Haversine, Geometry::Triangle(triangle)) => {
+                Ok(triangle.to_polygon().exterior().haversine_length())
+            }
+            (GeodesicLengthMethod::Geodesic, Geometry::Triangle(triangle)) => {
+                Ok(triangle.to_polygon().exterior().geodesic_length())
+            }
+            (GeodesicLengthMethod::Vincenty, Geometry::Triangle(triangle)) => triangle
+                .to_polygon()
+                .exterior()
+                .vincenty_length()
+                .map_err(map_vincenty_error),
+        }?;
+        total += length;
+    }
+
+    Ok(total)
+}
+
+fn geodesic_length_wkb(series: &Series, method: GeodesicLengthMethod) -> Result<Series> {
+    let mut result = MutablePrimitiveArray::<f64>::with_capacity(series.len());
+
+    for geom in iter_geom(series) {
+        result.push(Some(geodesic_length_geometry(geom, &method)?));
+    }
+
+    let result: PrimitiveArray<f64> = result.into();
+    let series = Series::try_from(("result", Box::new(result) as Box<dyn Array>))?;
+    Ok(series)
+}
+
+fn euclidean_perimeter_wkb(series: &Series) -> Result<Series> {
+    let mut result = MutablePrimitiveArray::<f64>::with_capacity(series.len());
+
     for geom in iter_geom(series) {
         let length: f64 = match geom {
             Geometry::Point(_) => Ok(0.0),
             Geometry::Line(line) => Ok(line.euclidean_length()),
             Geometry::LineString(line_string) => Ok(line_string.euclidean_length()),
-            Geometry::Polygon(polygon) => Ok(polygon.exterior().euclidean_length()),
+            Geometry::Polygon(polygon) => Ok(polygon.exterior().euclidean_length()
+                + polygon
+                    .interiors()
+                    .iter()
+                    .map(|ring| ring.euclidean_length())
+                    .sum::<f64>()),
             Geometry::MultiPoint(_) => Ok(0.0),
             Geometry::MultiLineString(multi_line_string) => {
                 Ok(multi_line_string.euclidean_length())
             }
             Geometry::MultiPolygon(mutli_polygon) => Ok(mutli_polygon
                 .iter()
-                .map(|poly| poly.exterior().euclidean_length())
+                .map(|poly| {
+                    poly.exterior().euclidean_length()
+                        + poly
+                            .interiors()
+                            .iter()
+                            .map(|ring| ring.euclidean_length())
+                            .sum::<f64>()
+                })
                 .sum()),
             Geometry::GeometryCollection(_) => Err(PolarsError::ComputeError(ErrString::from(
                 "Length methods are not implemented for geometry collection",
@@ -54,7 +237,7 @@ fn euclidean_length_wkb(series: &Series) -> Result<Series> {
     Ok(series)
 }
 
-fn geodesic_length_wkb(series: &Series, method: GeodesicLengthMethod) -> Result<Series> {
+fn geodesic_perimeter_wkb(series: &Series, method: GeodesicLengthMethod) -> Result<Series> {
     let mut result = MutablePrimitiveArray::<f64>::with_capacity(series.len());
 
     let map_vincenty_error =
@@ -81,15 +264,32 @@ fn geodesic_length_wkb(series: &Series, method: GeodesicLengthMethod) -> Result<
             }
 
             (GeodesicLengthMethod::Haversine, Geometry::Polygon(polygon)) => {
-                Ok(polygon.exterior().haversine_length())
+                Ok(polygon.exterior().haversine_length()
+                    + polygon
+                        .interiors()
+                        .iter()
+                        .map(|ring| ring.haversine_length())
+                        .sum::<f64>())
             }
             (GeodesicLengthMethod::Geodesic, Geometry::Polygon(polygon)) => {
-                Ok(polygon.exterior().geodesic_length())
+                Ok(polygon.exterior().geodesic_length()
+                    + polygon
+                        .interiors()
+                        .iter()
+                        .map(|ring| ring.geodesic_length())
+                        .sum::<f64>())
+            }
+            (GeodesicLengthMethod::Vincenty, Geometry::Polygon(polygon)) => {
+                let exterior = polygon.exterior().vincenty_length();
+                let interiors: std::result::Result<Vec<f64>, _> = polygon
+                    .interiors()
+                    .iter()
+                    .map(|ring| ring.vincenty_length())
+                    .collect();
+                exterior
+                    .and_then(|ext| interiors.map(|ints| ext + ints.iter().sum::<f64>()))
+                    .map_err(map_vincenty_error)
             }
-            (GeodesicLengthMethod::Vincenty, Geometry::Polygon(polygon)) => polygon
-                .exterior()
-                .vincenty_length()
-                .map_err(map_vincenty_error),
 
             (_, Geometry::MultiPoint(_)) => Ok(0.0),
 
@@ -108,20 +308,42 @@ fn geodesic_length_wkb(series: &Series, method: GeodesicLengthMethod) -> Result<
             (GeodesicLengthMethod::Haversine, Geometry::MultiPolygon(mutli_polygon)) => {
                 Ok(mutli_polygon
                     .iter()
-                    .map(|poly| poly.exterior().haversine_length())
+                    .map(|poly| {
+                        poly.exterior().haversine_length()
+                            + poly
+                                .interiors()
+                                .iter()
+                                .map(|ring| ring.haversine_length())
+                                .sum::<f64>()
+                    })
                     .sum())
             }
             (GeodesicLengthMethod::Geodesic, Geometry::MultiPolygon(mutli_polygon)) => {
                 Ok(mutli_polygon
                     .iter()
-                    .map(|poly| poly.exterior().geodesic_length())
+                    .map(|poly| {
+                        poly.exterior().geodesic_length()
+                            + poly
+                                .interiors()
+                                .iter()
+                                .map(|ring| ring.geodesic_length())
+                                .sum::<f64>()
+                    })
                     .sum())
             }
 
             (GeodesicLengthMethod::Vincenty, Geometry::MultiPolygon(mutli_polygon)) => {
                 let result: std::result::Result<Vec<f64>, _> = mutli_polygon
                     .iter()
-                    .map(|poly| poly.exterior().vincenty_length())
+                    .map(|poly| {
+                        let exterior = poly.exterior().vincenty_length()?;
+                        let interiors: std::result::Result<Vec<f64>, _> = poly
+                            .interiors()
+                            .iter()
+                            .map(|ring| ring.vincenty_length())
+                            .collect();
+                        interiors.map(|ints| exterior + ints.iter().sum::<f64>())
+                    })
                     .collect();
                 result.map(|v| v.iter().sum()).map_err(map_vincenty_error)
             }
@@ -155,7 +377,7 @@ fn geodesic_length_wkb(series: &Series, method: GeodesicLengthMethod) -> Result<
     }
 
     let result: PrimitiveArray<f64> = result.into();
-    let series = Series::try_from(("result", Box::new(result) as Box<dyn Array>))?;
+    let series = Series::try_from(("geometry", Box::new(result) as Box<dyn Array>))?;
     Ok(series)
 }
 
@@ -163,7 +385,7 @@ fn geodesic_length_wkb(series: &Series, method: GeodesicLengthMethod) -> Result<
 mod tests {
     use super::GeodesicLengthMethod;
     use crate::geoseries::GeoSeries;
-    use geo::{line_string, Geometry, LineString};
+    use geo::{line_string, Geometry, GeometryCollection, LineString};
     use geozero::{CoordDimensions, ToWkb};
     use polars::export::arrow::array::{Array, BinaryArray, MutableBinaryArray};
     use polars::prelude::Series;
@@ -195,6 +417,32 @@ mod tests {
         assert_eq!(10.0_f64, as_vec[0]);
     }
 
+    #[test]
+    fn euclidean_length_geometry_collection() {
+        let mut test_data = MutableBinaryArray::<i32>::with_capacity(1);
+
+        let line_string = line_string![
+            (x: 1., y: 1.),
+            (x: 11., y: 1.),
+        ];
+        let nested = Geometry::GeometryCollection(GeometryCollection(vec![Geometry::LineString(
+            line_string,
+        )]));
+        let collection: Geometry<f64> = Geometry::GeometryCollection(GeometryCollection(vec![nested]));
+
+        let test_wkb = collection.to_wkb(CoordDimensions::xy()).unwrap();
+        test_data.push(Some(test_wkb));
+
+        let test_array: BinaryArray<i32> = test_data.into();
+
+        let series =
+            Series::try_from(("geometry", Box::new(test_array) as Box<dyn Array>)).unwrap();
+        let lengths = series.euclidean_length().unwrap();
+        let as_vec: Vec<f64> = lengths.f64().unwrap().into_no_null_iter().collect();
+
+        assert_eq!(10.0_f64, as_vec[0]);
+    }
+
     #[test]
     fn haversine_length() {
         let mut test_data = MutableBinaryArray::<i32>::with_capacity(1);
@@ -1,31 +1,83 @@
-use crate::error::{inner_type_name, GeopolarsError, Result};
-use crate::util::iter_geom;
-use geo::{Geometry, Point};
+use crate::error::Result;
+use crate::geoarrow::point::array::PointSeries;
+use crate::util::{get_geoarrow_type, GeoArrowType};
+use geozero::error::Result as GeozeroResult;
+use geozero::wkb::Wkb;
+use geozero::{GeomProcessor, GeozeroGeometry};
 use polars::export::arrow::array::{Array, MutablePrimitiveArray, PrimitiveArray};
 use polars::prelude::Series;
 
 pub(crate) fn x(series: &Series) -> Result<Series> {
-    x_wkb(series)
+    match get_geoarrow_type(series) {
+        GeoArrowType::Point => x_geoarrow_point(series),
+        _ => x_wkb(series),
+    }
 }
 
 pub(crate) fn y(series: &Series) -> Result<Series> {
-    y_wkb(series)
+    match get_geoarrow_type(series) {
+        GeoArrowType::Point => y_geoarrow_point(series),
+        _ => y_wkb(series),
+    }
+}
+
+/// Return the `z` ordinate of point geometries in a GeoSeries, or null where a slot isn't a
+/// single `Point` or doesn't carry a third ordinate.
+///
+/// Unlike `x`/`y`, this has no native GeoArrow fast path: the in-crate `PointArray` backing
+/// `GeoArrowType::Point` is always 2D, so a native-Point column has no `z` buffer to read and
+/// every slot is null.
+pub(crate) fn z(series: &Series) -> Result<Series> {
+    match get_geoarrow_type(series) {
+        GeoArrowType::Point => {
+            let result = MutablePrimitiveArray::<f64>::from(vec![None; series.len()]);
+            let result: PrimitiveArray<f64> = result.into();
+            let series = Series::try_from(("result", Box::new(result) as Box<dyn Array>))?;
+            Ok(series)
+        }
+        _ => z_wkb(series),
+    }
+}
+
+/// Reads the `x` coordinate buffer of a native GeoArrow Point column directly, without
+/// materializing a [`Point`] per row.
+fn x_geoarrow_point(series: &Series) -> Result<Series> {
+    let mut result = MutablePrimitiveArray::<f64>::with_capacity(series.len());
+
+    for chunk in PointSeries(series).chunks() {
+        let parts = chunk.parts();
+        for i in 0..parts.len() {
+            result.push(parts.get_as_geo(i).map(|point| point.x()));
+        }
+    }
+
+    let result: PrimitiveArray<f64> = result.into();
+    let series = Series::try_from(("result", Box::new(result) as Box<dyn Array>))?;
+    Ok(series)
+}
+
+/// Reads the `y` coordinate buffer of a native GeoArrow Point column directly, without
+/// materializing a [`Point`] per row.
+fn y_geoarrow_point(series: &Series) -> Result<Series> {
+    let mut result = MutablePrimitiveArray::<f64>::with_capacity(series.len());
+
+    for chunk in PointSeries(series).chunks() {
+        let parts = chunk.parts();
+        for i in 0..parts.len() {
+            result.push(parts.get_as_geo(i).map(|point| point.y()));
+        }
+    }
+
+    let result: PrimitiveArray<f64> = result.into();
+    let series = Series::try_from(("result", Box::new(result) as Box<dyn Array>))?;
+    Ok(series)
 }
 
 fn x_wkb(series: &Series) -> Result<Series> {
     let mut result = MutablePrimitiveArray::<f64>::with_capacity(series.len());
 
-    for geom in iter_geom(series) {
-        let point: Point<f64> = match geom {
-            Geometry::Point(point) => point,
-            geom => {
-                return Err(GeopolarsError::MismatchedGeometry {
-                    expected: "Point",
-                    found: inner_type_name(&geom),
-                })
-            }
-        };
-        result.push(Some(point.x()));
+    for ordinates in iter_point_ordinates(series) {
+        result.push(ordinates.map(|o| o.x));
     }
 
     let result: PrimitiveArray<f64> = result.into();
@@ -36,17 +88,8 @@ fn x_wkb(series: &Series) -> Result<Series> {
 fn y_wkb(series: &Series) -> Result<Series> {
     let mut result = MutablePrimitiveArray::<f64>::with_capacity(series.len());
 
-    for geom in iter_geom(series) {
-        let point: Point<f64> = match geom {
-            Geometry::Point(point) => point,
-            geom => {
-                return Err(GeopolarsError::MismatchedGeometry {
-                    expected: "Point",
-                    found: inner_type_name(&geom),
-                })
-            }
-        };
-        result.push(Some(point.y()));
+    for ordinates in iter_point_ordinates(series) {
+        result.push(ordinates.map(|o| o.y));
     }
 
     let result: PrimitiveArray<f64> = result.into();
@@ -54,88 +97,78 @@ fn y_wkb(series: &Series) -> Result<Series> {
     Ok(series)
 }
 
+fn z_wkb(series: &Series) -> Result<Series> {
+    let mut result = MutablePrimitiveArray::<f64>::with_capacity(series.len());
+
+    for ordinates in iter_point_ordinates(series) {
+        result.push(ordinates.and_then(|o| o.z));
+    }
+
+    let result: PrimitiveArray<f64> = result.into();
+    let series = Series::try_from(("result", Box::new(result) as Box<dyn Array>))?;
+    Ok(series)
+}
+
+/// The ordinates of a single `Point` WKB geometry, as seen by [`PointOrdinateProcessor`].
+struct PointOrdinates {
+    x: f64,
+    y: f64,
+    z: Option<f64>,
+}
+
+/// A [`GeomProcessor`] that reads the ordinates of a standalone WKB `Point`, leaving `z` as
+/// `None` when the point has no third ordinate.
+///
+/// geozero only invokes `point_begin`/`point_end` for a top-level `Point` geometry (a `Point`
+/// nested in a `MultiPoint` or other collection streams its coordinate directly, with no
+/// `point_begin` wrapper), so `saw_point` distinguishes "this row is a lone Point" from anything
+/// else, matching how GeoPandas yields `NaN`/null for non-point rows rather than erroring.
+#[derive(Default)]
+struct PointOrdinateProcessor {
+    saw_point: bool,
+    ordinates: Option<PointOrdinates>,
+}
+
+impl GeomProcessor for PointOrdinateProcessor {
+    fn xy(&mut self, x: f64, y: f64, _idx: usize) -> GeozeroResult<()> {
+        self.ordinates = Some(PointOrdinates { x, y, z: None });
+        Ok(())
+    }
+
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        _m: Option<f64>,
+        _t: Option<f64>,
+        _tm: Option<u64>,
+        _idx: usize,
+    ) -> GeozeroResult<()> {
+        self.ordinates = Some(PointOrdinates { x, y, z });
+        Ok(())
+    }
 
-    //     let mut result = MutablePrimitiveArray::<f64>::with_capacity(self.len());
-
-    //     match get_geoarrow_type(self) {
-    //         GeoArrowType::Point => {
-    //             for chunk in self.chunks().iter() {
-    //                 let struct_chunk = chunk.as_any().downcast_ref::<StructArray>().unwrap();
-    //                 let x_array = struct_chunk.values()[0]
-    //                     .as_any()
-    //                     .downcast_ref::<PrimitiveArray<f64>>()
-    //                     .unwrap();
-    //                 for x in x_array {
-    //                     result.push(x.cloned())
-    //                 }
-    //             }
-    //         }
-    //         GeoArrowType::WKB => {
-    //             for geom in iter_geom(self) {
-    //                 let point: Point<f64> = match geom {
-    //                     Geometry::Point(point) => point,
-    //                     geom => {
-    //                         return Err(GeopolarsError::MismatchedGeometry {
-    //                             expected: "Point",
-    //                             found: inner_type_name(&geom),
-    //                         })
-    //                     }
-    //                 };
-    //                 result.push(Some(point.x()));
-    //             }
-    //         }
-    //         _ => {
-    //             return Err(GeopolarsError::MismatchedGeometry {
-    //                 expected: "Point",
-    //                 found: "todo",
-    //             })
-    //         }
-    //     }
-
-    //     let result: PrimitiveArray<f64> = result.into();
-    //     let series = Series::try_from(("result", Box::new(result) as ArrayRef))?;
-    //     Ok(series)
-    // }
-
-    // fn y(&self) -> Result<Series> {
-    //     let mut result = MutablePrimitiveArray::<f64>::with_capacity(self.len());
-
-    //     match get_geoarrow_type(self) {
-    //         GeoArrowType::Point => {
-    //             for chunk in self.chunks().iter() {
-    //                 let struct_chunk = chunk.as_any().downcast_ref::<StructArray>().unwrap();
-    //                 let x_array = struct_chunk.values()[1]
-    //                     .as_any()
-    //                     .downcast_ref::<PrimitiveArray<f64>>()
-    //                     .unwrap();
-    //                 for x in x_array {
-    //                     result.push(x.cloned())
-    //                 }
-    //             }
-    //         }
-    //         GeoArrowType::WKB => {
-    //             for geom in iter_geom(self) {
-    //                 let point: Point<f64> = match geom {
-    //                     Geometry::Point(point) => point,
-    //                     geom => {
-    //                         return Err(GeopolarsError::MismatchedGeometry {
-    //                             expected: "Point",
-    //                             found: inner_type_name(&geom),
-    //                         })
-    //                     }
-    //                 };
-    //                 result.push(Some(point.x()));
-    //             }
-    //         }
-    //         _ => {
-    //             return Err(GeopolarsError::MismatchedGeometry {
-    //                 expected: "Point",
-    //                 found: "todo",
-    //             })
-    //         }
-    //     }
-
-    //     let result: PrimitiveArray<f64> = result.into();
-    //     let series = Series::try_from(("result", Box::new(result) as ArrayRef))?;
-    //     Ok(series)
-    // }
+    fn point_begin(&mut self, _idx: usize) -> GeozeroResult<()> {
+        self.saw_point = true;
+        Ok(())
+    }
+}
+
+/// Iterates the ordinates of each WKB row, yielding `None` for rows that are null or aren't a
+/// single `Point` (e.g. `MultiPoint`, `LineString`, ...), instead of erroring out the whole
+/// series.
+fn iter_point_ordinates(series: &Series) -> impl Iterator<Item = Option<PointOrdinates>> + '_ {
+    let chunks = series.binary().expect("series was not a binary type");
+
+    chunks.into_iter().map(|row| {
+        let wkb = row?;
+        let mut processor = PointOrdinateProcessor::default();
+        Wkb(wkb.to_vec()).process_geom(&mut processor).ok()?;
+        if processor.saw_point {
+            processor.ordinates
+        } else {
+            None
+        }
+    })
+}
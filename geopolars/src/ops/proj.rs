@@ -1,7 +1,10 @@
-use crate::error::Result;
+use crate::error::{GeopolarsError, Result};
 use crate::util::from_geom_vec;
 use crate::util::iter_geom;
 use geo::Geometry;
+use geozero::wkb::Ewkb;
+use geozero::{CoordDimensions, ToGeo, ToWkb};
+use polars::export::arrow::array::{Array, BinaryArray, MutableBinaryArray};
 use polars::prelude::Series;
 use proj::Transform;
 use std::path::PathBuf;
@@ -68,3 +71,112 @@ fn to_crs_with_options_wkb(
 
     from_geom_vec(&output_vec?)
 }
+
+/// Extended WKB's SRID flag lives in the top bit of the geometry-type `u32`
+/// (following the byte-order marker); plain WKB from [`geozero::ToWkb::to_wkb`] never sets it.
+const EWKB_SRID_FLAG: u32 = 0x2000_0000;
+
+/// Reads an EWKB geometry's optional SRID straight out of its header, without fully decoding the
+/// geometry: byte 0 is the byte-order marker, bytes 1..5 are the geometry-type `u32` (whose top
+/// bit, [`EWKB_SRID_FLAG`], marks that an SRID follows), and bytes 5..9 are that SRID if present.
+fn read_ewkb_srid(bytes: &[u8]) -> Result<Option<u32>> {
+    let little_endian = match bytes.first() {
+        Some(0) => false,
+        Some(1) => true,
+        Some(other) => {
+            return Err(GeopolarsError::EwkbParseError(format!(
+                "Unknown EWKB byte order marker: {other}"
+            )))
+        }
+        None => return Err(GeopolarsError::EwkbParseError("EWKB buffer is empty".to_string())),
+    };
+
+    let geom_type_bytes: [u8; 4] = bytes.get(1..5).and_then(|s| s.try_into().ok()).ok_or_else(
+        || GeopolarsError::EwkbParseError("EWKB buffer too short for geometry type".to_string()),
+    )?;
+    let geom_type = if little_endian {
+        u32::from_le_bytes(geom_type_bytes)
+    } else {
+        u32::from_be_bytes(geom_type_bytes)
+    };
+
+    if geom_type & EWKB_SRID_FLAG == 0 {
+        return Ok(None);
+    }
+
+    let srid_bytes: [u8; 4] = bytes
+        .get(5..9)
+        .and_then(|s| s.try_into().ok())
+        .ok_or_else(|| GeopolarsError::EwkbParseError("EWKB buffer too short for SRID".to_string()))?;
+    Ok(Some(if little_endian {
+        u32::from_le_bytes(srid_bytes)
+    } else {
+        u32::from_be_bytes(srid_bytes)
+    }))
+}
+
+/// Parses an EPSG code out of a `"EPSG:<code>"` (case-insensitive) CRS string, for embedding back
+/// into the output EWKB's SRID. Any other `to` string (a `PROJ` pipeline, a WKT definition, ...)
+/// still reprojects correctly, it just leaves the output SRID unset, since there's no EPSG code to
+/// recover from it.
+fn parse_epsg_code(crs: &str) -> Option<i32> {
+    crs.strip_prefix("EPSG:")
+        .or_else(|| crs.strip_prefix("epsg:"))
+        .and_then(|code| code.parse::<i32>().ok())
+}
+
+fn from_geom_vec_as_ewkb(geoms: &[Geometry], srid: Option<i32>) -> Result<Series> {
+    let mut wkb_array = MutableBinaryArray::<i32>::with_capacity(geoms.len());
+
+    for geom in geoms {
+        let ewkb = geom
+            .to_ewkb(CoordDimensions::xy(), srid)
+            .map_err(|err| GeopolarsError::EwkbParseError(err.to_string()))?;
+        wkb_array.push(Some(ewkb));
+    }
+    let array: BinaryArray<i32> = wkb_array.into();
+
+    Ok(Series::try_from((
+        "geometry",
+        Box::new(array) as Box<dyn Array>,
+    ))?)
+}
+
+/// Reprojects an EWKB-encoded `series` to `to`, reading each geometry's source CRS from its own
+/// embedded SRID instead of requiring a `from` CRS string up front, and re-embeds the *target*
+/// SRID (when `to` is itself an `"EPSG:<code>"` string) into the output EWKB so a later
+/// `to_crs_from_ewkb` call downstream can do the same.
+///
+/// # Arguments
+///
+/// * `series` - A `Series` of EWKB-encoded geometries (e.g. a PostGIS `ST_AsEWKB` dump).
+/// * `to` - The target CRS, as an EPSG code (e.g. `"EPSG:3857"`) or any other string accepted by
+///   `PROJ`.
+pub(crate) fn to_crs_from_ewkb(series: &Series, to: &str, proj_options: ProjOptions) -> Result<Series> {
+    let chunks = series.binary()?;
+    let to_srid = parse_epsg_code(to);
+
+    let output_vec: Result<Vec<Geometry>> = chunks
+        .into_iter()
+        .map(|row| {
+            let bytes = row
+                .ok_or_else(|| GeopolarsError::EwkbParseError("row is null".to_string()))?;
+            let srid = read_ewkb_srid(bytes)?.ok_or_else(|| {
+                GeopolarsError::EwkbParseError("EWKB geometry has no SRID".to_string())
+            })?;
+            let from = format!("EPSG:{srid}");
+
+            let mut geom: Geometry = Ewkb(bytes.to_vec())
+                .to_geo()
+                .map_err(|err| GeopolarsError::EwkbParseError(err.to_string()))?;
+
+            let proj = proj_options
+                .to_proj_builder()?
+                .proj_known_crs(&from, to, None)?;
+            geom.transform(&proj)?;
+            Ok(geom)
+        })
+        .collect();
+
+    from_geom_vec_as_ewkb(&output_vec?, to_srid)
+}
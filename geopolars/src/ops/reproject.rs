@@ -0,0 +1,183 @@
+use crate::error::{GeopolarsError, Result};
+use crate::util::{get_geoarrow_type, GeoArrowType};
+use geodesy::{Coor4D, CoordinateSet, Direction, Minimal};
+use polars::export::arrow::array::{Array, ListArray, PrimitiveArray, StructArray};
+use polars::prelude::Series;
+
+/// Unlike [`crate::ops::centroid`] and [`crate::ops::bounds`], this deliberately does not walk
+/// `series` through [`crate::util::iter_linestrings`]/[`crate::util::iter_polygons`]: those yield
+/// one borrowed coordinate slice per geometry, but a geodesy pipeline is cheapest applied once over
+/// the *entire* flat coordinate buffer via [`CoordinateSet`] (see `apply_pipeline` below), so
+/// reprojection never needs a per-geometry view in the first place.
+pub(crate) fn reproject(series: &Series, definition: &str, direction: Direction) -> Result<Series> {
+    match get_geoarrow_type(series) {
+        GeoArrowType::Point => reproject_point(series, definition, direction),
+        GeoArrowType::LineString => reproject_linestring(series, definition, direction),
+        GeoArrowType::Polygon => reproject_polygon(series, definition, direction),
+        GeoArrowType::MultiPolygon | GeoArrowType::WKB => todo!(),
+    }
+}
+
+/// Adapts a GeoArrow column's separated `x`/`y` coordinate buffers into the [`CoordinateSet`]
+/// geodesy's operators expect, so a reprojection pipeline runs once over the whole column's flat
+/// coordinate buffer instead of once per geometry. `geom_offsets`/`ring_offsets`/`validity`
+/// describe how coordinates are grouped into geometries, not the coordinates themselves, so they
+/// never pass through this adapter: callers copy them over unchanged once the coordinates come
+/// back transformed.
+struct CoordinateSliceSet<'a> {
+    x: &'a mut [f64],
+    y: &'a mut [f64],
+}
+
+impl<'a> CoordinateSet for CoordinateSliceSet<'a> {
+    fn len(&self) -> usize {
+        self.x.len()
+    }
+
+    fn get_coord(&self, index: usize) -> Coor4D {
+        Coor4D::raw(self.x[index], self.y[index], 0.0, f64::NAN)
+    }
+
+    fn set_coord(&mut self, index: usize, value: &Coor4D) {
+        self.x[index] = value[0];
+        self.y[index] = value[1];
+    }
+}
+
+/// Builds `definition` as a geodesy operation and applies it to `x`/`y` in place, in a single
+/// pass over both slices.
+pub(crate) fn apply_pipeline(
+    definition: &str,
+    direction: Direction,
+    x: &mut [f64],
+    y: &mut [f64],
+) -> Result<()> {
+    let mut context = Minimal::default();
+    let op = context
+        .op(definition)
+        .map_err(|err| GeopolarsError::GeodesyError(err.to_string()))?;
+
+    let mut coords = CoordinateSliceSet { x, y };
+    context
+        .apply(op, direction, &mut coords)
+        .map_err(|err| GeopolarsError::GeodesyError(err.to_string()))?;
+    Ok(())
+}
+
+fn coords_from_struct(struct_array: &StructArray) -> (Vec<f64>, Vec<f64>) {
+    let x_array = struct_array.values()[0]
+        .as_any()
+        .downcast_ref::<PrimitiveArray<f64>>()
+        .unwrap();
+    let y_array = struct_array.values()[1]
+        .as_any()
+        .downcast_ref::<PrimitiveArray<f64>>()
+        .unwrap();
+    (x_array.values().to_vec(), y_array.values().to_vec())
+}
+
+fn struct_with_new_coords(struct_array: &StructArray, x: Vec<f64>, y: Vec<f64>) -> StructArray {
+    let x_array = struct_array.values()[0]
+        .as_any()
+        .downcast_ref::<PrimitiveArray<f64>>()
+        .unwrap();
+    let y_array = struct_array.values()[1]
+        .as_any()
+        .downcast_ref::<PrimitiveArray<f64>>()
+        .unwrap();
+
+    StructArray::new(
+        struct_array.data_type().clone(),
+        vec![
+            PrimitiveArray::new(x_array.data_type().clone(), x.into(), None).boxed(),
+            PrimitiveArray::new(y_array.data_type().clone(), y.into(), None).boxed(),
+        ],
+        struct_array.validity().cloned(),
+    )
+}
+
+fn reproject_point(series: &Series, definition: &str, direction: Direction) -> Result<Series> {
+    let struct_array = series.chunks()[0]
+        .as_any()
+        .downcast_ref::<StructArray>()
+        .unwrap();
+
+    let (mut x, mut y) = coords_from_struct(struct_array);
+    apply_pipeline(definition, direction, &mut x, &mut y)?;
+    let reprojected_struct = struct_with_new_coords(struct_array, x, y);
+
+    let series = Series::try_from(("geometry", Box::new(reprojected_struct) as Box<dyn Array>))?;
+    Ok(series)
+}
+
+/// Reprojects a GeoArrow LineString column's flat coordinate buffer in a single bulk pass,
+/// copying `geom_offsets`/`validity` verbatim: a reprojection only ever rewrites coordinate
+/// values, so the offsets that say which points belong to which `LineString` never change.
+fn reproject_linestring(series: &Series, definition: &str, direction: Direction) -> Result<Series> {
+    let list_array = series.chunks()[0]
+        .as_any()
+        .downcast_ref::<ListArray<i64>>()
+        .unwrap();
+    let struct_array = list_array
+        .values()
+        .as_any()
+        .downcast_ref::<StructArray>()
+        .unwrap();
+
+    let (mut x, mut y) = coords_from_struct(struct_array);
+    apply_pipeline(definition, direction, &mut x, &mut y)?;
+    let reprojected_struct = struct_with_new_coords(struct_array, x, y);
+
+    let reprojected_list = ListArray::<i64>::new(
+        list_array.data_type().clone(),
+        list_array.offsets().clone(),
+        reprojected_struct.boxed(),
+        list_array.validity().cloned(),
+    );
+
+    let series = Series::try_from(("geometry", Box::new(reprojected_list) as Box<dyn Array>))?;
+    Ok(series)
+}
+
+/// Reprojects a GeoArrow Polygon column's flat coordinate buffer in a single bulk pass, copying
+/// both the `geom_offsets` (polygon -> rings) and `ring_offsets` (ring -> points) verbatim, for
+/// the same reason [`reproject_linestring`] copies its `geom_offsets`.
+fn reproject_polygon(series: &Series, definition: &str, direction: Direction) -> Result<Series> {
+    let polygon_list = series.chunks()[0]
+        .as_any()
+        .downcast_ref::<ListArray<i64>>()
+        .unwrap();
+    let ring_list = polygon_list
+        .values()
+        .as_any()
+        .downcast_ref::<ListArray<i64>>()
+        .unwrap();
+    let struct_array = ring_list
+        .values()
+        .as_any()
+        .downcast_ref::<StructArray>()
+        .unwrap();
+
+    let (mut x, mut y) = coords_from_struct(struct_array);
+    apply_pipeline(definition, direction, &mut x, &mut y)?;
+    let reprojected_struct = struct_with_new_coords(struct_array, x, y);
+
+    let reprojected_ring_list = ListArray::<i64>::new(
+        ring_list.data_type().clone(),
+        ring_list.offsets().clone(),
+        reprojected_struct.boxed(),
+        ring_list.validity().cloned(),
+    );
+    let reprojected_polygon_list = ListArray::<i64>::new(
+        polygon_list.data_type().clone(),
+        polygon_list.offsets().clone(),
+        reprojected_ring_list.boxed(),
+        polygon_list.validity().cloned(),
+    );
+
+    let series = Series::try_from((
+        "geometry",
+        Box::new(reprojected_polygon_list) as Box<dyn Array>,
+    ))?;
+    Ok(series)
+}
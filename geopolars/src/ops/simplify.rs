@@ -3,11 +3,13 @@ use crate::geoarrow::linestring::array::LineStringSeries;
 use crate::geoarrow::linestring::mutable::MutableLineStringArray;
 use crate::geoarrow::polygon::array::PolygonSeries;
 use crate::geoarrow::polygon::mutable::MutablePolygonArray;
-use crate::util::{get_geoarrow_type, iter_geom, GeoArrowType};
+use crate::util::{get_geoarrow_type, series_to_wkb_array, wkb_array_to_series, GeoArrowType};
 use geo::algorithm::simplify::Simplify;
-use geo::{Geometry, LineString, Polygon};
-use geozero::{CoordDimensions, ToWkb};
-use polars::export::arrow::array::{Array, BinaryArray, MutableBinaryArray};
+use geo::{Geometry, GeometryCollection, LineString, MultiPolygon, Polygon};
+use geopolars_arrow::{
+    GeometryArrayTrait, MultiPolygonArray, MutableMultiPolygonArray, MutableWKBArray,
+};
+use polars::export::arrow::array::Array;
 use polars::prelude::Series;
 
 pub(crate) fn simplify(series: &Series, tolerance: f64) -> Result<Series> {
@@ -16,34 +18,39 @@ pub(crate) fn simplify(series: &Series, tolerance: f64) -> Result<Series> {
         GeoArrowType::Point => Ok(series.clone()),
         GeoArrowType::LineString => simplify_geoarrow_linestring(series, tolerance),
         GeoArrowType::Polygon => simplify_geoarrow_polygon(series, tolerance),
+        GeoArrowType::MultiPolygon => simplify_geoarrow_multipolygon(series, tolerance),
     }
 }
 
 fn simplify_wkb(series: &Series, tolerance: f64) -> Result<Series> {
-    let mut output_array = MutableBinaryArray::<i32>::with_capacity(series.len());
-
-    for geom in iter_geom(series) {
-        let value = match geom {
-            Geometry::Point(g) => Geometry::Point(g),
-            Geometry::MultiPoint(g) => Geometry::MultiPoint(g),
-            Geometry::LineString(g) => Geometry::LineString(g.simplify(&tolerance)),
-            Geometry::MultiLineString(g) => Geometry::MultiLineString(g.simplify(&tolerance)),
-            Geometry::Polygon(g) => Geometry::Polygon(g.simplify(&tolerance)),
-            Geometry::MultiPolygon(g) => Geometry::MultiPolygon(g.simplify(&tolerance)),
-            _ => unimplemented!(),
-        };
-
-        let wkb = value
-            .to_wkb(CoordDimensions::xy())
-            .expect("Unable to create wkb");
-
-        output_array.push(Some(wkb));
-    }
+    let wkb_array = series_to_wkb_array(series);
 
-    let result: BinaryArray<i32> = output_array.into();
+    let output_geoms: Vec<Option<Geometry>> = wkb_array
+        .iter_geo()
+        .map(|maybe_geom| maybe_geom.map(|geom| simplify_geometry(geom, tolerance)))
+        .collect();
 
-    let series = Series::try_from(("geometry", Box::new(result) as Box<dyn Array>))?;
-    Ok(series)
+    let mut_wkb_array: MutableWKBArray = output_geoms.into();
+    wkb_array_to_series(mut_wkb_array.into())
+}
+
+/// Simplifies a single geometry, recursing into each member of a `GeometryCollection` so that
+/// collections are simplified in place rather than rejected outright.
+fn simplify_geometry(geom: Geometry, tolerance: f64) -> Geometry {
+    match geom {
+        Geometry::Point(g) => Geometry::Point(g),
+        Geometry::MultiPoint(g) => Geometry::MultiPoint(g),
+        Geometry::LineString(g) => Geometry::LineString(g.simplify(&tolerance)),
+        Geometry::MultiLineString(g) => Geometry::MultiLineString(g.simplify(&tolerance)),
+        Geometry::Polygon(g) => Geometry::Polygon(g.simplify(&tolerance)),
+        Geometry::MultiPolygon(g) => Geometry::MultiPolygon(g.simplify(&tolerance)),
+        Geometry::GeometryCollection(g) => Geometry::GeometryCollection(GeometryCollection(
+            g.into_iter()
+                .map(|member| simplify_geometry(member, tolerance))
+                .collect(),
+        )),
+        _ => unimplemented!(),
+    }
 }
 
 fn simplify_geoarrow_linestring(series: &Series, tolerance: f64) -> Result<Series> {
@@ -82,6 +89,22 @@ fn simplify_geoarrow_polygon(series: &Series, tolerance: f64) -> Result<Series>
     Ok(series)
 }
 
+fn simplify_geoarrow_multipolygon(series: &Series, tolerance: f64) -> Result<Series> {
+    let multipolygon_array: MultiPolygonArray = series.chunks()[0].clone().try_into().unwrap();
+
+    let output_geoms: Vec<Option<MultiPolygon>> = multipolygon_array
+        .iter_geo()
+        .map(|maybe_mp| maybe_mp.map(|mp| mp.simplify(&tolerance)))
+        .collect();
+
+    let mut_multipolygon_arr: MutableMultiPolygonArray = output_geoms.into();
+    let series = Series::try_from((
+        "geometry",
+        Box::new(mut_multipolygon_arr.into_arrow()) as Box<dyn Array>,
+    ))?;
+    Ok(series)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::geoarrow::linestring::array::LineStringSeries;
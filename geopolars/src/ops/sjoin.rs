@@ -0,0 +1,114 @@
+use crate::error::{GeopolarsError, Result};
+use crate::spatial_index::{spatial_join_pairs, SpatialJoinArgs};
+use crate::util::{u64_index_series, Predicate};
+use polars::error::ErrString;
+use polars::prelude::{PolarsError, Series};
+
+/// Spatially joins `left` against `right` on `predicate`, returning the matched
+/// `(left_index, right_index)` pairs as two `u64` index `Series`, in the shape a polars
+/// `DataFrame::join` (via `join_asof`/explicit index columns) expects.
+///
+/// Both series must be WKB-backed `geometry` columns. Delegates to
+/// [`crate::spatial_index::spatial_join_pairs`], the same R-tree-backed candidate-then-exact-
+/// predicate core [`crate::spatial_index::spatial_join`] uses for whole-`DataFrame` joins, rather
+/// than building a second index over the same geometries.
+pub(crate) fn sjoin(left: &Series, right: &Series, predicate: &str) -> Result<(Series, Series)> {
+    let (predicate, max_distance) = parse_predicate(predicate)?;
+
+    let options = SpatialJoinArgs {
+        predicate,
+        max_distance,
+        ..Default::default()
+    };
+
+    let pairs = spatial_join_pairs(left, right, &options)?;
+    let (left_indices, right_indices): (Vec<u32>, Vec<u32>) = pairs
+        .into_iter()
+        .map(|(l, r)| (l as u32, r as u32))
+        .unzip();
+
+    Ok((
+        u64_index_series("left_index", &left_indices),
+        u64_index_series("right_index", &right_indices),
+    ))
+}
+
+/// Parses a predicate name into a [`Predicate`], plus, for `dwithin`, the distance to pair with
+/// [`SpatialJoinArgs::max_distance`].
+///
+/// Accepts `"intersects"`, `"contains"`, `"within"`, and `"dwithin(<distance>)"` (e.g.
+/// `"dwithin(0.5)"`), matching the predicate names GeoPandas' own `sjoin`/`sjoin_nearest` accept.
+fn parse_predicate(predicate: &str) -> Result<(Predicate, Option<f64>)> {
+    let predicate = predicate.trim();
+
+    if let Some(distance) = predicate
+        .strip_prefix("dwithin(")
+        .and_then(|rest| rest.strip_suffix(')'))
+    {
+        let distance: f64 = distance.trim().parse().map_err(|_| {
+            GeopolarsError::PolarsError(Box::new(PolarsError::ComputeError(ErrString::from(
+                format!("Invalid distance in dwithin predicate: {distance}"),
+            ))))
+        })?;
+        return Ok((Predicate::DWithin, Some(distance)));
+    }
+
+    match predicate {
+        "intersects" => Ok((Predicate::Intersects, None)),
+        "contains" => Ok((Predicate::Contains, None)),
+        "within" => Ok((Predicate::Within, None)),
+        other => Err(GeopolarsError::PolarsError(Box::new(
+            PolarsError::ComputeError(ErrString::from(format!(
+                "Unknown spatial join predicate: {other}"
+            ))),
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::sjoin;
+    use crate::util::from_geom_vec;
+    use geo::{polygon, Geometry, Point};
+
+    #[test]
+    fn sjoin_intersects_points_in_polygon() {
+        let points = from_geom_vec(&[
+            Geometry::Point(Point::new(1.0, 1.0)),
+            Geometry::Point(Point::new(100.0, 100.0)),
+        ])
+        .unwrap();
+
+        let polygons = from_geom_vec(&[Geometry::Polygon(polygon![
+            (x: 0.0, y: 0.0),
+            (x: 10.0, y: 0.0),
+            (x: 10.0, y: 10.0),
+            (x: 0.0, y: 10.0),
+        ])])
+        .unwrap();
+
+        let (left, right) = sjoin(&points, &polygons, "intersects").unwrap();
+        assert_eq!(left.len(), 1);
+        assert_eq!(right.len(), 1);
+        assert_eq!(left.u64().unwrap().get(0), Some(0));
+        assert_eq!(right.u64().unwrap().get(0), Some(0));
+    }
+
+    #[test]
+    fn sjoin_dwithin_parses_distance() {
+        let points = from_geom_vec(&[Geometry::Point(Point::new(0.0, 0.0))]).unwrap();
+        let other = from_geom_vec(&[Geometry::Point(Point::new(1.0, 0.0))]).unwrap();
+
+        let (left, _right) = sjoin(&points, &other, "dwithin(2.0)").unwrap();
+        assert_eq!(left.len(), 1);
+
+        let (left, _right) = sjoin(&points, &other, "dwithin(0.5)").unwrap();
+        assert_eq!(left.len(), 0);
+    }
+
+    #[test]
+    fn sjoin_rejects_unknown_predicate() {
+        let points = from_geom_vec(&[Geometry::Point(Point::new(0.0, 0.0))]).unwrap();
+        assert!(sjoin(&points, &points, "touches").is_err());
+    }
+}
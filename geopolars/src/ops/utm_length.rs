@@ -0,0 +1,104 @@
+use crate::error::Result;
+use crate::ops::reproject::apply_pipeline;
+use crate::util::iter_geom;
+use geo::algorithm::centroid::Centroid;
+use geo::algorithm::euclidean_length::EuclideanLength;
+use geo::algorithm::map_coords::MapCoords;
+use geo::{CoordsIter, Geometry};
+use geodesy::Direction;
+use polars::error::ErrString;
+use polars::export::arrow::array::{Array, MutablePrimitiveArray, PrimitiveArray};
+use polars::prelude::{PolarsError, Series};
+
+/// Computes the WGS84 UTM zone number (1-60) implied by a representative longitude, following the
+/// standard `floor((lon + 180) / 6) + 1` rule.
+fn utm_zone_number(lon: f64) -> i32 {
+    (((lon + 180.0) / 6.0).floor() as i32 + 1).clamp(1, 60)
+}
+
+/// Returns the EPSG code of the UTM zone a `(lon, lat)` coordinate falls into: `326xx` in the
+/// northern hemisphere, `327xx` in the southern.
+fn utm_epsg(lon: f64, lat: f64) -> i32 {
+    let hemisphere_base = if lat >= 0.0 { 32600 } else { 32700 };
+    hemisphere_base + utm_zone_number(lon)
+}
+
+/// Reprojects every coordinate of `geom` from WGS84 into UTM `zone`, in a single pass over its
+/// flattened coordinate buffer, mirroring [`crate::ops::reproject::reproject`]'s bulk-pipeline
+/// approach rather than invoking `geodesy` once per coordinate.
+///
+/// A `south`/`+south` flag is deliberately never passed to the `utm` pipeline: it only shifts
+/// northings by a constant 10,000,000 m false-northing offset, which cancels out of any length
+/// computed from the reprojected geometry. The zone's hemisphere still matters for the EPSG code
+/// `utm_length` exposes to the caller, just not for the reprojected coordinates themselves.
+fn reproject_to_utm(geom: Geometry, zone: i32) -> Result<Geometry> {
+    let mut x: Vec<f64> = geom.coords_iter().map(|c| c.x).collect();
+    let mut y: Vec<f64> = geom.coords_iter().map(|c| c.y).collect();
+
+    apply_pipeline(&format!("utm zone={zone}"), Direction::Fwd, &mut x, &mut y)?;
+
+    let mut next = 0;
+    let reprojected = geom.map_coords(|_| {
+        let c = geo::Coord {
+            x: x[next],
+            y: y[next],
+        };
+        next += 1;
+        c
+    });
+    Ok(reprojected)
+}
+
+/// Computes each geometry's length after reprojecting it from WGS84 into the UTM zone implied by
+/// its centroid, returning both the length and the EPSG code of the zone chosen for that row.
+///
+/// This is a much faster alternative to [`crate::ops::length::geodesic_length`] for local/regional
+/// data, since it trades per-segment geodesic trigonometry for a single cheap planar
+/// (transverse-Mercator) reprojection followed by a euclidean length. Unlike the geodesic methods,
+/// it isn't aware of geometries spanning more than one UTM zone: the returned `zone` column lets a
+/// caller detect that case in their own data (every zone's EPSG code should match, for a geometry
+/// that stays local) and fall back to `geodesic_length` for rows that don't.
+pub(crate) fn utm_length(series: &Series) -> Result<(Series, Series)> {
+    let mut lengths = MutablePrimitiveArray::<f64>::with_capacity(series.len());
+    let mut zones = MutablePrimitiveArray::<i32>::with_capacity(series.len());
+
+    for geom in iter_geom(series) {
+        let representative = geom.centroid().ok_or_else(|| {
+            PolarsError::ComputeError(ErrString::from(
+                "Cannot compute a UTM zone for an empty geometry",
+            ))
+        })?;
+        let epsg = utm_epsg(representative.x(), representative.y());
+        let reprojected = reproject_to_utm(geom, epsg % 100)?;
+
+        let length = match reprojected {
+            Geometry::Point(_) => 0.0,
+            Geometry::Line(line) => line.euclidean_length(),
+            Geometry::LineString(line_string) => line_string.euclidean_length(),
+            Geometry::Polygon(polygon) => polygon.exterior().euclidean_length(),
+            Geometry::MultiPoint(_) => 0.0,
+            Geometry::MultiLineString(multi_line_string) => multi_line_string.euclidean_length(),
+            Geometry::MultiPolygon(multi_polygon) => multi_polygon
+                .iter()
+                .map(|poly| poly.exterior().euclidean_length())
+                .sum(),
+            Geometry::GeometryCollection(_) => {
+                return Err(PolarsError::ComputeError(ErrString::from(
+                    "Length methods are not implemented for geometry collection",
+                ))
+                .into())
+            }
+            Geometry::Rect(rec) => rec.to_polygon().exterior().euclidean_length(),
+            Geometry::Triangle(triangle) => triangle.to_polygon().exterior().euclidean_length(),
+        };
+
+        lengths.push(Some(length));
+        zones.push(Some(epsg));
+    }
+
+    let lengths: PrimitiveArray<f64> = lengths.into();
+    let zones: PrimitiveArray<i32> = zones.into();
+    let lengths = Series::try_from(("geometry", Box::new(lengths) as Box<dyn Array>))?;
+    let zones = Series::try_from(("utm_zone", Box::new(zones) as Box<dyn Array>))?;
+    Ok((lengths, zones))
+}
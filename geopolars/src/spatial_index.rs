@@ -1,12 +1,14 @@
 use std::sync::Arc;
 
 use geo::{
-    prelude::BoundingRect, Geometry, Line, LineString, MultiLineString, MultiPoint, MultiPolygon,
-    Point, Polygon,
+    prelude::BoundingRect, Centroid, EuclideanDistance, Geometry, Line, LineString,
+    MultiLineString, MultiPoint, MultiPolygon, Point, Polygon,
 };
 use polars::error::ErrString;
 use polars::prelude::{DataFrame, JoinType, NamedFrom, PolarsError, PolarsResult, Series};
-use rstar::{RTree, RTreeObject, AABB};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use rstar::{Envelope, PointDistance, RTree, RTreeObject, AABB};
 
 use crate::util::{geom_at_index, iter_geom, Predicate};
 
@@ -17,6 +19,20 @@ pub struct SpatialJoinArgs<'a> {
     pub r_suffix: Option<&'a str>,
     pub l_index: Option<Arc<SpatialIndex>>,
     pub r_index: Option<Arc<SpatialIndex>>,
+    /// When set, matches pairs whose geometries are within this distance of each other instead
+    /// of requiring them to overlap, per [`Predicate::DWithin`]. Candidate generation expands
+    /// each left node's envelope by this amount before querying the right tree, so nearby (but
+    /// non-intersecting) pairs the plain envelope-intersection pass would otherwise drop are
+    /// still considered.
+    pub max_distance: Option<f64>,
+    /// A [`PreparedSpatialIndex`] over `lhs`'s geometries. When set and `predicate` is
+    /// [`Predicate::Contains`] with a point on the right, point-in-polygon checks reuse the
+    /// cached edge R-tree for the left polygon instead of re-walking its rings for every
+    /// candidate pair that shares it.
+    pub l_prepared: Option<Arc<PreparedSpatialIndex>>,
+    /// The mirror of `l_prepared` for [`Predicate::Within`], where the containing polygon is on
+    /// the right instead of the left.
+    pub r_prepared: Option<Arc<PreparedSpatialIndex>>,
 }
 
 impl<'a> Default for SpatialJoinArgs<'a> {
@@ -28,22 +44,73 @@ impl<'a> Default for SpatialJoinArgs<'a> {
             r_suffix: Some("_right"),
             l_index: None,
             r_index: None,
+            max_distance: None,
+            l_prepared: None,
+            r_prepared: None,
         }
     }
 }
 
-pub fn spatial_join(
-    lhs: &DataFrame,
-    rhs: &DataFrame,
-    options: SpatialJoinArgs,
-) -> PolarsResult<DataFrame> {
-    use geo::algorithm::{contains::Contains, intersects::Intersects};
+/// Resolves whether `(lhs_index, rhs_index)` is an actual hit, preferring a cached prepared
+/// accelerator (see [`PreparedSpatialIndex`]) over [`confirm_predicate`]'s general DE-9IM path
+/// when one is available for this pair's predicate and geometry shapes.
+fn evaluate_hit(
+    lhs_index: usize,
+    lhs_geom: &Geometry<f64>,
+    rhs_index: usize,
+    rhs_geom: &Geometry<f64>,
+    options: &SpatialJoinArgs,
+) -> bool {
+    if let Some(max_distance) = options.max_distance {
+        return lhs_geom.euclidean_distance(rhs_geom) <= max_distance;
+    }
 
-    let lhs_geometry = lhs.column("geometry")?;
-    let rhs_geometry = rhs.column("geometry")?;
+    if let (Predicate::Contains, Some(prepared), Geometry::Point(point)) =
+        (&options.predicate, &options.l_prepared, rhs_geom)
+    {
+        if let Some(hit) = prepared.contains_point(lhs_index, point) {
+            return hit;
+        }
+    }
+
+    if let (Predicate::Within, Some(prepared), Geometry::Point(point)) =
+        (&options.predicate, &options.r_prepared, lhs_geom)
+    {
+        if let Some(hit) = prepared.contains_point(rhs_index, point) {
+            return hit;
+        }
+    }
+
+    confirm_predicate(lhs_geom, rhs_geom, &options.predicate)
+}
 
+/// Expands an `AABB` by `max_distance` in every direction, so querying the expanded envelope for
+/// intersections can't miss a geometry that lies within `max_distance` of the original envelope
+/// but doesn't overlap it.
+fn expand_envelope(envelope: &AABB<[f64; 2]>, max_distance: f64) -> AABB<[f64; 2]> {
+    let lower = envelope.lower();
+    let upper = envelope.upper();
+    AABB::from_corners(
+        [lower[0] - max_distance, lower[1] - max_distance],
+        [upper[0] + max_distance, upper[1] + max_distance],
+    )
+}
+
+/// The candidate-generation-then-exact-confirmation core of [`spatial_join`]: builds (or reuses)
+/// an R-tree over each side, prunes candidate pairs by envelope overlap, and confirms each one
+/// against `options.predicate` (or `options.max_distance`), returning the matched
+/// `(lhs_index, rhs_index)` pairs.
+///
+/// Factored out so callers that want raw index pairs rather than a joined `DataFrame` - e.g.
+/// [`crate::ops::sjoin::sjoin`] - can reuse the exact same R-tree/predicate logic
+/// [`spatial_join`] uses instead of rebuilding it.
+pub(crate) fn spatial_join_pairs(
+    lhs_geometry: &Series,
+    rhs_geometry: &Series,
+    options: &SpatialJoinArgs,
+) -> PolarsResult<Vec<(usize, usize)>> {
     // If we where not given a left index, generate one on the fly
-    let spatial_index_left: Arc<SpatialIndex> = options.l_index.unwrap_or_else(|| {
+    let spatial_index_left: Arc<SpatialIndex> = options.l_index.clone().unwrap_or_else(|| {
         let spatial_index_left: SpatialIndex = lhs_geometry
             .try_into()
             .map_err(|_| {
@@ -56,7 +123,7 @@ pub fn spatial_join(
     });
 
     // If we where not given a right index, generate one on the fly
-    let spatial_index_right: Arc<SpatialIndex> = options.r_index.unwrap_or_else(|| {
+    let spatial_index_right: Arc<SpatialIndex> = options.r_index.clone().unwrap_or_else(|| {
         let spatial_index_right: SpatialIndex = rhs_geometry
             .try_into()
             .map_err(|_| {
@@ -68,77 +135,79 @@ pub fn spatial_join(
         Arc::new(spatial_index_right)
     });
 
-    // Use the r-tree to generate potential overlaps between the two geometry sets
-    let potential_overlaps = spatial_index_left
-        .r_tree
-        .intersection_candidates_with_other_tree(&spatial_index_right.r_tree);
-
-    let mut left_series: Vec<usize> = vec![];
-    let mut right_series: Vec<usize> = vec![];
-
-    // Explicitly check which of the potential overlaps actually hit using the
-    // provided geometry check
-    for intersection in potential_overlaps {
-        let (lhs_node, rhs_node) = intersection;
+    // Use the r-tree to generate potential overlaps between the two geometry sets. With a
+    // `max_distance`, a plain envelope intersection would drop pairs that are near but don't
+    // overlap, so each left node's envelope is grown by `max_distance` before querying instead.
+    let potential_overlaps: Vec<(&TreeNode, &TreeNode)> = match options.max_distance {
+        Some(max_distance) => spatial_index_left
+            .r_tree
+            .iter()
+            .flat_map(|lhs_node| {
+                let expanded = expand_envelope(&lhs_node.envelope(), max_distance);
+                spatial_index_right
+                    .r_tree
+                    .locate_in_envelope_intersecting(&expanded)
+                    .map(move |rhs_node| (lhs_node, rhs_node))
+            })
+            .collect(),
+        None => spatial_index_left
+            .r_tree
+            .intersection_candidates_with_other_tree(&spatial_index_right.r_tree)
+            .collect(),
+    };
 
-        let lhs_geom = geom_at_index(lhs_geometry, lhs_node.index)?;
-        let rhs_geom = geom_at_index(rhs_geometry, rhs_node.index)?;
+    // Explicitly check which of the potential overlaps actually hit using the provided geometry
+    // check. `geom_at_index` only ever reads from `lhs_geometry`/`rhs_geometry`'s Arrow buffers,
+    // so under the `parallel` feature this runs across threads via rayon; the index pairs are
+    // sorted afterwards so the join below stays deterministic regardless of thread scheduling.
+    #[cfg(feature = "parallel")]
+    let hits: Vec<(usize, usize)> = {
+        let mut hits: Vec<(usize, usize)> = potential_overlaps
+            .par_iter()
+            .filter_map(|(lhs_node, rhs_node)| {
+                let lhs_geom = geom_at_index(lhs_geometry, lhs_node.index).ok()?;
+                let rhs_geom = geom_at_index(rhs_geometry, rhs_node.index).ok()?;
+
+                let actual_hit =
+                    evaluate_hit(lhs_node.index, &lhs_geom, rhs_node.index, &rhs_geom, options);
+
+                actual_hit.then_some((lhs_node.index, rhs_node.index))
+            })
+            .collect();
+        hits.sort_unstable();
+        hits
+    };
 
-        let actual_hit = match (&lhs_geom, &rhs_geom, &options.predicate) {
-            // Points and Polygons
-            (Geometry::Point(point), Geometry::Polygon(poly), _) => poly.contains(point),
-            (Geometry::Polygon(poly), Geometry::Point(point), _) => poly.contains(point),
+    #[cfg(not(feature = "parallel"))]
+    let hits: Vec<(usize, usize)> = {
+        let mut hits = vec![];
+        for (lhs_node, rhs_node) in potential_overlaps {
+            let lhs_geom = geom_at_index(lhs_geometry, lhs_node.index)?;
+            let rhs_geom = geom_at_index(rhs_geometry, rhs_node.index)?;
 
-            // Points and MultiPolygons
-            (Geometry::Point(point), Geometry::MultiPolygon(poly), _) => poly.contains(point),
-            (Geometry::MultiPolygon(poly), Geometry::Point(point), _) => poly.contains(point),
+            let actual_hit =
+                evaluate_hit(lhs_node.index, &lhs_geom, rhs_node.index, &rhs_geom, options);
 
-            // Polygon and Polygon
-            (Geometry::Polygon(poly_lhs), Geometry::Polygon(poly_rhs), Predicate::Contains) => {
-                poly_lhs.contains(poly_rhs)
-            }
-            (Geometry::Polygon(poly_lhs), Geometry::Polygon(poly_rhs), Predicate::Intersects) => {
-                poly_lhs.intersects(poly_rhs)
+            if actual_hit {
+                hits.push((lhs_node.index, rhs_node.index));
             }
+        }
+        hits
+    };
 
-            // Multi Polygon and Polygon
-            (
-                Geometry::MultiPolygon(poly_lhs),
-                Geometry::Polygon(poly_rhs),
-                Predicate::Contains,
-            ) => poly_lhs.contains(poly_rhs),
-            (
-                Geometry::MultiPolygon(poly_lhs),
-                Geometry::Polygon(poly_rhs),
-                Predicate::Intersects,
-            ) => poly_lhs.intersects(poly_rhs),
-
-            // Polygon and MultiPolygon
-            (
-                Geometry::Polygon(poly_lhs),
-                Geometry::MultiPolygon(poly_rhs),
-                Predicate::Intersects,
-            ) => poly_lhs.intersects(poly_rhs),
-
-            // Line and Point
-            (Geometry::Line(line), Geometry::Point(point), _) => line.contains(point),
-            (Geometry::Point(point), Geometry::Line(line), _) => line.contains(point),
-
-            // LineString and Point
-            (Geometry::LineString(line), Geometry::Point(point), _) => line.contains(point),
-            (Geometry::Point(point), Geometry::LineString(line), _) => line.contains(point),
-
-            // MultiLineString and Point
-            (Geometry::MultiLineString(line), Geometry::Point(point), _) => line.contains(point),
-            (Geometry::Point(point), Geometry::MultiLineString(line), _) => line.contains(point),
-            _ => false,
-        };
+    Ok(hits)
+}
 
-        if actual_hit {
-            left_series.push(lhs_node.index);
-            right_series.push(rhs_node.index);
-        }
-    }
+pub fn spatial_join(
+    lhs: &DataFrame,
+    rhs: &DataFrame,
+    options: SpatialJoinArgs,
+) -> PolarsResult<DataFrame> {
+    let lhs_geometry = lhs.column("geometry")?;
+    let rhs_geometry = rhs.column("geometry")?;
+
+    let hits = spatial_join_pairs(lhs_geometry, rhs_geometry, &options)?;
+    let (left_series, right_series): (Vec<usize>, Vec<usize>) = hits.into_iter().unzip();
 
     // Now we have two vecs with the alligned left right node indexes we perform a
     // join using polars existing code.
@@ -197,7 +266,143 @@ pub fn spatial_join(
     }
 }
 
-#[derive(Debug)]
+/// How many extra candidates [`knn_join`] pulls per `k` requested, to absorb the gap between
+/// [`RTree::nearest_neighbor_iter_with_distance_2`]'s envelope-distance ordering and the exact
+/// geometry distance it's re-sorted by afterwards.
+const KNN_OVERSAMPLE: usize = 4;
+
+/// For each of `lhs`'s geometries, finds its `k` nearest geometries in `rhs`, returning an inner
+/// join of the two dataframes plus two extra columns: `nn_rank` (0-based rank among the `k`
+/// matches for that left row) and `distance` (the exact `geo::EuclideanDistance` between the
+/// pair). If `max_distance` is set, matches farther than it are dropped, so a left row can end up
+/// with fewer than `k` matches.
+///
+/// Candidates come from the right tree's [`RTree::nearest_neighbor_iter_with_distance_2`],
+/// queried against the left geometry's centroid; that orders by distance from the centroid to
+/// each node's envelope, which is a lower bound on the true geometry-to-geometry distance rather
+/// than the distance itself. To correct for that, this pulls `k * KNN_OVERSAMPLE` candidates,
+/// recomputes the exact distance for each, re-sorts by it, and truncates to `k` — the same
+/// lower-bound-then-refine shape [`spatial_join`] uses its envelope index for.
+pub fn knn_join(
+    lhs: &DataFrame,
+    rhs: &DataFrame,
+    k: usize,
+    max_distance: Option<f64>,
+) -> PolarsResult<DataFrame> {
+    let lhs_geometry = lhs.column("geometry")?;
+    let rhs_geometry = rhs.column("geometry")?;
+
+    let spatial_index_right: SpatialIndex = rhs_geometry.try_into().map_err(|_| {
+        PolarsError::ComputeError(ErrString::from(
+            "Failed to generate the spatial index for the right dataframe",
+        ))
+    })?;
+
+    let mut lhs_join: Vec<u64> = vec![];
+    let mut rhs_join: Vec<u64> = vec![];
+    let mut nn_rank: Vec<u32> = vec![];
+    let mut distance: Vec<f64> = vec![];
+
+    for (index, lhs_geom) in iter_geom(lhs_geometry).enumerate() {
+        let query_point: [f64; 2] = lhs_geom
+            .centroid()
+            .map(|p| [p.x(), p.y()])
+            .unwrap_or([0.0, 0.0]);
+
+        let mut candidates: Vec<usize> = vec![];
+        for (node, envelope_distance_2) in spatial_index_right
+            .r_tree
+            .nearest_neighbor_iter_with_distance_2(&query_point)
+        {
+            if let Some(max_distance) = max_distance {
+                if envelope_distance_2.sqrt() > max_distance {
+                    break;
+                }
+            }
+            candidates.push(node.index);
+            if candidates.len() >= k * KNN_OVERSAMPLE.max(1) {
+                break;
+            }
+        }
+
+        let mut exact: Vec<(usize, f64)> = candidates
+            .into_iter()
+            .map(|rhs_index| {
+                let rhs_geom = geom_at_index(rhs_geometry, rhs_index)?;
+                Ok((rhs_index, lhs_geom.euclidean_distance(&rhs_geom)))
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        exact.sort_by(|a, b| a.1.total_cmp(&b.1));
+        if let Some(max_distance) = max_distance {
+            exact.retain(|(_, dist)| *dist <= max_distance);
+        }
+        exact.truncate(k);
+
+        for (rank, (rhs_index, dist)) in exact.into_iter().enumerate() {
+            lhs_join.push(index as u64);
+            rhs_join.push(rhs_index as u64);
+            nn_rank.push(rank as u32);
+            distance.push(dist);
+        }
+    }
+
+    let lhs_index: Vec<u64> = (0..lhs.shape().0).map(|i| i as u64).collect();
+    let rhs_index: Vec<u64> = (0..rhs.shape().0).map(|i| i as u64).collect();
+    let lhs_index = Series::new("lhs_index", lhs_index);
+    let rhs_index = Series::new("rhs_index", rhs_index);
+
+    let lhs_join = Series::new("lhs_join", lhs_join);
+    let rhs_join = Series::new("rhs_join", rhs_join);
+    let nn_rank = Series::new("nn_rank", nn_rank);
+    let distance = Series::new("distance", distance);
+
+    let join_df = DataFrame::new(vec![lhs_join, rhs_join, nn_rank, distance])?;
+
+    let lhs_with_index = lhs.hstack(&[lhs_index])?;
+    let rhs_with_index = rhs.hstack(&[rhs_index])?;
+
+    let join_one = lhs_with_index.inner_join(&join_df, ["lhs_index"], ["lhs_join"])?;
+    let join_two = join_one.inner_join(&rhs_with_index, ["rhs_join"], ["rhs_index"])?;
+    let result = join_two.drop("lhs_index")?.drop("rhs_join")?;
+    Ok(result)
+}
+
+/// Confirms a candidate pair surfaced by the envelope index against the exact geometries, since
+/// the index alone can only rule pairs out, not confirm them.
+///
+/// Every predicate is answered from a single [`Relate::relate`] call rather than a hand-written
+/// per-geometry-type match: the DE-9IM [`IntersectionMatrix`] it returns already knows how to
+/// answer `is_contains`/`is_within`/etc for any pair of geometry types, including combinations
+/// (LineString vs Polygon, MultiPoint vs anything, …) the old exhaustive table silently dropped.
+fn confirm_predicate(
+    lhs_geom: &Geometry<f64>,
+    rhs_geom: &Geometry<f64>,
+    predicate: &Predicate,
+) -> bool {
+    use geo::{HasDimensions, Relate};
+
+    // `DWithin` is resolved by `options.max_distance` before `confirm_predicate` is ever called.
+    if matches!(predicate, Predicate::DWithin) {
+        return false;
+    }
+
+    let matrix = lhs_geom.relate(rhs_geom);
+    match predicate {
+        Predicate::Intersects => matrix.is_intersects(),
+        Predicate::Contains => matrix.is_contains(),
+        Predicate::Within => matrix.is_within(),
+        Predicate::Covers => matrix.is_covers(),
+        Predicate::CoveredBy => matrix.is_coveredby(),
+        Predicate::Touches => matrix.is_touches(lhs_geom.dimensions(), rhs_geom.dimensions()),
+        Predicate::Crosses => matrix.is_crosses(lhs_geom.dimensions(), rhs_geom.dimensions()),
+        Predicate::Overlaps => matrix.is_overlaps(lhs_geom.dimensions(), rhs_geom.dimensions()),
+        Predicate::Equals => matrix.is_equal_topo(),
+        Predicate::DWithin => unreachable!(),
+    }
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub enum NodeEnvelope {
     Point([f64; 2]),
     BBox([[f64; 2]; 2]),
@@ -269,7 +474,7 @@ impl From<Line<f64>> for NodeEnvelope {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub struct TreeNode {
     pub index: usize,
     pub envelope: NodeEnvelope,
@@ -286,6 +491,17 @@ impl RTreeObject for TreeNode {
     }
 }
 
+impl PointDistance for TreeNode {
+    /// The squared distance from `point` to this node's envelope, used by
+    /// [`RTree::nearest_neighbor_iter_with_distance_2`] in [`knn_join`]. This is a lower bound on
+    /// the true distance to the node's actual geometry (which the tree doesn't store), not the
+    /// exact distance — `knn_join` re-sorts its candidates by `geo`'s exact `EuclideanDistance`
+    /// before truncating to `k`.
+    fn distance_2(&self, point: &[f64; 2]) -> f64 {
+        self.envelope().distance_2(point)
+    }
+}
+
 impl TryFrom<Geometry<f64>> for NodeEnvelope {
     type Error = PolarsError;
     fn try_from(geom: Geometry<f64>) -> Result<Self, Self::Error> {
@@ -309,7 +525,58 @@ pub struct SpatialIndex {
     pub r_tree: RTree<TreeNode>,
 }
 
-impl SpatialIndex {}
+impl SpatialIndex {
+    /// Serializes `r_tree` via bincode, so a computed index can be written alongside a GeoParquet
+    /// file and reloaded on a later run instead of rebuilding it from the geometry column.
+    pub fn to_bytes(&self) -> PolarsResult<Vec<u8>> {
+        bincode::serialize(&self.r_tree).map_err(|err| {
+            PolarsError::ComputeError(ErrString::from(format!(
+                "Failed to serialize spatial index: {err}"
+            )))
+        })
+    }
+
+    /// Deserializes an index previously written by [`SpatialIndex::to_bytes`]. `len` is the row
+    /// count of the dataframe this index is meant to be applied to; every restored node's index
+    /// must fall within it, since a mismatched index would otherwise look up the wrong row (or
+    /// panic) once the index is used in [`spatial_join`].
+    pub fn from_bytes(bytes: &[u8], len: usize) -> PolarsResult<Self> {
+        let r_tree: RTree<TreeNode> = bincode::deserialize(bytes).map_err(|err| {
+            PolarsError::ComputeError(ErrString::from(format!(
+                "Failed to deserialize spatial index: {err}"
+            )))
+        })?;
+
+        if r_tree.iter().any(|node| node.index >= len) {
+            return Err(PolarsError::ComputeError(ErrString::from(
+                "Spatial index contains a node index out of bounds for this dataframe",
+            )));
+        }
+
+        Ok(SpatialIndex { r_tree })
+    }
+
+    /// Writes this index to `path` via [`SpatialIndex::to_bytes`].
+    pub fn save(&self, path: impl AsRef<std::path::Path>) -> PolarsResult<()> {
+        let bytes = self.to_bytes()?;
+        std::fs::write(path, bytes).map_err(|err| {
+            PolarsError::ComputeError(ErrString::from(format!(
+                "Failed to write spatial index: {err}"
+            )))
+        })
+    }
+
+    /// Reads an index previously written by [`SpatialIndex::save`], validating node indices
+    /// against `len` the same way [`SpatialIndex::from_bytes`] does.
+    pub fn load(path: impl AsRef<std::path::Path>, len: usize) -> PolarsResult<Self> {
+        let bytes = std::fs::read(path).map_err(|err| {
+            PolarsError::ComputeError(ErrString::from(format!(
+                "Failed to read spatial index: {err}"
+            )))
+        })?;
+        Self::from_bytes(&bytes, len)
+    }
+}
 
 impl<'a> TryFrom<&'a Series> for SpatialIndex {
     type Error = PolarsError;
@@ -343,6 +610,89 @@ impl TryFrom<Series> for SpatialIndex {
     }
 }
 
+/// A single boundary edge of a prepared polygon, stored in an [`RTree`] so a point-in-polygon
+/// test only visits the edges near its ray instead of every ring vertex.
+#[derive(Debug, Clone, Copy)]
+struct EdgeSegment(Line<f64>);
+
+impl RTreeObject for EdgeSegment {
+    type Envelope = AABB<[f64; 2]>;
+
+    fn envelope(&self) -> Self::Envelope {
+        AABB::from_corners(
+            [self.0.start.x, self.0.start.y],
+            [self.0.end.x, self.0.end.y],
+        )
+    }
+}
+
+/// A GEOS-`PreparedGeometry`-style accelerator, built over [`SpatialIndex`]'s geometries so
+/// `spatial_join` can test many candidate points against the same polygon without re-walking its
+/// rings for every candidate pair. This crate has no GEOS dependency (see `geopolars-geos` for
+/// the GEOS-backed equivalent), so the cached accelerator is an edge [`RTree`] per polygon
+/// instead of a true `PreparedGeometry`: a point-in-polygon test only visits the edges whose
+/// bounding box spans the test ray, rather than every ring vertex, which is the same saving
+/// `PreparedGeometry` buys for this access pattern.
+pub struct PreparedSpatialIndex {
+    pub index: Arc<SpatialIndex>,
+    edge_trees: std::collections::HashMap<usize, RTree<EdgeSegment>>,
+}
+
+impl PreparedSpatialIndex {
+    /// Builds an edge R-tree for every `Polygon`/`MultiPolygon` node in `index`, keyed by
+    /// [`TreeNode::index`]. `geometry` must be the same column `index` was built from.
+    pub fn new(index: Arc<SpatialIndex>, geometry: &Series) -> PolarsResult<Self> {
+        let mut edge_trees = std::collections::HashMap::new();
+        for node in index.r_tree.iter() {
+            let geom = geom_at_index(geometry, node.index)?;
+            if let Some(edges) = polygon_edges(&geom) {
+                edge_trees.insert(node.index, RTree::bulk_load(edges));
+            }
+        }
+        Ok(Self { index, edge_trees })
+    }
+
+    /// A crossing-number point-in-polygon test against the prepared geometry at `index`, using
+    /// only the edges whose bounding box spans a rightward ray from `point`. Returns `None` if
+    /// `index` has no prepared (non-polygonal) geometry.
+    pub fn contains_point(&self, index: usize, point: &Point<f64>) -> Option<bool> {
+        let edges = self.edge_trees.get(&index)?;
+        let ray = AABB::from_corners([point.x(), point.y()], [f64::INFINITY, point.y()]);
+        let crossings = edges
+            .locate_in_envelope_intersecting(&ray)
+            .filter(|edge| ray_crosses_edge(point, &edge.0))
+            .count();
+        Some(crossings % 2 == 1)
+    }
+}
+
+fn polygon_edges(geom: &Geometry<f64>) -> Option<Vec<EdgeSegment>> {
+    match geom {
+        Geometry::Polygon(poly) => Some(ring_edges(poly)),
+        Geometry::MultiPolygon(multi) => Some(multi.iter().flat_map(ring_edges).collect()),
+        _ => None,
+    }
+}
+
+fn ring_edges(poly: &Polygon<f64>) -> Vec<EdgeSegment> {
+    std::iter::once(poly.exterior())
+        .chain(poly.interiors())
+        .flat_map(|ring| ring.lines())
+        .map(EdgeSegment)
+        .collect()
+}
+
+/// Whether a rightward horizontal ray cast from `point` crosses `edge`, per the standard
+/// crossing-number point-in-polygon algorithm.
+fn ray_crosses_edge(point: &Point<f64>, edge: &Line<f64>) -> bool {
+    let (y0, y1) = (edge.start.y, edge.end.y);
+    if (y0 > point.y()) == (y1 > point.y()) {
+        return false;
+    }
+    let x_intersect = edge.start.x + (point.y() - y0) / (y1 - y0) * (edge.end.x - edge.start.x);
+    x_intersect > point.x()
+}
+
 #[cfg(test)]
 mod tests {
     use std::sync::Arc;
@@ -1,15 +1,27 @@
-use crate::error::Result;
+use crate::error::{GeopolarsError, Result};
 use geo::{Coord, Geometry, LineString, Point, Polygon};
 use geozero::{wkb::Wkb, ToGeo};
 use geozero::{CoordDimensions, ToWkb};
+use geopolars_arrow::offset::{narrow_offsets, widen_offsets};
+use geopolars_arrow::WKBArray;
 use polars::datatypes::{AnyValue, DataType};
 use polars::error::ErrString;
 use polars::export::arrow::array::{Array, BinaryArray, MutableBinaryArray};
 use polars::export::arrow::array::{ListArray, PrimitiveArray, StructArray};
+use polars::export::arrow::datatypes::DataType as ArrowDataType;
+use polars::export::arrow::offset::OffsetsBuffer;
 use polars::export::num;
 use polars::prelude::{PolarsError, PolarsResult, Series};
 use std::convert::Into;
 
+#[cfg(feature = "binary_view")]
+use geopolars_arrow::WKBViewArray;
+
+/// Always encodes WKB as [`CoordDimensions::xy`], and [`iter_geom`]/[`iter_geom_opt`] decode back
+/// into `geo::Geometry<f64>`, which has no `z`/`m` field to decode into. Round-tripping elevation
+/// or measure data through this WKB path isn't possible with the `geo` crate's types; callers that
+/// need it should build on `geopolars_arrow::PointArray`/`MutablePointArray` instead, which carry
+/// an explicit `z` buffer (see `MutablePointArray::z`).
 pub fn from_geom_vec(geoms: &[Geometry<f64>]) -> Result<Series> {
     let mut wkb_array = MutableBinaryArray::<i32>::with_capacity(geoms.len());
 
@@ -25,6 +37,30 @@ pub fn from_geom_vec(geoms: &[Geometry<f64>]) -> Result<Series> {
     Ok(series)
 }
 
+/// Like [`from_geom_vec`], but allows null rows for operations that can't produce a geometry for
+/// every input.
+pub fn from_geom_vec_opt(geoms: &[Option<Geometry<f64>>]) -> Result<Series> {
+    let mut wkb_array = MutableBinaryArray::<i32>::with_capacity(geoms.len());
+
+    for geom in geoms {
+        let wkb = geom
+            .as_ref()
+            .map(|g| {
+                g.to_wkb(CoordDimensions::xy()).map_err(|_| {
+                    PolarsError::ComputeError(ErrString::from(
+                        "Failed to convert geom vec to GeoSeries",
+                    ))
+                })
+            })
+            .transpose()?;
+        wkb_array.push(wkb);
+    }
+    let array: BinaryArray<i32> = wkb_array.into();
+
+    let series = Series::try_from(("geometry", Box::new(array) as Box<dyn Array>))?;
+    Ok(series)
+}
+
 /// Helper function to iterate over geometries from polars Series
 pub(crate) fn iter_geom(series: &Series) -> impl Iterator<Item = Geometry<f64>> + '_ {
     let chunks = series.binary().expect("series was not a list type");
@@ -38,6 +74,20 @@ pub(crate) fn iter_geom(series: &Series) -> impl Iterator<Item = Geometry<f64>>
     })
 }
 
+/// Like [`iter_geom`], but a null row comes back as `None` instead of panicking.
+pub(crate) fn iter_geom_opt(series: &Series) -> impl Iterator<Item = Option<Geometry<f64>>> + '_ {
+    let chunks = series.binary().expect("series was not a list type");
+
+    let iter = chunks.into_iter();
+    iter.map(|row| {
+        row.map(|value| {
+            Wkb(value.to_vec())
+                .to_geo()
+                .expect("unable to convert to geo")
+        })
+    })
+}
+
 /// Access to a geometry at a specified index
 pub fn geom_at_index(series: &Series, index: usize) -> PolarsResult<Geometry<f64>> {
     // let struct_type = DataType::Struct(vec![X_FIELD, Y_FIELD]);
@@ -54,6 +104,48 @@ pub fn geom_at_index(series: &Series, index: usize) -> PolarsResult<Geometry<f64
     }
 }
 
+/// The Arrow logical type backing a geometry `Series`, as distinguished by its physical
+/// GeoArrow layout rather than by decoding each geometry.
+///
+/// Following geoarrow2's `GeoDataType` approach, this is derived purely from `Series::dtype()`
+/// (the same dispatch as [`geom_at_index`]), so kernels can branch onto the native buffer layout
+/// in O(1) instead of always falling back to decoding every feature from WKB.
+pub enum GeoArrowType {
+    Point,
+    LineString,
+    Polygon,
+    /// A nested `List<List<List<Struct>>>` column, as produced by
+    /// [`geopolars_arrow::MultiPolygonArray::into_arrow`].
+    ///
+    /// `MultiPoint` and `MultiLineString` have no `GeoArrowType` variant of their own: this
+    /// crate's `geopolars_arrow` arrays encode them by reusing `LineString`'s and `Polygon`'s
+    /// exact Arrow layouts (see `MultiPointArray::into_arrow`/`MultiLineStringArray::into_arrow`),
+    /// so a bare `Series::dtype()` cannot distinguish a `MultiPoint` column from a `LineString`
+    /// one, or a `MultiLineString` column from a `Polygon` one, without GeoArrow extension-type
+    /// metadata that this crate doesn't carry. Only `MultiPolygon` is unambiguous, since it adds
+    /// a third level of nesting that a plain `Polygon` column never has.
+    MultiPolygon,
+    WKB,
+}
+
+/// Inspects the Arrow logical type of `series` and returns which [`GeoArrowType`] it's encoded
+/// as.
+pub(crate) fn get_geoarrow_type(series: &Series) -> GeoArrowType {
+    match series.dtype() {
+        DataType::Binary => GeoArrowType::WKB,
+        DataType::Struct(_) => GeoArrowType::Point,
+        DataType::List(dt) => match *dt.clone() {
+            DataType::Struct(_) => GeoArrowType::LineString,
+            DataType::List(inner_dt) => match *inner_dt.clone() {
+                DataType::List(_) => GeoArrowType::MultiPolygon,
+                _ => GeoArrowType::Polygon,
+            },
+            _ => unimplemented!(),
+        },
+        _ => unimplemented!(),
+    }
+}
+
 fn geom_at_index_wkb(series: &Series, index: usize) -> PolarsResult<Geometry<f64>> {
     let buffer = match series.get(index) {
         Ok(AnyValue::Binary(buf)) => buf,
@@ -178,7 +270,236 @@ fn geom_at_index_polygon(series: &Series, index: usize) -> PolarsResult<Geometry
     Ok(Geometry::Polygon(p))
 }
 
+/// A binary spatial predicate, evaluated via `geo`'s [`Relate`](geo::Relate) DE-9IM intersection
+/// matrix rather than a per-geometry-type match.
 pub enum Predicate {
     Intersects,
     Contains,
+    Within,
+    Covers,
+    CoveredBy,
+    Touches,
+    Crosses,
+    Overlaps,
+    Equals,
+    /// The left geometry is within [`SpatialJoinArgs::max_distance`](crate::spatial_index::SpatialJoinArgs::max_distance)
+    /// of the right geometry, rather than strictly overlapping it.
+    DWithin,
+}
+
+/// A borrowed view into a single row of a GeoArrow LineString column, as yielded by
+/// [`iter_linestrings`].
+pub(crate) struct LineStringRef<'a> {
+    pub xs: &'a [f64],
+    pub ys: &'a [f64],
+}
+
+/// A borrowed view into a single row of a GeoArrow Polygon column, as yielded by [`iter_polygons`].
+///
+/// `ring_offsets` delimits each ring's span within `xs`/`ys`, renumbered to start at 0 for this
+/// polygon's slice alone (rather than the column's overall `ring_offsets` buffer), matching what
+/// [`crate::ops::centroid`]'s ring-by-ring walk expects.
+pub(crate) struct PolygonRef<'a> {
+    pub xs: &'a [f64],
+    pub ys: &'a [f64],
+    pub ring_offsets: Vec<i64>,
+}
+
+/// Downcasts a GeoArrow coordinate `StructArray`'s two children to `x`/`y` `PrimitiveArray<f64>`s,
+/// surfacing a [`GeopolarsError::MismatchedGeometry`] instead of panicking if the buffer isn't
+/// shaped the way every GeoArrow array in this crate is built.
+fn xy_arrays(struct_array: &StructArray) -> Result<(&PrimitiveArray<f64>, &PrimitiveArray<f64>)> {
+    let values = struct_array.values();
+    let x = values[0]
+        .as_any()
+        .downcast_ref::<PrimitiveArray<f64>>()
+        .ok_or(GeopolarsError::MismatchedGeometry {
+            expected: "Float64 x coordinate array",
+            found: "a differently-typed Arrow array",
+        })?;
+    let y = values[1]
+        .as_any()
+        .downcast_ref::<PrimitiveArray<f64>>()
+        .ok_or(GeopolarsError::MismatchedGeometry {
+            expected: "Float64 y coordinate array",
+            found: "a differently-typed Arrow array",
+        })?;
+    Ok((x, y))
+}
+
+/// A validity-aware, borrowing iterator over a GeoArrow LineString column, yielding `None` for
+/// rows the column's validity bitmap marks null instead of an empty or garbage `LineStringRef`.
+///
+/// Every kernel that needs to walk a LineString column row by row (today: [`crate::ops::centroid`]
+/// and [`crate::ops::bounds`]) can share this traversal rather than re-downcasting the same
+/// `ListArray<i64>`/`StructArray`/`PrimitiveArray<f64>` chain and re-deriving offsets itself.
+pub(crate) fn iter_linestrings(
+    series: &Series,
+) -> Result<impl Iterator<Item = Option<LineStringRef<'_>>>> {
+    let list_array = series.chunks()[0]
+        .as_any()
+        .downcast_ref::<ListArray<i64>>()
+        .ok_or(GeopolarsError::MismatchedGeometry {
+            expected: "ListArray<i64>",
+            found: "a differently-typed Arrow array",
+        })?;
+    let struct_array = list_array
+        .values()
+        .as_any()
+        .downcast_ref::<StructArray>()
+        .ok_or(GeopolarsError::MismatchedGeometry {
+            expected: "StructArray",
+            found: "a differently-typed Arrow array",
+        })?;
+    let (x_array, y_array) = xy_arrays(struct_array)?;
+
+    let offsets = list_array.offsets().clone();
+    let validity = list_array.validity().cloned();
+
+    Ok((0..list_array.len()).map(move |row| {
+        if !validity.as_ref().map_or(true, |v| v.get_bit(row)) {
+            return None;
+        }
+        let (start, end) = offsets.start_end(row);
+        Some(LineStringRef {
+            xs: &x_array.values()[start..end],
+            ys: &y_array.values()[start..end],
+        })
+    }))
+}
+
+/// A validity-aware, borrowing iterator over a GeoArrow Polygon column, yielding `None` for rows
+/// the column's validity bitmap marks null instead of an empty or garbage `PolygonRef`.
+///
+/// See [`iter_linestrings`] for the motivation: this shares the same traversal across every kernel
+/// that needs to walk a Polygon column ring by ring.
+pub(crate) fn iter_polygons(
+    series: &Series,
+) -> Result<impl Iterator<Item = Option<PolygonRef<'_>>>> {
+    let polygon_list = series.chunks()[0]
+        .as_any()
+        .downcast_ref::<ListArray<i64>>()
+        .ok_or(GeopolarsError::MismatchedGeometry {
+            expected: "ListArray<i64>",
+            found: "a differently-typed Arrow array",
+        })?;
+    let ring_list = polygon_list
+        .values()
+        .as_any()
+        .downcast_ref::<ListArray<i64>>()
+        .ok_or(GeopolarsError::MismatchedGeometry {
+            expected: "nested ListArray<i64>",
+            found: "a differently-typed Arrow array",
+        })?;
+    let struct_array = ring_list
+        .values()
+        .as_any()
+        .downcast_ref::<StructArray>()
+        .ok_or(GeopolarsError::MismatchedGeometry {
+            expected: "StructArray",
+            found: "a differently-typed Arrow array",
+        })?;
+    let (x_array, y_array) = xy_arrays(struct_array)?;
+
+    let polygon_offsets = polygon_list.offsets().clone();
+    let ring_offsets = ring_list.offsets().clone();
+    let validity = polygon_list.validity().cloned();
+
+    Ok((0..polygon_list.len()).map(move |row| {
+        if !validity.as_ref().map_or(true, |v| v.get_bit(row)) {
+            return None;
+        }
+        let (ring_start, ring_end) = polygon_offsets.start_end(row);
+        let coord_start = ring_offsets.start_end(ring_start).0;
+        let coord_end = if ring_end > ring_start {
+            ring_offsets.start_end(ring_end - 1).1
+        } else {
+            coord_start
+        };
+        let local_ring_offsets = ring_offsets.as_slice()[ring_start..=ring_end]
+            .iter()
+            .map(|&o| o - coord_start as i64)
+            .collect();
+
+        Some(PolygonRef {
+            xs: &x_array.values()[coord_start..coord_end],
+            ys: &y_array.values()[coord_start..coord_end],
+            ring_offsets: local_ring_offsets,
+        })
+    }))
+}
+
+/// Converts `series`'s underlying `BinaryArray<i32>` chunk into a [`WKBArray`], which widens its
+/// offsets to `i64` to match the buffer layout [`WKBArray`] expects.
+pub(crate) fn series_to_wkb_array(series: &Series) -> WKBArray {
+    let chunk = series.chunks()[0]
+        .as_any()
+        .downcast_ref::<BinaryArray<i32>>()
+        .unwrap();
+
+    let widened_offsets = widen_offsets(chunk.offsets());
+    let array = BinaryArray::<i64>::new(
+        ArrowDataType::LargeBinary,
+        widened_offsets,
+        chunk.values().clone(),
+        chunk.validity().cloned(),
+    );
+
+    WKBArray::new(array)
+}
+
+/// Converts a [`WKBArray`] back into a `Series`, narrowing its `i64` offsets back to the `i32`
+/// offsets this crate's `GeoArrowType::WKB` series are stored as.
+pub(crate) fn wkb_array_to_series(array: WKBArray) -> Result<Series> {
+    let chunk = array.into_arrow();
+
+    let narrowed_offsets: OffsetsBuffer<i32> = narrow_offsets(chunk.offsets());
+    let result = BinaryArray::<i32>::new(
+        ArrowDataType::Binary,
+        narrowed_offsets,
+        chunk.values().clone(),
+        chunk.validity().cloned(),
+    );
+
+    let series = Series::try_from(("geometry", Box::new(result) as Box<dyn Array>))?;
+    Ok(series)
+}
+
+/// Converts `series`'s underlying `BinaryArray<i32>` chunk into a [`WKBViewArray`], going through
+/// [`series_to_wkb_array`] and [`WKBArray::to_binview`] rather than duplicating the
+/// widen-offsets-then-repack logic here.
+///
+/// `WKBViewArray`'s `BinaryView` layout inlines short values and stores long ones as a
+/// buffer-index-plus-offset, so dispatch code that only needs a row's geometry type (via
+/// [`WKBView::geometry_type_code`](geopolars_arrow::WKBView::geometry_type_code)) never has to
+/// chase the out-of-line values buffer the way a plain `BinaryArray` row read would.
+#[cfg(feature = "binary_view")]
+pub(crate) fn series_to_wkb_view_array(series: &Series) -> WKBViewArray {
+    series_to_wkb_array(series).to_binview()
+}
+
+/// Converts a [`WKBViewArray`] back into a `Series`, going through [`WKBArray::from_binview`] and
+/// [`wkb_array_to_series`] so the output keeps the same `i32`-offset `Binary` layout every other
+/// `GeoArrowType::WKB` series in this crate uses.
+#[cfg(feature = "binary_view")]
+pub(crate) fn wkb_view_array_to_series(array: &WKBViewArray) -> Result<Series> {
+    wkb_array_to_series(WKBArray::from_binview(array))
+}
+
+/// Like [`iter_geom`], but reads each row's geometry type code off the `BinaryView` layout's
+/// inlined prefix via [`WKBView::geometry_type_code`](geopolars_arrow::WKBView::geometry_type_code)
+/// instead of decoding the whole geometry, for dispatch code that only needs to branch on type.
+#[cfg(feature = "binary_view")]
+pub(crate) fn iter_geometry_type_codes(series: &Series) -> impl Iterator<Item = Option<u32>> {
+    let view_array = series_to_wkb_view_array(series);
+    (0..view_array.len())
+        .map(|i| view_array.get(i).map(|view| view.geometry_type_code()))
+        .collect::<Vec<_>>()
+        .into_iter()
+}
+
+/// Builds a `u64` index `Series`, as returned by spatial-join-style operations that hand back row
+/// indices for a caller to feed into a polars join.
+pub(crate) fn u64_index_series(name: &str, indices: &[u32]) -> Series {
+    Series::new(name, indices.iter().map(|&i| i as u64).collect::<Vec<_>>())
 }
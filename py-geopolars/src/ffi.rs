@@ -69,6 +69,45 @@ pub fn py_series_to_rust_series(series: &PyAny) -> PyResult<Series> {
     Series::try_from((name.as_str(), array)).map_err(|e| PyValueError::new_err(format!("{}", e)))
 }
 
+/// Imports a `Series` from Python via the Arrow C Stream interface, pulling one chunk at a time
+/// instead of forcing pyarrow to `rechunk()` into a single contiguous array first.
+///
+/// `stream` is anything pyarrow can export as a C Stream (e.g. a `pyarrow.ChunkedArray` or the
+/// single-column `RecordBatchReader` backing one); each batch it yields becomes one chunk of the
+/// returned `Series`, so a dataset too large to hold as one array never has to be consolidated
+/// just to cross the FFI boundary.
+pub fn py_stream_to_rust_series(name: &str, stream: &PyAny) -> PyResult<Series> {
+    // prepare a pointer to receive the ArrowArrayStream struct
+    let stream_struct = Box::new(ffi::ArrowArrayStream::empty());
+    let stream_ptr = Box::into_raw(stream_struct);
+
+    // make the conversion through PyArrow's private API
+    stream.call_method1("_export_to_c", (stream_ptr as Py_uintptr_t,))?;
+
+    let mut reader = unsafe {
+        ffi::ArrowArrayStreamReader::try_new(stream_ptr)
+            .map_err(|e| PyValueError::new_err(format!("{}", e)))?
+    };
+
+    let mut series: Option<Series> = None;
+    while let Some(chunk) = unsafe { reader.next() } {
+        let chunk = chunk.map_err(|e| PyValueError::new_err(format!("{}", e)))?;
+        let chunk_series = Series::try_from((name, chunk))
+            .map_err(|e| PyValueError::new_err(format!("{}", e)))?;
+
+        series = Some(match series {
+            None => chunk_series,
+            Some(mut acc) => {
+                acc.append(&chunk_series)
+                    .map_err(|e| PyValueError::new_err(format!("{}", e)))?;
+                acc
+            }
+        });
+    }
+
+    series.ok_or_else(|| PyValueError::new_err("empty Arrow C Stream"))
+}
+
 // Allow dead code because this function will be used later
 #[allow(dead_code)]
 pub fn rust_series_to_py_series(series: &Series) -> PyResult<PyObject> {
@@ -111,6 +150,31 @@ pub fn rust_series_to_py_geoseries(series: &Series) -> PyResult<PyObject> {
     })
 }
 
+/// Exports a (possibly multi-chunk) `Series` to Python via the Arrow C Stream interface, handing
+/// chunks over one at a time instead of the `rechunk()` + single-`ArrowArray` handoff
+/// `rust_series_to_py_series`/`rust_series_to_py_geoseries` both do.
+///
+/// Returns a `pyarrow.RecordBatchReader` rather than a `Series`/`ChunkedArray`, since that's the
+/// pyarrow object the C Stream interface (`_import_from_c`) is exposed on; the caller can collect
+/// it into a `Table`/`ChunkedArray` on the Python side without ever rechunking.
+pub fn rust_series_to_py_stream(series: &Series) -> PyResult<PyObject> {
+    let data_type = series.chunks()[0].data_type().clone();
+    let field = ArrowField::new(series.name(), data_type, true);
+    let chunks: Vec<ArrayRef> = series.chunks().iter().cloned().collect();
+    let iter = Box::new(chunks.into_iter().map(Ok));
+
+    let mut stream = unsafe { ffi::export_iterator(iter, field) };
+    let stream_ptr = &mut stream as *mut ffi::ArrowArrayStream;
+
+    Python::with_gil(|py| {
+        let pyarrow = py.import("pyarrow")?;
+        let reader = pyarrow
+            .getattr("RecordBatchReader")?
+            .call_method1("_import_from_c", (stream_ptr as Py_uintptr_t,))?;
+        Ok(reader.to_object(py))
+    })
+}
+
 #[cfg(feature = "proj")]
 pub fn proj_data_directory() -> PyResult<PathBuf> {
     use pyo3::exceptions::PyIOError;
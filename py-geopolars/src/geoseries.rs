@@ -149,6 +149,38 @@ pub(crate) fn distance(series: &PyAny, other: &PyAny) -> PyResult<PyObject> {
     ffi::rust_series_to_py_series(&out)
 }
 
+#[pyfunction]
+pub(crate) fn intersects(series: &PyAny, other: &PyAny) -> PyResult<PyObject> {
+    let series = ffi::py_series_to_rust_series(series)?;
+    let other = ffi::py_series_to_rust_series(other)?;
+    let out = series.intersects(&other).map_err(PyGeopolarsError::from)?;
+    ffi::rust_series_to_py_series(&out)
+}
+
+#[pyfunction]
+pub(crate) fn contains(series: &PyAny, other: &PyAny) -> PyResult<PyObject> {
+    let series = ffi::py_series_to_rust_series(series)?;
+    let other = ffi::py_series_to_rust_series(other)?;
+    let out = series.contains(&other).map_err(PyGeopolarsError::from)?;
+    ffi::rust_series_to_py_series(&out)
+}
+
+#[pyfunction]
+pub(crate) fn within(series: &PyAny, other: &PyAny) -> PyResult<PyObject> {
+    let series = ffi::py_series_to_rust_series(series)?;
+    let other = ffi::py_series_to_rust_series(other)?;
+    let out = series.within(&other).map_err(PyGeopolarsError::from)?;
+    ffi::rust_series_to_py_series(&out)
+}
+
+#[pyfunction]
+pub(crate) fn disjoint(series: &PyAny, other: &PyAny) -> PyResult<PyObject> {
+    let series = ffi::py_series_to_rust_series(series)?;
+    let other = ffi::py_series_to_rust_series(other)?;
+    let out = series.disjoint(&other).map_err(PyGeopolarsError::from)?;
+    ffi::rust_series_to_py_series(&out)
+}
+
 // #[pyfunction]
 // pub(crate) fn to_crs(series: &PyAny, from: &str, to: &str) -> PyResult<PyObject> {
 //     let series = ffi::py_series_to_rust_series(series)?;